@@ -12,11 +12,10 @@ use crate::OutputFormat;
 
 /// Execute cache stats command
 pub fn stats_command(cache_dir: Option<PathBuf>, format: OutputFormat) -> Result<()> {
-    let default_cache_dir = std::env::current_dir()
-        .context("Failed to get current directory")?
-        .join(".kreuzberg");
-
-    let cache_path = cache_dir.unwrap_or(default_cache_dir);
+    let cache_path = match cache_dir {
+        Some(dir) => dir,
+        None => cache::resolve_cache_dir().context("Failed to resolve cache directory")?,
+    };
     let cache_dir_str = cache_path.to_string_lossy();
 
     let stats = cache::get_cache_metadata(&cache_dir_str).with_context(|| {
@@ -58,11 +57,10 @@ pub fn stats_command(cache_dir: Option<PathBuf>, format: OutputFormat) -> Result
 
 /// Execute cache clear command
 pub fn clear_command(cache_dir: Option<PathBuf>, format: OutputFormat) -> Result<()> {
-    let default_cache_dir = std::env::current_dir()
-        .context("Failed to get current directory")?
-        .join(".kreuzberg");
-
-    let cache_path = cache_dir.unwrap_or(default_cache_dir);
+    let cache_path = match cache_dir {
+        Some(dir) => dir,
+        None => cache::resolve_cache_dir().context("Failed to resolve cache directory")?,
+    };
     let cache_dir_str = cache_path.to_string_lossy();
 
     let (removed_files, freed_mb) = cache::clear_cache_directory(&cache_dir_str).with_context(|| {
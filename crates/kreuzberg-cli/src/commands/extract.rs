@@ -112,9 +112,13 @@ pub fn apply_extraction_overrides(
                     _ => "eng".to_string(),
                 },
             };
-            // Preserve existing paddle_ocr_config and element_config from config file/inline JSON
+            // Preserve existing paddle_ocr_config, element_config, preprocessing, auto_rotate, fallback and table_detection from config file/inline JSON
             let existing_paddle_config = config.ocr.as_ref().and_then(|o| o.paddle_ocr_config.clone());
             let existing_element_config = config.ocr.as_ref().and_then(|o| o.element_config.clone());
+            let existing_preprocessing = config.ocr.as_ref().and_then(|o| o.preprocessing.clone());
+            let existing_auto_rotate = config.ocr.as_ref().is_some_and(|o| o.auto_rotate);
+            let existing_fallback = config.ocr.as_ref().and_then(|o| o.fallback.clone());
+            let existing_table_detection = config.ocr.as_ref().and_then(|o| o.table_detection.clone());
             config.ocr = Some(OcrConfig {
                 backend: backend.to_string(),
                 language,
@@ -122,6 +126,11 @@ pub fn apply_extraction_overrides(
                 output_format: None,
                 paddle_ocr_config: existing_paddle_config,
                 element_config: existing_element_config,
+                preprocessing: existing_preprocessing,
+                auto_rotate: existing_auto_rotate,
+                fallback: existing_fallback,
+                table_detection: existing_table_detection,
+                cache: None,
             });
         } else {
             config.ocr = None;
@@ -344,6 +353,11 @@ mod tests {
                 output_format: None,
                 paddle_ocr_config: None,
                 element_config: None,
+                preprocessing: None,
+                auto_rotate: false,
+                fallback: None,
+                table_detection: None,
+                cache: None,
             }),
             ..Default::default()
         };
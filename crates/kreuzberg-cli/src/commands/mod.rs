@@ -2,6 +2,7 @@
 //!
 //! This module organizes the CLI commands into focused submodules:
 //! - `extract` - Document extraction commands
+//! - `searchable` - Searchable PDF generation from scans
 //! - `cache` - Cache management operations
 //! - `server` - API and MCP server commands
 //! - `config` - Configuration loading and discovery
@@ -9,12 +10,14 @@
 pub mod cache;
 pub mod config;
 pub mod extract;
+pub mod searchable;
 pub mod server;
 
 // Re-export command functions for convenience
 pub use cache::{clear_command, stats_command};
 pub use config::load_config;
 pub use extract::{apply_extraction_overrides, batch_command, extract_command};
+pub use searchable::searchable_command;
 #[cfg(feature = "mcp")]
 pub use server::mcp_command;
 #[cfg(feature = "api")]
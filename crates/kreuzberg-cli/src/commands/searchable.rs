@@ -0,0 +1,38 @@
+//! Searchable command - Write scans as PDFs with an invisible OCR text layer
+//!
+//! This module provides the searchable command, which OCRs a scanned PDF or image
+//! and writes the original page images with the recognized text on top.
+
+use anyhow::{Context, Result};
+use kreuzberg::ExtractionConfig;
+use kreuzberg::detect_mime_type;
+use kreuzberg::pdf::make_searchable_pdf_sync;
+use std::path::PathBuf;
+
+/// Execute searchable PDF command
+pub fn searchable_command(
+    path: PathBuf,
+    output: PathBuf,
+    config: ExtractionConfig,
+    mime_type: Option<String>,
+) -> Result<()> {
+    let path_str = path.to_string_lossy().to_string();
+    let mime_type = match mime_type {
+        Some(mime_type) => mime_type,
+        None => detect_mime_type(&path_str, true)
+            .with_context(|| format!("Failed to detect MIME type for file '{}'.", path.display()))?,
+    };
+
+    let content = std::fs::read(&path).with_context(|| format!("Failed to read file '{}'", path.display()))?;
+    let pdf = make_searchable_pdf_sync(&content, &mime_type, &config).with_context(|| {
+        format!(
+            "Failed to make a searchable PDF from '{}'. Only scanned PDFs and images are supported.",
+            path.display()
+        )
+    })?;
+
+    std::fs::write(&output, pdf).with_context(|| format!("Failed to write '{}'", output.display()))?;
+    println!("{}", output.display());
+
+    Ok(())
+}
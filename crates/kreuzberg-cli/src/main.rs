@@ -9,6 +9,7 @@
 //! - `extract`: Extract text/data from a single document
 //! - `batch`: Process multiple documents in parallel
 //! - `detect`: Identify MIME type of a file
+//! - `searchable`: Write a scan as a PDF with an invisible OCR text layer
 //! - `cache`: Manage cache (clear, stats)
 //! - `serve`: Start API server (requires `api` feature)
 //! - `version`: Show version information
@@ -49,6 +50,9 @@
 //!
 //! # Detect MIME type
 //! kreuzberg detect unknown-file.bin
+//!
+//! # Make a scan searchable
+//! kreuzberg searchable scan.tiff --output scan.pdf
 //! ```
 
 #![deny(unsafe_code)]
@@ -62,7 +66,10 @@ use clap::{Parser, Subcommand};
 use commands::mcp_command;
 #[cfg(feature = "api")]
 use commands::serve_command;
-use commands::{apply_extraction_overrides, batch_command, clear_command, extract_command, load_config, stats_command};
+use commands::{
+    apply_extraction_overrides, batch_command, clear_command, extract_command, load_config, searchable_command,
+    stats_command,
+};
 use kreuzberg::{OutputFormat as ContentOutputFormat, detect_mime_type};
 use serde_json::json;
 use std::path::{Path, PathBuf};
@@ -118,7 +125,7 @@ enum Commands {
         #[arg(long)]
         ocr_backend: Option<String>,
 
-        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu). PaddleOCR: flexible (en, ch, french, korean).
+        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu, eng+deu). PaddleOCR: flexible (en, ch, french, korean). "auto" detects the language of each page.
         #[arg(long)]
         ocr_language: Option<String>,
 
@@ -150,7 +157,7 @@ enum Commands {
         #[arg(long)]
         detect_language: Option<bool>,
 
-        /// Content output format (plain, markdown, djot, html). Canonical flag.
+        /// Content output format (plain, markdown, djot, html, hocr, alto). Canonical flag.
         ///
         /// Controls the format of the extracted content.
         /// Note: This is different from --format which controls CLI output (text/json).
@@ -199,7 +206,7 @@ enum Commands {
         #[arg(long)]
         ocr_backend: Option<String>,
 
-        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu). PaddleOCR: flexible (en, ch, french, korean).
+        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu, eng+deu). PaddleOCR: flexible (en, ch, french, korean). "auto" detects the language of each page.
         #[arg(long)]
         ocr_language: Option<String>,
 
@@ -215,7 +222,7 @@ enum Commands {
         #[arg(long)]
         quality: Option<bool>,
 
-        /// Content output format (plain, markdown, djot, html). Canonical flag.
+        /// Content output format (plain, markdown, djot, html, hocr, alto). Canonical flag.
         ///
         /// Controls the format of the extracted content.
         /// Note: This is different from --format which controls CLI output (text/json).
@@ -229,6 +236,36 @@ enum Commands {
         content_format: Option<ContentOutputFormatArg>,
     },
 
+    /// Make a searchable PDF from a scanned PDF or image
+    ///
+    /// The pages are OCR'd and written with their original images and the
+    /// recognized text as an invisible layer, so the scan can be searched and
+    /// copied from in any PDF viewer.
+    Searchable {
+        /// Path to the scanned PDF or image (PNG, JPEG, TIFF, ...)
+        path: PathBuf,
+
+        /// Path of the PDF to write
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// MIME type hint (auto-detected if not provided)
+        #[arg(short, long)]
+        mime_type: Option<String>,
+
+        /// OCR backend to use (tesseract, paddle-ocr, easyocr)
+        #[arg(long)]
+        ocr_backend: Option<String>,
+
+        /// OCR language code. Tesseract: ISO 639-3 (eng, fra, deu, eng+deu). PaddleOCR: flexible (en, ch, french, korean). "auto" detects the language of each page.
+        #[arg(long)]
+        ocr_language: Option<String>,
+    },
+
     /// Detect MIME type of a file
     Detect {
         /// Path to the file
@@ -301,7 +338,7 @@ enum Commands {
 enum CacheCommands {
     /// Show cache statistics
     Stats {
-        /// Cache directory (default: .kreuzberg in current directory)
+        /// Cache directory (default: $KREUZBERG_CACHE_DIR or .kreuzberg in current directory)
         #[arg(short, long)]
         cache_dir: Option<PathBuf>,
 
@@ -312,7 +349,7 @@ enum CacheCommands {
 
     /// Clear the cache
     Clear {
-        /// Cache directory (default: .kreuzberg in current directory)
+        /// Cache directory (default: $KREUZBERG_CACHE_DIR or .kreuzberg in current directory)
         #[arg(short, long)]
         cache_dir: Option<PathBuf>,
 
@@ -353,6 +390,10 @@ enum ContentOutputFormatArg {
     Djot,
     /// HTML format
     Html,
+    /// hOCR (XHTML) with word bounding boxes
+    Hocr,
+    /// ALTO XML with word positions
+    Alto,
 }
 
 impl From<ContentOutputFormatArg> for ContentOutputFormat {
@@ -362,6 +403,8 @@ impl From<ContentOutputFormatArg> for ContentOutputFormat {
             ContentOutputFormatArg::Markdown => ContentOutputFormat::Markdown,
             ContentOutputFormatArg::Djot => ContentOutputFormat::Djot,
             ContentOutputFormatArg::Html => ContentOutputFormat::Html,
+            ContentOutputFormatArg::Hocr => ContentOutputFormat::Hocr,
+            ContentOutputFormatArg::Alto => ContentOutputFormat::Alto,
         }
     }
 }
@@ -620,6 +663,38 @@ fn main() -> Result<()> {
             batch_command(paths, config, format)?;
         }
 
+        Commands::Searchable {
+            path,
+            output,
+            config: config_path,
+            mime_type,
+            ocr_backend,
+            ocr_language,
+        } => {
+            validate_file_exists(&path)?;
+
+            let mut config = load_config(config_path)?;
+            // OCR always runs; backend or language flags replace a missing OCR config
+            let configure_ocr = ocr_backend.is_some() || (ocr_language.is_some() && config.ocr.is_none());
+            apply_extraction_overrides(
+                &mut config,
+                configure_ocr.then_some(true),
+                ocr_backend.as_deref(),
+                ocr_language.as_deref(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            searchable_command(path, output, config, mime_type)?;
+        }
+
         Commands::Detect { path, format } => {
             validate_file_exists(&path)?;
 
@@ -173,6 +173,28 @@ typedef int (*ResultCallback)(const struct CExtractionResultView *result,
                               uintptr_t file_index,
                               void *user_data);
 
+/**
+ * Callback function invoked for each extracted page of a document.
+ *
+ * # Arguments
+ *
+ * * `page_json` - Null-terminated UTF-8 JSON of the `PageContent` (valid only during callback)
+ * * `page_number` - One-based number of the page in the document
+ * * `user_data` - User-provided context pointer
+ *
+ * # Returns
+ *
+ * - `0` to continue with the next page
+ * - Non-zero to stop extraction (no further callbacks)
+ *
+ * # Safety
+ *
+ * - `page_json` is valid only during the callback execution
+ * - Caller must copy the string if needed beyond callback scope
+ * - `user_data` is passed through opaquely (caller manages lifetime)
+ */
+typedef int (*PageCallback)(const char *page_json, uintptr_t page_number, void *user_data);
+
 /**
  * C-compatible structured error details returned by `kreuzberg_get_error_details()`.
  *
@@ -669,6 +691,54 @@ int kreuzberg_extract_batch_parallel(const char *const *files,
                                      struct Option_ErrorCallback error_callback,
                                      uintptr_t max_parallel);
 
+/**
+ * Extract a document page by page with callback-based page delivery.
+ *
+ * Pages are passed to the callback as they are extracted, so the caller sees
+ * the first page of a large PDF right away and pages are never accumulated in
+ * memory. Post-processors run on every page. Formats without pages are
+ * delivered as a single page.
+ *
+ * # Arguments
+ *
+ * * `file_path` - Null-terminated path of the file to extract
+ * * `config_json` - Optional JSON configuration string (NULL for defaults)
+ * * `page_callback` - Callback invoked for each extracted page
+ * * `user_data` - Optional user context passed to callbacks
+ * * `error_callback` - Optional callback invoked when extraction fails while
+ *   streaming; its index is the number of pages delivered before the failure
+ *
+ * # Returns
+ *
+ * - `0` on success (all pages delivered or stopped by callback)
+ * - `-1` on error (invalid arguments, configuration parsing failure, or a file
+ *   that cannot be opened or has an unsupported format; see `kreuzberg_last_error`)
+ *
+ * # Safety
+ *
+ * - `file_path` must be a valid null-terminated UTF-8 string
+ * - `config_json` must be valid null-terminated UTF-8 if not NULL
+ * - `page_callback` must be a valid function pointer
+ * - `error_callback` must be a valid function pointer if not NULL
+ * - Page JSON passed to the callback is valid only during the callback
+ *
+ * # Example (C)
+ *
+ * ```c
+ * int process_page(const char* page_json, size_t page_number, void* data) {
+ *     printf("Page %zu: %s\n", page_number, page_json);
+ *     return 0; // Continue
+ * }
+ *
+ * kreuzberg_extract_pages_streaming("large.pdf", NULL, process_page, NULL, NULL);
+ * ```
+ */
+int kreuzberg_extract_pages_streaming(const char *file_path,
+                                      const char *config_json,
+                                      PageCallback page_callback,
+                                      void *user_data,
+                                      struct Option_ErrorCallback error_callback);
+
 /**
  * Parse an ExtractionConfig from a JSON string.
  *
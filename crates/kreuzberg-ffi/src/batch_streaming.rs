@@ -2,6 +2,8 @@
 //!
 //! Provides callback-based batch processing to avoid holding all results in memory.
 //! Processes files one at a time, calling a user-provided callback for each result.
+//! Single large documents can be streamed page by page with
+//! `kreuzberg_extract_pages_streaming`.
 //!
 //! # Benefits
 //!
@@ -397,6 +399,162 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_parallel(
     }
 }
 
+/// Callback function invoked for each extracted page of a document.
+///
+/// # Arguments
+///
+/// * `page_json` - Null-terminated UTF-8 JSON of the `PageContent` (valid only during callback)
+/// * `page_number` - One-based number of the page in the document
+/// * `user_data` - User-provided context pointer
+///
+/// # Returns
+///
+/// - `0` to continue with the next page
+/// - Non-zero to stop extraction (no further callbacks)
+///
+/// # Safety
+///
+/// - `page_json` is valid only during the callback execution
+/// - Caller must copy the string if needed beyond callback scope
+/// - `user_data` is passed through opaquely (caller manages lifetime)
+pub type PageCallback =
+    unsafe extern "C" fn(page_json: *const c_char, page_number: usize, user_data: *mut c_void) -> c_int;
+
+/// Extract a document page by page with callback-based page delivery.
+///
+/// Pages are passed to the callback as they are extracted, so the caller sees
+/// the first page of a large PDF right away and pages are never accumulated in
+/// memory. Post-processors run on every page. Formats without pages are
+/// delivered as a single page.
+///
+/// # Arguments
+///
+/// * `file_path` - Null-terminated path of the file to extract
+/// * `config_json` - Optional JSON configuration string (NULL for defaults)
+/// * `page_callback` - Callback invoked for each extracted page
+/// * `user_data` - Optional user context passed to callbacks
+/// * `error_callback` - Optional callback invoked when extraction fails while
+///   streaming; its index is the number of pages delivered before the failure
+///
+/// # Returns
+///
+/// - `0` on success (all pages delivered or stopped by callback)
+/// - `-1` on error (invalid arguments, configuration parsing failure, or a file
+///   that cannot be opened or has an unsupported format; see `kreuzberg_last_error`)
+///
+/// # Safety
+///
+/// - `file_path` must be a valid null-terminated UTF-8 string
+/// - `config_json` must be valid null-terminated UTF-8 if not NULL
+/// - `page_callback` must be a valid function pointer
+/// - `error_callback` must be a valid function pointer if not NULL
+/// - Page JSON passed to the callback is valid only during the callback
+///
+/// # Example (C)
+///
+/// ```c
+/// int process_page(const char* page_json, size_t page_number, void* data) {
+///     printf("Page %zu: %s\n", page_number, page_json);
+///     return 0; // Continue
+/// }
+///
+/// kreuzberg_extract_pages_streaming("large.pdf", NULL, process_page, NULL, NULL);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extract_pages_streaming(
+    file_path: *const c_char,
+    config_json: *const c_char,
+    page_callback: PageCallback,
+    user_data: *mut c_void,
+    error_callback: Option<ErrorCallback>,
+) -> c_int {
+    use futures::StreamExt;
+
+    clear_last_error();
+
+    if file_path.is_null() {
+        set_last_error("File path cannot be NULL".to_string());
+        return -1;
+    }
+
+    let file_path = match unsafe { CStr::from_ptr(file_path) }.to_str() {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(format!("Invalid UTF-8 in file path: {}", e));
+            return -1;
+        }
+    };
+
+    let config = if !config_json.is_null() {
+        match unsafe { CStr::from_ptr(config_json) }.to_str() {
+            Ok(config_str) => match parse_extraction_config_from_json(config_str) {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    set_last_error(format!("Invalid configuration: {}", e));
+                    return -1;
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in config: {}", e));
+                return -1;
+            }
+        }
+    } else {
+        Default::default()
+    };
+
+    let rt = match tokio::runtime::Runtime::new() {
+        Ok(rt) => rt,
+        Err(e) => {
+            set_last_error(format!("Failed to create runtime: {}", e));
+            return -1;
+        }
+    };
+
+    rt.block_on(async {
+        let mut pages = match kreuzberg::core::extractor::extract_file_pages(file_path, None, &config).await {
+            Ok(pages) => pages,
+            Err(e) => {
+                set_last_error(format!("Extraction failed: {}", e));
+                return -1;
+            }
+        };
+
+        let mut delivered = 0;
+        while let Some(page) = pages.next().await {
+            let page_json = page
+                .map_err(|e| format!("Extraction failed: {}", e))
+                .and_then(|page| {
+                    let json = serde_json::to_string(&page).map_err(|e| format!("Failed to serialize page: {}", e))?;
+                    Ok((page.page_number, json))
+                })
+                .and_then(|(page_number, json)| {
+                    CString::new(json)
+                        .map(|json| (page_number, json))
+                        .map_err(|e| format!("Page contains a NUL byte: {}", e))
+                });
+
+            match page_json {
+                Ok((page_number, json)) => {
+                    delivered += 1;
+                    if unsafe { page_callback(json.as_ptr(), page_number, user_data) } != 0 {
+                        return 0;
+                    }
+                }
+                Err(e) => {
+                    if let Some(err_cb) = error_callback
+                        && let Ok(err_msg) = CString::new(e)
+                    {
+                        unsafe { err_cb(delivered, err_msg.as_ptr(), user_data) };
+                    }
+                }
+            }
+        }
+
+        0
+    })
+}
+
 /// Internal function to extract a file with error handling.
 ///
 /// Returns Result<ExtractionResult, String> for easier error propagation.
@@ -462,6 +620,63 @@ mod tests {
         guard.errors.push(format!("File {}: {}", file_index, msg));
     }
 
+    unsafe extern "C" fn test_page_callback(
+        page_json: *const c_char,
+        page_number: usize,
+        user_data: *mut c_void,
+    ) -> c_int {
+        let ctx = unsafe { &mut *(user_data as *mut Mutex<TestContext>) };
+        let guard = ctx.get_mut().unwrap();
+
+        let json = unsafe { CStr::from_ptr(page_json).to_string_lossy().to_string() };
+        let page: serde_json::Value = serde_json::from_str(&json).unwrap();
+        guard.results.push(format!(
+            "Page {}: {}",
+            page_number,
+            page["content"].as_str().unwrap_or_default()
+        ));
+
+        0
+    }
+
+    #[test]
+    fn test_pages_streaming_text_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("test.txt");
+        std::fs::write(&file, "Page content").unwrap();
+        let path = CString::new(file.to_str().unwrap()).unwrap();
+
+        let mut ctx = Mutex::new(TestContext {
+            results: Vec::new(),
+            errors: Vec::new(),
+        });
+
+        let result = unsafe {
+            kreuzberg_extract_pages_streaming(
+                path.as_ptr(),
+                ptr::null(),
+                test_page_callback,
+                &mut ctx as *mut _ as *mut c_void,
+                Some(test_error_callback),
+            )
+        };
+
+        assert_eq!(result, 0);
+        let ctx = ctx.into_inner().unwrap();
+        assert_eq!(ctx.results.len(), 1);
+        assert!(ctx.results[0].starts_with("Page 1: Page content"));
+        assert!(ctx.errors.is_empty());
+    }
+
+    #[test]
+    fn test_pages_streaming_missing_file() {
+        let path = CString::new("/nonexistent/document.pdf").unwrap();
+        let result = unsafe {
+            kreuzberg_extract_pages_streaming(path.as_ptr(), ptr::null(), test_page_callback, ptr::null_mut(), None)
+        };
+        assert_eq!(result, -1);
+    }
+
     #[test]
     fn test_batch_streaming_basic() {
         let temp_dir = tempfile::tempdir().unwrap();
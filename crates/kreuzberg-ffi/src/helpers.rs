@@ -582,6 +582,8 @@ mod tests {
             markdown: "| Col1 | Col2 |\n|------|------|\n| A1 | A2 |\n| B1 | B2 |".to_string(),
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let chunk = Chunk {
@@ -23,7 +23,8 @@ mod util;
 mod validation;
 
 pub use batch_streaming::{
-    ErrorCallback, ResultCallback, kreuzberg_extract_batch_parallel, kreuzberg_extract_batch_streaming,
+    ErrorCallback, PageCallback, ResultCallback, kreuzberg_extract_batch_parallel, kreuzberg_extract_batch_streaming,
+    kreuzberg_extract_pages_streaming,
 };
 pub use config::{
     kreuzberg_config_discover, kreuzberg_config_free, kreuzberg_config_from_file, kreuzberg_config_from_json,
@@ -74,6 +74,43 @@ pub struct JsOcrElementConfig {
     pub build_hierarchy: Option<bool>,
 }
 
+impl From<JsOcrElementConfig> for kreuzberg::OcrElementConfig {
+    fn from(ec: JsOcrElementConfig) -> Self {
+        kreuzberg::OcrElementConfig {
+            include_elements: ec.include_elements.unwrap_or(false),
+            min_level: ec
+                .min_level
+                .as_deref()
+                .map(|s| match s {
+                    "word" | "Word" => kreuzberg::OcrElementLevel::Word,
+                    "line" | "Line" => kreuzberg::OcrElementLevel::Line,
+                    "block" | "Block" => kreuzberg::OcrElementLevel::Block,
+                    "page" | "Page" => kreuzberg::OcrElementLevel::Page,
+                    _ => kreuzberg::OcrElementLevel::default(),
+                })
+                .unwrap_or_default(),
+            min_confidence: ec.min_confidence.unwrap_or(0.0),
+            build_hierarchy: ec.build_hierarchy.unwrap_or(false),
+        }
+    }
+}
+
+impl From<kreuzberg::OcrElementConfig> for JsOcrElementConfig {
+    fn from(ec: kreuzberg::OcrElementConfig) -> Self {
+        JsOcrElementConfig {
+            include_elements: Some(ec.include_elements),
+            min_level: Some(match ec.min_level {
+                kreuzberg::OcrElementLevel::Word => "word".to_string(),
+                kreuzberg::OcrElementLevel::Line => "line".to_string(),
+                kreuzberg::OcrElementLevel::Block => "block".to_string(),
+                kreuzberg::OcrElementLevel::Page => "page".to_string(),
+            }),
+            min_confidence: Some(ec.min_confidence),
+            build_hierarchy: Some(ec.build_hierarchy),
+        }
+    }
+}
+
 impl From<JsOcrConfig> for RustOcrConfig {
     fn from(val: JsOcrConfig) -> Self {
         RustOcrConfig {
@@ -135,22 +172,12 @@ impl From<JsOcrConfig> for RustOcrConfig {
                 }
                 serde_json::Value::Object(map)
             }),
-            element_config: val.element_config.map(|ec| kreuzberg::OcrElementConfig {
-                include_elements: ec.include_elements.unwrap_or(false),
-                min_level: ec
-                    .min_level
-                    .as_deref()
-                    .map(|s| match s {
-                        "word" | "Word" => kreuzberg::OcrElementLevel::Word,
-                        "line" | "Line" => kreuzberg::OcrElementLevel::Line,
-                        "block" | "Block" => kreuzberg::OcrElementLevel::Block,
-                        "page" | "Page" => kreuzberg::OcrElementLevel::Page,
-                        _ => kreuzberg::OcrElementLevel::default(),
-                    })
-                    .unwrap_or_default(),
-                min_confidence: ec.min_confidence.unwrap_or(0.0),
-                build_hierarchy: ec.build_hierarchy.unwrap_or(false),
-            }),
+            element_config: val.element_config.map(Into::into),
+            preprocessing: None,
+            auto_rotate: false,
+            fallback: None,
+            table_detection: None,
+            cache: None,
         }
     }
 }
@@ -321,6 +348,16 @@ pub struct JsPdfConfig {
     pub extract_images: Option<bool>,
     pub passwords: Option<Vec<String>>,
     pub extract_metadata: Option<bool>,
+    pub extract_annotations: Option<bool>,
+    pub extract_form_fields: Option<bool>,
+    pub render_form_fields: Option<bool>,
+    pub extract_embedded_files: Option<bool>,
+    pub strip_page_furniture: Option<bool>,
+    pub ocr_image_regions: Option<bool>,
+    pub extract_figures: Option<bool>,
+    pub detect_formulas: Option<bool>,
+    pub text_layer_threshold: Option<f64>,
+    pub text_elements: Option<JsOcrElementConfig>,
     pub hierarchy: Option<JsHierarchyConfig>,
 }
 
@@ -330,6 +367,16 @@ impl From<JsPdfConfig> for RustPdfConfig {
             extract_images: val.extract_images.unwrap_or(false),
             passwords: val.passwords,
             extract_metadata: val.extract_metadata.unwrap_or(true),
+            extract_annotations: val.extract_annotations.unwrap_or(false),
+            extract_form_fields: val.extract_form_fields.unwrap_or(false),
+            render_form_fields: val.render_form_fields.unwrap_or(false),
+            extract_embedded_files: val.extract_embedded_files.unwrap_or(false),
+            strip_page_furniture: val.strip_page_furniture.unwrap_or(false),
+            ocr_image_regions: val.ocr_image_regions.unwrap_or(false),
+            extract_figures: val.extract_figures.unwrap_or(false),
+            detect_formulas: val.detect_formulas.unwrap_or(false),
+            text_layer_threshold: val.text_layer_threshold.unwrap_or(0.5),
+            text_elements: val.text_elements.map(Into::into),
             hierarchy: val.hierarchy.map(|h| h.into()),
             table_detection: None,
         }
@@ -927,6 +974,8 @@ pub struct JsPageConfig {
     pub extract_pages: Option<bool>,
     pub insert_page_markers: Option<bool>,
     pub marker_format: Option<String>,
+    /// Pages to extract: `{ first: 10 }`, `{ ranges: [[40, 55]] }` or `{ pages: [1, 3, 5] }`
+    pub selection: Option<serde_json::Value>,
 }
 
 #[napi(object)]
@@ -945,7 +994,7 @@ pub struct JsExtractionConfig {
     pub html_options: Option<JsHtmlOptions>,
     pub max_concurrent_extractions: Option<u32>,
     pub pages: Option<JsPageConfig>,
-    /// Output text format: "plain" | "markdown" | "djot" | "html"
+    /// Output text format: "plain" | "markdown" | "djot" | "html" | "hocr" | "alto"
     pub output_format: Option<String>,
     /// Result structure format: "unified" | "element_based"
     pub result_format: Option<String>,
@@ -963,6 +1012,11 @@ impl TryFrom<JsPageConfig> for kreuzberg::core::config::PageConfig {
             marker_format: val
                 .marker_format
                 .unwrap_or_else(|| "\n\n<!-- PAGE {page_num} -->\n\n".to_string()),
+            selection: val
+                .selection
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid page selection: {}", e)))?,
         })
     }
 }
@@ -973,6 +1027,9 @@ impl From<kreuzberg::core::config::PageConfig> for JsPageConfig {
             extract_pages: Some(config.extract_pages),
             insert_page_markers: Some(config.insert_page_markers),
             marker_format: Some(config.marker_format),
+            selection: config
+                .selection
+                .and_then(|selection| serde_json::to_value(selection).ok()),
         }
     }
 }
@@ -1029,6 +1086,7 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
                 .unwrap_or_default(),
             include_document_structure: val.include_document_structure.unwrap_or(false),
             security_limits: None,
+            cache: None,
         })
     }
 }
@@ -1067,17 +1125,7 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                         min_confidence: None,
                         output_format: None,
                     }),
-                element_config: ocr.element_config.map(|ec| JsOcrElementConfig {
-                    include_elements: Some(ec.include_elements),
-                    min_level: Some(match ec.min_level {
-                        kreuzberg::OcrElementLevel::Word => "word".to_string(),
-                        kreuzberg::OcrElementLevel::Line => "line".to_string(),
-                        kreuzberg::OcrElementLevel::Block => "block".to_string(),
-                        kreuzberg::OcrElementLevel::Page => "page".to_string(),
-                    }),
-                    min_confidence: Some(ec.min_confidence),
-                    build_hierarchy: Some(ec.build_hierarchy),
-                }),
+                element_config: ocr.element_config.map(Into::into),
             }),
             force_ocr: Some(val.force_ocr),
             chunking: val.chunking.map(|chunk| JsChunkingConfig {
@@ -1120,6 +1168,16 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                 extract_images: Some(pdf.extract_images),
                 passwords: pdf.passwords,
                 extract_metadata: Some(pdf.extract_metadata),
+                extract_annotations: Some(pdf.extract_annotations),
+                extract_form_fields: Some(pdf.extract_form_fields),
+                render_form_fields: Some(pdf.render_form_fields),
+                extract_embedded_files: Some(pdf.extract_embedded_files),
+                strip_page_furniture: Some(pdf.strip_page_furniture),
+                ocr_image_regions: Some(pdf.ocr_image_regions),
+                extract_figures: Some(pdf.extract_figures),
+                detect_formulas: Some(pdf.detect_formulas),
+                text_layer_threshold: Some(pdf.text_layer_threshold),
+                text_elements: pdf.text_elements.map(Into::into),
                 hierarchy: pdf.hierarchy.map(|h| JsHierarchyConfig {
                     enabled: Some(h.enabled),
                     k_clusters: Some(h.k_clusters as i32),
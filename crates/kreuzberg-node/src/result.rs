@@ -35,6 +35,7 @@ pub struct JsPageContent {
     pub images: Vec<JsExtractedImage>,
     pub hierarchy: Option<JsPageHierarchy>,
     pub is_blank: Option<bool>,
+    pub annotations: Option<serde_json::Value>,
 }
 
 #[napi(object)]
@@ -44,6 +45,17 @@ pub struct JsTable {
     pub markdown: String,
     pub page_number: u32,
     pub bounding_box: Option<JsBoundingBox>,
+    pub end_page_number: Option<u32>,
+    pub spans: Vec<JsCellSpan>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct JsCellSpan {
+    pub row: u32,
+    pub col: u32,
+    pub row_span: u32,
+    pub col_span: u32,
 }
 
 #[napi(object)]
@@ -236,6 +248,17 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                             x1: bb.x1,
                             y1: bb.y1,
                         }),
+                        end_page_number: t.end_page_number.map(|page| page as u32),
+                        spans: t
+                            .spans
+                            .iter()
+                            .map(|span| JsCellSpan {
+                                row: span.row as u32,
+                                col: span.col as u32,
+                                row_span: span.row_span as u32,
+                                col_span: span.col_span as u32,
+                            })
+                            .collect(),
                     })
                     .collect();
 
@@ -300,6 +323,17 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     }
                 });
 
+                let annotations = if page.annotations.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::to_value(&page.annotations).map_err(|e| {
+                        Error::new(
+                            Status::GenericFailure,
+                            format!("Failed to serialize page annotations: {}", e),
+                        )
+                    })?)
+                };
+
                 js_pages.push(JsPageContent {
                     page_number: page.page_number as u32,
                     content: page.content,
@@ -307,6 +341,7 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     images: page_images,
                     hierarchy,
                     is_blank: page.is_blank,
+                    annotations,
                 });
             }
             Some(js_pages)
@@ -411,6 +446,17 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                         x1: bb.x1,
                         y1: bb.y1,
                     }),
+                    end_page_number: t.end_page_number.map(|page| page as u32),
+                    spans: t
+                        .spans
+                        .iter()
+                        .map(|span| JsCellSpan {
+                            row: span.row as u32,
+                            col: span.col as u32,
+                            row_span: span.row_span as u32,
+                            col_span: span.col_span as u32,
+                        })
+                        .collect(),
                 })
                 .collect(),
             detected_languages: val.detected_languages,
@@ -637,6 +683,17 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                         x1: bb.x1,
                         y1: bb.y1,
                     }),
+                    end_page_number: t.end_page_number.map(|page| page as usize),
+                    spans: t
+                        .spans
+                        .into_iter()
+                        .map(|span| kreuzberg::CellSpan {
+                            row: span.row as usize,
+                            col: span.col as usize,
+                            row_span: span.row_span as usize,
+                            col_span: span.col_span as usize,
+                        })
+                        .collect(),
                 })
                 .collect(),
             detected_languages: val.detected_languages,
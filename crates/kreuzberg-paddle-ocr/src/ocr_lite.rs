@@ -9,7 +9,7 @@ use crate::{
     crnn_net::CrnnNet,
     db_net::DbNet,
     ocr_error::OcrError,
-    ocr_result::{OcrResult, Point, TextBlock},
+    ocr_result::{OcrResult, Point, TextBlock, TextBox},
     ocr_utils::OcrUtils,
     scale_param::ScaleParam,
 };
@@ -247,6 +247,91 @@ impl OcrLite {
         )
     }
 
+    /// Detect the orientation of a whole page.
+    ///
+    /// The detected text boxes decide whether lines run horizontally or
+    /// vertically; a page with vertical lines is turned a quarter clockwise and
+    /// detected again. The angle classifier then votes on whether the lines are
+    /// upside down.
+    ///
+    /// Returns the clockwise rotation (0, 90, 180 or 270 degrees) that makes the
+    /// text upright together with the share of text lines that agree, or `None`
+    /// when no text was found. Vertical writing (e.g. traditional CJK layouts)
+    /// is indistinguishable from a rotated page and is reported as such.
+    pub fn detect_orientation(
+        &mut self,
+        img_src: &image::RgbImage,
+        padding: u32,
+        max_side_len: u32,
+        box_score_thresh: f32,
+        box_thresh: f32,
+        un_clip_ratio: f32,
+    ) -> Result<Option<(u32, f32)>, OcrError> {
+        let (mut padded, mut text_boxes) = self.detect_text_boxes(
+            img_src,
+            padding,
+            max_side_len,
+            box_score_thresh,
+            box_thresh,
+            un_clip_ratio,
+        )?;
+
+        let (horizontal, vertical) = line_direction_votes(&text_boxes);
+        if horizontal + vertical == 0 {
+            return Ok(None);
+        }
+
+        let mut quarter_turn = 0;
+        if vertical > horizontal {
+            let turned = image::imageops::rotate90(img_src);
+            (padded, text_boxes) = self.detect_text_boxes(
+                &turned,
+                padding,
+                max_side_len,
+                box_score_thresh,
+                box_thresh,
+                un_clip_ratio,
+            )?;
+            if text_boxes.is_empty() {
+                return Ok(None);
+            }
+            quarter_turn = 90;
+        }
+
+        let part_images = OcrUtils::get_part_images(&padded, &text_boxes);
+        let angles = self.angle_net.get_angles(&part_images, true, false)?;
+        let flipped = angles.iter().filter(|angle| angle.index == 1).count();
+        let (rotation, agreement) = orientation_from_votes(quarter_turn, flipped, angles.len());
+
+        Ok(Some((rotation, agreement)))
+    }
+
+    /// Pad and scale `img_src`, then run text detection on it.
+    fn detect_text_boxes(
+        &mut self,
+        img_src: &image::RgbImage,
+        padding: u32,
+        max_side_len: u32,
+        box_score_thresh: f32,
+        box_thresh: f32,
+        un_clip_ratio: f32,
+    ) -> Result<(image::RgbImage, Vec<TextBox>), OcrError> {
+        let origin_max_side = img_src.width().max(img_src.height());
+        let resize = if max_side_len == 0 || max_side_len > origin_max_side {
+            origin_max_side
+        } else {
+            max_side_len
+        } + 2 * padding;
+
+        let padding_src = OcrUtils::make_padding(img_src, padding)?;
+        let scale = ScaleParam::get_scale_param(&padding_src, resize);
+        let text_boxes =
+            self.db_net
+                .get_text_boxes(&padding_src, &scale, box_score_thresh, box_thresh, un_clip_ratio)?;
+
+        Ok((padding_src, text_boxes))
+    }
+
     fn detect_once(
         &mut self,
         img_src: &image::RgbImage,
@@ -311,3 +396,80 @@ impl OcrLite {
         Ok(OcrResult { text_blocks })
     }
 }
+
+/// Count clearly horizontal and clearly vertical text boxes.
+///
+/// Boxes are quadrilaterals starting at the top-left corner in clockwise
+/// order. Near-square boxes (single glyphs) carry no direction and are ignored.
+fn line_direction_votes(text_boxes: &[TextBox]) -> (usize, usize) {
+    let edge = |a: &Point, b: &Point| ((a.x as f32 - b.x as f32).powi(2) + (a.y as f32 - b.y as f32).powi(2)).sqrt();
+
+    text_boxes
+        .iter()
+        .filter(|text_box| text_box.points.len() >= 4)
+        .fold((0, 0), |(horizontal, vertical), text_box| {
+            let width = edge(&text_box.points[0], &text_box.points[1]);
+            let height = edge(&text_box.points[0], &text_box.points[3]);
+            if width >= height * 1.5 {
+                (horizontal + 1, vertical)
+            } else if height >= width * 1.5 {
+                (horizontal, vertical + 1)
+            } else {
+                (horizontal, vertical)
+            }
+        })
+}
+
+/// Combine the quarter turn with the classifier's upside-down votes.
+///
+/// Returns the clockwise rotation and the share of lines agreeing with it.
+fn orientation_from_votes(quarter_turn: u32, flipped: usize, total: usize) -> (u32, f32) {
+    if total == 0 {
+        return (quarter_turn, 0.0);
+    }
+    if flipped * 2 > total {
+        ((quarter_turn + 180) % 360, flipped as f32 / total as f32)
+    } else {
+        (quarter_turn, (total - flipped) as f32 / total as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_box(width: u32, height: u32) -> TextBox {
+        TextBox {
+            points: vec![
+                Point { x: 10, y: 10 },
+                Point { x: 10 + width, y: 10 },
+                Point {
+                    x: 10 + width,
+                    y: 10 + height,
+                },
+                Point { x: 10, y: 10 + height },
+            ],
+            score: 0.9,
+        }
+    }
+
+    #[test]
+    fn test_line_direction_votes_ignores_square_boxes() {
+        let boxes = vec![
+            text_box(200, 20),
+            text_box(180, 24),
+            text_box(20, 160),
+            text_box(30, 30),
+        ];
+        assert_eq!(line_direction_votes(&boxes), (2, 1));
+    }
+
+    #[test]
+    fn test_orientation_from_votes() {
+        assert_eq!(orientation_from_votes(0, 1, 4), (0, 0.75));
+        assert_eq!(orientation_from_votes(0, 3, 4), (180, 0.75));
+        assert_eq!(orientation_from_votes(90, 0, 2), (90, 1.0));
+        assert_eq!(orientation_from_votes(90, 2, 2), (270, 1.0));
+        assert_eq!(orientation_from_votes(90, 0, 0), (90, 0.0));
+    }
+}
@@ -95,6 +95,7 @@ pub mod prelude {
         pdf::document::page::objects::*,
         pdf::document::page::render_config::*,
         pdf::document::page::size::*,
+        pdf::document::page::struct_tree::*,
         pdf::document::page::text::char::*,
         pdf::document::page::text::chars::*,
         pdf::document::page::text::search::*,
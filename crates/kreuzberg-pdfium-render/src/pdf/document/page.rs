@@ -11,6 +11,7 @@ pub mod object;
 pub mod objects;
 pub mod render_config;
 pub mod size;
+pub mod struct_tree;
 pub mod text;
 
 use object::ownership::PdfPageObjectOwnership;
@@ -30,6 +31,7 @@ use crate::pdf::document::page::objects::PdfPageObjects;
 use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
 use crate::pdf::document::page::render_config::{PdfPageRenderSettings, PdfRenderConfig};
 use crate::pdf::document::page::size::PdfPagePaperSize;
+use crate::pdf::document::page::struct_tree::PdfPageStructTree;
 use crate::pdf::document::page::text::PdfPageText;
 use crate::pdf::font::PdfFont;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
@@ -372,6 +374,19 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Returns the logical structure tree of this [PdfPage], if the document is tagged.
+    ///
+    /// Returns `None` when the document has no structure tree.
+    pub fn struct_tree(&self) -> Option<PdfPageStructTree<'_>> {
+        let struct_tree_handle = self.bindings().FPDF_StructTree_GetForPage(self.page_handle);
+
+        if struct_tree_handle.is_null() {
+            None
+        } else {
+            Some(PdfPageStructTree::from_pdfium(struct_tree_handle, self.bindings))
+        }
+    }
+
     /// Returns an immutable collection of the annotations that have been added to this [PdfPage].
     pub fn annotations(&self) -> &PdfPageAnnotations<'a> {
         &self.annotations
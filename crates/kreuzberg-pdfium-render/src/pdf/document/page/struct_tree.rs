@@ -0,0 +1,203 @@
+//! Defines the [PdfPageStructTree] struct, exposing the logical structure tree of a tagged
+//! PDF for a single [PdfPage], and the [PdfStructElement] struct for its elements.
+
+use crate::bindgen::{FPDF_STRUCTELEMENT, FPDF_STRUCTTREE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_ulong, c_void};
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// The logical structure tree of a single [PdfPage] in a tagged PDF.
+///
+/// The tree contains the structure elements (headings, paragraphs, lists, tables, figures)
+/// that have content on the page, in logical reading order. Elements reference the page
+/// content they tag through marked content IDs.
+pub struct PdfPageStructTree<'a> {
+    struct_tree_handle: FPDF_STRUCTTREE,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageStructTree<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(struct_tree_handle: FPDF_STRUCTTREE, bindings: &'a dyn PdfiumLibraryBindings) -> Self {
+        PdfPageStructTree {
+            struct_tree_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageStructTree].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the top-level structure elements of this [PdfPageStructTree].
+    pub fn children(&self) -> Vec<PdfStructElement<'_>> {
+        let count = self.bindings.FPDF_StructTree_CountChildren(self.struct_tree_handle);
+
+        (0..count.max(0))
+            .filter_map(|index| {
+                let handle = self
+                    .bindings
+                    .FPDF_StructTree_GetChildAtIndex(self.struct_tree_handle, index);
+
+                (!handle.is_null()).then(|| PdfStructElement::from_pdfium(handle, self.bindings))
+            })
+            .collect()
+    }
+}
+
+impl<'a> Drop for PdfPageStructTree<'a> {
+    /// Closes the [PdfPageStructTree], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDF_StructTree_Close(self.struct_tree_handle);
+    }
+}
+
+/// A kid of a [PdfStructElement]: either a child element or a marked content sequence
+/// on the page, identified by its marked content ID.
+#[derive(Clone)]
+pub enum PdfStructElementKid<'a> {
+    Element(PdfStructElement<'a>),
+    MarkedContent(i32),
+}
+
+/// A single element in a [PdfPageStructTree].
+///
+/// Elements are owned by their structure tree and only live as long as it does.
+#[derive(Clone)]
+pub struct PdfStructElement<'a> {
+    struct_element_handle: FPDF_STRUCTELEMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElement<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        struct_element_handle: FPDF_STRUCTELEMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElement {
+            struct_element_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the structure type of this [PdfStructElement], e.g. `H1`, `P`, `L`, `LI`,
+    /// `Table`, `TD` or `Figure`. Custom types are mapped to standard types through the
+    /// document's role map where it defines them.
+    pub fn element_type(&self) -> Option<String> {
+        self.read_string(|bindings, handle, buffer, length| bindings.FPDF_StructElement_GetType(handle, buffer, length))
+    }
+
+    /// Returns the alternate description (`/Alt`) of this [PdfStructElement], if any.
+    pub fn alt_text(&self) -> Option<String> {
+        self.read_string(|bindings, handle, buffer, length| {
+            bindings.FPDF_StructElement_GetAltText(handle, buffer, length)
+        })
+    }
+
+    /// Returns the replacement text (`/ActualText`) of this [PdfStructElement], if any.
+    pub fn actual_text(&self) -> Option<String> {
+        self.read_string(|bindings, handle, buffer, length| {
+            bindings.FPDF_StructElement_GetActualText(handle, buffer, length)
+        })
+    }
+
+    /// Returns the title (`/T`) of this [PdfStructElement], if any.
+    pub fn title(&self) -> Option<String> {
+        self.read_string(|bindings, handle, buffer, length| {
+            bindings.FPDF_StructElement_GetTitle(handle, buffer, length)
+        })
+    }
+
+    /// Returns the marked content IDs of the page content tagged directly by this
+    /// [PdfStructElement], in order. Content tagged by child elements is not included.
+    pub fn marked_content_ids(&self) -> Vec<i32> {
+        let count = self
+            .bindings
+            .FPDF_StructElement_GetMarkedContentIdCount(self.struct_element_handle);
+
+        (0..count.max(0))
+            .map(|index| {
+                self.bindings
+                    .FPDF_StructElement_GetMarkedContentIdAtIndex(self.struct_element_handle, index)
+            })
+            .filter(|id| *id >= 0)
+            .collect()
+    }
+
+    /// Returns the kids of this [PdfStructElement] in document order: child structure
+    /// elements interleaved with the marked content sequences tagged directly by this element.
+    /// Object references (annotations, XObjects) are skipped.
+    pub fn kids(&self) -> Vec<PdfStructElementKid<'a>> {
+        let count = self
+            .bindings
+            .FPDF_StructElement_CountChildren(self.struct_element_handle);
+
+        (0..count.max(0))
+            .filter_map(|index| {
+                let handle = self
+                    .bindings
+                    .FPDF_StructElement_GetChildAtIndex(self.struct_element_handle, index);
+
+                if !handle.is_null() {
+                    return Some(PdfStructElementKid::Element(PdfStructElement::from_pdfium(
+                        handle,
+                        self.bindings,
+                    )));
+                }
+
+                let id = self
+                    .bindings
+                    .FPDF_StructElement_GetChildMarkedContentID(self.struct_element_handle, index);
+
+                (id >= 0).then_some(PdfStructElementKid::MarkedContent(id))
+            })
+            .collect()
+    }
+
+    /// Returns the child structure elements of this [PdfStructElement], in order.
+    pub fn children(&self) -> Vec<PdfStructElement<'a>> {
+        self.kids()
+            .into_iter()
+            .filter_map(|kid| match kid {
+                PdfStructElementKid::Element(element) => Some(element),
+                PdfStructElementKid::MarkedContent(_) => None,
+            })
+            .collect()
+    }
+
+    /// Reads a UTF-16LE string property with the usual Pdfium two-call protocol: the first
+    /// call with a null buffer returns the length in bytes, the second fills the buffer.
+    fn read_string(
+        &self,
+        read: impl Fn(&dyn PdfiumLibraryBindings, FPDF_STRUCTELEMENT, *mut c_void, c_ulong) -> c_ulong,
+    ) -> Option<String> {
+        let buffer_length = read(self.bindings, self.struct_element_handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = read(
+            self.bindings,
+            self.struct_element_handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        if result != buffer_length {
+            return None;
+        }
+
+        get_string_from_pdfium_utf16le_bytes(buffer).filter(|value| !value.is_empty())
+    }
+}
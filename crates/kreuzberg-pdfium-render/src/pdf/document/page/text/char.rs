@@ -339,6 +339,25 @@ impl<'a> PdfPageTextChar<'a> {
         }
     }
 
+    /// Returns the marked content ID of the page text object containing this character,
+    /// linking it to an element of the page's structure tree in tagged PDFs.
+    ///
+    /// Returns `None` for characters outside marked content, and for characters Pdfium
+    /// generated itself (spaces and line breaks) that belong to no text object.
+    pub fn marked_content_id(&self) -> Option<i32> {
+        let object_handle = self
+            .bindings()
+            .FPDFText_GetTextObject(self.text_page_handle(), self.index);
+
+        if object_handle.is_null() {
+            return None;
+        }
+
+        let id = self.bindings().FPDFPageObj_GetMarkedContentID(object_handle);
+
+        (id >= 0).then_some(id)
+    }
+
     /// Returns the text rendering mode for this character.
     pub fn render_mode(&self) -> Result<PdfPageTextRenderMode, PdfiumError> {
         self.text_object().map(|text_object| text_object.render_mode())
@@ -592,6 +592,14 @@ pub struct ExtractedTable {
     /// Bounding box as associative array {x0, y0, x1, y1} or null
     #[php(prop)]
     pub bounding_box: Option<HashMap<String, f64>>,
+
+    /// Last page of a table continued across pages, or null
+    #[php(prop)]
+    pub end_page_number: Option<usize>,
+
+    /// Merged cells as associative arrays {row, col, row_span, col_span}
+    #[php(prop)]
+    pub spans: Vec<HashMap<String, usize>>,
 }
 
 #[php_impl]
@@ -619,6 +627,19 @@ impl ExtractedTable {
             markdown: table.markdown,
             page_number: table.page_number,
             bounding_box,
+            end_page_number: table.end_page_number,
+            spans: table
+                .spans
+                .into_iter()
+                .map(|span| {
+                    HashMap::from([
+                        ("row".to_string(), span.row),
+                        ("col".to_string(), span.col),
+                        ("row_span".to_string(), span.row_span),
+                        ("col_span".to_string(), span.col_span),
+                    ])
+                })
+                .collect(),
         })
     }
 }
@@ -1128,10 +1149,40 @@ pub(crate) fn php_array_to_table(arr: &ext_php_rs::types::ZendHashTable) -> PhpR
             y1: bb_arr.get("y1").and_then(|v| v.double()).unwrap_or(0.0),
         });
 
+    let end_page_number = arr.get("end_page_number").and_then(|v| v.long()).map(|v| v as usize);
+
+    let spans = arr
+        .get("spans")
+        .and_then(|v| v.array())
+        .map(|spans_arr| {
+            spans_arr
+                .iter()
+                .filter_map(|(_, span_val)| span_val.array())
+                .map(|span_arr| {
+                    let get = |key: &str, default: usize| {
+                        span_arr
+                            .get(key)
+                            .and_then(|v| v.long())
+                            .map(|v| v as usize)
+                            .unwrap_or(default)
+                    };
+                    kreuzberg::types::CellSpan {
+                        row: get("row", 0),
+                        col: get("col", 0),
+                        row_span: get("row_span", 1),
+                        col_span: get("col_span", 1),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     Ok(kreuzberg::types::Table {
         cells,
         markdown,
         page_number,
         bounding_box,
+        end_page_number,
+        spans,
     })
 }
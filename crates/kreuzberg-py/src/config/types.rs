@@ -117,9 +117,11 @@ impl ExtractionConfig {
                         "markdown" => kreuzberg::core::config::formats::OutputFormat::Markdown,
                         "djot" => kreuzberg::core::config::formats::OutputFormat::Djot,
                         "html" => kreuzberg::core::config::formats::OutputFormat::Html,
+                        "hocr" => kreuzberg::core::config::formats::OutputFormat::Hocr,
+                        "alto" => kreuzberg::core::config::formats::OutputFormat::Alto,
                         other => {
                             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                                "Invalid output_format: {}. Must be 'plain', 'markdown', 'djot', or 'html'",
+                                "Invalid output_format: {}. Must be 'plain', 'markdown', 'djot', 'html', 'hocr', or 'alto'",
                                 other
                             )));
                         }
@@ -128,6 +130,7 @@ impl ExtractionConfig {
                     kreuzberg::core::config::formats::OutputFormat::Plain
                 },
                 security_limits: None,
+                cache: None,
             },
             html_options_dict,
         })
@@ -311,6 +314,8 @@ impl ExtractionConfig {
             kreuzberg::core::config::formats::OutputFormat::Djot => "djot".to_string(),
             kreuzberg::core::config::formats::OutputFormat::Html => "html".to_string(),
             kreuzberg::core::config::formats::OutputFormat::Structured => "structured".to_string(),
+            kreuzberg::core::config::formats::OutputFormat::Hocr => "hocr".to_string(),
+            kreuzberg::core::config::formats::OutputFormat::Alto => "alto".to_string(),
         }
     }
 
@@ -322,6 +327,8 @@ impl ExtractionConfig {
             "djot" => kreuzberg::core::config::formats::OutputFormat::Djot,
             "html" => kreuzberg::core::config::formats::OutputFormat::Html,
             "structured" | "json" => kreuzberg::core::config::formats::OutputFormat::Structured,
+            "hocr" => kreuzberg::core::config::formats::OutputFormat::Hocr,
+            "alto" => kreuzberg::core::config::formats::OutputFormat::Alto,
             _ => kreuzberg::core::config::formats::OutputFormat::Plain, // Default on invalid
         };
     }
@@ -450,6 +457,11 @@ impl OcrConfig {
                 output_format: None,
                 paddle_ocr_config: paddle_ocr_json,
                 element_config: element_cfg,
+                preprocessing: None,
+                auto_rotate: false,
+                fallback: None,
+                table_detection: None,
+                cache: None,
             },
         })
     }
@@ -872,22 +884,54 @@ pub struct PdfConfig {
 #[pymethods]
 impl PdfConfig {
     #[new]
-    #[pyo3(signature = (extract_images=None, passwords=None, extract_metadata=None, hierarchy=None))]
+    #[pyo3(signature = (extract_images=None, passwords=None, extract_metadata=None, hierarchy=None, extract_annotations=None, extract_form_fields=None, render_form_fields=None, extract_embedded_files=None, strip_page_furniture=None, ocr_image_regions=None, extract_figures=None, detect_formulas=None, text_layer_threshold=None, text_elements=None))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
+        py: Python<'_>,
         extract_images: Option<bool>,
         passwords: Option<Vec<String>>,
         extract_metadata: Option<bool>,
         hierarchy: Option<HierarchyConfig>,
-    ) -> Self {
-        Self {
+        extract_annotations: Option<bool>,
+        extract_form_fields: Option<bool>,
+        render_form_fields: Option<bool>,
+        extract_embedded_files: Option<bool>,
+        strip_page_furniture: Option<bool>,
+        ocr_image_regions: Option<bool>,
+        extract_figures: Option<bool>,
+        detect_formulas: Option<bool>,
+        text_layer_threshold: Option<f64>,
+        text_elements: Option<Bound<'_, pyo3::types::PyAny>>,
+    ) -> PyResult<Self> {
+        let text_elements = if let Some(obj) = text_elements {
+            let json_mod = py.import("json")?;
+            let json_str: String = json_mod.call_method1("dumps", (&obj,))?.extract()?;
+            Some(
+                serde_json::from_str(&json_str)
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid text_elements: {e}")))?,
+            )
+        } else {
+            None
+        };
+        Ok(Self {
             inner: kreuzberg::PdfConfig {
                 extract_images: extract_images.unwrap_or(false),
                 passwords,
                 extract_metadata: extract_metadata.unwrap_or(true),
+                extract_annotations: extract_annotations.unwrap_or(false),
+                extract_form_fields: extract_form_fields.unwrap_or(false),
+                render_form_fields: render_form_fields.unwrap_or(false),
+                extract_embedded_files: extract_embedded_files.unwrap_or(false),
+                strip_page_furniture: strip_page_furniture.unwrap_or(false),
+                ocr_image_regions: ocr_image_regions.unwrap_or(false),
+                extract_figures: extract_figures.unwrap_or(false),
+                detect_formulas: detect_formulas.unwrap_or(false),
+                text_layer_threshold: text_layer_threshold.unwrap_or(0.5),
+                text_elements,
                 hierarchy: hierarchy.map(|h| h.inner),
                 table_detection: None,
             },
-        }
+        })
     }
 
     #[getter]
@@ -920,6 +964,96 @@ impl PdfConfig {
         self.inner.extract_metadata = value;
     }
 
+    #[getter]
+    fn extract_annotations(&self) -> bool {
+        self.inner.extract_annotations
+    }
+
+    #[setter]
+    fn set_extract_annotations(&mut self, value: bool) {
+        self.inner.extract_annotations = value;
+    }
+
+    #[getter]
+    fn extract_form_fields(&self) -> bool {
+        self.inner.extract_form_fields
+    }
+
+    #[setter]
+    fn set_extract_form_fields(&mut self, value: bool) {
+        self.inner.extract_form_fields = value;
+    }
+
+    #[getter]
+    fn render_form_fields(&self) -> bool {
+        self.inner.render_form_fields
+    }
+
+    #[setter]
+    fn set_render_form_fields(&mut self, value: bool) {
+        self.inner.render_form_fields = value;
+    }
+
+    #[getter]
+    fn extract_embedded_files(&self) -> bool {
+        self.inner.extract_embedded_files
+    }
+
+    #[setter]
+    fn set_extract_embedded_files(&mut self, value: bool) {
+        self.inner.extract_embedded_files = value;
+    }
+
+    #[getter]
+    fn strip_page_furniture(&self) -> bool {
+        self.inner.strip_page_furniture
+    }
+
+    #[setter]
+    fn set_strip_page_furniture(&mut self, value: bool) {
+        self.inner.strip_page_furniture = value;
+    }
+
+    #[getter]
+    fn ocr_image_regions(&self) -> bool {
+        self.inner.ocr_image_regions
+    }
+
+    #[setter]
+    fn set_ocr_image_regions(&mut self, value: bool) {
+        self.inner.ocr_image_regions = value;
+    }
+
+    #[getter]
+    fn extract_figures(&self) -> bool {
+        self.inner.extract_figures
+    }
+
+    #[setter]
+    fn set_extract_figures(&mut self, value: bool) {
+        self.inner.extract_figures = value;
+    }
+
+    #[getter]
+    fn detect_formulas(&self) -> bool {
+        self.inner.detect_formulas
+    }
+
+    #[setter]
+    fn set_detect_formulas(&mut self, value: bool) {
+        self.inner.detect_formulas = value;
+    }
+
+    #[getter]
+    fn text_layer_threshold(&self) -> f64 {
+        self.inner.text_layer_threshold
+    }
+
+    #[setter]
+    fn set_text_layer_threshold(&mut self, value: f64) {
+        self.inner.text_layer_threshold = value;
+    }
+
     #[getter]
     fn hierarchy(&self) -> Option<HierarchyConfig> {
         self.inner.hierarchy.clone().map(Into::into)
@@ -1160,8 +1294,10 @@ impl ImagePreprocessingConfig {
         denoise=None,
         contrast_enhance=None,
         binarization_method=None,
-        invert_colors=None
+        invert_colors=None,
+        binarize=None
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         target_dpi: Option<i32>,
         auto_rotate: Option<bool>,
@@ -1170,14 +1306,16 @@ impl ImagePreprocessingConfig {
         contrast_enhance: Option<bool>,
         binarization_method: Option<String>,
         invert_colors: Option<bool>,
+        binarize: Option<bool>,
     ) -> Self {
         Self {
             inner: kreuzberg::types::ImagePreprocessingConfig {
                 target_dpi: target_dpi.unwrap_or(300),
-                auto_rotate: auto_rotate.unwrap_or(true),
-                deskew: deskew.unwrap_or(true),
+                auto_rotate: auto_rotate.unwrap_or(false),
+                deskew: deskew.unwrap_or(false),
                 denoise: denoise.unwrap_or(false),
                 contrast_enhance: contrast_enhance.unwrap_or(false),
+                binarize: binarize.unwrap_or(true),
                 binarization_method: binarization_method.unwrap_or_else(|| "otsu".to_string()),
                 invert_colors: invert_colors.unwrap_or(false),
             },
@@ -1234,6 +1372,16 @@ impl ImagePreprocessingConfig {
         self.inner.contrast_enhance = value;
     }
 
+    #[getter]
+    fn binarize(&self) -> bool {
+        self.inner.binarize
+    }
+
+    #[setter]
+    fn set_binarize(&mut self, value: bool) {
+        self.inner.binarize = value;
+    }
+
     #[getter]
     fn binarization_method(&self) -> String {
         self.inner.binarization_method.clone()
@@ -1583,6 +1731,7 @@ impl TesseractConfig {
 /// Example:
 ///     >>> from kreuzberg import PageConfig
 ///     >>> config = PageConfig(extract_pages=True, insert_page_markers=True)
+///     >>> config = PageConfig(selection={"ranges": [[40, 55]]})
 #[pyclass(name = "PageConfig", module = "kreuzberg")]
 #[derive(Clone)]
 pub struct PageConfig {
@@ -1592,15 +1741,32 @@ pub struct PageConfig {
 #[pymethods]
 impl PageConfig {
     #[new]
-    #[pyo3(signature = (extract_pages=None, insert_page_markers=None, marker_format=None))]
-    fn new(extract_pages: Option<bool>, insert_page_markers: Option<bool>, marker_format: Option<String>) -> Self {
-        Self {
+    #[pyo3(signature = (extract_pages=None, insert_page_markers=None, marker_format=None, selection=None))]
+    fn new(
+        py: Python<'_>,
+        extract_pages: Option<bool>,
+        insert_page_markers: Option<bool>,
+        marker_format: Option<String>,
+        selection: Option<Bound<'_, pyo3::types::PyAny>>,
+    ) -> PyResult<Self> {
+        let selection = if let Some(obj) = selection {
+            let json_mod = py.import("json")?;
+            let json_str: String = json_mod.call_method1("dumps", (&obj,))?.extract()?;
+            Some(
+                serde_json::from_str(&json_str)
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid selection: {e}")))?,
+            )
+        } else {
+            None
+        };
+        Ok(Self {
             inner: kreuzberg::core::config::PageConfig {
                 extract_pages: extract_pages.unwrap_or(false),
                 insert_page_markers: insert_page_markers.unwrap_or(false),
                 marker_format: marker_format.unwrap_or_else(|| "\n\n<!-- PAGE {page_num} -->\n\n".to_string()),
+                selection,
             },
-        }
+        })
     }
 
     #[getter]
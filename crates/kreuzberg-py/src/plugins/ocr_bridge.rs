@@ -551,6 +551,8 @@ fn extract_tables(obj: &Bound<'_, PyAny>) -> Result<Vec<kreuzberg::types::Table>
             markdown,
             page_number,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         });
     }
 
@@ -503,6 +503,16 @@ impl ExtractionResult {
                     page_dict.set_item("is_blank", is_blank)?;
                 }
 
+                if !page.annotations.is_empty() {
+                    let annotations_json = serde_json::to_value(&page.annotations).map_err(|e| {
+                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                            "Failed to serialize page annotations: {}",
+                            e
+                        ))
+                    })?;
+                    page_dict.set_item("annotations", json_value_to_py(py, &annotations_json)?)?;
+                }
+
                 page_list.append(page_dict)?;
             }
             Some(page_list.unbind())
@@ -803,6 +813,8 @@ impl PyChunk {
 ///     cells (list[list[str]]): Table data as nested lists (rows of columns)
 ///     markdown (str): Markdown representation of the table
 ///     page_number (int): Page number where table was found
+///     end_page_number (int | None): Last page of a table continued across pages
+///     spans (list[dict]): Merged cells as dicts with row, col, row_span and col_span
 ///
 /// Example:
 ///     >>> result = extract_file_sync("document.pdf", None, ExtractionConfig())
@@ -822,6 +834,11 @@ pub struct ExtractedTable {
 
     #[pyo3(get)]
     pub bounding_box: Option<Py<PyDict>>,
+
+    #[pyo3(get)]
+    pub end_page_number: Option<usize>,
+
+    spans: Py<PyList>,
 }
 
 #[pymethods]
@@ -831,6 +848,11 @@ impl ExtractedTable {
         self.cells.bind(py).clone()
     }
 
+    #[getter]
+    fn spans<'py>(&self, py: Python<'py>) -> Bound<'py, PyList> {
+        self.spans.bind(py).clone()
+    }
+
     fn __repr__(&self) -> String {
         Python::attach(|py| {
             let rows = self.cells.bind(py).len();
@@ -947,11 +969,23 @@ impl ExtractedTable {
             dict.unbind()
         });
 
+        let spans = PyList::empty(py);
+        for span in table.spans {
+            let dict = PyDict::new(py);
+            dict.set_item("row", span.row)?;
+            dict.set_item("col", span.col)?;
+            dict.set_item("row_span", span.row_span)?;
+            dict.set_item("col_span", span.col_span)?;
+            spans.append(dict)?;
+        }
+
         Ok(Self {
             cells: cells.unbind(),
             markdown: table.markdown,
             page_number: table.page_number,
             bounding_box,
+            end_page_number: table.end_page_number,
+            spans: spans.unbind(),
         })
     }
 }
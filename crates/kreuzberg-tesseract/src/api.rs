@@ -681,6 +681,10 @@ impl TesseractAPI {
     /// # Returns
     ///
     /// Returns a tuple containing the orientation in degrees, the orientation confidence, the script name, and the script confidence.
+    ///
+    /// The orientation is the clockwise angle the page content is turned by; rotating the
+    /// image by `(360 - orientation) % 360` degrees clockwise makes it upright. Requires the `osd`
+    /// language to be loaded.
     pub fn detect_os(&self) -> Result<(i32, f32, String, f32)> {
         let handle = self.handle.lock().map_err(|_| TesseractError::MutexLockError)?;
         let mut orient_deg = 0;
@@ -706,13 +710,9 @@ impl TesseractAPI {
         }
         let script_name = if !script_name_ptr.is_null() {
             // SAFETY: script_name_ptr is non-null and points to a valid null-terminated C string
-            // allocated by Tesseract. We read it and then free it with TessDeleteText.
+            // owned by the engine's unicharset. It is copied here and must NOT be freed.
             let c_str = unsafe { CStr::from_ptr(script_name_ptr) };
-            let result = c_str.to_str()?.to_owned();
-            // SAFETY: TessDeleteText() must be called exactly once to free the string allocated
-            // by TessBaseAPIDetectOrientationScript()
-            unsafe { TessDeleteText(script_name_ptr) };
-            result
+            c_str.to_str()?.to_owned()
         } else {
             String::new()
         };
@@ -1,8 +1,15 @@
 //! API request handlers.
 
-use axum::{Json, extract::State};
+use axum::{
+    Json,
+    body::Body,
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use futures::StreamExt;
 
-use crate::{batch_extract_bytes, cache, extract_bytes};
+use crate::{batch_extract_bytes, cache, extract_bytes, extract_bytes_pages};
 
 use super::{
     error::{ApiError, JsonApi, MultipartApi},
@@ -103,8 +110,114 @@ pub async fn info_handler() -> Json<InfoResponse> {
 )]
 pub async fn extract_handler(
     State(state): State<ApiState>,
-    MultipartApi(mut multipart): MultipartApi,
+    MultipartApi(multipart): MultipartApi,
 ) -> Result<Json<ExtractResponse>, ApiError> {
+    let (files, config) = read_extract_form(&state, multipart).await?;
+
+    if files.is_empty() {
+        return Err(ApiError::validation(crate::error::KreuzbergError::validation(
+            "No files provided for extraction",
+        )));
+    }
+
+    #[cfg(feature = "otel")]
+    tracing::Span::current().record("files_count", files.len());
+
+    // Use provided config or fall back to default from state
+    let final_config = config.as_ref().unwrap_or(&state.default_config);
+
+    if files.len() == 1 {
+        let (data, mime_type, _file_name) = files
+            .into_iter()
+            .next()
+            .expect("files.len() == 1 guarantees one element exists");
+        let result = extract_bytes(&data, mime_type.as_str(), final_config).await?;
+        return Ok(Json(vec![result]));
+    }
+
+    let files_data: Vec<(Vec<u8>, String)> = files.into_iter().map(|(data, mime, _name)| (data, mime)).collect();
+
+    let results = batch_extract_bytes(files_data, final_config).await?;
+    Ok(Json(results))
+}
+
+/// Streaming extract endpoint handler.
+///
+/// POST /extract/stream
+///
+/// Accepts the same multipart form as `/extract` with exactly one file, and
+/// streams its pages as newline-delimited JSON (`application/x-ndjson`): one
+/// `PageContent` object per line, in page order, as soon as each page is
+/// extracted. Errors up to and including the first page (such as a corrupt or
+/// password-protected PDF) are returned as a regular error response; an error
+/// after that is written as an `ErrorResponse` line.
+#[utoipa::path(
+    post,
+    path = "/extract/stream",
+    tag = "extraction",
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Pages as newline-delimited JSON", body = crate::types::PageContent, content_type = "application/x-ndjson"),
+        (status = 400, description = "Bad request", body = crate::api::types::ErrorResponse),
+        (status = 413, description = "Payload too large", body = crate::api::types::ErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
+    )
+)]
+#[cfg_attr(
+    feature = "otel",
+    tracing::instrument(name = "api.extract_stream", skip(state, multipart))
+)]
+pub async fn extract_stream_handler(
+    State(state): State<ApiState>,
+    MultipartApi(multipart): MultipartApi,
+) -> Result<Response, ApiError> {
+    let (files, config) = read_extract_form(&state, multipart).await?;
+    let Ok([(data, mime_type, _file_name)]) = <[UploadedFile; 1]>::try_from(files) else {
+        return Err(ApiError::validation(crate::error::KreuzbergError::validation(
+            "Streaming extraction takes exactly one file",
+        )));
+    };
+    let config = config.unwrap_or_else(|| (*state.default_config).clone());
+
+    let mut pages = extract_bytes_pages(data, &mime_type, &config).await?;
+    // A document that cannot be opened (a corrupt or password-protected PDF) fails
+    // on its first item, so wait for it before committing to a 200 response.
+    let first = match pages.next().await {
+        Some(Err(e)) => return Err(e.into()),
+        first => first,
+    };
+    let lines = futures::stream::iter(first).chain(pages).map(|page| {
+        let line = match page {
+            Ok(page) => serde_json::to_vec(&page).map_err(|e| crate::error::KreuzbergError::Serialization {
+                message: format!("Failed to serialize page: {}", e),
+                source: None,
+            }),
+            Err(e) => Err(e),
+        };
+        let mut line =
+            line.unwrap_or_else(|e| serde_json::to_vec(&ApiError::from(e).body).unwrap_or_else(|_| b"{}".to_vec()));
+        line.push(b'\n');
+        Ok::<_, std::convert::Infallible>(line)
+    });
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(lines),
+    )
+        .into_response())
+}
+
+/// Files uploaded to an extract endpoint: bytes, MIME type and file name.
+type UploadedFile = (Vec<u8>, String, Option<String>);
+
+/// Read the multipart form of the extract endpoints.
+///
+/// Returns the uploaded files and the request's extraction config, if it set
+/// `config` or `output_format`.
+async fn read_extract_form(
+    state: &ApiState,
+    mut multipart: axum::extract::Multipart,
+) -> Result<(Vec<UploadedFile>, Option<crate::core::config::ExtractionConfig>), ApiError> {
     let mut files = Vec::new();
     let mut config: Option<crate::core::config::ExtractionConfig> = None;
 
@@ -134,12 +247,16 @@ pub async fn extract_handler(
                     .await
                     .map_err(|e| ApiError::validation(crate::error::KreuzbergError::validation(e.to_string())))?;
 
-                config = Some(serde_json::from_str(&config_str).map_err(|e| {
-                    ApiError::validation(crate::error::KreuzbergError::validation(format!(
-                        "Invalid extraction configuration: {}",
-                        e
-                    )))
-                })?);
+                let mut request_config: crate::core::config::ExtractionConfig = serde_json::from_str(&config_str)
+                    .map_err(|e| {
+                        ApiError::validation(crate::error::KreuzbergError::validation(format!(
+                            "Invalid extraction configuration: {}",
+                            e
+                        )))
+                    })?;
+                // The cache backend is a server-side setting; requests cannot redirect it ~keep
+                request_config.cache = state.default_config.cache.clone();
+                config = Some(request_config);
             }
             "output_format" => {
                 let format_str = field
@@ -154,9 +271,11 @@ pub async fn extract_handler(
                     "markdown" => crate::core::config::OutputFormat::Markdown,
                     "djot" => crate::core::config::OutputFormat::Djot,
                     "html" => crate::core::config::OutputFormat::Html,
+                    "hocr" => crate::core::config::OutputFormat::Hocr,
+                    "alto" => crate::core::config::OutputFormat::Alto,
                     _ => {
                         return Err(ApiError::validation(crate::error::KreuzbergError::validation(format!(
-                            "Invalid output_format: '{}'. Valid values: 'plain', 'markdown', 'djot', 'html'",
+                            "Invalid output_format: '{}'. Valid values: 'plain', 'markdown', 'djot', 'html', 'hocr', 'alto'",
                             format_str
                         ))));
                     }
@@ -166,42 +285,33 @@ pub async fn extract_handler(
         }
     }
 
-    if files.is_empty() {
-        return Err(ApiError::validation(crate::error::KreuzbergError::validation(
-            "No files provided for extraction",
-        )));
-    }
-
-    #[cfg(feature = "otel")]
-    tracing::Span::current().record("files_count", files.len());
-
-    // Use provided config or fall back to default from state
-    let final_config = config.as_ref().unwrap_or(&state.default_config);
+    Ok((files, config))
+}
 
-    if files.len() == 1 {
-        let (data, mime_type, _file_name) = files
-            .into_iter()
-            .next()
-            .expect("files.len() == 1 guarantees one element exists");
-        let result = extract_bytes(&data, mime_type.as_str(), final_config).await?;
-        return Ok(Json(vec![result]));
+/// Backend holding the server's cache.
+///
+/// Uses the `cache` section of the server's default extraction config (set
+/// directly or inherited from `ServerConfig::cache`), falling back to the
+/// filesystem cache root (`KREUZBERG_CACHE_DIR` or `.kreuzberg`).
+fn configured_cache_backend(state: &ApiState) -> Result<std::sync::Arc<dyn cache::CacheBackend>, ApiError> {
+    match &state.default_config.cache {
+        Some(cache_config) => cache::shared_backend(cache_config),
+        None => cache::shared_backend(&crate::core::config::CacheConfig::default()),
     }
-
-    let files_data: Vec<(Vec<u8>, String)> = files.into_iter().map(|(data, mime, _name)| (data, mime)).collect();
-
-    let results = batch_extract_bytes(files_data, final_config).await?;
-    Ok(Json(results))
+    .map_err(ApiError::internal)
 }
 
 /// Cache stats endpoint handler.
 ///
 /// GET /cache/stats
 ///
+/// Reports on every cache namespace held by the configured backend (extraction
+/// and OCR results).
+///
 /// # Errors
 ///
 /// Returns `ApiError::Internal` if:
-/// - Current directory cannot be determined
-/// - Cache directory path contains non-UTF8 characters
+/// - The cache backend cannot be opened
 /// - Cache metadata retrieval fails
 #[utoipa::path(
     get,
@@ -212,28 +322,14 @@ pub async fn extract_handler(
         (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
     )
 )]
-#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_stats"))]
-pub async fn cache_stats_handler() -> Result<Json<CacheStatsResponse>, ApiError> {
-    let cache_dir = std::env::current_dir()
-        .map_err(|e| {
-            ApiError::internal(crate::error::KreuzbergError::Other(format!(
-                "Failed to get current directory: {}",
-                e
-            )))
-        })?
-        .join(".kreuzberg");
-
-    let cache_dir_str = cache_dir.to_str().ok_or_else(|| {
-        ApiError::internal(crate::error::KreuzbergError::Other(format!(
-            "Cache directory path contains non-UTF8 characters: {}",
-            cache_dir.display()
-        )))
-    })?;
-
-    let stats = cache::get_cache_metadata(cache_dir_str).map_err(ApiError::internal)?;
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_stats", skip(state)))]
+pub async fn cache_stats_handler(State(state): State<ApiState>) -> Result<Json<CacheStatsResponse>, ApiError> {
+    let backend = configured_cache_backend(&state)?;
+    let stats = backend.stats(None).map_err(ApiError::internal)?;
 
     Ok(Json(CacheStatsResponse {
-        directory: cache_dir.to_string_lossy().to_string(),
+        directory: backend.location(None).to_string_lossy().to_string(),
+        backend: backend.name().to_string(),
         total_files: stats.total_files,
         total_size_mb: stats.total_size_mb,
         available_space_mb: stats.available_space_mb,
@@ -246,11 +342,12 @@ pub async fn cache_stats_handler() -> Result<Json<CacheStatsResponse>, ApiError>
 ///
 /// DELETE /cache/clear
 ///
+/// Removes every entry of the configured backend (extraction and OCR results).
+///
 /// # Errors
 ///
 /// Returns `ApiError::Internal` if:
-/// - Current directory cannot be determined
-/// - Cache directory path contains non-UTF8 characters
+/// - The cache backend cannot be opened
 /// - Cache clearing operation fails
 #[utoipa::path(
     delete,
@@ -261,28 +358,14 @@ pub async fn cache_stats_handler() -> Result<Json<CacheStatsResponse>, ApiError>
         (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
     )
 )]
-#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_clear"))]
-pub async fn cache_clear_handler() -> Result<Json<CacheClearResponse>, ApiError> {
-    let cache_dir = std::env::current_dir()
-        .map_err(|e| {
-            ApiError::internal(crate::error::KreuzbergError::Other(format!(
-                "Failed to get current directory: {}",
-                e
-            )))
-        })?
-        .join(".kreuzberg");
-
-    let cache_dir_str = cache_dir.to_str().ok_or_else(|| {
-        ApiError::internal(crate::error::KreuzbergError::Other(format!(
-            "Cache directory path contains non-UTF8 characters: {}",
-            cache_dir.display()
-        )))
-    })?;
-
-    let (removed_files, freed_mb) = cache::clear_cache_directory(cache_dir_str).map_err(ApiError::internal)?;
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.cache_clear", skip(state)))]
+pub async fn cache_clear_handler(State(state): State<ApiState>) -> Result<Json<CacheClearResponse>, ApiError> {
+    let backend = configured_cache_backend(&state)?;
+    let (removed_files, freed_mb) = backend.clear(None).map_err(ApiError::internal)?;
 
     Ok(Json(CacheClearResponse {
-        directory: cache_dir.to_string_lossy().to_string(),
+        directory: backend.location(None).to_string_lossy().to_string(),
+        backend: backend.name().to_string(),
         removed_files,
         freed_mb,
     }))
@@ -6,6 +6,7 @@
 //! # Endpoints
 //!
 //! - `POST /extract` - Extract text from uploaded files (multipart form data)
+//! - `POST /extract/stream` - Stream the pages of one uploaded file as NDJSON
 //! - `POST /embed` - Generate embeddings for text (JSON body with texts array)
 //! - `POST /chunk` - Chunk text into smaller pieces (JSON body with text and config)
 //! - `GET /health` - Health check endpoint
@@ -61,6 +62,9 @@
 //!      -F 'config={"ocr":{"language":"eng"}}' \
 //!      http://localhost:8000/extract
 //!
+//! # Stream the pages of a large PDF as they are extracted
+//! curl -N -F "files=@large.pdf" http://localhost:8000/extract/stream
+//!
 //! # Health check
 //! curl http://localhost:8000/health
 //!
@@ -33,6 +33,7 @@ use utoipa::OpenApi;
         crate::api::handlers::health_handler,
         crate::api::handlers::info_handler,
         crate::api::handlers::extract_handler,
+        crate::api::handlers::extract_stream_handler,
         crate::api::handlers::cache_stats_handler,
         crate::api::handlers::cache_clear_handler,
         crate::api::handlers::embed_handler,
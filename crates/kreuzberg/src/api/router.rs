@@ -17,8 +17,8 @@ use crate::{ExtractionConfig, core::ServerConfig};
 
 use super::{
     handlers::{
-        cache_clear_handler, cache_stats_handler, chunk_handler, embed_handler, extract_handler, health_handler,
-        info_handler,
+        cache_clear_handler, cache_stats_handler, chunk_handler, embed_handler, extract_handler,
+        extract_stream_handler, health_handler, info_handler,
     },
     types::{ApiSizeLimits, ApiState},
 };
@@ -95,6 +95,7 @@ pub fn create_router_with_limits(config: ExtractionConfig, limits: ApiSizeLimits
 /// * `config` - Default extraction configuration. Per-request configs override these defaults.
 /// * `limits` - Size limits for request bodies and multipart uploads.
 /// * `server_config` - Server configuration including host, port, and CORS settings.
+///   Its `cache` section is used when `config.cache` is unset.
 ///
 /// # Examples
 ///
@@ -119,6 +120,11 @@ pub fn create_router_with_limits_and_server_config(
     limits: ApiSizeLimits,
     server_config: ServerConfig,
 ) -> Router {
+    let mut config = config;
+    if config.cache.is_none() {
+        config.cache = server_config.cache.clone();
+    }
+
     let state = ApiState {
         default_config: Arc::new(config),
     };
@@ -155,6 +161,7 @@ pub fn create_router_with_limits_and_server_config(
 
     let mut router = Router::new()
         .route("/extract", post(extract_handler))
+        .route("/extract/stream", post(extract_stream_handler))
         .route("/embed", post(embed_handler))
         .route("/chunk", post(chunk_handler))
         .route("/health", get(health_handler))
@@ -184,9 +184,12 @@ pub struct ApiState {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
 pub struct CacheStatsResponse {
-    /// Cache directory path
+    /// Cache location (directory or database file)
     #[cfg_attr(feature = "api", schema(example = "/tmp/kreuzberg-cache"))]
     pub directory: String,
+    /// Cache backend name (filesystem, sqlite, memory)
+    #[cfg_attr(feature = "api", schema(example = "filesystem"))]
+    pub backend: String,
     /// Total number of cache files
     pub total_files: usize,
     /// Total cache size in MB
@@ -203,9 +206,12 @@ pub struct CacheStatsResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
 pub struct CacheClearResponse {
-    /// Cache directory path
+    /// Cache location (directory or database file)
     #[cfg_attr(feature = "api", schema(example = "/tmp/kreuzberg-cache"))]
     pub directory: String,
+    /// Cache backend name (filesystem, sqlite, memory)
+    #[cfg_attr(feature = "api", schema(example = "filesystem"))]
+    pub backend: String,
     /// Number of files removed
     pub removed_files: usize,
     /// Space freed in MB
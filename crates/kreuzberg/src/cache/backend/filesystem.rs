@@ -0,0 +1,284 @@
+//! Filesystem cache backend: one msgpack file per entry.
+//!
+//! Layout: `<root>/<namespace>/<key>.msgpack`. This is the layout Kreuzberg has
+//! always used, so existing cache directories keep working and the standalone
+//! helpers in [`crate::cache`] (`get_cache_metadata`, `smart_cleanup_cache`,
+//! `clear_cache_directory`) keep operating on the same files.
+
+use crate::error::{KreuzbergError, Result};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
+
+use super::super::cleanup::{collect_cache_files, get_cache_metadata, smart_cleanup_cache};
+use super::super::core::CacheStats;
+use super::CacheBackend;
+
+/// Run `smart_cleanup_cache` every this many writes.
+const CLEANUP_INTERVAL: usize = 100;
+
+pub struct FilesystemBackend {
+    root: PathBuf,
+    max_age_days: f64,
+    max_cache_size_mb: f64,
+    min_free_space_mb: f64,
+    /// Tracks cache files being deleted to prevent read-during-delete race conditions
+    deleting_files: Mutex<HashSet<PathBuf>>,
+    /// Counter for triggering periodic cleanup
+    write_counter: AtomicUsize,
+}
+
+impl FilesystemBackend {
+    pub fn new(root: PathBuf, max_age_days: f64, max_cache_size_mb: f64, min_free_space_mb: f64) -> Result<Self> {
+        fs::create_dir_all(&root)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
+
+        Ok(Self {
+            root,
+            max_age_days,
+            max_cache_size_mb,
+            min_free_space_mb,
+            deleting_files: Mutex::new(HashSet::new()),
+            write_counter: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn namespace_dir(&self, namespace: &str) -> PathBuf {
+        self.root.join(namespace)
+    }
+
+    fn entry_path(&self, namespace: &str, key: &str) -> PathBuf {
+        self.namespace_dir(namespace).join(format!("{}.msgpack", key))
+    }
+
+    fn is_expired(&self, path: &Path) -> bool {
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|elapsed| elapsed.as_secs() as f64 / (24.0 * 3600.0) > self.max_age_days)
+    }
+
+    fn is_being_deleted(&self, path: &Path) -> Result<bool> {
+        let deleting = self
+            .deleting_files
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
+        Ok(deleting.contains(path))
+    }
+
+    /// Mark a file path as being deleted to prevent concurrent reads.
+    ///
+    /// # TOCTOU Race Condition
+    ///
+    /// There is a Time-Of-Check-To-Time-Of-Use (TOCTOU) race condition between:
+    /// 1. Iterating directory entries in `clear()` (getting path/metadata)
+    /// 2. Marking the file for deletion here
+    /// 3. Actually deleting the file
+    ///
+    /// **Race scenario:**
+    /// - Thread A: Begins iterating in `clear()`, gets path
+    /// - Thread B: Calls `get()`, checks `deleting_files` (not marked yet), proceeds
+    /// - Thread A: Calls `mark_for_deletion()` here
+    /// - Thread A: Deletes file with `fs::remove_file()`
+    /// - Thread B: Tries to read file, but it's already deleted
+    ///
+    /// **Why this is acceptable:**
+    /// - Cache operations are best-effort optimizations, not critical
+    /// - `get()` already handles file read failures gracefully (treats as cache miss)
+    /// - The worst case is a failed read → cache miss → recomputation
+    /// - No data corruption or invariant violations occur
+    /// - Alternative (atomic operation) would require complex locking impacting performance
+    fn mark_for_deletion(&self, path: &Path) -> Result<()> {
+        let mut deleting = self
+            .deleting_files
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
+        deleting.insert(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Remove a file path from the deletion set
+    fn unmark_deletion(&self, path: &Path) -> Result<()> {
+        let mut deleting = self
+            .deleting_files
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
+        deleting.remove(path);
+        Ok(())
+    }
+
+    fn path_str(path: &Path) -> Result<&str> {
+        path.to_str()
+            .ok_or_else(|| KreuzbergError::validation("Cache directory path contains invalid UTF-8".to_string()))
+    }
+}
+
+impl CacheBackend for FilesystemBackend {
+    fn name(&self) -> &'static str {
+        "filesystem"
+    }
+
+    fn get(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.entry_path(namespace, key);
+
+        if self.is_being_deleted(&path)? || !path.exists() || self.is_expired(&path) {
+            return Ok(None);
+        }
+
+        match fs::read(&path) {
+            Ok(content) => Ok(Some(content)),
+            Err(e) => {
+                // Best-effort cleanup of corrupted cache files ~keep
+                tracing::debug!("Failed to read cache file {:?}: {}", path, e);
+                if let Err(e) = fs::remove_file(&path) {
+                    tracing::debug!("Failed to remove corrupted cache file: {}", e);
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    fn set(&self, namespace: &str, key: &str, data: &[u8]) -> Result<()> {
+        let dir = self.namespace_dir(namespace);
+        fs::create_dir_all(&dir)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
+
+        // Write to a temp file and rename so concurrent readers (or replicas on a
+        // shared volume) never observe a partially written entry ~keep
+        let path = self.entry_path(namespace, key);
+        let temp_path = dir.join(format!(
+            "{}.tmp.{}.{:?}",
+            key,
+            std::process::id(),
+            std::thread::current().id()
+        ));
+
+        fs::write(&temp_path, data).map_err(|e| KreuzbergError::cache(format!("Failed to write cache file: {}", e)))?;
+        fs::rename(&temp_path, &path).map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            KreuzbergError::cache(format!("Failed to write cache file: {}", e))
+        })?;
+
+        let count = self.write_counter.fetch_add(1, Ordering::Relaxed);
+        if count.is_multiple_of(CLEANUP_INTERVAL) {
+            // Cache cleanup failure - safe to ignore, cache is optional fallback ~keep
+            let _ = self.cleanup();
+        }
+
+        Ok(())
+    }
+
+    fn remove(&self, namespace: &str, key: &str) -> Result<bool> {
+        let path = self.entry_path(namespace, key);
+        match fs::remove_file(&path) {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(KreuzbergError::cache(format!("Failed to remove cache file: {}", e))),
+        }
+    }
+
+    fn clear(&self, namespace: Option<&str>) -> Result<(usize, f64)> {
+        let dir_path = match namespace {
+            Some(namespace) => self.namespace_dir(namespace),
+            None => self.root.clone(),
+        };
+
+        if !dir_path.exists() {
+            return Ok((0, 0.0));
+        }
+
+        let mut removed_count = 0;
+        let mut removed_size = 0.0;
+
+        for (path, metadata) in collect_cache_files(&dir_path)? {
+            let size_mb = metadata.len() as f64 / (1024.0 * 1024.0);
+
+            // Mark file for deletion to prevent concurrent access ~keep
+            if let Err(e) = self.mark_for_deletion(&path) {
+                tracing::debug!("Failed to mark file for deletion: {} (continuing anyway)", e);
+            }
+
+            match fs::remove_file(&path) {
+                Ok(_) => {
+                    removed_count += 1;
+                    removed_size += size_mb;
+                }
+                Err(e) => {
+                    tracing::debug!("Failed to remove {:?}: {}", path, e);
+                }
+            }
+
+            // Unmark after deletion (successful or not) to allow retries ~keep
+            if let Err(e) = self.unmark_deletion(&path) {
+                tracing::debug!("Failed to unmark deleted file: {} (non-critical)", e);
+            }
+        }
+
+        Ok((removed_count, removed_size))
+    }
+
+    fn stats(&self, namespace: Option<&str>) -> Result<CacheStats> {
+        let dir_path = self.location(namespace);
+        get_cache_metadata(Self::path_str(&dir_path)?)
+    }
+
+    fn cleanup(&self) -> Result<(usize, f64)> {
+        smart_cleanup_cache(
+            Self::path_str(&self.root)?,
+            self.max_age_days,
+            self.max_cache_size_mb,
+            self.min_free_space_mb,
+        )
+    }
+
+    fn location(&self, namespace: Option<&str>) -> PathBuf {
+        match namespace {
+            Some(namespace) => self.namespace_dir(namespace),
+            None => self.root.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_filesystem_layout_matches_legacy() {
+        let temp_dir = tempdir().unwrap();
+        let backend = FilesystemBackend::new(temp_dir.path().to_path_buf(), 30.0, 100.0, 0.0).unwrap();
+
+        backend.set("results", "abc", b"data").unwrap();
+        assert!(temp_dir.path().join("results").join("abc.msgpack").exists());
+        assert_eq!(
+            get_cache_metadata(temp_dir.path().to_str().unwrap())
+                .unwrap()
+                .total_files,
+            1
+        );
+    }
+
+    #[test]
+    fn test_filesystem_ttl_expiry() {
+        let temp_dir = tempdir().unwrap();
+        let backend = FilesystemBackend::new(temp_dir.path().to_path_buf(), 0.0, 100.0, 0.0).unwrap();
+
+        backend.set("results", "old", b"data").unwrap();
+        let path = backend.entry_path("results", "old");
+        let past = SystemTime::now() - std::time::Duration::from_secs(3600);
+        filetime::set_file_mtime(&path, filetime::FileTime::from_system_time(past)).unwrap();
+
+        assert!(backend.get("results", "old").unwrap().is_none());
+        assert_eq!(backend.cleanup().unwrap().0, 1);
+        assert!(!path.exists());
+    }
+}
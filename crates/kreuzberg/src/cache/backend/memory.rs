@@ -0,0 +1,266 @@
+//! In-memory LRU cache backend.
+//!
+//! Entries are kept in an `LruCache`, which tracks access order in a linked list,
+//! so lookups, inserts and evictions are O(1). Intended for short-lived workers
+//! where a disk cache would never be reused.
+
+use crate::error::{KreuzbergError, Result};
+use lru::LruCache;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use super::super::core::CacheStats;
+use super::{CacheBackend, EVICTION_TARGET_RATIO, age_days, now_secs};
+
+struct MemoryEntry {
+    data: Vec<u8>,
+    created_at: f64,
+}
+
+struct MemoryStore {
+    // Unbounded by count: the byte budget is enforced by `MemoryBackend::evict_to` ~keep
+    entries: LruCache<(String, String), MemoryEntry>,
+    total_bytes: usize,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            total_bytes: 0,
+        }
+    }
+}
+
+impl MemoryStore {
+    fn remove(&mut self, key: &(String, String)) -> Option<usize> {
+        let entry = self.entries.pop(key)?;
+        self.total_bytes -= entry.data.len();
+        Some(entry.data.len())
+    }
+
+    /// Remove every entry matching `predicate`.
+    fn remove_where(&mut self, predicate: impl Fn(&(String, String), &MemoryEntry) -> bool) {
+        let keys: Vec<(String, String)> = self
+            .entries
+            .iter()
+            .filter(|(key, entry)| predicate(key, entry))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &keys {
+            self.remove(key);
+        }
+    }
+}
+
+pub struct MemoryBackend {
+    max_age_days: f64,
+    max_size_bytes: usize,
+    store: Mutex<MemoryStore>,
+}
+
+impl MemoryBackend {
+    pub fn new(max_age_days: f64, max_size_mb: f64) -> Self {
+        Self {
+            max_age_days,
+            max_size_bytes: (max_size_mb.max(0.0) * 1024.0 * 1024.0) as usize,
+            store: Mutex::new(MemoryStore::default()),
+        }
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, MemoryStore>> {
+        self.store
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Memory cache mutex poisoned: {}", e)))
+    }
+
+    fn is_expired(&self, entry: &MemoryEntry, now: f64) -> bool {
+        age_days(entry.created_at, now) > self.max_age_days
+    }
+
+    /// Drop least recently used entries until the store fits `target_bytes`.
+    fn evict_to(store: &mut MemoryStore, target_bytes: usize) {
+        while store.total_bytes > target_bytes {
+            let Some((_, entry)) = store.entries.pop_lru() else {
+                break;
+            };
+            store.total_bytes -= entry.data.len();
+        }
+    }
+}
+
+fn bytes_to_mb(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+impl CacheBackend for MemoryBackend {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    fn get(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        let mut store = self.lock()?;
+        let lookup = (namespace.to_string(), key.to_string());
+
+        let expired = match store.entries.peek(&lookup) {
+            Some(entry) => self.is_expired(entry, now_secs()),
+            None => return Ok(None),
+        };
+        if expired {
+            store.remove(&lookup);
+            return Ok(None);
+        }
+
+        // `get` marks the entry as most recently used ~keep
+        Ok(store.entries.get(&lookup).map(|entry| entry.data.clone()))
+    }
+
+    fn set(&self, namespace: &str, key: &str, data: &[u8]) -> Result<()> {
+        if data.len() > self.max_size_bytes {
+            // A single entry larger than the whole budget would evict everything ~keep
+            return Ok(());
+        }
+
+        let mut store = self.lock()?;
+        let lookup = (namespace.to_string(), key.to_string());
+        store.remove(&lookup);
+
+        store.total_bytes += data.len();
+        store.entries.put(
+            lookup,
+            MemoryEntry {
+                data: data.to_vec(),
+                created_at: now_secs(),
+            },
+        );
+
+        if store.total_bytes > self.max_size_bytes {
+            let target = (self.max_size_bytes as f64 * EVICTION_TARGET_RATIO) as usize;
+            Self::evict_to(&mut store, target);
+        }
+
+        Ok(())
+    }
+
+    fn remove(&self, namespace: &str, key: &str) -> Result<bool> {
+        let mut store = self.lock()?;
+        let lookup = (namespace.to_string(), key.to_string());
+        Ok(store.remove(&lookup).is_some())
+    }
+
+    fn clear(&self, namespace: Option<&str>) -> Result<(usize, f64)> {
+        let mut store = self.lock()?;
+        let before_count = store.entries.len();
+        let before_bytes = store.total_bytes;
+
+        match namespace {
+            Some(namespace) => store.remove_where(|(ns, _), _| ns == namespace),
+            None => {
+                store.entries.clear();
+                store.total_bytes = 0;
+            }
+        }
+
+        Ok((
+            before_count - store.entries.len(),
+            bytes_to_mb(before_bytes - store.total_bytes),
+        ))
+    }
+
+    fn stats(&self, namespace: Option<&str>) -> Result<CacheStats> {
+        let store = self.lock()?;
+        let now = now_secs();
+
+        let mut total_files = 0;
+        let mut total_bytes = 0;
+        let mut oldest_age = 0.0f64;
+        let mut newest_age = f64::INFINITY;
+
+        for ((ns, _), entry) in &store.entries {
+            if namespace.is_some_and(|namespace| namespace != ns) {
+                continue;
+            }
+            total_files += 1;
+            total_bytes += entry.data.len();
+            let age = age_days(entry.created_at, now);
+            oldest_age = oldest_age.max(age);
+            newest_age = newest_age.min(age);
+        }
+
+        if total_files == 0 {
+            newest_age = 0.0;
+        }
+
+        Ok(CacheStats {
+            total_files,
+            total_size_mb: bytes_to_mb(total_bytes),
+            available_space_mb: bytes_to_mb(self.max_size_bytes.saturating_sub(store.total_bytes)),
+            oldest_file_age_days: oldest_age,
+            newest_file_age_days: newest_age,
+        })
+    }
+
+    fn cleanup(&self) -> Result<(usize, f64)> {
+        let mut store = self.lock()?;
+        let now = now_secs();
+        let before_count = store.entries.len();
+        let before_bytes = store.total_bytes;
+
+        store.remove_where(|_, entry| self.is_expired(entry, now));
+
+        if store.total_bytes > self.max_size_bytes {
+            let target = (self.max_size_bytes as f64 * EVICTION_TARGET_RATIO) as usize;
+            Self::evict_to(&mut store, target);
+        }
+
+        Ok((
+            before_count - store.entries.len(),
+            bytes_to_mb(before_bytes - store.total_bytes),
+        ))
+    }
+
+    fn location(&self, _namespace: Option<&str>) -> PathBuf {
+        PathBuf::from(":memory:")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_backend_evicts_least_recently_used() {
+        // 1 KiB budget, 400 byte entries: the third insert exceeds the budget ~keep
+        let backend = MemoryBackend::new(30.0, 1.0 / 1024.0);
+        let entry = vec![0u8; 400];
+
+        backend.set("results", "a", &entry).unwrap();
+        backend.set("results", "b", &entry).unwrap();
+        assert!(backend.get("results", "a").unwrap().is_some());
+        backend.set("results", "c", &entry).unwrap();
+
+        assert!(backend.get("results", "b").unwrap().is_none());
+        assert!(backend.get("results", "a").unwrap().is_some());
+        assert!(backend.get("results", "c").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_memory_backend_ttl() {
+        let backend = MemoryBackend::new(0.0, 1.0);
+        backend.set("results", "a", b"data").unwrap();
+        {
+            let mut store = backend.lock().unwrap();
+            let lookup = ("results".to_string(), "a".to_string());
+            store.entries.peek_mut(&lookup).unwrap().created_at -= 3600.0;
+        }
+        assert!(backend.get("results", "a").unwrap().is_none());
+        assert_eq!(backend.stats(None).unwrap().total_files, 0);
+    }
+
+    #[test]
+    fn test_memory_backend_skips_oversized_entries() {
+        let backend = MemoryBackend::new(30.0, 1.0 / 1024.0);
+        backend.set("results", "big", &vec![0u8; 2048]).unwrap();
+        assert!(backend.get("results", "big").unwrap().is_none());
+    }
+}
@@ -0,0 +1,246 @@
+//! Pluggable storage backends for Kreuzberg caches.
+//!
+//! A [`CacheBackend`] stores opaque byte values under a `(namespace, key)` pair.
+//! Namespaces correspond to cache types (`results`, `ocr`, ...), so a single
+//! backend instance can be shared by every cache in the process.
+//!
+//! Three implementations are provided:
+//!
+//! - [`FilesystemBackend`]: one msgpack file per entry below a cache root (default)
+//! - [`SqliteBackend`]: a single SQLite database file (`cache-sqlite` feature)
+//! - [`MemoryBackend`]: a bounded in-process LRU
+//!
+//! Every backend treats entries older than `max_age_days` as expired and evicts
+//! least recently used entries once the store grows beyond `max_size_mb`.
+
+mod filesystem;
+mod memory;
+#[cfg(feature = "cache-sqlite")]
+mod sqlite;
+
+use crate::core::config::{CacheBackendType, CacheConfig};
+use crate::error::{KreuzbergError, Result};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use super::core::CacheStats;
+use super::results::resolve_cache_dir;
+
+pub use filesystem::FilesystemBackend;
+pub use memory::MemoryBackend;
+#[cfg(feature = "cache-sqlite")]
+pub use sqlite::SqliteBackend;
+
+/// Fraction of `max_size_mb` a size-triggered eviction shrinks the store to.
+pub(crate) const EVICTION_TARGET_RATIO: f64 = 0.8;
+
+/// Storage backend for cached byte values.
+///
+/// Implementations must be safe to share between threads. Cache operations are
+/// best-effort: callers treat errors from `get` as misses and errors from `set`
+/// as non-fatal.
+pub trait CacheBackend: Send + Sync {
+    /// Short backend identifier (`filesystem`, `sqlite`, `memory`).
+    fn name(&self) -> &'static str;
+
+    /// Fetch an entry. Expired entries are reported as misses.
+    fn get(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Store an entry, replacing any previous value for the key.
+    fn set(&self, namespace: &str, key: &str, data: &[u8]) -> Result<()>;
+
+    /// Remove an entry. Returns `true` if something was deleted.
+    fn remove(&self, namespace: &str, key: &str) -> Result<bool>;
+
+    /// Remove all entries of `namespace`, or every entry when `None`.
+    ///
+    /// Returns the number of removed entries and the freed size in MB.
+    fn clear(&self, namespace: Option<&str>) -> Result<(usize, f64)>;
+
+    /// Statistics for `namespace`, or for the whole store when `None`.
+    fn stats(&self, namespace: Option<&str>) -> Result<CacheStats>;
+
+    /// Apply TTL and size-based eviction across all namespaces.
+    ///
+    /// Returns the number of removed entries and the freed size in MB.
+    fn cleanup(&self) -> Result<(usize, f64)>;
+
+    /// Human readable location of the store (directory, database file, ...).
+    ///
+    /// For the filesystem backend a namespace resolves to its subdirectory.
+    fn location(&self, namespace: Option<&str>) -> PathBuf;
+}
+
+static SHARED_BACKENDS: Lazy<Mutex<HashMap<String, Arc<dyn CacheBackend>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Create a new backend instance for `config`.
+pub fn create_backend(config: &CacheConfig) -> Result<Arc<dyn CacheBackend>> {
+    let location = match &config.path {
+        Some(path) => path.clone(),
+        None => resolve_cache_dir()?,
+    };
+
+    match config.backend {
+        CacheBackendType::Filesystem => Ok(Arc::new(FilesystemBackend::new(
+            location,
+            config.max_age_days,
+            config.max_size_mb,
+            config.min_free_space_mb,
+        )?)),
+        CacheBackendType::Memory => Ok(Arc::new(MemoryBackend::new(config.max_age_days, config.max_size_mb))),
+        #[cfg(feature = "cache-sqlite")]
+        CacheBackendType::Sqlite => Ok(Arc::new(SqliteBackend::open(
+            sqlite_database_path(&location),
+            config.max_age_days,
+            config.max_size_mb,
+            config.min_free_space_mb,
+        )?)),
+        #[cfg(not(feature = "cache-sqlite"))]
+        CacheBackendType::Sqlite => Err(KreuzbergError::MissingDependency(
+            "The SQLite cache backend requires the 'cache-sqlite' feature".to_string(),
+        )),
+    }
+}
+
+/// Return the process-wide backend for `config`, creating it on first use.
+///
+/// Caches configured identically share one instance, so the memory backend
+/// keeps its entries across calls and the SQLite backend reuses its connection.
+pub fn shared_backend(config: &CacheConfig) -> Result<Arc<dyn CacheBackend>> {
+    let registry_key = serde_json::to_string(config)
+        .map_err(|e| KreuzbergError::cache(format!("Failed to serialize cache config: {}", e)))?;
+
+    let mut backends = SHARED_BACKENDS
+        .lock()
+        .map_err(|e| KreuzbergError::LockPoisoned(format!("Cache backend registry mutex poisoned: {}", e)))?;
+
+    if let Some(backend) = backends.get(&registry_key) {
+        return Ok(Arc::clone(backend));
+    }
+
+    let backend = create_backend(config)?;
+    backends.insert(registry_key, Arc::clone(&backend));
+    Ok(backend)
+}
+
+/// Resolve the database file for the SQLite backend.
+///
+/// Paths without an extension (or existing directories) are treated as cache
+/// directories holding `cache.sqlite`.
+#[cfg_attr(not(feature = "cache-sqlite"), allow(dead_code))]
+fn sqlite_database_path(location: &Path) -> PathBuf {
+    if location.is_dir() || location.extension().is_none() {
+        location.join("cache.sqlite")
+    } else {
+        location.to_path_buf()
+    }
+}
+
+/// Age in days of a UNIX timestamp (seconds) relative to `now`.
+pub(crate) fn age_days(timestamp_secs: f64, now_secs: f64) -> f64 {
+    ((now_secs - timestamp_secs) / (24.0 * 3600.0)).max(0.0)
+}
+
+/// Current UNIX time in (fractional) seconds.
+pub(crate) fn now_secs() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn exercise_backend(backend: &dyn CacheBackend) {
+        assert!(backend.get("results", "k1").unwrap().is_none());
+
+        backend.set("results", "k1", b"one").unwrap();
+        backend.set("ocr", "k1", b"other namespace").unwrap();
+        assert_eq!(backend.get("results", "k1").unwrap().as_deref(), Some(&b"one"[..]));
+        assert_eq!(
+            backend.get("ocr", "k1").unwrap().as_deref(),
+            Some(&b"other namespace"[..])
+        );
+
+        backend.set("results", "k1", b"replaced").unwrap();
+        assert_eq!(backend.get("results", "k1").unwrap().as_deref(), Some(&b"replaced"[..]));
+
+        assert_eq!(backend.stats(Some("results")).unwrap().total_files, 1);
+        assert_eq!(backend.stats(None).unwrap().total_files, 2);
+
+        assert!(backend.remove("results", "k1").unwrap());
+        assert!(!backend.remove("results", "k1").unwrap());
+
+        backend.set("results", "k2", b"two").unwrap();
+        assert_eq!(backend.clear(Some("results")).unwrap().0, 1);
+        assert!(backend.get("ocr", "k1").unwrap().is_some());
+        assert_eq!(backend.clear(None).unwrap().0, 1);
+        assert_eq!(backend.stats(None).unwrap().total_files, 0);
+    }
+
+    #[test]
+    fn test_filesystem_backend_contract() {
+        let temp_dir = tempdir().unwrap();
+        let backend = FilesystemBackend::new(temp_dir.path().to_path_buf(), 30.0, 100.0, 0.0).unwrap();
+        exercise_backend(&backend);
+    }
+
+    #[test]
+    fn test_memory_backend_contract() {
+        let backend = MemoryBackend::new(30.0, 100.0);
+        exercise_backend(&backend);
+    }
+
+    #[cfg(feature = "cache-sqlite")]
+    #[test]
+    fn test_sqlite_backend_contract() {
+        let temp_dir = tempdir().unwrap();
+        let backend = SqliteBackend::open(temp_dir.path().join("cache.sqlite"), 30.0, 100.0, 0.0).unwrap();
+        exercise_backend(&backend);
+    }
+
+    #[test]
+    fn test_shared_backend_reuses_instances() {
+        let config = CacheConfig {
+            backend: CacheBackendType::Memory,
+            max_size_mb: 7.0,
+            ..Default::default()
+        };
+        let first = shared_backend(&config).unwrap();
+        first.set("results", "shared", b"value").unwrap();
+
+        let second = shared_backend(&config).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(second.get("results", "shared").unwrap().is_some());
+    }
+
+    #[cfg(not(feature = "cache-sqlite"))]
+    #[test]
+    fn test_sqlite_backend_requires_feature() {
+        let config = CacheConfig {
+            backend: CacheBackendType::Sqlite,
+            ..Default::default()
+        };
+        assert!(matches!(
+            create_backend(&config),
+            Err(KreuzbergError::MissingDependency(_))
+        ));
+    }
+
+    #[test]
+    fn test_sqlite_database_path() {
+        assert_eq!(
+            sqlite_database_path(Path::new("/tmp/kreuzberg-cache")),
+            PathBuf::from("/tmp/kreuzberg-cache/cache.sqlite")
+        );
+        assert_eq!(
+            sqlite_database_path(Path::new("/tmp/shared.db")),
+            PathBuf::from("/tmp/shared.db")
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! SQLite cache backend: every namespace in one database file.
+//!
+//! The database runs in WAL mode with a busy timeout so several processes (for
+//! example API replicas mounting the same volume) can read and write the same
+//! file concurrently.
+
+use crate::error::{KreuzbergError, Result};
+use rusqlite::{Connection, OptionalExtension, params};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use super::super::core::CacheStats;
+use super::super::utilities::get_available_disk_space;
+use super::{CacheBackend, EVICTION_TARGET_RATIO, age_days, now_secs};
+
+/// Run eviction every this many writes.
+const CLEANUP_INTERVAL: usize = 100;
+
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS cache_entries (
+    namespace   TEXT    NOT NULL,
+    key         TEXT    NOT NULL,
+    value       BLOB    NOT NULL,
+    size        INTEGER NOT NULL,
+    created_at  REAL    NOT NULL,
+    accessed_at REAL    NOT NULL,
+    PRIMARY KEY (namespace, key)
+);
+CREATE INDEX IF NOT EXISTS idx_cache_entries_accessed ON cache_entries (accessed_at);
+";
+
+pub struct SqliteBackend {
+    path: PathBuf,
+    max_age_days: f64,
+    max_cache_size_mb: f64,
+    min_free_space_mb: f64,
+    connection: Mutex<Connection>,
+    write_counter: AtomicUsize,
+}
+
+fn sqlite_error(context: &str, error: rusqlite::Error) -> KreuzbergError {
+    KreuzbergError::cache(format!("{}: {}", context, error))
+}
+
+fn bytes_to_mb(bytes: i64) -> f64 {
+    bytes.max(0) as f64 / (1024.0 * 1024.0)
+}
+
+impl SqliteBackend {
+    /// Open (or create) the cache database at `path`.
+    pub fn open(path: PathBuf, max_age_days: f64, max_cache_size_mb: f64, min_free_space_mb: f64) -> Result<Self> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)
+                .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
+        }
+
+        let connection = Connection::open(&path).map_err(|e| sqlite_error("Failed to open cache database", e))?;
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .map_err(|e| sqlite_error("Failed to configure cache database", e))?;
+        // journal_mode returns the resulting mode as a row ~keep
+        connection
+            .query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))
+            .map_err(|e| sqlite_error("Failed to configure cache database", e))?;
+        connection
+            .execute_batch(SCHEMA)
+            .map_err(|e| sqlite_error("Failed to initialize cache database", e))?;
+
+        Ok(Self {
+            path,
+            max_age_days,
+            max_cache_size_mb,
+            min_free_space_mb,
+            connection: Mutex::new(connection),
+            write_counter: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
+        self.connection
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("SQLite cache connection mutex poisoned: {}", e)))
+    }
+
+    fn expiry_cutoff(&self) -> f64 {
+        now_secs() - self.max_age_days * 24.0 * 3600.0
+    }
+
+    fn available_space_mb(&self) -> Result<f64> {
+        let dir = self.path.parent().unwrap_or_else(|| Path::new("."));
+        get_available_disk_space(&dir.to_string_lossy())
+    }
+}
+
+impl CacheBackend for SqliteBackend {
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    fn get(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        let connection = self.lock()?;
+
+        let row: Option<(Vec<u8>, f64)> = connection
+            .query_row(
+                "SELECT value, created_at FROM cache_entries WHERE namespace = ?1 AND key = ?2",
+                params![namespace, key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(|e| sqlite_error("Failed to read cache entry", e))?;
+
+        let Some((value, created_at)) = row else {
+            return Ok(None);
+        };
+
+        if created_at < self.expiry_cutoff() {
+            connection
+                .execute(
+                    "DELETE FROM cache_entries WHERE namespace = ?1 AND key = ?2",
+                    params![namespace, key],
+                )
+                .map_err(|e| sqlite_error("Failed to remove expired cache entry", e))?;
+            return Ok(None);
+        }
+
+        // Access time drives LRU eviction; failing to bump it is harmless ~keep
+        if let Err(e) = connection.execute(
+            "UPDATE cache_entries SET accessed_at = ?3 WHERE namespace = ?1 AND key = ?2",
+            params![namespace, key, now_secs()],
+        ) {
+            tracing::debug!("Failed to update cache access time: {}", e);
+        }
+
+        Ok(Some(value))
+    }
+
+    fn set(&self, namespace: &str, key: &str, data: &[u8]) -> Result<()> {
+        {
+            let connection = self.lock()?;
+            let now = now_secs();
+            connection
+                .execute(
+                    "INSERT OR REPLACE INTO cache_entries (namespace, key, value, size, created_at, accessed_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?5)",
+                    params![namespace, key, data, data.len() as i64, now],
+                )
+                .map_err(|e| sqlite_error("Failed to write cache entry", e))?;
+        }
+
+        let count = self.write_counter.fetch_add(1, Ordering::Relaxed);
+        if count.is_multiple_of(CLEANUP_INTERVAL) {
+            // Cache cleanup failure - safe to ignore, cache is optional fallback ~keep
+            let _ = self.cleanup();
+        }
+
+        Ok(())
+    }
+
+    fn remove(&self, namespace: &str, key: &str) -> Result<bool> {
+        let connection = self.lock()?;
+        let removed = connection
+            .execute(
+                "DELETE FROM cache_entries WHERE namespace = ?1 AND key = ?2",
+                params![namespace, key],
+            )
+            .map_err(|e| sqlite_error("Failed to remove cache entry", e))?;
+        Ok(removed > 0)
+    }
+
+    fn clear(&self, namespace: Option<&str>) -> Result<(usize, f64)> {
+        let connection = self.lock()?;
+
+        let (count, bytes): (i64, i64) = connection
+            .query_row(
+                "SELECT COUNT(*), COALESCE(SUM(size), 0) FROM cache_entries
+                 WHERE ?1 IS NULL OR namespace = ?1",
+                params![namespace],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|e| sqlite_error("Failed to read cache statistics", e))?;
+
+        connection
+            .execute(
+                "DELETE FROM cache_entries WHERE ?1 IS NULL OR namespace = ?1",
+                params![namespace],
+            )
+            .map_err(|e| sqlite_error("Failed to clear cache", e))?;
+
+        Ok((count.max(0) as usize, bytes_to_mb(bytes)))
+    }
+
+    fn stats(&self, namespace: Option<&str>) -> Result<CacheStats> {
+        let (count, bytes, oldest, newest): (i64, i64, Option<f64>, Option<f64>) = {
+            let connection = self.lock()?;
+            connection
+                .query_row(
+                    "SELECT COUNT(*), COALESCE(SUM(size), 0), MIN(created_at), MAX(created_at) FROM cache_entries
+                     WHERE ?1 IS NULL OR namespace = ?1",
+                    params![namespace],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+                )
+                .map_err(|e| sqlite_error("Failed to read cache statistics", e))?
+        };
+
+        let now = now_secs();
+        Ok(CacheStats {
+            total_files: count.max(0) as usize,
+            total_size_mb: bytes_to_mb(bytes),
+            available_space_mb: self.available_space_mb()?,
+            oldest_file_age_days: oldest.map(|t| age_days(t, now)).unwrap_or(0.0),
+            newest_file_age_days: newest.map(|t| age_days(t, now)).unwrap_or(0.0),
+        })
+    }
+
+    fn cleanup(&self) -> Result<(usize, f64)> {
+        let available_space_mb = self.available_space_mb()?;
+        let connection = self.lock()?;
+
+        let (expired_count, expired_bytes): (i64, i64) = connection
+            .query_row(
+                "SELECT COUNT(*), COALESCE(SUM(size), 0) FROM cache_entries WHERE created_at < ?1",
+                params![self.expiry_cutoff()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|e| sqlite_error("Failed to read cache statistics", e))?;
+        connection
+            .execute(
+                "DELETE FROM cache_entries WHERE created_at < ?1",
+                params![self.expiry_cutoff()],
+            )
+            .map_err(|e| sqlite_error("Failed to evict expired cache entries", e))?;
+
+        let mut removed_count = expired_count.max(0) as usize;
+        let mut removed_bytes = expired_bytes.max(0);
+
+        let total_bytes: i64 = connection
+            .query_row("SELECT COALESCE(SUM(size), 0) FROM cache_entries", [], |row| row.get(0))
+            .map_err(|e| sqlite_error("Failed to read cache statistics", e))?;
+
+        let low_disk = available_space_mb < self.min_free_space_mb;
+        if bytes_to_mb(total_bytes) > self.max_cache_size_mb || low_disk {
+            let target_ratio = if low_disk { 0.5 } else { EVICTION_TARGET_RATIO };
+            let target_bytes = (self.max_cache_size_mb * target_ratio * 1024.0 * 1024.0) as i64;
+            let mut excess = total_bytes - target_bytes;
+
+            let mut statement = connection
+                .prepare("SELECT namespace, key, size FROM cache_entries ORDER BY accessed_at ASC")
+                .map_err(|e| sqlite_error("Failed to read cache entries", e))?;
+            let mut victims = Vec::new();
+            let mut rows = statement
+                .query([])
+                .map_err(|e| sqlite_error("Failed to read cache entries", e))?;
+            while excess > 0
+                && let Some(row) = rows
+                    .next()
+                    .map_err(|e| sqlite_error("Failed to read cache entries", e))?
+            {
+                let namespace: String = row
+                    .get(0)
+                    .map_err(|e| sqlite_error("Failed to read cache entries", e))?;
+                let key: String = row
+                    .get(1)
+                    .map_err(|e| sqlite_error("Failed to read cache entries", e))?;
+                let size: i64 = row
+                    .get(2)
+                    .map_err(|e| sqlite_error("Failed to read cache entries", e))?;
+                excess -= size;
+                victims.push((namespace, key, size));
+            }
+            drop(rows);
+            drop(statement);
+
+            for (namespace, key, size) in victims {
+                match connection.execute(
+                    "DELETE FROM cache_entries WHERE namespace = ?1 AND key = ?2",
+                    params![namespace, key],
+                ) {
+                    Ok(_) => {
+                        removed_count += 1;
+                        removed_bytes += size;
+                    }
+                    Err(e) => tracing::debug!("Failed to evict cache entry {}/{}: {}", namespace, key, e),
+                }
+            }
+        }
+
+        Ok((removed_count, bytes_to_mb(removed_bytes)))
+    }
+
+    fn location(&self, _namespace: Option<&str>) -> PathBuf {
+        self.path.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_sqlite_backend_shared_between_handles() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("shared.sqlite");
+        let first = SqliteBackend::open(path.clone(), 30.0, 100.0, 0.0).unwrap();
+        let second = SqliteBackend::open(path, 30.0, 100.0, 0.0).unwrap();
+
+        first.set("results", "key", b"from replica one").unwrap();
+        assert_eq!(
+            second.get("results", "key").unwrap().as_deref(),
+            Some(&b"from replica one"[..])
+        );
+    }
+
+    #[test]
+    fn test_sqlite_backend_size_eviction() {
+        let temp_dir = tempdir().unwrap();
+        let backend = SqliteBackend::open(temp_dir.path().join("cache.sqlite"), 30.0, 1.0 / 1024.0, 0.0).unwrap();
+        let entry = vec![0u8; 400];
+
+        backend.set("results", "a", &entry).unwrap();
+        backend.set("results", "b", &entry).unwrap();
+        backend.set("results", "c", &entry).unwrap();
+
+        let (removed, _) = backend.cleanup().unwrap();
+        assert_eq!(removed, 1);
+        assert!(backend.get("results", "a").unwrap().is_none());
+        assert_eq!(backend.stats(None).unwrap().total_files, 2);
+    }
+
+    #[test]
+    fn test_sqlite_backend_ttl() {
+        let temp_dir = tempdir().unwrap();
+        let backend = SqliteBackend::open(temp_dir.path().join("cache.sqlite"), 1.0, 100.0, 0.0).unwrap();
+        backend.set("results", "old", b"data").unwrap();
+        backend
+            .lock()
+            .unwrap()
+            .execute("UPDATE cache_entries SET created_at = created_at - 2 * 86400", [])
+            .unwrap();
+
+        assert!(backend.get("results", "old").unwrap().is_none());
+        assert_eq!(backend.stats(None).unwrap().total_files, 0);
+    }
+}
@@ -2,12 +2,65 @@
 
 use crate::error::{KreuzbergError, Result};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use super::core::{CacheEntry, CacheScanResult, CacheStats};
 use super::utilities::get_available_disk_space;
 
+/// Collect all `.msgpack` cache entries below `dir`.
+///
+/// Subdirectories are walked as well, so pointing at the cache root covers the
+/// per-type caches (`ocr/`, `results/`, ...) stored beneath it.
+pub(super) fn collect_cache_files(dir: &Path) -> Result<Vec<(PathBuf, fs::Metadata)>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let read_dir = match fs::read_dir(&current) {
+            Ok(read_dir) => read_dir,
+            Err(e) if current == dir => {
+                return Err(KreuzbergError::cache(format!("Failed to read cache directory: {}", e)));
+            }
+            Err(e) => {
+                tracing::debug!("Failed to read cache subdirectory {:?}: {}", current, e);
+                continue;
+            }
+        };
+
+        for entry in read_dir {
+            let entry = match entry {
+                Ok(e) => e,
+                Err(e) => {
+                    tracing::debug!("Error reading cache entry: {}", e);
+                    continue;
+                }
+            };
+
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = entry.path();
+
+            if file_type.is_dir() {
+                pending.push(path);
+                continue;
+            }
+
+            let metadata = match entry.metadata() {
+                Ok(m) if m.is_file() => m,
+                _ => continue,
+            };
+
+            if path.extension().and_then(|s| s.to_str()) == Some("msgpack") {
+                files.push((path, metadata));
+            }
+        }
+    }
+
+    Ok(files)
+}
+
 pub(super) fn scan_cache_directory(cache_dir: &str) -> Result<CacheScanResult> {
     let dir_path = Path::new(cache_dir);
 
@@ -29,33 +82,12 @@ pub(super) fn scan_cache_directory(cache_dir: &str) -> Result<CacheScanResult> {
         .unwrap_or_default()
         .as_secs() as f64;
 
-    let read_dir =
-        fs::read_dir(dir_path).map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
-
     let mut total_size = 0u64;
     let mut oldest_age = 0.0f64;
     let mut newest_age = f64::INFINITY;
     let mut entries = Vec::new();
 
-    for entry in read_dir {
-        let entry = match entry {
-            Ok(e) => e,
-            Err(e) => {
-                tracing::debug!("Error reading cache entry: {}", e);
-                continue;
-            }
-        };
-
-        let metadata = match entry.metadata() {
-            Ok(m) if m.is_file() => m,
-            _ => continue,
-        };
-
-        let path = entry.path();
-        if path.extension().and_then(|s| s.to_str()) != Some("msgpack") {
-            continue;
-        }
-
+    for (path, metadata) in collect_cache_files(dir_path)? {
         let modified = match metadata.modified() {
             Ok(m) => m,
             Err(e) => {
@@ -227,28 +259,7 @@ pub fn clear_cache_directory(cache_dir: &str) -> Result<(usize, f64)> {
     let mut removed_count = 0;
     let mut removed_size = 0.0;
 
-    let read_dir =
-        fs::read_dir(dir_path).map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
-
-    for entry in read_dir {
-        let entry = match entry {
-            Ok(e) => e,
-            Err(e) => {
-                tracing::debug!("Error reading entry: {}", e);
-                continue;
-            }
-        };
-
-        let metadata = match entry.metadata() {
-            Ok(m) if m.is_file() => m,
-            _ => continue,
-        };
-
-        let path = entry.path();
-        if path.extension().and_then(|s| s.to_str()) != Some("msgpack") {
-            continue;
-        }
-
+    for (path, metadata) in collect_cache_files(dir_path)? {
         let size_mb = metadata.len() as f64 / (1024.0 * 1024.0);
         match fs::remove_file(&path) {
             Ok(_) => {
@@ -1,5 +1,9 @@
 //! Core cache implementation with GenericCache struct.
 //!
+//! `GenericCache` is a namespaced view onto a [`CacheBackend`]: the cache type
+//! (`ocr`, `results`, ...) selects the namespace, while storage, TTL and
+//! size-based eviction are handled by the backend.
+//!
 //! # Lock Poisoning Handling
 //!
 //! This module uses `Arc<Mutex<T>>` for thread-safe state management and implements
@@ -17,8 +21,8 @@
 //! - Follows CLAUDE.md requirement: "Lock poisoning must be handled - never `.unwrap()` on Mutex/RwLock"
 //!
 //! **Affected state:**
-//! - `processing_locks`: Tracks cache keys currently being processed (6 lock sites)
-//! - `deleting_files`: Prevents read-during-delete race conditions (3 lock sites)
+//! - `processing_locks`: Tracks cache keys currently being processed (3 lock sites)
+//! - Backend state (see [`super::backend`]) follows the same rules
 //!
 //! This approach ensures that lock poisoning (rare in practice) is surfaced to users
 //! rather than causing panics, maintaining system stability during concurrent operations.
@@ -28,11 +32,19 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use super::cleanup::smart_cleanup_cache;
+use super::backend::{CacheBackend, FilesystemBackend};
+
+/// Prefix of values stored together with source file metadata.
+///
+/// Layout: magic, source size (u64 LE), source mtime in seconds (u64 LE), data.
+const SOURCE_ENVELOPE_MAGIC: &[u8; 8] = b"\0KZCSRC1";
+const SOURCE_ENVELOPE_LEN: usize = SOURCE_ENVELOPE_MAGIC.len() + 16;
+
+/// Cache type (and subdirectory name) used for OCR results.
+pub const OCR_CACHE_TYPE: &str = "ocr";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheStats {
@@ -55,20 +67,16 @@ pub(super) struct CacheScanResult {
     pub(super) entries: Vec<CacheEntry>,
 }
 
+#[derive(Clone)]
 pub struct GenericCache {
+    backend: Arc<dyn CacheBackend>,
     cache_dir: PathBuf,
     cache_type: String,
-    max_age_days: f64,
-    max_cache_size_mb: f64,
-    min_free_space_mb: f64,
     processing_locks: Arc<Mutex<HashSet<String>>>,
-    /// Tracks cache keys being deleted to prevent read-during-delete race conditions
-    deleting_files: Arc<Mutex<HashSet<PathBuf>>>,
-    /// Counter for triggering periodic cleanup (every 100 writes)
-    write_counter: Arc<AtomicUsize>,
 }
 
 impl GenericCache {
+    /// Create a filesystem-backed cache in `<cache_dir>/<cache_type>`.
     pub fn new(
         cache_type: String,
         cache_dir: Option<String>,
@@ -76,126 +84,70 @@ impl GenericCache {
         max_cache_size_mb: f64,
         min_free_space_mb: f64,
     ) -> Result<Self> {
-        let cache_dir_path = if let Some(dir) = cache_dir {
-            PathBuf::from(dir).join(&cache_type)
+        let root = if let Some(dir) = cache_dir {
+            PathBuf::from(dir)
         } else {
             // OSError/RuntimeError must bubble up - system errors need user reports ~keep
-            std::env::current_dir()?.join(".kreuzberg").join(&cache_type)
+            std::env::current_dir()?.join(".kreuzberg")
         };
 
-        fs::create_dir_all(&cache_dir_path)
+        let backend = FilesystemBackend::new(root, max_age_days, max_cache_size_mb, min_free_space_mb)?;
+        let cache = Self::with_backend(cache_type, Arc::new(backend));
+
+        fs::create_dir_all(&cache.cache_dir)
             .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
 
-        Ok(Self {
-            cache_dir: cache_dir_path,
-            cache_type,
-            max_age_days,
-            max_cache_size_mb,
-            min_free_space_mb,
-            processing_locks: Arc::new(Mutex::new(HashSet::new())),
-            deleting_files: Arc::new(Mutex::new(HashSet::new())),
-            write_counter: Arc::new(AtomicUsize::new(0)),
-        })
+        Ok(cache)
     }
 
-    fn get_cache_path(&self, cache_key: &str) -> PathBuf {
-        self.cache_dir.join(format!("{}.msgpack", cache_key))
+    /// Create a cache storing its entries in the `cache_type` namespace of `backend`.
+    pub fn with_backend(cache_type: String, backend: Arc<dyn CacheBackend>) -> Self {
+        let cache_dir = backend.location(Some(&cache_type));
+        Self {
+            backend,
+            cache_dir,
+            cache_type,
+            processing_locks: Arc::new(Mutex::new(HashSet::new())),
+        }
     }
 
-    fn get_metadata_path(&self, cache_key: &str) -> PathBuf {
-        self.cache_dir.join(format!("{}.meta", cache_key))
+    /// Size and mtime (seconds) of `source_file`, used to invalidate entries
+    /// derived from a file that has changed since it was cached.
+    fn source_fingerprint(source_file: &str) -> Option<(u64, u64)> {
+        let metadata = fs::metadata(source_file).ok()?;
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())?;
+        Some((metadata.len(), mtime))
     }
 
-    fn is_valid(&self, cache_path: &Path, source_file: Option<&str>) -> bool {
-        if !cache_path.exists() {
-            return false;
+    /// Strip the source envelope from a stored value, validating it against
+    /// `source_file` when given. Returns `None` if the source has changed, or if
+    /// `source_file` is given and the value carries no envelope to check it
+    /// against (legacy entries, entries stored without a source).
+    fn unwrap_source_envelope(mut stored: Vec<u8>, source_file: Option<&str>) -> Option<Vec<u8>> {
+        if stored.len() < SOURCE_ENVELOPE_LEN || !stored.starts_with(SOURCE_ENVELOPE_MAGIC) {
+            return source_file.is_none().then_some(stored);
         }
 
-        if let Ok(metadata) = fs::metadata(cache_path)
-            && let Ok(modified) = metadata.modified()
-            && let Ok(elapsed) = SystemTime::now().duration_since(modified)
-        {
-            let age_days = elapsed.as_secs() as f64 / (24.0 * 3600.0);
-            if age_days > self.max_age_days {
-                return false;
-            }
-        }
-
-        if let Some(source_path) = source_file {
-            let Some(file_stem) = cache_path.file_stem().and_then(|s| s.to_str()) else {
-                return false;
-            };
-            let meta_path = self.get_metadata_path(file_stem);
-
-            if meta_path.exists() {
-                if let Ok(meta_metadata) = fs::metadata(&meta_path)
-                    && meta_metadata.len() == 16
-                    && let Ok(cached_meta_bytes) = fs::read(&meta_path)
-                {
-                    let cached_size = u64::from_le_bytes([
-                        cached_meta_bytes[0],
-                        cached_meta_bytes[1],
-                        cached_meta_bytes[2],
-                        cached_meta_bytes[3],
-                        cached_meta_bytes[4],
-                        cached_meta_bytes[5],
-                        cached_meta_bytes[6],
-                        cached_meta_bytes[7],
-                    ]);
-                    let cached_mtime = u64::from_le_bytes([
-                        cached_meta_bytes[8],
-                        cached_meta_bytes[9],
-                        cached_meta_bytes[10],
-                        cached_meta_bytes[11],
-                        cached_meta_bytes[12],
-                        cached_meta_bytes[13],
-                        cached_meta_bytes[14],
-                        cached_meta_bytes[15],
-                    ]);
-
-                    if let Ok(source_metadata) = fs::metadata(source_path) {
-                        let current_size = source_metadata.len();
-                        let Some(current_mtime) = source_metadata
-                            .modified()
-                            .ok()
-                            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
-                            .map(|d| d.as_secs())
-                        else {
-                            return false;
-                        };
-
-                        return cached_size == current_size && cached_mtime == current_mtime;
-                    }
-                }
-                return false;
-            }
-        }
-
-        true
-    }
+        let offset = SOURCE_ENVELOPE_MAGIC.len();
+        let read_u64 = |at: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&stored[at..at + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let cached = (read_u64(offset), read_u64(offset + 8));
 
-    fn save_metadata(&self, cache_key: &str, source_file: Option<&str>) {
         if let Some(source_path) = source_file
-            && let Ok(metadata) = fs::metadata(source_path)
+            && Self::source_fingerprint(source_path) != Some(cached)
         {
-            let size = metadata.len();
-            let Some(mtime) = metadata
-                .modified()
-                .ok()
-                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
-                .map(|d| d.as_secs())
-            else {
-                return;
-            };
-
-            let mut bytes = Vec::with_capacity(16);
-            bytes.extend_from_slice(&size.to_le_bytes());
-            bytes.extend_from_slice(&mtime.to_le_bytes());
-
-            let meta_path = self.get_metadata_path(cache_key);
-            // Cache metadata write failure - safe to ignore, cache is optional fallback ~keep
-            let _ = fs::write(meta_path, bytes);
+            return None;
         }
+
+        stored.drain(..SOURCE_ENVELOPE_LEN);
+        Some(stored)
     }
 
     #[cfg_attr(feature = "otel", tracing::instrument(
@@ -206,45 +158,22 @@ impl GenericCache {
         )
     ))]
     pub fn get(&self, cache_key: &str, source_file: Option<&str>) -> Result<Option<Vec<u8>>> {
-        let cache_path = self.get_cache_path(cache_key);
-
-        {
-            let deleting = self
-                .deleting_files
-                .lock()
-                .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
-            if deleting.contains(&cache_path) {
-                #[cfg(feature = "otel")]
-                tracing::Span::current().record("cache.hit", false);
-                return Ok(None);
+        let stored = match self.backend.get(&self.cache_type, cache_key) {
+            Ok(stored) => stored,
+            Err(KreuzbergError::LockPoisoned(msg)) => return Err(KreuzbergError::LockPoisoned(msg)),
+            Err(e) => {
+                // Backend read failures degrade to a cache miss ~keep
+                tracing::debug!("Cache read failed for {}: {}", cache_key, e);
+                None
             }
-        }
+        };
 
-        if !self.is_valid(&cache_path, source_file) {
-            #[cfg(feature = "otel")]
-            tracing::Span::current().record("cache.hit", false);
-            return Ok(None);
-        }
+        let content = stored.and_then(|stored| Self::unwrap_source_envelope(stored, source_file));
 
-        match fs::read(&cache_path) {
-            Ok(content) => {
-                #[cfg(feature = "otel")]
-                tracing::Span::current().record("cache.hit", true);
-                Ok(Some(content))
-            }
-            Err(_) => {
-                // Best-effort cleanup of corrupted cache files ~keep
-                if let Err(e) = fs::remove_file(&cache_path) {
-                    tracing::debug!("Failed to remove corrupted cache file: {}", e);
-                }
-                if let Err(e) = fs::remove_file(self.get_metadata_path(cache_key)) {
-                    tracing::debug!("Failed to remove corrupted metadata file: {}", e);
-                }
-                #[cfg(feature = "otel")]
-                tracing::Span::current().record("cache.hit", false);
-                Ok(None)
-            }
-        }
+        #[cfg(feature = "otel")]
+        tracing::Span::current().record("cache.hit", content.is_some());
+
+        Ok(content)
     }
 
     #[cfg_attr(feature = "otel", tracing::instrument(
@@ -255,27 +184,17 @@ impl GenericCache {
         )
     ))]
     pub fn set(&self, cache_key: &str, data: Vec<u8>, source_file: Option<&str>) -> Result<()> {
-        let cache_path = self.get_cache_path(cache_key);
-
-        fs::write(&cache_path, &data)
-            .map_err(|e| KreuzbergError::cache(format!("Failed to write cache file: {}", e)))?;
-
-        self.save_metadata(cache_key, source_file);
-
-        let count = self.write_counter.fetch_add(1, Ordering::Relaxed);
-        if count.is_multiple_of(100)
-            && let Some(cache_path_str) = self.cache_dir.to_str()
-        {
-            // Cache cleanup failure - safe to ignore, cache is optional fallback ~keep
-            let _ = smart_cleanup_cache(
-                cache_path_str,
-                self.max_age_days,
-                self.max_cache_size_mb,
-                self.min_free_space_mb,
-            );
+        match source_file.and_then(Self::source_fingerprint) {
+            Some((size, mtime)) => {
+                let mut stored = Vec::with_capacity(SOURCE_ENVELOPE_LEN + data.len());
+                stored.extend_from_slice(SOURCE_ENVELOPE_MAGIC);
+                stored.extend_from_slice(&size.to_le_bytes());
+                stored.extend_from_slice(&mtime.to_le_bytes());
+                stored.extend_from_slice(&data);
+                self.backend.set(&self.cache_type, cache_key, &stored)
+            }
+            None => self.backend.set(&self.cache_type, cache_key, &data),
         }
-
-        Ok(())
     }
 
     pub fn is_processing(&self, cache_key: &str) -> Result<bool> {
@@ -307,117 +226,22 @@ impl GenericCache {
         Ok(())
     }
 
-    /// Mark a file path as being deleted to prevent concurrent reads.
-    ///
-    /// # TOCTOU Race Condition
-    ///
-    /// There is a Time-Of-Check-To-Time-Of-Use (TOCTOU) race condition between:
-    /// 1. Iterating directory entries in `clear()` (getting path/metadata)
-    /// 2. Marking the file for deletion here
-    /// 3. Actually deleting the file
-    ///
-    /// **Race scenario:**
-    /// - Thread A: Begins iterating in `clear()`, gets path
-    /// - Thread B: Calls `get()`, checks `deleting_files` (not marked yet), proceeds
-    /// - Thread A: Calls `mark_for_deletion()` here
-    /// - Thread A: Deletes file with `fs::remove_file()`
-    /// - Thread B: Tries to read file, but it's already deleted
-    ///
-    /// **Why this is acceptable:**
-    /// - Cache operations are best-effort optimizations, not critical
-    /// - `get()` already handles file read failures gracefully (treats as cache miss)
-    /// - The worst case is a failed read → cache miss → recomputation
-    /// - No data corruption or invariant violations occur
-    /// - Alternative (atomic operation) would require complex locking impacting performance
-    fn mark_for_deletion(&self, path: &Path) -> Result<()> {
-        let mut deleting = self
-            .deleting_files
-            .lock()
-            .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
-        deleting.insert(path.to_path_buf());
-        Ok(())
-    }
-
-    /// Remove a file path from the deletion set
-    fn unmark_deletion(&self, path: &Path) -> Result<()> {
-        let mut deleting = self
-            .deleting_files
-            .lock()
-            .map_err(|e| KreuzbergError::LockPoisoned(format!("Deleting files mutex poisoned: {}", e)))?;
-        deleting.remove(&path.to_path_buf());
-        Ok(())
-    }
-
+    /// Remove every entry of this cache type.
     pub fn clear(&self) -> Result<(usize, f64)> {
-        let dir_path = &self.cache_dir;
-
-        if !dir_path.exists() {
-            return Ok((0, 0.0));
-        }
-
-        let mut removed_count = 0;
-        let mut removed_size = 0.0;
-
-        let read_dir = fs::read_dir(dir_path)
-            .map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
-
-        for entry in read_dir {
-            let entry = match entry {
-                Ok(e) => e,
-                Err(e) => {
-                    tracing::debug!("Error reading entry: {}", e);
-                    continue;
-                }
-            };
-
-            let metadata = match entry.metadata() {
-                Ok(m) if m.is_file() => m,
-                _ => continue,
-            };
-
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) != Some("msgpack") {
-                continue;
-            }
-
-            let size_mb = metadata.len() as f64 / (1024.0 * 1024.0);
-
-            // Mark file for deletion to prevent concurrent access ~keep
-            if let Err(e) = self.mark_for_deletion(&path) {
-                tracing::debug!("Failed to mark file for deletion: {} (continuing anyway)", e);
-            }
-
-            match fs::remove_file(&path) {
-                Ok(_) => {
-                    removed_count += 1;
-                    removed_size += size_mb;
-                    // Unmark after successful deletion ~keep
-                    if let Err(e) = self.unmark_deletion(&path) {
-                        tracing::debug!("Failed to unmark deleted file: {} (non-critical)", e);
-                    }
-                }
-                Err(e) => {
-                    tracing::debug!("Failed to remove {:?}: {}", path, e);
-                    // Unmark after failed deletion to allow retries ~keep
-                    if let Err(e) = self.unmark_deletion(&path) {
-                        tracing::debug!("Failed to unmark file after deletion error: {} (non-critical)", e);
-                    }
-                }
-            }
-        }
-
-        Ok((removed_count, removed_size))
+        self.backend.clear(Some(&self.cache_type))
     }
 
     pub fn get_stats(&self) -> Result<CacheStats> {
-        use super::cleanup::get_cache_metadata;
-        let cache_path_str = self
-            .cache_dir
-            .to_str()
-            .ok_or_else(|| KreuzbergError::validation("Cache directory path contains invalid UTF-8".to_string()))?;
-        get_cache_metadata(cache_path_str)
+        self.backend.stats(Some(&self.cache_type))
     }
 
+    /// Apply TTL and size-based eviction on the underlying backend.
+    pub fn cleanup(&self) -> Result<(usize, f64)> {
+        self.backend.cleanup()
+    }
+
+    /// Location of this cache: the type subdirectory for the filesystem
+    /// backend, the database file for SQLite and `:memory:` for the LRU.
     pub fn cache_dir(&self) -> &Path {
         &self.cache_dir
     }
@@ -425,4 +249,8 @@ impl GenericCache {
     pub fn cache_type(&self) -> &str {
         &self.cache_type
     }
+
+    pub fn backend(&self) -> &Arc<dyn CacheBackend> {
+        &self.backend
+    }
 }
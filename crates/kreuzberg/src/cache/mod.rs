@@ -1,20 +1,27 @@
 //! Generic cache implementation with lock poisoning recovery.
 //!
 //! This module provides a thread-safe caching system with automatic cleanup,
-//! processing locks, and validation capabilities.
+//! processing locks, and validation capabilities. Storage is pluggable through
+//! the [`CacheBackend`] trait (filesystem, SQLite, in-memory LRU).
 
+mod backend;
 mod cleanup;
 mod core;
+mod results;
 mod utilities;
 
 // Re-export all public types and functions for backward compatibility
+#[cfg(feature = "cache-sqlite")]
+pub use backend::SqliteBackend;
+pub use backend::{CacheBackend, FilesystemBackend, MemoryBackend, create_backend, shared_backend};
 pub use cleanup::{
     batch_cleanup_caches, cleanup_cache, clear_cache_directory, get_cache_metadata, is_cache_valid, smart_cleanup_cache,
 };
-pub use core::{CacheStats, GenericCache};
+pub use core::{CacheStats, GenericCache, OCR_CACHE_TYPE};
+pub use results::{RESULT_CACHE_TYPE, ResultCache, config_fingerprint, resolve_cache_dir};
 pub use utilities::{
-    fast_hash, filter_old_cache_entries, generate_cache_key, get_available_disk_space, sort_cache_by_access_time,
-    validate_cache_key,
+    ContentHasher, StableHasher, fast_hash, filter_old_cache_entries, generate_cache_key, get_available_disk_space,
+    sort_cache_by_access_time, validate_cache_key,
 };
 
 #[cfg(test)]
@@ -38,6 +45,17 @@ mod tests {
         assert_eq!(key1.len(), 32);
     }
 
+    #[test]
+    fn test_cache_keys_are_stable_across_processes() {
+        // Fixed expectations: persistent caches only hit if keys never depend on a per-process seed.
+        assert_eq!(
+            generate_cache_key(&[("key1", "value1"), ("key2", "value2")]),
+            "0000000000000000d875577d041a3fa3"
+        );
+        assert_eq!(fast_hash(b"test data"), 9614878565312889663);
+        assert_eq!(ContentHasher::digest(b"test data"), (16385655728919050181, 9));
+    }
+
     #[test]
     fn test_validate_cache_key() {
         assert!(validate_cache_key("0123456789abcdef0123456789abcdef"));
@@ -56,6 +74,20 @@ mod tests {
         assert_ne!(fast_hash(data1), fast_hash(data3));
     }
 
+    #[test]
+    fn test_content_hasher_is_independent_of_chunking() {
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let whole = ContentHasher::digest(&data);
+
+        let mut hasher = ContentHasher::new();
+        for chunk in data.chunks(7_919) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finish(), whole);
+        assert_eq!(whole.1, data.len() as u64);
+        assert_ne!(ContentHasher::digest(&data[1..]), whole);
+    }
+
     #[test]
     fn test_filter_old_cache_entries() {
         let cache_times = vec![100.0, 200.0, 300.0, 400.0];
@@ -233,6 +265,34 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_generic_cache_source_file_requires_envelope() {
+        let temp_dir = tempdir().unwrap();
+        let cache = GenericCache::new(
+            "test".to_string(),
+            Some(temp_dir.path().to_str().unwrap().to_string()),
+            30.0,
+            500.0,
+            1000.0,
+        )
+        .unwrap();
+
+        let source_file = temp_dir.path().join("source.txt");
+        File::create(&source_file).unwrap();
+        let source_file = source_file.to_str().unwrap();
+
+        // Stored without source metadata: unverifiable against a source file
+        cache.set("plain_key", b"cached data".to_vec(), None).unwrap();
+        assert_eq!(cache.get("plain_key", Some(source_file)).unwrap(), None);
+        assert_eq!(cache.get("plain_key", None).unwrap(), Some(b"cached data".to_vec()));
+
+        // Enveloped entries are still readable without a source file
+        cache
+            .set("source_key", b"cached data".to_vec(), Some(source_file))
+            .unwrap();
+        assert_eq!(cache.get("source_key", None).unwrap(), Some(b"cached data".to_vec()));
+    }
+
     #[test]
     fn test_generic_cache_processing_locks() {
         let temp_dir = tempdir().unwrap();
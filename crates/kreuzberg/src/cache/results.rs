@@ -0,0 +1,334 @@
+//! Whole-document extraction result cache.
+//!
+//! Stores the output of a document extractor keyed by the document content hash,
+//! its MIME type, the candidate extractors and a fingerprint of the
+//! [`ExtractionConfig`]. Entries live in the `results` namespace of the
+//! configured [`CacheBackend`] (by default the `results` subdirectory of the
+//! cache root) and are managed by the same [`GenericCache`] machinery (age/size
+//! based cleanup, statistics) as every other Kreuzberg cache.
+//!
+//! The cached value is the raw extractor output. The post-processing pipeline
+//! (post-processors, chunking, validators, output formatting) still runs on
+//! every call, so dynamically registered plugins always see the result.
+
+use crate::core::config::{CacheConfig, ExtractionConfig};
+use crate::error::{KreuzbergError, Result};
+use crate::types::ExtractionResult;
+use once_cell::sync::OnceCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::backend::{CacheBackend, shared_backend};
+use super::core::{CacheStats, GenericCache};
+use super::utilities::{ContentHasher, fast_hash, generate_cache_key};
+
+/// Cache type (and subdirectory name) used for extraction results.
+pub const RESULT_CACHE_TYPE: &str = "results";
+
+const DEFAULT_MAX_AGE_DAYS: f64 = 30.0;
+const DEFAULT_MAX_CACHE_SIZE_MB: f64 = 1024.0;
+const DEFAULT_MIN_FREE_SPACE_MB: f64 = 1000.0;
+
+/// `ExtractionConfig` fields that never change the extracted output.
+const NON_OUTPUT_CONFIG_FIELDS: &[&str] = &["use_cache", "max_concurrent_extractions", "cache"];
+
+static GLOBAL_RESULT_CACHE: OnceCell<Option<ResultCache>> = OnceCell::new();
+
+/// Resolve the root cache directory.
+///
+/// Uses `KREUZBERG_CACHE_DIR` when set, otherwise `.kreuzberg` in the current
+/// working directory.
+pub fn resolve_cache_dir() -> Result<PathBuf> {
+    if let Ok(dir) = std::env::var("KREUZBERG_CACHE_DIR")
+        && !dir.is_empty()
+    {
+        return Ok(PathBuf::from(dir));
+    }
+    // OSError/RuntimeError must bubble up - system errors need user reports ~keep
+    Ok(std::env::current_dir()?.join(".kreuzberg"))
+}
+
+/// Cache for complete extraction results.
+#[derive(Clone)]
+pub struct ResultCache {
+    inner: GenericCache,
+}
+
+impl ResultCache {
+    /// Create a result cache below `cache_dir` (or [`resolve_cache_dir`] when `None`).
+    pub fn new(cache_dir: Option<String>) -> Result<Self> {
+        let cache_dir = match cache_dir {
+            Some(dir) => dir,
+            None => resolve_cache_dir()?.to_string_lossy().to_string(),
+        };
+
+        let inner = GenericCache::new(
+            RESULT_CACHE_TYPE.to_string(),
+            Some(cache_dir),
+            DEFAULT_MAX_AGE_DAYS,
+            DEFAULT_MAX_CACHE_SIZE_MB,
+            DEFAULT_MIN_FREE_SPACE_MB,
+        )?;
+
+        Ok(Self { inner })
+    }
+
+    /// Create a result cache on top of an existing backend.
+    pub fn with_backend(backend: Arc<dyn CacheBackend>) -> Self {
+        Self {
+            inner: GenericCache::with_backend(RESULT_CACHE_TYPE.to_string(), backend),
+        }
+    }
+
+    /// Result cache for a `cache` config section.
+    ///
+    /// `None` selects the default filesystem cache ([`ResultCache::global`]);
+    /// otherwise the process-wide backend for that configuration is used. Returns
+    /// `None` when the backend cannot be opened, in which case callers skip
+    /// caching.
+    pub fn for_config(config: Option<&CacheConfig>) -> Option<ResultCache> {
+        let Some(config) = config else {
+            return Self::global().cloned();
+        };
+
+        match shared_backend(config) {
+            Ok(backend) => Some(Self::with_backend(backend)),
+            Err(e) => {
+                tracing::debug!("{} result cache unavailable: {}", config.backend, e);
+                None
+            }
+        }
+    }
+
+    /// Process-wide result cache, created lazily in the default location.
+    ///
+    /// Returns `None` when the cache directory cannot be created (read-only
+    /// filesystems, WASM); callers then simply skip caching.
+    pub fn global() -> Option<&'static ResultCache> {
+        GLOBAL_RESULT_CACHE
+            .get_or_init(|| match ResultCache::new(None) {
+                Ok(cache) => Some(cache),
+                Err(e) => {
+                    tracing::debug!("Extraction result cache unavailable: {}", e);
+                    None
+                }
+            })
+            .as_ref()
+    }
+
+    /// Build the cache key for a document.
+    ///
+    /// `extractors` lists the names and versions of the candidate extractors so
+    /// that registering or upgrading an extractor invalidates stale entries.
+    pub fn cache_key(content: &[u8], mime_type: &str, extractors: &str, config: &ExtractionConfig) -> Result<String> {
+        Self::digest_cache_key(ContentHasher::digest(content), mime_type, extractors, config)
+    }
+
+    /// Build the cache key from a [`ContentHasher`] digest (hash, length) of the document.
+    pub fn digest_cache_key(
+        (hash, len): (u64, u64),
+        mime_type: &str,
+        extractors: &str,
+        config: &ExtractionConfig,
+    ) -> Result<String> {
+        let content_hash = format!("{:016x}", hash);
+        let content_len = len.to_string();
+        let fingerprint = config_fingerprint(config)?;
+
+        Ok(generate_cache_key(&[
+            ("content_hash", &content_hash),
+            ("content_len", &content_len),
+            ("mime_type", mime_type),
+            ("extractors", extractors),
+            ("config", &fingerprint),
+            ("version", env!("CARGO_PKG_VERSION")),
+        ]))
+    }
+
+    pub fn get(&self, cache_key: &str) -> Result<Option<ExtractionResult>> {
+        let Some(bytes) = self.inner.get(cache_key, None)? else {
+            return Ok(None);
+        };
+
+        match rmp_serde::from_slice(&bytes) {
+            Ok(result) => Ok(Some(result)),
+            Err(e) => {
+                // Undecodable entries (e.g. written by an older schema) count as a miss ~keep
+                tracing::debug!("Discarding unreadable result cache entry {}: {}", cache_key, e);
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn set(&self, cache_key: &str, result: &ExtractionResult) -> Result<()> {
+        let bytes = rmp_serde::to_vec_named(result)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to serialize extraction result: {}", e)))?;
+        self.inner.set(cache_key, bytes, None)
+    }
+
+    pub fn clear(&self) -> Result<(usize, f64)> {
+        self.inner.clear()
+    }
+
+    pub fn get_stats(&self) -> Result<CacheStats> {
+        self.inner.get_stats()
+    }
+
+    pub fn cache_dir(&self) -> &std::path::Path {
+        self.inner.cache_dir()
+    }
+
+    pub fn backend(&self) -> &Arc<dyn CacheBackend> {
+        self.inner.backend()
+    }
+}
+
+/// Stable fingerprint of the output-relevant `ExtractionConfig` fields.
+///
+/// The config is serialized to JSON (struct fields serialize in declaration
+/// order, so the output is deterministic) with runtime-only knobs removed.
+pub fn config_fingerprint(config: &ExtractionConfig) -> Result<String> {
+    let mut value = serde_json::to_value(config)
+        .map_err(|e| KreuzbergError::cache(format!("Failed to fingerprint extraction config: {}", e)))?;
+
+    if let Some(object) = value.as_object_mut() {
+        for field in NON_OUTPUT_CONFIG_FIELDS {
+            object.remove(*field);
+        }
+    }
+
+    Ok(format!("{:016x}", fast_hash(value.to_string().as_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, Table};
+    use tempfile::tempdir;
+
+    fn sample_result() -> ExtractionResult {
+        ExtractionResult {
+            content: "cached content".to_string(),
+            mime_type: "application/pdf".into(),
+            metadata: Metadata {
+                title: Some("Cached".to_string()),
+                ..Default::default()
+            },
+            tables: vec![Table {
+                cells: vec![vec!["a".to_string(), "b".to_string()]],
+                markdown: "| a | b |".to_string(),
+                page_number: 1,
+                bounding_box: None,
+                end_page_number: None,
+                spans: vec![],
+            }],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            elements: None,
+            djot_content: None,
+            ocr_elements: None,
+            document: None,
+            #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+            extracted_keywords: None,
+            quality_score: Some(0.9),
+            processing_warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_result_cache_roundtrip() {
+        let temp_dir = tempdir().unwrap();
+        let cache = ResultCache::new(Some(temp_dir.path().to_string_lossy().to_string())).unwrap();
+        assert!(cache.cache_dir().ends_with(RESULT_CACHE_TYPE));
+
+        let key = ResultCache::cache_key(
+            b"doc",
+            "application/pdf",
+            "pdf-extractor@1",
+            &ExtractionConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(cache.get(&key).unwrap().map(|r| r.content), None);
+
+        cache.set(&key, &sample_result()).unwrap();
+        let cached = cache.get(&key).unwrap().unwrap();
+        assert_eq!(cached.content, "cached content");
+        assert_eq!(cached.metadata.title.as_deref(), Some("Cached"));
+        assert_eq!(cached.tables.len(), 1);
+        assert_eq!(cached.quality_score, Some(0.9));
+
+        assert_eq!(cache.get_stats().unwrap().total_files, 1);
+        assert_eq!(cache.clear().unwrap().0, 1);
+        assert!(cache.get(&key).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cache_key_depends_on_inputs() {
+        let config = ExtractionConfig::default();
+        let base = ResultCache::cache_key(b"doc", "text/plain", "plain@1", &config).unwrap();
+
+        assert_eq!(
+            base,
+            ResultCache::cache_key(b"doc", "text/plain", "plain@1", &config).unwrap()
+        );
+        assert_ne!(
+            base,
+            ResultCache::cache_key(b"doc2", "text/plain", "plain@1", &config).unwrap()
+        );
+        assert_ne!(
+            base,
+            ResultCache::cache_key(b"doc", "text/markdown", "plain@1", &config).unwrap()
+        );
+        assert_ne!(
+            base,
+            ResultCache::cache_key(b"doc", "text/plain", "plain@2", &config).unwrap()
+        );
+
+        let forced = ExtractionConfig {
+            force_ocr: true,
+            ..Default::default()
+        };
+        assert_ne!(
+            base,
+            ResultCache::cache_key(b"doc", "text/plain", "plain@1", &forced).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_result_cache_for_memory_config() {
+        let config = CacheConfig {
+            backend: crate::core::config::CacheBackendType::Memory,
+            max_size_mb: 3.0,
+            ..Default::default()
+        };
+        let cache = ResultCache::for_config(Some(&config)).unwrap();
+        assert_eq!(cache.backend().name(), "memory");
+
+        let key = ResultCache::cache_key(b"mem", "text/plain", "plain@1", &ExtractionConfig::default()).unwrap();
+        cache.set(&key, &sample_result()).unwrap();
+
+        let again = ResultCache::for_config(Some(&config)).unwrap();
+        assert_eq!(again.get(&key).unwrap().unwrap().content, "cached content");
+        assert_eq!(again.get_stats().unwrap().total_files, 1);
+    }
+
+    #[test]
+    fn test_config_fingerprint_ignores_runtime_fields() {
+        let config = ExtractionConfig::default();
+        let tweaked = ExtractionConfig {
+            use_cache: false,
+            max_concurrent_extractions: Some(3),
+            cache: Some(CacheConfig {
+                backend: crate::core::config::CacheBackendType::Memory,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            config_fingerprint(&config).unwrap(),
+            config_fingerprint(&tweaked).unwrap()
+        );
+    }
+}
@@ -1,7 +1,6 @@
 //! Cache utilities for key generation and disk space management.
 
 use crate::error::Result;
-use ahash::AHasher;
 use std::hash::{Hash, Hasher};
 
 #[cfg(unix)]
@@ -16,27 +15,24 @@ const CACHE_KEY_HASH_WIDTH: usize = 32;
 ///
 /// # Algorithm
 ///
-/// Uses ahash (non-cryptographic 64-bit hash) for performance. Cache keys are
-/// generated by:
+/// Cache keys are generated by:
 /// 1. Sorting key-value pairs by key (for determinism)
 /// 2. Concatenating as "key1=val1&key2=val2&..."
-/// 3. Hashing with ahash and formatting as 32-character hex
+/// 3. Hashing with [`StableHasher`] (BLAKE3 truncated to 64 bits) and formatting
+///    as 32-character hex
+///
+/// The hash is unseeded, so the same parameters produce the same key in every
+/// process. Persistent caches rely on this to hit across restarts and replicas.
 ///
 /// # Collision Probability
 ///
-/// AHash produces 64-bit hashes, leading to birthday paradox collisions:
+/// Keys carry 64 bits of the hash, leading to birthday paradox collisions:
 /// - **~0.01%** probability at 1 million cache entries
 /// - **~1%** probability at 100 million entries
 /// - **~50%** probability at 4.3 billion (2^32) entries
 ///
 /// For context: P(collision) ≈ n^2 / (2 * 2^64) where n = number of entries.
 ///
-/// # Performance vs Security Trade-off
-///
-/// - **ahash**: ~10x faster than SHA256, sufficient for cache keys
-/// - **SHA256**: Collision-resistant but overkill for caching
-/// - **Practical risk**: Low for typical usage (< 1M entries)
-///
 /// # Impact of Collisions
 ///
 /// If two different configurations hash to the same key:
@@ -44,12 +40,6 @@ const CACHE_KEY_HASH_WIDTH: usize = 32;
 /// - Results in incorrect data served from cache
 /// - Detected via metadata validation (size/mtime checks)
 ///
-/// # Recommendations
-///
-/// - **< 1M entries**: ahash is safe and fast
-/// - **> 100M entries**: Monitor cache size, consider periodic clearing
-/// - **Critical data**: If collision risk is unacceptable, add SHA256 option
-///
 /// # Example
 ///
 /// ```rust
@@ -77,7 +67,7 @@ pub fn generate_cache_key(parts: &[(&str, &str)]) -> String {
         cache_str.push_str(&format!("{}={}", key, val));
     }
 
-    let mut hasher = AHasher::default();
+    let mut hasher = StableHasher::new();
     cache_str.hash(&mut hasher);
     let hash = hasher.finish();
 
@@ -126,12 +116,94 @@ pub fn get_available_disk_space(path: &str) -> Result<f64> {
     }
 }
 
+/// [`Hasher`] whose output depends only on the hashed data.
+///
+/// Unlike `AHasher::default()`, which is seeded randomly per process, this
+/// hashes with unkeyed BLAKE3 and returns the first 64 bits of the digest, so
+/// cache keys built with it stay valid across runs, restarts and replicas.
+/// `usize` values (e.g. slice length prefixes) are hashed as little-endian
+/// `u64` so keys do not depend on the pointer width.
+#[derive(Clone, Default)]
+pub struct StableHasher {
+    hasher: blake3::Hasher,
+}
+
+impl StableHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.hasher.update(&(i as u64).to_le_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        digest_to_u64(&self.hasher)
+    }
+}
+
+fn digest_to_u64(hasher: &blake3::Hasher) -> u64 {
+    let digest = hasher.finalize();
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest.as_bytes()[..8]);
+    u64::from_le_bytes(prefix)
+}
+
 pub fn fast_hash(data: &[u8]) -> u64 {
-    let mut hasher = AHasher::default();
+    let mut hasher = StableHasher::new();
     data.hash(&mut hasher);
     hasher.finish()
 }
 
+/// Incremental hash of document content, for hashing files without loading them.
+///
+/// BLAKE3 is a streaming hash, so the result does not depend on how the content
+/// is split across [`ContentHasher::update`] calls: a streamed file and the same
+/// bytes in memory produce the same hash.
+pub struct ContentHasher {
+    hasher: blake3::Hasher,
+    len: u64,
+}
+
+impl ContentHasher {
+    pub fn new() -> Self {
+        Self {
+            hasher: blake3::Hasher::new(),
+            len: 0,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.len += data.len() as u64;
+        self.hasher.update(data);
+    }
+
+    /// The content hash and the number of bytes hashed.
+    pub fn finish(mut self) -> (u64, u64) {
+        self.hasher.update(&self.len.to_le_bytes());
+        (digest_to_u64(&self.hasher), self.len)
+    }
+
+    /// Hash and length of in-memory content.
+    pub fn digest(content: &[u8]) -> (u64, u64) {
+        let mut hasher = Self::new();
+        hasher.update(content);
+        hasher.finish()
+    }
+}
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub fn validate_cache_key(key: &str) -> bool {
     key.len() == 32 && key.chars().all(|c| c.is_ascii_hexdigit())
 }
@@ -0,0 +1,132 @@
+//! Cache backend configuration.
+//!
+//! Selects where cached extraction results are stored and how they are evicted.
+//! Can be set on both `ExtractionConfig` and `ServerConfig`; when the extraction
+//! config leaves it unset, the API server falls back to its own setting.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Storage backend used for cached data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheBackendType {
+    /// One msgpack file per entry below the cache directory (default).
+    #[default]
+    Filesystem,
+    /// Single SQLite database file, suitable for volumes shared between replicas.
+    ///
+    /// Requires the `cache-sqlite` feature.
+    Sqlite,
+    /// Bounded in-process LRU. Entries are lost when the process exits.
+    Memory,
+}
+
+impl CacheBackendType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CacheBackendType::Filesystem => "filesystem",
+            CacheBackendType::Sqlite => "sqlite",
+            CacheBackendType::Memory => "memory",
+        }
+    }
+}
+
+impl std::fmt::Display for CacheBackendType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for CacheBackendType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "filesystem" | "fs" | "file" => Ok(CacheBackendType::Filesystem),
+            "sqlite" => Ok(CacheBackendType::Sqlite),
+            "memory" | "lru" => Ok(CacheBackendType::Memory),
+            other => Err(format!(
+                "Invalid cache backend '{}'. Valid options: filesystem, sqlite, memory",
+                other
+            )),
+        }
+    }
+}
+
+/// Cache backend configuration.
+///
+/// # Example
+///
+/// ```toml
+/// [cache]
+/// backend = "sqlite"
+/// path = "/var/cache/kreuzberg/cache.sqlite"
+/// max_age_days = 7
+/// max_size_mb = 4096
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Storage backend.
+    pub backend: CacheBackendType,
+
+    /// Backend location.
+    ///
+    /// Filesystem: cache root directory. SQLite: database file (a directory is
+    /// accepted and `cache.sqlite` is created inside it). Ignored by the memory
+    /// backend. Defaults to `$KREUZBERG_CACHE_DIR` or `.kreuzberg` in the
+    /// working directory.
+    pub path: Option<PathBuf>,
+
+    /// Entries older than this many days are treated as expired (TTL).
+    pub max_age_days: f64,
+
+    /// Size budget in megabytes. Least recently used entries are evicted once
+    /// the cache grows beyond it.
+    pub max_size_mb: f64,
+
+    /// Minimum free disk space in megabytes before a more aggressive cleanup
+    /// kicks in. Only used by disk-backed stores.
+    pub min_free_space_mb: f64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            backend: CacheBackendType::Filesystem,
+            path: None,
+            max_age_days: 30.0,
+            max_size_mb: 1024.0,
+            min_free_space_mb: 1000.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_config_defaults() {
+        let config = CacheConfig::default();
+        assert_eq!(config.backend, CacheBackendType::Filesystem);
+        assert!(config.path.is_none());
+        assert_eq!(config.max_age_days, 30.0);
+    }
+
+    #[test]
+    fn test_cache_config_deserialize_partial() {
+        let config: CacheConfig = serde_json::from_str(r#"{"backend": "memory", "max_size_mb": 64}"#).unwrap();
+        assert_eq!(config.backend, CacheBackendType::Memory);
+        assert_eq!(config.max_size_mb, 64.0);
+        assert_eq!(config.max_age_days, 30.0);
+    }
+
+    #[test]
+    fn test_cache_backend_type_from_str() {
+        assert_eq!("SQLite".parse::<CacheBackendType>(), Ok(CacheBackendType::Sqlite));
+        assert_eq!("lru".parse::<CacheBackendType>(), Ok(CacheBackendType::Memory));
+        assert!("redis".parse::<CacheBackendType>().is_err());
+    }
+}
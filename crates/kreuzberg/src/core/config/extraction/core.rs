@@ -134,6 +134,14 @@ pub struct ExtractionConfig {
     /// Independent of `result_format` — can be combined with Unified or ElementBased.
     #[serde(default)]
     pub include_document_structure: bool,
+
+    /// Cache backend configuration (None = filesystem cache in the default location)
+    ///
+    /// Selects the storage used for cached extraction results when `use_cache`
+    /// is enabled. When running the API server, an unset value falls back to
+    /// `ServerConfig::cache`.
+    #[serde(default)]
+    pub cache: Option<super::super::cache::CacheConfig>,
 }
 
 impl Default for ExtractionConfig {
@@ -161,6 +169,7 @@ impl Default for ExtractionConfig {
             result_format: crate::types::OutputFormat::Unified,
             output_format: OutputFormat::Plain,
             include_document_structure: false,
+            cache: None,
         }
     }
 }
@@ -14,6 +14,8 @@ use std::str::FromStr;
 /// accordingly. `Plain` returns the raw extracted text.
 /// `Structured` returns JSON with full OCR element data including bounding
 /// boxes and confidence scores.
+/// `Hocr` and `Alto` render the positioned words of OCR results (and of the
+/// text layer of digital PDFs) as hOCR or ALTO XML.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
@@ -39,6 +41,20 @@ pub enum OutputFormat {
     /// - Building custom document viewers
     /// - Extracting maximum information from OCR results
     Structured,
+    /// hOCR 1.2 (XHTML) with page, block, line and word bounding boxes.
+    Hocr,
+    /// ALTO v4 XML with page, block, line and word positions.
+    Alto,
+}
+
+impl OutputFormat {
+    /// Whether rendering this format needs positioned OCR elements.
+    ///
+    /// Extractors use this to produce `ocr_elements` even when
+    /// `OcrElementConfig::include_elements` is off.
+    pub fn requires_ocr_elements(self) -> bool {
+        matches!(self, OutputFormat::Hocr | OutputFormat::Alto)
+    }
 }
 
 impl std::fmt::Display for OutputFormat {
@@ -49,6 +65,8 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Djot => write!(f, "djot"),
             OutputFormat::Html => write!(f, "html"),
             OutputFormat::Structured => write!(f, "structured"),
+            OutputFormat::Hocr => write!(f, "hocr"),
+            OutputFormat::Alto => write!(f, "alto"),
         }
     }
 }
@@ -63,8 +81,10 @@ impl FromStr for OutputFormat {
             "djot" => Ok(OutputFormat::Djot),
             "html" => Ok(OutputFormat::Html),
             "structured" | "json" => Ok(OutputFormat::Structured),
+            "hocr" => Ok(OutputFormat::Hocr),
+            "alto" => Ok(OutputFormat::Alto),
             _ => Err(format!(
-                "Invalid output format: '{}'. Valid formats: plain, text, markdown, md, djot, html, structured, json",
+                "Invalid output format: '{}'. Valid formats: plain, text, markdown, md, djot, html, structured, json, hocr, alto",
                 s
             )),
         }
@@ -113,6 +133,22 @@ mod tests {
         assert_eq!("JSON".parse::<OutputFormat>().unwrap(), OutputFormat::Structured);
     }
 
+    #[test]
+    fn test_output_format_from_str_layout_xml() {
+        assert_eq!("hocr".parse::<OutputFormat>().unwrap(), OutputFormat::Hocr);
+        assert_eq!("hOCR".parse::<OutputFormat>().unwrap(), OutputFormat::Hocr);
+        assert_eq!("alto".parse::<OutputFormat>().unwrap(), OutputFormat::Alto);
+        assert_eq!("ALTO".parse::<OutputFormat>().unwrap(), OutputFormat::Alto);
+    }
+
+    #[test]
+    fn test_output_format_requires_ocr_elements() {
+        assert!(OutputFormat::Hocr.requires_ocr_elements());
+        assert!(OutputFormat::Alto.requires_ocr_elements());
+        assert!(!OutputFormat::Structured.requires_ocr_elements());
+        assert!(!OutputFormat::Plain.requires_ocr_elements());
+    }
+
     #[test]
     fn test_output_format_from_str_invalid() {
         let result = "invalid".parse::<OutputFormat>();
@@ -129,6 +165,8 @@ mod tests {
         assert_eq!(OutputFormat::Djot.to_string(), "djot");
         assert_eq!(OutputFormat::Html.to_string(), "html");
         assert_eq!(OutputFormat::Structured.to_string(), "structured");
+        assert_eq!(OutputFormat::Hocr.to_string(), "hocr");
+        assert_eq!(OutputFormat::Alto.to_string(), "alto");
     }
 
     #[test]
@@ -145,6 +183,8 @@ mod tests {
             OutputFormat::Djot,
             OutputFormat::Html,
             OutputFormat::Structured,
+            OutputFormat::Hocr,
+            OutputFormat::Alto,
         ] {
             let json = serde_json::to_string(&format).unwrap();
             let deserialized: OutputFormat = serde_json::from_str(&json).unwrap();
@@ -162,5 +202,7 @@ mod tests {
             serde_json::to_string(&OutputFormat::Structured).unwrap(),
             "\"structured\""
         );
+        assert_eq!(serde_json::to_string(&OutputFormat::Hocr).unwrap(), "\"hocr\"");
+        assert_eq!(serde_json::to_string(&OutputFormat::Alto).unwrap(), "\"alto\"");
     }
 }
@@ -3,6 +3,7 @@
 //! This module provides utilities for loading extraction configuration from various
 //! sources (TOML, YAML, JSON) and discovering configuration files in the project hierarchy.
 
+pub mod cache;
 pub mod extraction;
 pub mod formats;
 pub mod ocr;
@@ -11,10 +12,11 @@ pub mod pdf;
 pub mod processing;
 
 // Re-export main types for backward compatibility
+pub use cache::{CacheBackendType, CacheConfig};
 pub use extraction::{ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, TokenReductionConfig};
 pub use formats::OutputFormat;
-pub use ocr::{OcrConfig, canonical_ocr_backend_name};
-pub use page::PageConfig;
+pub use ocr::{OcrConfig, OcrFallbackConfig, OcrTableConfig, canonical_ocr_backend_name, is_auto_language};
+pub use page::{PageConfig, PageSelection};
 #[cfg(feature = "pdf")]
 pub use pdf::{HierarchyConfig, PdfConfig};
 pub use processing::{ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, PostProcessorConfig};
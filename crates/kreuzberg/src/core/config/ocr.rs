@@ -6,9 +6,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::formats::OutputFormat;
-use crate::core::config_validation::validate_ocr_backend;
+use crate::core::config_validation::{validate_binarization_method, validate_confidence, validate_ocr_backend};
 use crate::error::KreuzbergError;
-use crate::types::OcrElementConfig;
+use crate::types::{ImagePreprocessingConfig, OcrElementConfig};
 
 /// OCR configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,7 +17,8 @@ pub struct OcrConfig {
     #[serde(default = "default_rapid_ocr_backend")]
     pub backend: String,
 
-    /// Language code (e.g., "eng", "deu")
+    /// Language code (e.g., "eng", "deu", "eng+deu"), or "auto" to detect the
+    /// script and languages of each page before recognition
     #[serde(default = "default_eng")]
     pub language: String,
 
@@ -36,6 +37,111 @@ pub struct OcrConfig {
     /// OCR element extraction configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub element_config: Option<OcrElementConfig>,
+
+    /// Image preprocessing applied before OCR, shared by all backends (optional).
+    ///
+    /// Falls back to `tesseract_config.preprocessing` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preprocessing: Option<ImagePreprocessingConfig>,
+
+    /// Detect page orientation and rotate scanned pages upright before recognition.
+    ///
+    /// Uses the backend's own detector (Tesseract OSD, PaddleOCR angle classifier)
+    /// with a projection-profile fallback. Also enabled by `preprocessing.auto_rotate`.
+    #[serde(default)]
+    pub auto_rotate: bool,
+
+    /// Retry pages the backend recognized with low confidence on a second backend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<OcrFallbackConfig>,
+
+    /// Reconstruct tables from the geometry of OCR elements, for every backend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_detection: Option<OcrTableConfig>,
+
+    /// Cache store for OCR results (None = filesystem cache in the default location).
+    ///
+    /// Not configured directly: extractors copy `ExtractionConfig::cache` here so
+    /// OCR results land in the same store as cached extraction results.
+    #[serde(skip)]
+    pub cache: Option<super::cache::CacheConfig>,
+}
+
+/// Second OCR backend for pages recognized with low confidence.
+///
+/// Pages (and image regions of native PDF pages) whose mean recognition
+/// confidence is below `min_confidence` are recognized again with `backend`,
+/// and the result with the higher confidence is kept. The backend that produced
+/// each page is reported in `PageInfo.ocr_backend`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrFallbackConfig {
+    /// Backend to retry with; any name registered in the OCR backend registry
+    pub backend: String,
+
+    /// Language for the fallback backend (defaults to the primary `language`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Mean recognition confidence (0.0-1.0) below which a page is retried
+    #[serde(default = "default_fallback_min_confidence")]
+    pub min_confidence: f64,
+}
+
+impl Default for OcrFallbackConfig {
+    fn default() -> Self {
+        Self {
+            backend: "paddle-ocr".to_string(),
+            language: None,
+            min_confidence: default_fallback_min_confidence(),
+        }
+    }
+}
+
+fn default_fallback_min_confidence() -> f64 {
+    0.6
+}
+
+/// Table reconstruction from OCR element geometry.
+///
+/// Works on the words (or lines) any backend reports as `OcrElement`s: they are
+/// clustered into rows by their vertical center and into columns by their
+/// horizontal extent. Runs of rows with at least `min_columns` separated cells
+/// become a `Table`. Used for pages whose backend found no tables itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrTableConfig {
+    /// Minimum recognition confidence (0.0-1.0) of elements used for tables
+    #[serde(default = "default_table_min_confidence")]
+    pub min_confidence: f64,
+
+    /// Minimum number of rows of a table
+    #[serde(default = "default_table_min_rows")]
+    pub min_rows: usize,
+
+    /// Minimum number of columns of a table
+    #[serde(default = "default_table_min_columns")]
+    pub min_columns: usize,
+}
+
+impl Default for OcrTableConfig {
+    fn default() -> Self {
+        Self {
+            min_confidence: default_table_min_confidence(),
+            min_rows: default_table_min_rows(),
+            min_columns: default_table_min_columns(),
+        }
+    }
+}
+
+fn default_table_min_confidence() -> f64 {
+    0.3
+}
+
+fn default_table_min_rows() -> usize {
+    2
+}
+
+fn default_table_min_columns() -> usize {
+    2
 }
 
 impl Default for OcrConfig {
@@ -47,6 +153,11 @@ impl Default for OcrConfig {
             output_format: None,
             paddle_ocr_config: None,
             element_config: None,
+            preprocessing: None,
+            auto_rotate: false,
+            fallback: None,
+            table_detection: None,
+            cache: None,
         }
     }
 }
@@ -86,7 +197,39 @@ impl OcrConfig {
     /// assert!(bad_config.validate().is_err());
     /// ```
     pub fn validate(&self) -> Result<(), KreuzbergError> {
-        validate_ocr_backend(&self.backend)
+        validate_ocr_backend(&self.backend)?;
+        if let Some(preprocessing) = self.effective_preprocessing() {
+            validate_binarization_method(&preprocessing.binarization_method)?;
+        }
+        if let Some(fallback) = &self.fallback {
+            validate_ocr_backend(&fallback.backend)?;
+            validate_confidence(fallback.min_confidence)?;
+        }
+        if let Some(table_detection) = &self.table_detection {
+            validate_confidence(table_detection.min_confidence)?;
+        }
+        Ok(())
+    }
+
+    /// Preprocessing configuration to apply before OCR, if any.
+    ///
+    /// `preprocessing` takes precedence over `tesseract_config.preprocessing`.
+    pub fn effective_preprocessing(&self) -> Option<&ImagePreprocessingConfig> {
+        self.preprocessing.as_ref().or_else(|| {
+            self.tesseract_config
+                .as_ref()
+                .and_then(|tesseract| tesseract.preprocessing.as_ref())
+        })
+    }
+
+    /// Whether the OCR pipeline should detect and correct page orientation.
+    pub fn orientation_detection_enabled(&self) -> bool {
+        self.auto_rotate || self.effective_preprocessing().is_some_and(|p| p.auto_rotate)
+    }
+
+    /// Whether the language of each page is detected before recognition (`language = "auto"`).
+    pub fn auto_language(&self) -> bool {
+        is_auto_language(&self.language)
     }
 }
 
@@ -105,6 +248,11 @@ pub fn canonical_ocr_backend_name(backend: &str) -> String {
     }
 }
 
+/// Whether a language value requests automatic detection (case-insensitive `"auto"`).
+pub fn is_auto_language(language: &str) -> bool {
+    language.eq_ignore_ascii_case("auto")
+}
+
 fn default_eng() -> String {
     "eng".to_string()
 }
@@ -113,6 +261,16 @@ fn default_eng() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_auto_language() {
+        let config = OcrConfig {
+            language: "Auto".to_string(),
+            ..Default::default()
+        };
+        assert!(config.auto_language());
+        assert!(!OcrConfig::default().auto_language());
+    }
+
     #[test]
     fn test_ocr_config_default() {
         let config = OcrConfig::default();
@@ -206,4 +364,103 @@ mod tests {
         let config = OcrConfig::default();
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_effective_preprocessing_prefers_top_level() {
+        let tesseract_preprocessing = ImagePreprocessingConfig {
+            binarization_method: "adaptive".to_string(),
+            ..Default::default()
+        };
+        let mut config = OcrConfig {
+            tesseract_config: Some(crate::types::TesseractConfig {
+                preprocessing: Some(tesseract_preprocessing),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.effective_preprocessing().unwrap().binarization_method,
+            "adaptive"
+        );
+
+        config.preprocessing = Some(ImagePreprocessingConfig {
+            binarization_method: "sauvola".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(config.effective_preprocessing().unwrap().binarization_method, "sauvola");
+        assert!(OcrConfig::default().effective_preprocessing().is_none());
+    }
+
+    #[test]
+    fn test_validate_invalid_binarization_method() {
+        let config = OcrConfig {
+            preprocessing: Some(ImagePreprocessingConfig {
+                binarization_method: "niblack".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_orientation_detection_enabled() {
+        assert!(!OcrConfig::default().orientation_detection_enabled());
+        assert!(
+            OcrConfig {
+                auto_rotate: true,
+                ..Default::default()
+            }
+            .orientation_detection_enabled()
+        );
+
+        let mut config = OcrConfig {
+            preprocessing: Some(ImagePreprocessingConfig::default()),
+            ..Default::default()
+        };
+        assert!(!config.orientation_detection_enabled());
+        config.preprocessing.as_mut().unwrap().auto_rotate = true;
+        assert!(config.orientation_detection_enabled());
+    }
+
+    #[test]
+    fn test_validate_fallback() {
+        let mut config = OcrConfig {
+            backend: "tesseract".to_string(),
+            fallback: Some(OcrFallbackConfig::default()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        config.fallback.as_mut().unwrap().min_confidence = 1.5;
+        assert!(config.validate().is_err());
+
+        config.fallback = Some(OcrFallbackConfig {
+            backend: "typo".to_string(),
+            ..Default::default()
+        });
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_fallback_deserialize_defaults() {
+        let config: OcrConfig = serde_json::from_str(r#"{"fallback": {"backend": "paddle-ocr"}}"#).unwrap();
+        let fallback = config.fallback.unwrap();
+        assert_eq!(fallback.min_confidence, 0.6);
+        assert!(fallback.language.is_none());
+    }
+
+    #[test]
+    fn test_table_detection_deserialize_and_validate() {
+        let mut config: OcrConfig = serde_json::from_str(r#"{"table_detection": {"min_rows": 3}}"#).unwrap();
+        let table_detection = config.table_detection.as_ref().unwrap();
+        assert_eq!(table_detection.min_rows, 3);
+        assert_eq!(table_detection.min_columns, 2);
+        assert_eq!(table_detection.min_confidence, 0.3);
+
+        config.backend = "tesseract".to_string();
+        assert!(config.validate().is_ok());
+        config.table_detection.as_mut().unwrap().min_confidence = -0.1;
+        assert!(config.validate().is_err());
+    }
 }
@@ -3,6 +3,7 @@
 //! Controls how pages are extracted, tracked, and represented in extraction results.
 //! When `None`, page tracking is disabled.
 
+use crate::KreuzbergError;
 use serde::{Deserialize, Serialize};
 
 /// Page extraction and tracking configuration.
@@ -27,6 +28,81 @@ pub struct PageConfig {
     /// Default: "\n\n<!-- PAGE {page_num} -->\n\n"
     #[serde(default = "default_page_marker_format")]
     pub marker_format: String,
+
+    /// Extract only these pages of a PDF (default: all pages)
+    ///
+    /// Text extraction, OCR, table detection and image extraction only see the
+    /// selected pages. Page numbers in the result stay those of the original
+    /// document. Other formats ignore the selection.
+    #[serde(default)]
+    pub selection: Option<PageSelection>,
+}
+
+/// A selection of document pages (1-indexed).
+///
+/// Serialized as `{"first": 10}`, `{"ranges": [[40, 55]]}` or `{"pages": [1, 3, 5]}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PageSelection {
+    /// The first N pages.
+    First(usize),
+    /// Inclusive page ranges.
+    Ranges(Vec<(usize, usize)>),
+    /// Individual pages.
+    Pages(Vec<usize>),
+}
+
+impl PageSelection {
+    /// Resolve the selection against a document of `page_count` pages.
+    ///
+    /// Returns the selected page numbers in document order, without duplicates.
+    /// `First` is clamped to the document length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `KreuzbergError::Validation` if a range or page lies outside the
+    /// document, a range is reversed, or nothing is selected.
+    pub fn resolve(&self, page_count: usize) -> Result<Vec<usize>, KreuzbergError> {
+        let mut pages: Vec<usize> = match self {
+            PageSelection::First(count) => (1..=(*count).min(page_count)).collect(),
+            PageSelection::Ranges(ranges) => {
+                let mut pages = Vec::new();
+                for &(start, end) in ranges {
+                    if start > end {
+                        return Err(KreuzbergError::validation(format!(
+                            "Invalid page range: start ({}) > end ({})",
+                            start, end
+                        )));
+                    }
+                    // Check the bounds before expanding, so a huge range cannot
+                    // allocate more pages than the document has ~keep
+                    if let Some(page) = [start, end].into_iter().find(|&page| page == 0 || page > page_count) {
+                        return Err(out_of_range(page, page_count));
+                    }
+                    pages.extend(start..=end);
+                }
+                pages
+            }
+            PageSelection::Pages(pages) => pages.clone(),
+        };
+
+        if let Some(&page) = pages.iter().find(|&&page| page == 0 || page > page_count) {
+            return Err(out_of_range(page, page_count));
+        }
+        pages.sort_unstable();
+        pages.dedup();
+        if pages.is_empty() {
+            return Err(KreuzbergError::validation("Page selection is empty"));
+        }
+        Ok(pages)
+    }
+}
+
+fn out_of_range(page: usize, page_count: usize) -> KreuzbergError {
+    KreuzbergError::validation(format!(
+        "Page {} is out of range: the document has {} pages",
+        page, page_count
+    ))
 }
 
 impl Default for PageConfig {
@@ -35,6 +111,7 @@ impl Default for PageConfig {
             extract_pages: false,
             insert_page_markers: false,
             marker_format: "\n\n<!-- PAGE {page_num} -->\n\n".to_string(),
+            selection: None,
         }
     }
 }
@@ -53,5 +130,38 @@ mod tests {
         assert!(!config.extract_pages);
         assert!(!config.insert_page_markers);
         assert_eq!(config.marker_format, "\n\n<!-- PAGE {page_num} -->\n\n");
+        assert!(config.selection.is_none());
+    }
+
+    #[test]
+    fn test_page_selection_resolve() {
+        assert_eq!(PageSelection::First(3).resolve(10).unwrap(), vec![1, 2, 3]);
+        assert_eq!(PageSelection::First(30).resolve(2).unwrap(), vec![1, 2]);
+        assert_eq!(
+            PageSelection::Ranges(vec![(8, 9), (2, 3), (3, 4)]).resolve(10).unwrap(),
+            vec![2, 3, 4, 8, 9]
+        );
+        assert_eq!(PageSelection::Pages(vec![5, 1, 5]).resolve(10).unwrap(), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_page_selection_resolve_errors() {
+        assert!(PageSelection::Ranges(vec![(40, 55)]).resolve(50).is_err());
+        assert!(PageSelection::Ranges(vec![(5, 3)]).resolve(10).is_err());
+        assert!(PageSelection::Ranges(vec![(0, 3)]).resolve(10).is_err());
+        let err = PageSelection::Ranges(vec![(1, usize::MAX)]).resolve(10).unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+        assert!(PageSelection::Pages(vec![0]).resolve(10).is_err());
+        assert!(PageSelection::Pages(Vec::new()).resolve(10).is_err());
+        assert!(PageSelection::First(0).resolve(10).is_err());
+    }
+
+    #[test]
+    fn test_page_selection_serde() {
+        let config: PageConfig = serde_json::from_str(r#"{"selection": {"ranges": [[40, 55]]}}"#).unwrap();
+        assert_eq!(config.selection, Some(PageSelection::Ranges(vec![(40, 55)])));
+
+        let json = serde_json::to_value(PageSelection::First(10)).unwrap();
+        assert_eq!(json, serde_json::json!({"first": 10}));
     }
 }
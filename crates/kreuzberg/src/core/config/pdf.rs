@@ -21,11 +21,66 @@ pub struct PdfConfig {
     #[serde(default = "default_true")]
     pub extract_metadata: bool,
 
+    /// Extract page annotations (comments, highlights, sticky notes, links)
+    #[serde(default)]
+    pub extract_annotations: bool,
+
+    /// Extract interactive form fields (AcroForm and XFA) with their values
+    #[serde(default)]
+    pub extract_form_fields: bool,
+
+    /// Append the filled-in form fields to the content as a "Field: Value" section
+    /// (requires `extract_form_fields`)
+    #[serde(default)]
+    pub render_form_fields: bool,
+
+    /// Extract the files embedded in the PDF (attachments, portfolio members)
+    /// through the extractor registry, subject to the security limits
+    #[serde(default)]
+    pub extract_embedded_files: bool,
+
+    /// Detect running headers and footers (page numbers, running titles, repeated
+    /// notices) and move them out of the content into header/footer furniture
+    #[serde(default)]
+    pub strip_page_furniture: bool,
+
+    /// OCR image regions that have no text layer (scanned stamps, signatures, pasted
+    /// screenshots) on pages with native text, and merge their text into the content.
+    /// Requires an OCR configuration.
+    #[serde(default)]
+    pub ocr_image_regions: bool,
+
+    /// Minimum text-layer health score (0.0-1.0) of a page. With an OCR configuration,
+    /// pages whose native text scores lower (fonts without ToUnicode maps, mojibake)
+    /// are re-extracted with OCR. 0.0 disables the check.
+    #[serde(default = "default_text_layer_threshold")]
+    pub text_layer_threshold: f64,
+
+    /// Detect figures drawn with vector paths (charts, diagrams) and return them as
+    /// rendered PNG crops in `images`, with their bounding box and caption
+    #[serde(default)]
+    pub extract_figures: bool,
+
+    /// Detect display equations in untagged PDFs (math fonts, symbol density, centered
+    /// lines) and emit them as `$$` blocks in Markdown and `Formula` nodes, with a
+    /// linearized text form
+    #[serde(default)]
+    pub detect_formulas: bool,
+
+    /// Word and line geometry of the native text layer, exported as `ocr_elements`
+    /// in the same shape as OCR output (None = disabled). Words and lines carry
+    /// page coordinates in points, font name, size and bold/italic flags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_elements: Option<crate::types::OcrElementConfig>,
+
     /// Hierarchy extraction configuration (None = hierarchy extraction disabled)
     #[serde(default)]
     pub hierarchy: Option<HierarchyConfig>,
 
-    /// Table detection configuration (None = use defaults).
+    /// Table detection configuration (None = cluster word positions into tables).
+    ///
+    /// When set, ruled tables are found by the line-based table finder first,
+    /// which keeps merged cells in `Table::spans`.
     #[serde(default)]
     pub table_detection: Option<PdfTableDetectionConfig>,
 }
@@ -124,6 +179,10 @@ pub struct PdfTableDetectionConfig {
     /// Fallback spatial clustering row threshold ratio.
     #[serde(default = "default_fallback_row_threshold_ratio")]
     pub fallback_row_threshold_ratio: f64,
+    /// Merge tables that continue on the next page into one table, dropping
+    /// repeated header rows.
+    #[serde(default = "default_true")]
+    pub stitch_multi_page_tables: bool,
 }
 
 #[cfg(feature = "pdf")]
@@ -155,6 +214,7 @@ impl Default for PdfTableDetectionConfig {
             text_y_tolerance: None,
             fallback_column_threshold: default_fallback_column_threshold(),
             fallback_row_threshold_ratio: default_fallback_row_threshold_ratio(),
+            stitch_multi_page_tables: true,
         }
     }
 }
@@ -254,6 +314,11 @@ fn default_ocr_coverage_threshold() -> Option<f32> {
     None
 }
 
+#[cfg(feature = "pdf")]
+fn default_text_layer_threshold() -> f64 {
+    0.5
+}
+
 #[cfg(feature = "pdf")]
 fn default_table_snap_tolerance() -> f64 {
     3.0
@@ -340,5 +405,6 @@ mod tests {
         assert_eq!(config.join_tolerance, 3.0);
         assert_eq!(config.fallback_column_threshold, 50);
         assert_eq!(config.fallback_row_threshold_ratio, 0.5);
+        assert!(config.stitch_multi_page_tables);
     }
 }
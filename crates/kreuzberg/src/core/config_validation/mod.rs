@@ -151,6 +151,15 @@ mod tests {
         assert!(validate_language_code("*").is_ok());
     }
 
+    #[test]
+    fn test_validate_language_code_auto_and_combined() {
+        assert!(validate_language_code("auto").is_ok());
+        assert!(validate_language_code("AUTO").is_ok());
+        assert!(validate_language_code("eng+deu").is_ok());
+        assert!(validate_language_code("eng+invalid").is_err());
+        assert!(validate_language_code("eng+").is_err());
+    }
+
     #[test]
     fn test_validate_language_code_invalid() {
         let result = validate_language_code("invalid");
@@ -228,6 +228,8 @@ pub fn validate_ocr_backend(backend: &str) -> Result<()> {
 /// assert!(validate_language_code("eng").is_ok());
 /// assert!(validate_language_code("de").is_ok());
 /// assert!(validate_language_code("deu").is_ok());
+/// assert!(validate_language_code("eng+deu").is_ok());
+/// assert!(validate_language_code("auto").is_ok());
 /// assert!(validate_language_code("invalid").is_err());
 /// ```
 pub fn validate_language_code(code: &str) -> Result<()> {
@@ -238,7 +240,13 @@ pub fn validate_language_code(code: &str) -> Result<()> {
         return Ok(());
     }
 
-    if VALID_LANGUAGE_CODES.contains(&code_lower.as_str()) {
+    // "auto" detects the languages of each page before OCR
+    if crate::core::config::is_auto_language(&code_lower) {
+        return Ok(());
+    }
+
+    // Mixed-language documents combine codes with '+' (e.g. "eng+deu")
+    if code_lower.split('+').all(|part| VALID_LANGUAGE_CODES.contains(&part)) {
         return Ok(());
     }
 
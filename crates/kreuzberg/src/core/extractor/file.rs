@@ -13,7 +13,7 @@ use crate::core::mime::{LEGACY_POWERPOINT_MIME_TYPE, LEGACY_WORD_MIME_TYPE};
 use crate::types::ExtractionResult;
 use std::path::Path;
 
-use super::helpers::get_extractors;
+use super::helpers::{digest_cache_key, get_cached_result, get_extractors, result_cache_key, store_cached_result};
 
 /// Sanitize a file path to return only the filename.
 ///
@@ -77,12 +77,12 @@ pub(in crate::core::extractor) fn record_error(error: &KreuzbergError) {
 /// Extract content from a file.
 ///
 /// This is the main entry point for file-based extraction. It performs the following steps:
-/// 1. Check cache for existing result (if caching enabled)
-/// 2. Detect or validate MIME type
-/// 3. Select appropriate extractor from registry
-/// 4. Extract content
-/// 5. Run post-processing pipeline
-/// 6. Store result in cache (if caching enabled)
+/// 1. Detect or validate MIME type
+/// 2. Select appropriate extractor from registry
+/// 3. Check the result cache for this content and config (if `use_cache` is enabled)
+/// 4. Extract content on a cache miss
+/// 5. Store the extractor output in the result cache (if `use_cache` is enabled)
+/// 6. Run post-processing pipeline
 ///
 /// # Arguments
 ///
@@ -182,6 +182,17 @@ pub(in crate::core::extractor) async fn extract_file_with_extractor(
     crate::extractors::ensure_initialized()?;
 
     let extractors = get_extractors(mime_type)?;
+
+    let cache_key = if config.use_cache {
+        let digest = hash_for_cache_key(path).await?;
+        digest_cache_key(digest, mime_type, &extractors, config)
+    } else {
+        None
+    };
+    if let Some(cached) = get_cached_result(config, cache_key.as_deref()) {
+        return crate::core::pipeline::run_pipeline(cached, config).await;
+    }
+
     let mut failures = Vec::new();
     let mut last_error = None;
 
@@ -189,6 +200,7 @@ pub(in crate::core::extractor) async fn extract_file_with_extractor(
         let extractor_name = extractor.name().to_string();
         match extractor.extract_file(path, mime_type, config).await {
             Ok(mut result) => {
+                store_cached_result(config, cache_key.as_deref(), &result);
                 result = crate::core::pipeline::run_pipeline(result, config).await?;
                 return Ok(result);
             }
@@ -221,6 +233,38 @@ pub(in crate::core::extractor) async fn extract_file_with_extractor(
     }
 }
 
+/// Hash a file's content for the result cache key, reading it in blocks
+/// instead of loading it into memory next to the extractor's own copy.
+async fn hash_for_cache_key(path: &Path) -> Result<(u64, u64)> {
+    let mut hasher = crate::cache::ContentHasher::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    #[cfg(feature = "tokio-runtime")]
+    {
+        use tokio::io::AsyncReadExt;
+        let mut file = tokio::fs::File::open(path).await?;
+        loop {
+            let read = file.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+    }
+    #[cfg(not(feature = "tokio-runtime"))]
+    {
+        use std::io::Read;
+        let mut file = std::fs::File::open(path)?;
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+    }
+    Ok(hasher.finish())
+}
+
 pub(in crate::core::extractor) async fn extract_bytes_with_extractor(
     content: &[u8],
     mime_type: &str,
@@ -229,6 +273,12 @@ pub(in crate::core::extractor) async fn extract_bytes_with_extractor(
     crate::extractors::ensure_initialized()?;
 
     let extractors = get_extractors(mime_type)?;
+
+    let cache_key = result_cache_key(content, mime_type, &extractors, config);
+    if let Some(cached) = get_cached_result(config, cache_key.as_deref()) {
+        return crate::core::pipeline::run_pipeline(cached, config).await;
+    }
+
     let mut failures = Vec::new();
     let mut last_error = None;
 
@@ -236,6 +286,7 @@ pub(in crate::core::extractor) async fn extract_bytes_with_extractor(
         let extractor_name = extractor.name().to_string();
         match extractor.extract_bytes(content, mime_type, config).await {
             Ok(mut result) => {
+                store_cached_result(config, cache_key.as_deref(), &result);
                 result = crate::core::pipeline::run_pipeline(result, config).await?;
                 return Ok(result);
             }
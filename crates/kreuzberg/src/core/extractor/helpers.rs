@@ -2,7 +2,10 @@
 //!
 //! This module provides shared utilities used across extraction modules.
 
+use crate::cache::{ContentHasher, ResultCache};
+use crate::core::config::ExtractionConfig;
 use crate::plugins::DocumentExtractor;
+use crate::types::ExtractionResult;
 use crate::utils::{PoolSizeHint, estimate_pool_size};
 use crate::{KreuzbergError, Result};
 use std::sync::Arc;
@@ -24,6 +27,82 @@ pub(in crate::core::extractor) fn get_extractors(mime_type: &str) -> Result<Vec<
     registry_read.get_all(mime_type)
 }
 
+/// Compute the result cache key for a document, or `None` when caching is disabled.
+///
+/// Key generation failures are logged and treated as "do not cache" so that the
+/// cache can never make an extraction fail.
+pub(in crate::core::extractor) fn result_cache_key(
+    content: &[u8],
+    mime_type: &str,
+    extractors: &[Arc<dyn DocumentExtractor>],
+    config: &ExtractionConfig,
+) -> Option<String> {
+    if !config.use_cache {
+        return None;
+    }
+    digest_cache_key(ContentHasher::digest(content), mime_type, extractors, config)
+}
+
+/// Like [`result_cache_key`], for a [`ContentHasher`] digest of the document.
+pub(in crate::core::extractor) fn digest_cache_key(
+    digest: (u64, u64),
+    mime_type: &str,
+    extractors: &[Arc<dyn DocumentExtractor>],
+    config: &ExtractionConfig,
+) -> Option<String> {
+    if !config.use_cache {
+        return None;
+    }
+
+    let extractor_ids = extractors
+        .iter()
+        .map(|e| format!("{}@{}", e.name(), e.version()))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    match ResultCache::digest_cache_key(digest, mime_type, &extractor_ids, config) {
+        Ok(key) => Some(key),
+        Err(e) => {
+            tracing::debug!("Failed to compute result cache key: {}", e);
+            None
+        }
+    }
+}
+
+/// Look up a cached extractor result in the cache selected by `config.cache`.
+pub(in crate::core::extractor) fn get_cached_result(
+    config: &ExtractionConfig,
+    cache_key: Option<&str>,
+) -> Option<ExtractionResult> {
+    let cache_key = cache_key?;
+    let cache = ResultCache::for_config(config.cache.as_ref())?;
+    match cache.get(cache_key) {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::debug!("Result cache lookup failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Store an extractor result in the cache (best effort).
+pub(in crate::core::extractor) fn store_cached_result(
+    config: &ExtractionConfig,
+    cache_key: Option<&str>,
+    result: &ExtractionResult,
+) {
+    let Some(cache_key) = cache_key else {
+        return;
+    };
+    let Some(cache) = ResultCache::for_config(config.cache.as_ref()) else {
+        return;
+    };
+    // Cache write failure - safe to ignore, cache is optional fallback ~keep
+    if let Err(e) = cache.set(cache_key, result) {
+        tracing::debug!("Failed to store extraction result in cache: {}", e);
+    }
+}
+
 /// Get optimal pool sizing hint for a document.
 ///
 /// This function calculates recommended pool sizes based on the document's
@@ -10,6 +10,7 @@
 //! - [`extract_bytes`] - Extract content from a byte array
 //! - [`batch_extract_file`] - Extract content from multiple files concurrently
 //! - [`batch_extract_bytes`] - Extract content from multiple byte arrays concurrently
+//! - [`extract_file_pages`] / [`extract_bytes_pages`] - Stream the pages of a document as they are extracted
 
 mod bytes;
 mod file;
@@ -19,6 +20,8 @@ mod sync;
 
 #[cfg(feature = "tokio-runtime")]
 mod batch;
+#[cfg(feature = "tokio-runtime")]
+mod stream;
 
 // Re-export public API
 pub use bytes::extract_bytes;
@@ -26,12 +29,16 @@ pub use file::extract_file;
 pub use helpers::get_pool_sizing_hint;
 pub use sync::{batch_extract_bytes_sync, extract_bytes_sync};
 
+#[cfg(all(feature = "tokio-runtime", feature = "pdf", feature = "ocr"))]
+pub(crate) use sync::GLOBAL_RUNTIME;
 #[cfg(feature = "tokio-runtime")]
 pub use sync::extract_file_sync;
 
 #[cfg(feature = "tokio-runtime")]
 pub use batch::{batch_extract_bytes, batch_extract_file};
 #[cfg(feature = "tokio-runtime")]
+pub use stream::{PageStream, extract_bytes_pages, extract_file_pages};
+#[cfg(feature = "tokio-runtime")]
 pub use sync::batch_extract_file_sync;
 
 #[cfg(test)]
@@ -191,6 +198,37 @@ mod tests {
         assert!(result3.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_extract_bytes_populates_result_cache() {
+        let cache_dir = tempdir().unwrap();
+        let content = format!("result cache entry {}", uuid::Uuid::new_v4());
+        let config = ExtractionConfig {
+            cache: Some(crate::core::config::CacheConfig {
+                path: Some(cache_dir.path().to_path_buf()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let first = extract_bytes(content.as_bytes(), "text/plain", &config).await.unwrap();
+
+        let extractors = helpers::get_extractors("text/plain").unwrap();
+        let key = helpers::result_cache_key(content.as_bytes(), "text/plain", &extractors, &config).unwrap();
+        let cache = crate::cache::ResultCache::for_config(config.cache.as_ref()).unwrap();
+        assert!(cache.cache_dir().starts_with(cache_dir.path()));
+        let cached = cache.get(&key).unwrap().expect("extractor output should be cached");
+        assert_text_content(&cached.content, &content);
+
+        let second = extract_bytes(content.as_bytes(), "text/plain", &config).await.unwrap();
+        assert_eq!(first.content, second.content);
+
+        let uncached = ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        };
+        assert!(helpers::result_cache_key(content.as_bytes(), "text/plain", &extractors, &uncached).is_none());
+    }
+
     #[tokio::test]
     async fn test_extract_file_empty() {
         let dir = tempdir().unwrap();
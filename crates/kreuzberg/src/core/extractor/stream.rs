@@ -0,0 +1,180 @@
+//! Page-by-page extraction.
+//!
+//! [`extract_file_pages`] and [`extract_bytes_pages`] yield the pages of a document
+//! as they are extracted, instead of one `ExtractionResult` once the whole document
+//! is done. PDFs are read a few pages at a time, so memory stays bounded for very
+//! large documents and the first page arrives right away. Other formats are
+//! extracted whole and their pages (slides for PPTX) are yielded from the result;
+//! formats without page tracking yield their whole content as page 1.
+//!
+//! Post-processors run on every page. Chunking, validators and output format
+//! conversion apply to whole documents and are not run on streamed pages.
+
+use crate::Result;
+use crate::core::config::{ExtractionConfig, PageConfig};
+use crate::types::PageContent;
+use crate::{KreuzbergError, core::pipeline::run_page_processors};
+use futures::{Stream, StreamExt};
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use super::helpers::get_extractors;
+
+/// A stream of extracted pages, in page order.
+pub type PageStream = Pin<Box<dyn Stream<Item = Result<PageContent>> + Send>>;
+
+/// Extract a file page by page.
+///
+/// The file is read and its MIME type detected before the stream is returned, so
+/// missing files and unsupported formats fail here; extraction errors are yielded
+/// by the stream.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use kreuzberg::core::config::ExtractionConfig;
+/// use kreuzberg::core::extractor::extract_file_pages;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let config = ExtractionConfig::default();
+/// let mut pages = extract_file_pages("large.pdf", None, &config).await?;
+/// while let Some(page) = pages.next().await {
+///     let page = page?;
+///     println!("Page {}: {} bytes", page.page_number, page.content.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn extract_file_pages(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+) -> Result<PageStream> {
+    use crate::core::{io, mime};
+
+    let path = path.as_ref();
+    io::validate_file_exists(path)?;
+    let detected_mime = mime::detect_or_validate(Some(path), mime_type)?;
+    let content = io::read_file_async(path).await?;
+
+    stream_pages(content, detected_mime, config).await
+}
+
+/// Extract a byte array page by page.
+///
+/// See [`extract_file_pages`].
+pub async fn extract_bytes_pages(content: Vec<u8>, mime_type: &str, config: &ExtractionConfig) -> Result<PageStream> {
+    let validated_mime = crate::core::mime::validate_mime_type(mime_type)?;
+
+    stream_pages(content, validated_mime, config).await
+}
+
+async fn stream_pages(content: Vec<u8>, mime_type: String, config: &ExtractionConfig) -> Result<PageStream> {
+    crate::extractors::ensure_initialized()?;
+
+    let extractors = get_extractors(&mime_type)?;
+
+    // Only the built-in PDF extractor reads pages incrementally; a custom extractor
+    // registered with a higher priority is respected through the whole-document path.
+    #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
+    if extractors
+        .first()
+        .is_some_and(|extractor| extractor.name() == "pdf-extractor")
+    {
+        return Ok(crate::extractors::pdf::stream_pdf_pages(content, config.clone()));
+    }
+
+    // Post-processors run per page below, not on the whole document
+    let mut document_config = config.clone();
+    document_config.pages = Some(PageConfig {
+        extract_pages: true,
+        ..config.pages.clone().unwrap_or_default()
+    });
+
+    let mut last_error = None;
+    let mut result = None;
+    for extractor in extractors {
+        match extractor.extract_bytes(&content, &mime_type, &document_config).await {
+            Ok(extracted) => {
+                result = Some(extracted);
+                break;
+            }
+            Err(err @ (KreuzbergError::Io(_) | KreuzbergError::LockPoisoned(_))) => return Err(err),
+            Err(err) => {
+                tracing::debug!(
+                    "Extractor '{}' failed for MIME '{}', trying fallback: {}",
+                    extractor.name(),
+                    mime_type,
+                    err
+                );
+                last_error = Some(err);
+            }
+        }
+    }
+    let Some(mut result) = result else {
+        return Err(last_error.unwrap_or_else(|| KreuzbergError::UnsupportedFormat(mime_type)));
+    };
+
+    let pages = match result.pages.take() {
+        Some(pages) if !pages.is_empty() => pages,
+        _ => vec![PageContent {
+            page_number: 1,
+            is_blank: Some(crate::extraction::blank_detection::is_page_text_blank(&result.content)),
+            content: result.content,
+            tables: result.tables.into_iter().map(Arc::new).collect(),
+            images: result.images.unwrap_or_default().into_iter().map(Arc::new).collect(),
+            hierarchy: None,
+            annotations: Vec::new(),
+        }],
+    };
+
+    let config = Arc::new(config.clone());
+    let mime_type: Arc<str> = mime_type.into();
+    Ok(futures::stream::iter(pages)
+        .then(move |mut page| {
+            let config = Arc::clone(&config);
+            let mime_type = Arc::clone(&mime_type);
+            async move {
+                run_page_processors(&mut page, &mime_type, &config).await?;
+                Ok(page)
+            }
+        })
+        .boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unpaged_format_streams_one_page() {
+        let config = ExtractionConfig::default();
+        let pages: Vec<_> = extract_bytes_pages(b"Hello, world!".to_vec(), "text/plain", &config)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(pages.len(), 1);
+        let page = pages.into_iter().next().unwrap().unwrap();
+        assert_eq!(page.page_number, 1);
+        assert_eq!(page.content.trim_end(), "Hello, world!");
+        assert_eq!(page.is_blank, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_fails_before_streaming() {
+        let config = ExtractionConfig::default();
+        let result = extract_file_pages("/nonexistent/document.pdf", None, &config).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_mime_type_fails_before_streaming() {
+        let config = ExtractionConfig::default();
+        let result = extract_bytes_pages(b"data".to_vec(), "application/x-unknown-format", &config).await;
+        assert!(result.is_err());
+    }
+}
@@ -38,7 +38,7 @@ use super::file::extract_file;
 /// This static is only available when the `tokio-runtime` feature is enabled.
 /// For WASM targets, use the truly synchronous extraction functions instead.
 #[cfg(feature = "tokio-runtime")]
-static GLOBAL_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
+pub(crate) static GLOBAL_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
     tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()
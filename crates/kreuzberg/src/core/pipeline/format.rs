@@ -1,10 +1,10 @@
 //! Output format conversion for extraction results.
 //!
 //! This module handles conversion of extraction results to various output formats
-//! (Plain, Djot, Markdown, HTML) with proper error handling and metadata recording.
+//! (Plain, Djot, Markdown, HTML, hOCR, ALTO) with proper error handling and metadata recording.
 
 use crate::core::config::OutputFormat;
-use crate::types::{ExtractionResult, ProcessingWarning};
+use crate::types::{ExtractionResult, ProcessingWarning, Table};
 use std::borrow::Cow;
 
 /// Apply output format conversion to the extraction result.
@@ -13,7 +13,10 @@ use std::borrow::Cow;
 /// - `Plain`: No conversion (default)
 /// - `Djot`: Use djot_content if available, otherwise keep plain text
 /// - `Markdown`: Convert to Markdown format (uses djot as it's similar)
-/// - `Html`: Convert to HTML format
+/// - `Html`: Convert to HTML format (plain content goes into `<pre>` blocks, tables
+///   into HTML tables with `rowspan`/`colspan`)
+/// - `Hocr` / `Alto`: Render `ocr_elements` as hOCR or ALTO XML (content is kept
+///   and a warning recorded when there are no elements)
 ///
 /// Skips conversion if content was already formatted during extraction (e.g., HTML extractor
 /// already produced djot or markdown output).
@@ -39,6 +42,8 @@ pub fn apply_output_format(result: &mut ExtractionResult, output_format: OutputF
         OutputFormat::Djot => "djot",
         OutputFormat::Html => "html",
         OutputFormat::Structured => "structured",
+        OutputFormat::Hocr => "hocr",
+        OutputFormat::Alto => "alto",
     };
     result.metadata.output_format = Some(format_name.to_string());
     // DEPRECATED: kept for backward compatibility; will be removed in next major version.
@@ -145,8 +150,7 @@ pub fn apply_output_format(result: &mut ExtractionResult, output_format: OutputF
                 }
             } else {
                 // For non-djot documents, wrap plain text in basic HTML
-                let escaped_content = html_escape(&result.content);
-                result.content = format!("<pre>{}</pre>", escaped_content);
+                result.content = plain_content_to_html(&result.content, &result.tables);
             }
         }
         OutputFormat::Structured => {
@@ -160,6 +164,64 @@ pub fn apply_output_format(result: &mut ExtractionResult, output_format: OutputF
             // and update the mime_type to indicate structured output.
             // (output_format metadata already set above)
         }
+        OutputFormat::Hocr | OutputFormat::Alto => match result.ocr_elements.as_deref() {
+            Some(elements) if !elements.is_empty() => {
+                let pages = crate::extraction::page_dimensions(result);
+                result.content = if output_format == OutputFormat::Hocr {
+                    crate::extraction::render_hocr(elements, &pages)
+                } else {
+                    crate::extraction::render_alto(elements, &pages)
+                };
+            }
+            _ => {
+                result.processing_warnings.push(ProcessingWarning {
+                    source: "output_format".to_string(),
+                    message: format!(
+                        "No positioned text elements available for {} output, content left unchanged",
+                        format_name
+                    ),
+                });
+            }
+        },
+    }
+}
+
+/// Render plain content as `<pre>` blocks with the tables as HTML tables.
+///
+/// Tables whose Markdown is embedded in the content are rendered in its place;
+/// the others follow the text. Tables go through [`Table::to_html`], so merged
+/// cells keep their `rowspan`/`colspan`.
+fn plain_content_to_html(content: &str, tables: &[Table]) -> String {
+    let mut html = String::new();
+    let mut rest = content;
+    let mut trailing_tables = Vec::new();
+    for table in tables {
+        let markdown = table.markdown.trim();
+        match rest.find(markdown).filter(|_| !markdown.is_empty()) {
+            Some(start) => {
+                push_pre(&mut html, &rest[..start]);
+                html.push_str(&table.to_html());
+                rest = &rest[start + markdown.len()..];
+            }
+            None => trailing_tables.push(table),
+        }
+    }
+    if html.is_empty() {
+        html = format!("<pre>{}</pre>", html_escape(rest));
+    } else {
+        push_pre(&mut html, rest);
+    }
+    for table in trailing_tables {
+        html.push_str(&table.to_html());
+    }
+    html
+}
+
+fn push_pre(html: &mut String, text: &str) {
+    if !text.trim().is_empty() {
+        html.push_str("<pre>");
+        html.push_str(&html_escape(text));
+        html.push_str("</pre>");
     }
 }
 
@@ -279,6 +341,70 @@ mod tests {
         assert!(!result.content.contains("<script>"));
     }
 
+    #[test]
+    fn test_apply_output_format_html_renders_merged_table_cells() {
+        use crate::types::{CellSpan, Table};
+
+        let table = Table {
+            cells: vec![
+                vec!["Region".to_string(), "Sales".to_string(), String::new()],
+                vec!["North".to_string(), "10".to_string(), "20".to_string()],
+            ],
+            markdown: "| Region | Sales |  |\n| --- | --- | --- |\n| North | 10 | 20 |\n".to_string(),
+            page_number: 1,
+            bounding_box: None,
+            end_page_number: None,
+            spans: vec![CellSpan {
+                row: 0,
+                col: 1,
+                row_span: 1,
+                col_span: 2,
+            }],
+        };
+        let mut result = ExtractionResult {
+            content: format!("Before\n\n{}\nAfter & more", table.markdown),
+            mime_type: Cow::Borrowed("application/pdf"),
+            tables: vec![table],
+            ..Default::default()
+        };
+
+        apply_output_format(&mut result, OutputFormat::Html);
+
+        assert_eq!(
+            result.content,
+            "<pre>Before\n\n</pre>\
+             <table><tr><th>Region</th><th colspan=\"2\">Sales</th></tr>\
+             <tr><td>North</td><td>10</td><td>20</td></tr></table>\
+             <pre>\n\nAfter &amp; more</pre>"
+        );
+    }
+
+    #[test]
+    fn test_apply_output_format_html_appends_tables_missing_from_content() {
+        use crate::types::Table;
+
+        let mut result = ExtractionResult {
+            content: "A B".to_string(),
+            mime_type: Cow::Borrowed("application/pdf"),
+            tables: vec![Table {
+                cells: vec![vec!["A".to_string(), "B".to_string()]],
+                markdown: "| A | B |\n| --- | --- |\n".to_string(),
+                page_number: 1,
+                bounding_box: None,
+                end_page_number: None,
+                spans: vec![],
+            }],
+            ..Default::default()
+        };
+
+        apply_output_format(&mut result, OutputFormat::Html);
+
+        assert_eq!(
+            result.content,
+            "<pre>A B</pre><table><tr><th>A</th><th>B</th></tr></table>"
+        );
+    }
+
     #[test]
     fn test_apply_output_format_markdown() {
         let mut result = ExtractionResult {
@@ -329,6 +455,8 @@ mod tests {
             markdown: "| A | B |".to_string(),
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let mut result = ExtractionResult {
@@ -388,4 +516,50 @@ mod tests {
         assert!(result.djot_content.is_some());
         assert_eq!(result.djot_content.as_ref().unwrap().blocks.len(), 1);
     }
+
+    #[test]
+    fn test_apply_output_format_hocr_from_ocr_elements() {
+        use crate::types::{OcrBoundingGeometry, OcrConfidence, OcrElement, OcrElementLevel};
+
+        let mut result = ExtractionResult {
+            content: "Hello".to_string(),
+            mime_type: Cow::Borrowed("image/png"),
+            ocr_elements: Some(vec![
+                OcrElement::new(
+                    "Hello",
+                    OcrBoundingGeometry::Rectangle {
+                        left: 5,
+                        top: 5,
+                        width: 40,
+                        height: 12,
+                    },
+                    OcrConfidence::from_tesseract(96.0),
+                )
+                .with_level(OcrElementLevel::Word),
+            ]),
+            ..Default::default()
+        };
+
+        apply_output_format(&mut result, OutputFormat::Hocr);
+
+        assert!(result.content.contains("class=\"ocrx_word\""));
+        assert!(result.content.contains(">Hello</span>"));
+        assert_eq!(result.metadata.output_format, Some("hocr".to_string()));
+        assert!(result.processing_warnings.is_empty());
+    }
+
+    #[test]
+    fn test_apply_output_format_alto_without_elements_warns() {
+        let mut result = ExtractionResult {
+            content: "Plain text".to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            ..Default::default()
+        };
+
+        apply_output_format(&mut result, OutputFormat::Alto);
+
+        assert_eq!(result.content, "Plain text");
+        assert_eq!(result.processing_warnings.len(), 1);
+        assert_eq!(result.processing_warnings[0].source, "output_format");
+    }
 }
@@ -18,6 +18,8 @@ pub use format::apply_output_format;
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::types::ExtractionResult;
+#[cfg(feature = "tokio-runtime")]
+use crate::types::PageContent;
 
 use execution::{execute_processors, execute_validators};
 use features::{execute_chunking, execute_language_detection};
@@ -95,6 +97,47 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
     Ok(result)
 }
 
+/// Run the registered post-processors on a single page.
+///
+/// Used by the page streaming API: the page's text and tables are wrapped in an
+/// `ExtractionResult` of the document's MIME type, processed stage by stage, and
+/// the text is written back. Chunking, language detection, validators and output
+/// format conversion apply to whole documents and are not run here.
+#[cfg(feature = "tokio-runtime")]
+pub(crate) async fn run_page_processors(
+    page: &mut PageContent,
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Result<()> {
+    let pp_config = config.postprocessor.as_ref();
+    if !pp_config.is_none_or(|c| c.enabled) {
+        return Ok(());
+    }
+
+    initialize_features();
+    initialize_processor_cache()?;
+    let (early_processors, middle_processors, late_processors) = get_processors_from_cache()?;
+
+    let mut result = ExtractionResult {
+        content: std::mem::take(&mut page.content),
+        mime_type: mime_type.to_string().into(),
+        tables: page.tables.iter().map(|table| (**table).clone()).collect(),
+        ..Default::default()
+    };
+    execute_processors(
+        &mut result,
+        config,
+        &pp_config,
+        early_processors,
+        middle_processors,
+        late_processors,
+    )
+    .await?;
+
+    page.content = result.content;
+    Ok(())
+}
+
 /// Run the post-processing pipeline synchronously (WASM-compatible version).
 ///
 /// This is a synchronous implementation for WASM and non-async contexts.
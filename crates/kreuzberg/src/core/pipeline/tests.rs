@@ -243,6 +243,8 @@ async fn test_pipeline_preserves_tables() {
         markdown: "| A | B |".to_string(),
         page_number: 0,
         bounding_box: None,
+        end_page_number: None,
+        spans: vec![],
     };
 
     let result = ExtractionResult {
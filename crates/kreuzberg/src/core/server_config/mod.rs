@@ -67,6 +67,7 @@ const DEFAULT_MAX_MULTIPART_FIELD_BYTES: usize = 104_857_600;
 /// - `max_request_body_bytes`: 104_857_600 (100 MB)
 /// - `max_multipart_field_bytes`: 104_857_600 (100 MB)
 /// - `max_upload_mb`: None (legacy field, not used if other fields set)
+/// - `cache`: None (filesystem result cache)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ServerConfig {
@@ -101,6 +102,13 @@ pub struct ServerConfig {
     /// New configurations should use `max_multipart_field_bytes` directly.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_upload_mb: Option<usize>,
+
+    /// Cache backend used for extraction results (None = filesystem cache).
+    ///
+    /// Applied to requests whose extraction config does not set its own `cache`
+    /// section. Point several replicas at the same SQLite file to share entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache: Option<crate::core::config::CacheConfig>,
 }
 
 impl Default for ServerConfig {
@@ -112,6 +120,7 @@ impl Default for ServerConfig {
             max_request_body_bytes: default_max_request_body_bytes(),
             max_multipart_field_bytes: default_max_multipart_field_bytes(),
             max_upload_mb: None,
+            cache: None,
         }
     }
 }
@@ -15,11 +15,13 @@ fn map_output_format(format: KreuzbergOutputFormat) -> LibOutputFormat {
     match format {
         KreuzbergOutputFormat::Markdown => LibOutputFormat::Markdown,
         KreuzbergOutputFormat::Djot => LibOutputFormat::Djot,
-        // Plain, Html, and Structured default to Markdown for HTML conversions
+        // Plain, Html, Structured, hOCR and ALTO default to Markdown for HTML conversions
         // Structured output includes the converted content plus full element metadata
-        KreuzbergOutputFormat::Plain | KreuzbergOutputFormat::Html | KreuzbergOutputFormat::Structured => {
-            LibOutputFormat::Markdown
-        }
+        KreuzbergOutputFormat::Plain
+        | KreuzbergOutputFormat::Html
+        | KreuzbergOutputFormat::Structured
+        | KreuzbergOutputFormat::Hocr
+        | KreuzbergOutputFormat::Alto => LibOutputFormat::Markdown,
     }
 }
 
@@ -426,7 +426,7 @@ pub struct ImageOcrResult {
 /// # Returns
 /// Frame count if valid TIFF, error otherwise.
 #[cfg(feature = "ocr")]
-fn detect_tiff_frame_count(bytes: &[u8]) -> Result<usize> {
+pub(crate) fn detect_tiff_frame_count(bytes: &[u8]) -> Result<usize> {
     use tiff::decoder::Decoder;
     let mut decoder =
         Decoder::new(Cursor::new(bytes)).map_err(|e| KreuzbergError::parsing(format!("TIFF decode: {}", e)))?;
@@ -438,6 +438,61 @@ fn detect_tiff_frame_count(bytes: &[u8]) -> Result<usize> {
     Ok(count)
 }
 
+/// Decode every frame of a TIFF.
+///
+/// Supports 1-bit and 8-bit grayscale as well as 8-bit RGB and RGBA frames,
+/// which covers the output of document scanners.
+#[cfg(feature = "ocr")]
+pub(crate) fn decode_tiff_frames(bytes: &[u8]) -> Result<Vec<image::DynamicImage>> {
+    use image::DynamicImage;
+    use tiff::ColorType;
+    use tiff::decoder::{Decoder, DecodingResult};
+
+    let tiff_error = |e: tiff::TiffError| KreuzbergError::parsing(format!("TIFF decode: {}", e));
+    let mut decoder = Decoder::new(Cursor::new(bytes)).map_err(tiff_error)?;
+
+    let mut frames = Vec::new();
+    loop {
+        let (width, height) = decoder.dimensions().map_err(tiff_error)?;
+        let color_type = decoder.colortype().map_err(tiff_error)?;
+        let DecodingResult::U8(data) = decoder.read_image().map_err(tiff_error)? else {
+            return Err(KreuzbergError::parsing(format!(
+                "Unsupported TIFF sample format: {:?}",
+                color_type
+            )));
+        };
+
+        let frame = match color_type {
+            ColorType::Gray(1) => {
+                // Rows are padded to whole bytes; 1 is white (WhiteIsZero is inverted by the decoder)
+                let stride = width.div_ceil(8) as usize;
+                (data.len() >= stride * height as usize).then(|| {
+                    DynamicImage::ImageLuma8(image::GrayImage::from_fn(width, height, |x, y| {
+                        let byte = data[y as usize * stride + x as usize / 8];
+                        image::Luma([if (byte >> (7 - x % 8)) & 1 == 1 { 255 } else { 0 }])
+                    }))
+                })
+            }
+            ColorType::Gray(8) => image::GrayImage::from_raw(width, height, data).map(DynamicImage::ImageLuma8),
+            ColorType::RGB(8) => image::RgbImage::from_raw(width, height, data).map(DynamicImage::ImageRgb8),
+            ColorType::RGBA(8) => image::RgbaImage::from_raw(width, height, data).map(DynamicImage::ImageRgba8),
+            other => {
+                return Err(KreuzbergError::parsing(format!(
+                    "Unsupported TIFF color type: {:?}",
+                    other
+                )));
+            }
+        };
+        let frame = frame.ok_or_else(|| KreuzbergError::parsing("TIFF frame data is truncated"))?;
+        frames.push(frame);
+
+        if !decoder.more_images() {
+            return Ok(frames);
+        }
+        decoder.next_image().map_err(tiff_error)?;
+    }
+}
+
 /// Extract text from image bytes using OCR with optional page tracking for multi-frame TIFFs.
 ///
 /// This function:
@@ -517,6 +572,7 @@ pub fn extract_text_from_image_with_ocr(
             images: vec![],
             hierarchy: None,
             is_blank: Some(crate::extraction::blank_detection::is_page_text_blank(frame_text)),
+            annotations: Vec::new(),
         });
 
         byte_offset = frame_end;
@@ -878,4 +934,26 @@ mod jp2_decode_tests {
         assert!(!is_jbig2(&[]));
         assert!(!is_jbig2(&[0x97, 0x4A])); // too short
     }
+
+    #[test]
+    #[cfg(feature = "ocr")]
+    fn test_decode_tiff_frames() {
+        use tiff::encoder::{TiffEncoder, colortype};
+
+        let mut bytes = Cursor::new(Vec::new());
+        {
+            let mut encoder = TiffEncoder::new(&mut bytes).unwrap();
+            encoder
+                .write_image::<colortype::Gray8>(4, 2, &[0, 50, 100, 150, 200, 250, 255, 0])
+                .unwrap();
+            encoder.write_image::<colortype::RGB8>(1, 1, &[10, 20, 30]).unwrap();
+        }
+        let bytes = bytes.into_inner();
+
+        let frames = decode_tiff_frames(&bytes).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].to_luma8().get_pixel(1, 0).0, [50]);
+        assert_eq!(frames[1].to_rgb8().get_pixel(0, 0).0, [10, 20, 30]);
+        assert!(decode_tiff_frames(b"not a tiff").is_err());
+    }
 }
@@ -5,7 +5,11 @@
 
 #[cfg(all(feature = "ocr", feature = "tokio-runtime"))]
 use crate::ocr::OcrProcessor;
+#[cfg(all(feature = "ocr", feature = "tokio-runtime"))]
+use crate::types::OcrRotation;
 use crate::types::{ExtractedImage, ExtractionResult, Metadata};
+#[cfg(all(feature = "ocr", feature = "tokio-runtime"))]
+use std::borrow::Cow;
 
 /// Process extracted images with OCR if configured.
 ///
@@ -27,17 +31,35 @@ pub async fn process_images_with_ocr(
     let ocr_config = config.ocr.as_ref().unwrap();
     let tess_config = ocr_config.tesseract_config.as_ref().cloned().unwrap_or_default();
     let output_format = config.output_format;
+    let cache_config = config.cache.clone();
 
     for image in &mut images {
-        let image_data = image.data.clone();
+        let (oriented, rotation) = if ocr_config.orientation_detection_enabled() {
+            let detected = detect_orientation(image.data.clone()).await?;
+            crate::ocr::orientation::apply_orientation(&image.data, detected)?
+        } else {
+            (Cow::Borrowed(&image.data[..]), None)
+        };
+
+        let (processed, mut preprocessing) = crate::image::apply_ocr_preprocessing(&oriented, ocr_config)?;
+        let processed = match processed {
+            Cow::Owned(processed) => Some(processed),
+            Cow::Borrowed(_) => None,
+        };
+        let image_data = match (processed, oriented) {
+            (Some(processed), _) | (None, Cow::Owned(processed)) => bytes::Bytes::from(processed),
+            (None, Cow::Borrowed(_)) => image.data.clone(),
+        };
+        if let Some(metadata) = preprocessing.as_mut() {
+            crate::ocr::orientation::record_rotation(metadata, rotation.as_ref());
+        }
         let tess_config_clone = tess_config.clone();
+        let cache_config = cache_config.clone();
         let span = tracing::Span::current();
 
         let ocr_result = tokio::task::spawn_blocking(move || {
             let _guard = span.entered();
-            let cache_dir = std::env::var("KREUZBERG_CACHE_DIR").ok().map(std::path::PathBuf::from);
-
-            let proc = OcrProcessor::new(cache_dir)?;
+            let proc = OcrProcessor::with_cache_config(cache_config.as_ref())?;
             let ocr_tess_config: crate::ocr::types::TesseractConfig = (&tess_config_clone).into();
             proc.process_image_with_format(&image_data, &ocr_tess_config, output_format)
         })
@@ -49,10 +71,18 @@ pub async fn process_images_with_ocr(
 
         match ocr_result {
             Ok(ocr_extraction) => {
+                let mut metadata = Metadata {
+                    image_preprocessing: preprocessing,
+                    ..Default::default()
+                };
+                if let Some(rotation) = rotation {
+                    let dimensions = image.width.zip(image.height).map(|(w, h)| (w as f64, h as f64));
+                    crate::ocr::orientation::set_page_rotation(&mut metadata, 1, dimensions, rotation);
+                }
                 let extraction_result = ExtractionResult {
                     content: ocr_extraction.content,
                     mime_type: ocr_extraction.mime_type.into(),
-                    metadata: Metadata::default(),
+                    metadata,
                     tables: vec![],
                     detected_languages: None,
                     chunks: None,
@@ -77,3 +107,21 @@ pub async fn process_images_with_ocr(
 
     Ok(images)
 }
+
+/// Detect the orientation of an embedded image with Tesseract OSD.
+///
+/// Failures are not fatal: `None` lets the heuristic decide.
+#[cfg(all(feature = "ocr", feature = "tokio-runtime"))]
+async fn detect_orientation(image_data: bytes::Bytes) -> crate::Result<Option<OcrRotation>> {
+    let detected = tokio::task::spawn_blocking(move || OcrProcessor::new(None)?.detect_orientation(&image_data))
+        .await
+        .map_err(|e| crate::KreuzbergError::Ocr {
+            message: format!("Orientation detection task failed: {}", e),
+            source: None,
+        })?;
+
+    Ok(detected.unwrap_or_else(|e| {
+        tracing::debug!("Tesseract orientation detection failed: {}", e);
+        None
+    }))
+}
@@ -0,0 +1,145 @@
+//! ALTO v4 rendering.
+
+use super::{BBox, LayoutPage, PageDimensions, escape_xml, layout_pages};
+use crate::types::OcrElement;
+use std::fmt::Write;
+
+/// Render elements as an ALTO v4 document.
+///
+/// Positions are written in `pixel` units; each page holds a single
+/// `PrintSpace` with `TextBlock`s, `TextLine`s and `String`s separated by `SP`
+/// elements. Word confidence is written as `WC` (0.0-1.0).
+pub fn render_alto(elements: &[OcrElement], pages: &[PageDimensions]) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(
+        "<alto xmlns=\"http://www.loc.gov/standards/alto/ns-v4#\" \
+         xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+         xsi:schemaLocation=\"http://www.loc.gov/standards/alto/ns-v4# \
+         http://www.loc.gov/alto/v4/alto-4-2.xsd\">\n",
+    );
+    out.push_str("  <Description>\n    <MeasurementUnit>pixel</MeasurementUnit>\n");
+    out.push_str("    <OCRProcessing ID=\"OCR_0\">\n      <ocrProcessingStep>\n        <processingSoftware>\n");
+    out.push_str("          <softwareName>kreuzberg</softwareName>\n");
+    let _ = writeln!(
+        out,
+        "          <softwareVersion>{}</softwareVersion>",
+        env!("CARGO_PKG_VERSION")
+    );
+    out.push_str("        </processingSoftware>\n      </ocrProcessingStep>\n    </OCRProcessing>\n");
+    out.push_str("  </Description>\n  <Layout>\n");
+
+    for page in layout_pages(elements, pages) {
+        write_page(&mut out, &page);
+    }
+
+    out.push_str("  </Layout>\n</alto>\n");
+    out
+}
+
+fn write_page(out: &mut String, page: &LayoutPage) {
+    let n = page.number;
+    let _ = writeln!(
+        out,
+        "    <Page ID=\"page_{n}\" PHYSICAL_IMG_NR=\"{n}\" WIDTH=\"{}\" HEIGHT=\"{}\">",
+        page.width, page.height
+    );
+    let _ = writeln!(
+        out,
+        "      <PrintSpace HPOS=\"0\" VPOS=\"0\" WIDTH=\"{}\" HEIGHT=\"{}\">",
+        page.width, page.height
+    );
+
+    let mut line_id = 0;
+    let mut word_id = 0;
+    for (index, block) in page.blocks.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "        <TextBlock ID=\"block_{n}_{}\" {}>",
+            index + 1,
+            position(block.bbox)
+        );
+        for line in &block.lines {
+            line_id += 1;
+            let _ = writeln!(
+                out,
+                "          <TextLine ID=\"line_{n}_{line_id}\" {}>",
+                position(line.bbox)
+            );
+            for (position_in_line, word) in line.words.iter().enumerate() {
+                if position_in_line > 0 {
+                    let previous = line.words[position_in_line - 1].bbox;
+                    let _ = writeln!(
+                        out,
+                        "            <SP WIDTH=\"{}\" VPOS=\"{}\" HPOS=\"{}\"/>",
+                        word.bbox.0.saturating_sub(previous.2),
+                        previous.1,
+                        previous.2
+                    );
+                }
+                word_id += 1;
+                let _ = writeln!(
+                    out,
+                    "            <String ID=\"string_{n}_{word_id}\" CONTENT=\"{}\" {} WC=\"{:.2}\"/>",
+                    escape_xml(&word.text),
+                    position(word.bbox),
+                    word.confidence.clamp(0.0, 1.0)
+                );
+            }
+            out.push_str("          </TextLine>\n");
+        }
+        out.push_str("        </TextBlock>\n");
+    }
+
+    out.push_str("      </PrintSpace>\n    </Page>\n");
+}
+
+fn position((x0, y0, x1, y1): BBox) -> String {
+    format!(
+        "HPOS=\"{x0}\" VPOS=\"{y0}\" WIDTH=\"{}\" HEIGHT=\"{}\"",
+        x1.saturating_sub(x0),
+        y1.saturating_sub(y0)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{paddle_line, word};
+    use super::*;
+
+    #[test]
+    fn test_render_alto_tesseract_words() {
+        let elements = vec![
+            word("Tom", 10, 10, 30, "p1_b1_par1_l1"),
+            word("\"Jerry\"", 60, 10, 50, "p1_b1_par1_l1"),
+        ];
+        let alto = render_alto(
+            &elements,
+            &[PageDimensions {
+                page_number: 1,
+                width: 200.0,
+                height: 100.0,
+            }],
+        );
+
+        assert!(alto.contains("<Page ID=\"page_1\" PHYSICAL_IMG_NR=\"1\" WIDTH=\"200\" HEIGHT=\"100\">"));
+        assert!(alto.contains("<TextLine ID=\"line_1_1\" HPOS=\"10\" VPOS=\"10\" WIDTH=\"100\" HEIGHT=\"20\">"));
+        assert!(alto.contains(
+            "<String ID=\"string_1_1\" CONTENT=\"Tom\" HPOS=\"10\" VPOS=\"10\" WIDTH=\"30\" HEIGHT=\"20\" WC=\"0.90\"/>"
+        ));
+        assert!(alto.contains("<SP WIDTH=\"20\" VPOS=\"10\" HPOS=\"40\"/>"));
+        assert!(alto.contains("CONTENT=\"&quot;Jerry&quot;\""));
+    }
+
+    #[test]
+    fn test_render_alto_paddle_lines_across_pages() {
+        let elements = vec![
+            paddle_line("first page", 0, 0, 100),
+            paddle_line("second", 0, 0, 60).with_page_number(2),
+        ];
+        let alto = render_alto(&elements, &[]);
+        assert_eq!(alto.matches("<Page ").count(), 2);
+        assert!(alto.contains("<Page ID=\"page_2\" PHYSICAL_IMG_NR=\"2\" WIDTH=\"60\" HEIGHT=\"20\">"));
+        assert!(alto.contains("CONTENT=\"second\" HPOS=\"0\" VPOS=\"0\" WIDTH=\"60\" HEIGHT=\"20\" WC=\"0.80\""));
+    }
+}
@@ -0,0 +1,134 @@
+//! hOCR 1.2 rendering.
+
+use super::{BBox, LayoutPage, PageDimensions, escape_xml, layout_pages};
+use crate::types::OcrElement;
+use std::fmt::Write;
+
+/// Render elements as an hOCR document.
+///
+/// Each page becomes an `ocr_page` holding `ocr_carea` / `ocr_par` blocks,
+/// `ocr_line`s and `ocrx_word`s, with `bbox` and `x_wconf` (0-100) properties
+/// the way Tesseract writes them.
+pub fn render_hocr(elements: &[OcrElement], pages: &[PageDimensions]) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(
+        "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \
+         \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">\n",
+    );
+    out.push_str("<html xmlns=\"http://www.w3.org/1999/xhtml\" xml:lang=\"en\" lang=\"en\">\n");
+    out.push_str(" <head>\n  <title></title>\n");
+    out.push_str("  <meta http-equiv=\"Content-Type\" content=\"text/html;charset=utf-8\"/>\n");
+    let _ = writeln!(
+        out,
+        "  <meta name=\"ocr-system\" content=\"kreuzberg {}\"/>",
+        env!("CARGO_PKG_VERSION")
+    );
+    out.push_str("  <meta name=\"ocr-capabilities\" content=\"ocr_page ocr_carea ocr_par ocr_line ocrx_word\"/>\n");
+    out.push_str(" </head>\n <body>\n");
+
+    for page in layout_pages(elements, pages) {
+        write_page(&mut out, &page);
+    }
+
+    out.push_str(" </body>\n</html>\n");
+    out
+}
+
+fn write_page(out: &mut String, page: &LayoutPage) {
+    let n = page.number;
+    let _ = writeln!(
+        out,
+        "  <div class=\"ocr_page\" id=\"page_{n}\" title=\"bbox 0 0 {} {}; ppageno {}\">",
+        page.width,
+        page.height,
+        n.saturating_sub(1)
+    );
+
+    let mut line_id = 0;
+    let mut word_id = 0;
+    for (index, block) in page.blocks.iter().enumerate() {
+        let b = index + 1;
+        let _ = writeln!(
+            out,
+            "   <div class=\"ocr_carea\" id=\"block_{n}_{b}\" title=\"{}\">",
+            bbox_title(block.bbox)
+        );
+        let _ = writeln!(
+            out,
+            "    <p class=\"ocr_par\" id=\"par_{n}_{b}\" title=\"{}\">",
+            bbox_title(block.bbox)
+        );
+        for line in &block.lines {
+            line_id += 1;
+            let _ = writeln!(
+                out,
+                "     <span class=\"ocr_line\" id=\"line_{n}_{line_id}\" title=\"{}\">",
+                bbox_title(line.bbox)
+            );
+            for word in &line.words {
+                word_id += 1;
+                let _ = writeln!(
+                    out,
+                    "      <span class=\"ocrx_word\" id=\"word_{n}_{word_id}\" title=\"{}; x_wconf {}\">{}</span>",
+                    bbox_title(word.bbox),
+                    (word.confidence * 100.0).round().clamp(0.0, 100.0) as u32,
+                    escape_xml(&word.text)
+                );
+            }
+            out.push_str("     </span>\n");
+        }
+        out.push_str("    </p>\n   </div>\n");
+    }
+
+    out.push_str("  </div>\n");
+}
+
+fn bbox_title((x0, y0, x1, y1): BBox) -> String {
+    format!("bbox {x0} {y0} {x1} {y1}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{paddle_line, word};
+    use super::*;
+
+    #[test]
+    fn test_render_hocr_tesseract_words() {
+        let elements = vec![
+            word("Tom", 10, 10, 30, "p1_b1_par1_l1"),
+            word("&", 45, 10, 10, "p1_b1_par1_l1"),
+            word("Jerry", 60, 10, 50, "p1_b1_par1_l1"),
+        ];
+        let hocr = render_hocr(
+            &elements,
+            &[PageDimensions {
+                page_number: 1,
+                width: 200.0,
+                height: 100.0,
+            }],
+        );
+
+        assert!(hocr.contains("<div class=\"ocr_page\" id=\"page_1\" title=\"bbox 0 0 200 100; ppageno 0\">"));
+        assert!(hocr.contains("<span class=\"ocr_line\" id=\"line_1_1\" title=\"bbox 10 10 110 30\">"));
+        assert!(
+            hocr.contains(
+                "<span class=\"ocrx_word\" id=\"word_1_2\" title=\"bbox 45 10 55 30; x_wconf 90\">&amp;</span>"
+            )
+        );
+        assert_eq!(hocr.matches("class=\"ocrx_word\"").count(), 3);
+    }
+
+    #[test]
+    fn test_render_hocr_paddle_lines() {
+        let hocr = render_hocr(&[paddle_line("ab cd", 0, 0, 50)], &[]);
+        assert!(hocr.contains("title=\"bbox 0 0 20 20; x_wconf 80\">ab</span>"));
+        assert!(hocr.contains("title=\"bbox 30 0 50 20; x_wconf 80\">cd</span>"));
+    }
+
+    #[test]
+    fn test_render_hocr_empty() {
+        let hocr = render_hocr(&[], &[]);
+        assert!(hocr.contains("<body>\n </body>"));
+    }
+}
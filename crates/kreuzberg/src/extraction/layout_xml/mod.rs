@@ -0,0 +1,397 @@
+//! hOCR and ALTO XML renderers for positioned text.
+//!
+//! Both formats describe a page as nested blocks, lines and words with pixel
+//! bounding boxes, which is what archives, digitisation pipelines and PDF
+//! re-layering tools consume. The renderers work purely on [`OcrElement`]s, so
+//! Tesseract words, PaddleOCR lines and the word geometry of native PDF text all
+//! produce the same document shape:
+//!
+//! - Words are grouped into lines by their `parent_id` (Tesseract, PDF text layer)
+//!   or, without one, by vertical overlap.
+//! - Backends that only report lines (PaddleOCR) get one word per whitespace
+//!   separated token, with the line box split by character count.
+//! - Lines are grouped into blocks by Tesseract's block/paragraph numbers, or
+//!   else by vertical gaps and horizontal overlap.
+
+mod alto;
+mod hocr;
+
+pub use alto::render_alto;
+pub use hocr::render_hocr;
+
+use crate::types::{ExtractionResult, OcrElement, OcrElementLevel};
+use std::collections::BTreeMap;
+
+/// Size of a page in the coordinate space of its elements.
+///
+/// Pixels for OCR'd images, PDF points for the text layer of digital PDFs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageDimensions {
+    /// Page number (1-indexed).
+    pub page_number: usize,
+    /// Page width.
+    pub width: f64,
+    /// Page height.
+    pub height: f64,
+}
+
+/// Collect the page dimensions known for an extraction result.
+///
+/// Page-level elements win, then the size of the preprocessed image the OCR
+/// backend saw, then `PageInfo::dimensions`. Pages without any of these are
+/// sized by the renderers from the extent of their elements.
+pub fn page_dimensions(result: &ExtractionResult) -> Vec<PageDimensions> {
+    let mut dimensions: BTreeMap<usize, (f64, f64)> = BTreeMap::new();
+
+    if let Some(pages) = result
+        .metadata
+        .pages
+        .as_ref()
+        .and_then(|structure| structure.pages.as_ref())
+    {
+        for page in pages {
+            if let Some(size) = page.dimensions {
+                dimensions.insert(page.number, size);
+            }
+        }
+    }
+
+    // OCR coordinates of a single image refer to the image after preprocessing.
+    if result.mime_type.starts_with("image/")
+        && let Some(preprocessing) = result.metadata.image_preprocessing.as_ref()
+    {
+        let (width, height) = preprocessing
+            .new_dimensions
+            .unwrap_or(preprocessing.original_dimensions);
+        dimensions.insert(1, (width as f64, height as f64));
+    }
+
+    for element in result.ocr_elements.iter().flatten() {
+        if element.level == OcrElementLevel::Page {
+            let (left, top, width, height) = element.geometry.to_aabb();
+            dimensions.insert(element.page_number, ((left + width) as f64, (top + height) as f64));
+        }
+    }
+
+    dimensions
+        .into_iter()
+        .map(|(page_number, (width, height))| PageDimensions {
+            page_number,
+            width,
+            height,
+        })
+        .collect()
+}
+
+/// Axis-aligned box as `(x0, y0, x1, y1)`.
+pub(crate) type BBox = (u32, u32, u32, u32);
+
+#[derive(Debug, Clone)]
+pub(crate) struct LayoutWord {
+    pub(crate) text: String,
+    pub(crate) bbox: BBox,
+    /// Recognition confidence (0.0-1.0).
+    pub(crate) confidence: f64,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LayoutLine {
+    pub(crate) bbox: BBox,
+    pub(crate) words: Vec<LayoutWord>,
+    /// Tesseract `(block_num, par_num)`, when known.
+    block_key: Option<(i64, i64)>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LayoutBlock {
+    pub(crate) bbox: BBox,
+    pub(crate) lines: Vec<LayoutLine>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LayoutPage {
+    pub(crate) number: usize,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) blocks: Vec<LayoutBlock>,
+}
+
+/// Arrange elements into pages of blocks, lines and words.
+pub(crate) fn layout_pages(elements: &[OcrElement], pages: &[PageDimensions]) -> Vec<LayoutPage> {
+    let mut by_page: BTreeMap<usize, Vec<&OcrElement>> = BTreeMap::new();
+    for page in pages {
+        by_page.entry(page.page_number).or_default();
+    }
+    for element in elements {
+        if !element.text.trim().is_empty() && element.level != OcrElementLevel::Page {
+            by_page.entry(element.page_number).or_default().push(element);
+        }
+    }
+
+    by_page
+        .into_iter()
+        .map(|(number, elements)| {
+            let lines = page_lines(&elements);
+            let blocks = group_blocks(lines);
+            let extent = blocks.iter().map(|block| block.bbox).reduce(union);
+            let (width, height) = match pages.iter().find(|page| page.page_number == number) {
+                Some(page) => (page.width.round() as u32, page.height.round() as u32),
+                None => extent.map(|(_, _, x1, y1)| (x1, y1)).unwrap_or((0, 0)),
+            };
+            LayoutPage {
+                number,
+                width,
+                height,
+                blocks,
+            }
+        })
+        .collect()
+}
+
+/// Build the lines of one page, preferring word elements over line elements.
+fn page_lines(elements: &[&OcrElement]) -> Vec<LayoutLine> {
+    let words: Vec<&OcrElement> = elements
+        .iter()
+        .copied()
+        .filter(|element| element.level == OcrElementLevel::Word)
+        .collect();
+
+    if words.is_empty() {
+        return elements
+            .iter()
+            .filter(|element| element.level == OcrElementLevel::Line)
+            .map(|element| {
+                let bbox = bbox(element);
+                LayoutLine {
+                    bbox,
+                    words: split_line_words(&element.text, bbox, element.confidence.recognition),
+                    block_key: block_key(element),
+                }
+            })
+            .collect();
+    }
+
+    let mut lines: Vec<LayoutLine> = Vec::new();
+    let mut keyed: Vec<(String, usize)> = Vec::new();
+    for element in words {
+        let word = LayoutWord {
+            text: element.text.trim().to_string(),
+            bbox: bbox(element),
+            confidence: element.confidence.recognition,
+        };
+
+        let existing = match element.parent_id.as_deref() {
+            Some(parent) => keyed.iter().find(|(key, _)| key == parent).map(|(_, index)| *index),
+            None => lines
+                .last()
+                .filter(|line| vertically_overlapping(line.bbox, word.bbox))
+                .map(|_| lines.len() - 1),
+        };
+
+        match existing {
+            Some(index) => {
+                let line = &mut lines[index];
+                line.bbox = union(line.bbox, word.bbox);
+                line.words.push(word);
+            }
+            None => {
+                if let Some(parent) = element.parent_id.as_deref() {
+                    keyed.push((parent.to_string(), lines.len()));
+                }
+                lines.push(LayoutLine {
+                    bbox: word.bbox,
+                    words: vec![word],
+                    block_key: block_key(element),
+                });
+            }
+        }
+    }
+    lines
+}
+
+/// Group consecutive lines into blocks.
+fn group_blocks(lines: Vec<LayoutLine>) -> Vec<LayoutBlock> {
+    let mut blocks: Vec<LayoutBlock> = Vec::new();
+    for line in lines {
+        let joins_previous = blocks.last().is_some_and(|block| {
+            let previous = block.lines.last().expect("blocks are never empty");
+            match (previous.block_key, line.block_key) {
+                (Some(previous_key), Some(key)) => previous_key == key,
+                _ => continues_block(previous.bbox, line.bbox),
+            }
+        });
+
+        if joins_previous && let Some(block) = blocks.last_mut() {
+            block.bbox = union(block.bbox, line.bbox);
+            block.lines.push(line);
+        } else {
+            blocks.push(LayoutBlock {
+                bbox: line.bbox,
+                lines: vec![line],
+            });
+        }
+    }
+    blocks
+}
+
+/// Whether `next` continues the block ending with `previous`: it starts below
+/// within one line height and overlaps horizontally.
+fn continues_block(previous: BBox, next: BBox) -> bool {
+    let line_height = (previous.3 - previous.1).max(next.3 - next.1);
+    let gap = next.1 as i64 - previous.3 as i64;
+    let horizontal_overlap = next.0 < previous.2 && previous.0 < next.2;
+    horizontal_overlap && gap >= -((line_height / 2) as i64) && gap <= line_height as i64
+}
+
+fn vertically_overlapping(a: BBox, b: BBox) -> bool {
+    let center = (b.1 + b.3) / 2;
+    a.1 <= center && center <= a.3
+}
+
+/// Split a line into words, dividing its width by character count.
+fn split_line_words(text: &str, bbox: BBox, confidence: f64) -> Vec<LayoutWord> {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let total_chars = text.trim().chars().count().max(1) as f64;
+    let width = (bbox.2 - bbox.0) as f64;
+
+    let mut offset = 0usize;
+    let mut words = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let length = token.chars().count();
+        let x0 = bbox.0 + (width * offset as f64 / total_chars).round() as u32;
+        let x1 = bbox.0 + (width * (offset + length) as f64 / total_chars).round() as u32;
+        words.push(LayoutWord {
+            text: token.to_string(),
+            bbox: (x0, bbox.1, x1.max(x0), bbox.3),
+            confidence,
+        });
+        offset += length + 1;
+    }
+    words
+}
+
+fn bbox(element: &OcrElement) -> BBox {
+    let (left, top, width, height) = element.geometry.to_aabb();
+    (left, top, left + width, top + height)
+}
+
+fn block_key(element: &OcrElement) -> Option<(i64, i64)> {
+    let metadata = &element.backend_metadata;
+    Some((metadata.get("block_num")?.as_i64()?, metadata.get("par_num")?.as_i64()?))
+}
+
+fn union(a: BBox, b: BBox) -> BBox {
+    (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3))
+}
+
+/// Escape text for XML content and attribute values.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            ch if ch.is_control() && !matches!(ch, '\t' | '\n' | '\r') => {}
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrBoundingGeometry, OcrConfidence};
+
+    pub(super) fn word(text: &str, left: u32, top: u32, width: u32, parent: &str) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Rectangle {
+                left,
+                top,
+                width,
+                height: 20,
+            },
+            OcrConfidence::from_tesseract(90.0),
+        )
+        .with_level(OcrElementLevel::Word)
+        .with_parent_id(parent)
+    }
+
+    pub(super) fn paddle_line(text: &str, left: u32, top: u32, width: u32) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Quadrilateral {
+                points: [
+                    (left, top),
+                    (left + width, top),
+                    (left + width, top + 20),
+                    (left, top + 20),
+                ],
+            },
+            OcrConfidence::from_paddle(0.9, 0.8),
+        )
+        .with_level(OcrElementLevel::Line)
+        .with_metadata("backend", serde_json::json!("paddle-ocr"))
+    }
+
+    #[test]
+    fn test_words_grouped_by_parent() {
+        let elements = vec![
+            word("Hello", 10, 10, 50, "p1_b1_par1_l1"),
+            word("world", 70, 10, 50, "p1_b1_par1_l1"),
+            word("Next", 10, 40, 40, "p1_b1_par1_l2"),
+        ];
+        let pages = layout_pages(&elements, &[]);
+        assert_eq!(pages.len(), 1);
+        let lines = &pages[0].blocks[0].lines;
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].words.len(), 2);
+        assert_eq!(lines[0].bbox, (10, 10, 120, 30));
+        assert_eq!((pages[0].width, pages[0].height), (120, 60));
+    }
+
+    #[test]
+    fn test_lines_without_words_are_split() {
+        let elements = vec![paddle_line("ab cd", 0, 0, 50)];
+        let pages = layout_pages(&elements, &[]);
+        let words = &pages[0].blocks[0].lines[0].words;
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].bbox, (0, 0, 20, 20));
+        assert_eq!(words[1].bbox, (30, 0, 50, 20));
+    }
+
+    #[test]
+    fn test_blocks_split_on_vertical_gap() {
+        let elements = vec![
+            paddle_line("first", 10, 10, 100),
+            paddle_line("second", 10, 32, 100),
+            paddle_line("far away", 10, 200, 100),
+        ];
+        let pages = layout_pages(&elements, &[]);
+        assert_eq!(pages[0].blocks.len(), 2);
+        assert_eq!(pages[0].blocks[0].lines.len(), 2);
+    }
+
+    #[test]
+    fn test_page_dimensions_take_precedence_over_extent() {
+        let elements = vec![word("x", 10, 10, 10, "l1")];
+        let pages = layout_pages(
+            &elements,
+            &[PageDimensions {
+                page_number: 1,
+                width: 612.0,
+                height: 792.0,
+            }],
+        );
+        assert_eq!((pages[0].width, pages[0].height), (612, 792));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a<b & \"c\"\u{7}"), "a&lt;b &amp; &quot;c&quot;");
+    }
+}
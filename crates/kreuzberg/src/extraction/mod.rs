@@ -1,4 +1,5 @@
 pub mod blank_detection;
+pub mod layout_xml;
 pub mod structured;
 pub mod text;
 pub mod transform;
@@ -58,6 +59,7 @@ pub mod xml;
 #[cfg(any(feature = "office", feature = "html", feature = "xml"))]
 pub mod markdown;
 
+pub use layout_xml::{PageDimensions, page_dimensions, render_alto, render_hocr};
 pub use structured::{JsonExtractionConfig, StructuredDataResult, parse_json, parse_toml, parse_yaml};
 pub use text::parse_text;
 pub use transform::{
@@ -68,6 +68,7 @@ impl ContentBuilder {
                 images: Vec::new(),
                 hierarchy: None,
                 is_blank,
+                annotations: Vec::new(),
             });
         }
     }
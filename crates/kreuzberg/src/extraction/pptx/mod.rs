@@ -194,6 +194,11 @@ fn extract_pptx_from_container<R: std::io::Read + std::io::Seek>(
                     table_count: None,
                     hidden: None,
                     is_blank: pc.is_blank,
+                    rotation: None,
+                    text_layer: None,
+                    ocr_backend: None,
+                    ocr_language: None,
+                    ocr_confidence: None,
                 })
                 .collect()
         }),
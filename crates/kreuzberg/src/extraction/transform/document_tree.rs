@@ -5,9 +5,10 @@
 //! layer classification.
 
 use crate::types::document_structure::GridCell;
+use crate::types::outline::OutlineHeadings;
 use crate::types::{
     BoundingBox, ContentLayer, DocumentNode, DocumentStructure, ExtractionResult, NodeContent, NodeId, NodeIndex,
-    TableGrid,
+    PageFurniture, PdfOutlineEntry, Table, TableGrid,
 };
 
 use super::elements::detect_list_items;
@@ -16,11 +17,13 @@ use super::types::ListType;
 /// Transform an `ExtractionResult` into a `DocumentStructure`.
 ///
 /// Processes pages (if available) or unified content to build a hierarchical tree:
-/// - Heading-driven section nesting via `Group` nodes
+/// - Heading-driven section nesting via `Group` nodes, with heading levels taken
+///   from the PDF outline (bookmarks) when the document has one
 /// - Table conversion from `Vec<Vec<String>>` to `TableGrid`
 /// - List detection and grouping into `List` containers
-/// - Image and page break nodes
-/// - Body/furniture content layer classification
+/// - Image, annotation and page break nodes
+/// - Body/furniture content layer classification, including the running headers
+///   and footers stripped from PDF pages
 ///
 /// The resulting structure is validated before returning.
 pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStructure {
@@ -28,15 +31,26 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
     let mut section_stack: Vec<(u8, NodeIndex)> = Vec::new();
 
     if let Some(ref pages) = result.pages {
+        let mut outline = OutlineHeadings::new(outline_entries(result));
         for page in pages {
             let page_num = page.page_number as u32;
             // Reset section stack for each new page (prevents cross-page nesting)
             section_stack.clear();
 
+            // Running headers stripped from the page content
+            let furniture = page_furniture(result);
+            for header in furniture
+                .iter()
+                .filter(|f| f.page_number == page.page_number && f.layer == ContentLayer::Header)
+            {
+                push_furniture_node(&mut doc, header);
+            }
+
             // Process hierarchy blocks (headings) first — they create section groups
             if let Some(ref hierarchy) = page.hierarchy {
                 for block in &hierarchy.blocks {
-                    let level = parse_heading_level(&block.level);
+                    let level =
+                        outline.resolve_level(&block.text, Some(page.page_number), parse_heading_level(&block.level));
                     let bbox = block.bbox.map(BoundingBox::from);
 
                     if let Some(level) = level {
@@ -58,7 +72,7 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
             // Process tables
             for table_arc in &page.tables {
                 let table = table_arc.as_ref();
-                let grid = table_cells_to_grid(&table.cells);
+                let grid = table_to_grid(table);
                 push_content_node(
                     &mut doc,
                     &section_stack,
@@ -87,7 +101,38 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
             // (hierarchy blocks already contain the structured body content)
             let has_hierarchy_blocks = page.hierarchy.as_ref().is_some_and(|h| !h.blocks.is_empty());
             if !has_hierarchy_blocks {
-                process_text_content(&mut doc, &section_stack, &page.content, Some(page_num));
+                process_text_content(
+                    &mut doc,
+                    &mut section_stack,
+                    &mut outline,
+                    &page.content,
+                    Some(page_num),
+                );
+            }
+
+            // Process page annotations (comments, highlights, links)
+            for annotation in &page.annotations {
+                push_content_node(
+                    &mut doc,
+                    &section_stack,
+                    NodeContent::Annotation {
+                        annotation_type: annotation.annotation_type,
+                        text: annotation.contents.clone(),
+                        author: annotation.author.clone(),
+                        highlighted_text: annotation.highlighted_text.clone(),
+                        url: annotation.url.clone(),
+                    },
+                    Some(page_num),
+                    annotation.bounding_box,
+                );
+            }
+
+            // Running footers stripped from the page content
+            for footer in furniture
+                .iter()
+                .filter(|f| f.page_number == page.page_number && f.layer == ContentLayer::Footer)
+            {
+                push_furniture_node(&mut doc, footer);
             }
 
             // Add PageBreak between pages (not after last)
@@ -97,11 +142,12 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
         }
     } else {
         // No pages — process unified content
-        process_text_content(&mut doc, &section_stack, &result.content, Some(1));
+        let mut outline = OutlineHeadings::new(outline_entries(result)).ignore_pages();
+        process_text_content(&mut doc, &mut section_stack, &mut outline, &result.content, Some(1));
 
         // Process global tables
         for table in &result.tables {
-            let grid = table_cells_to_grid(&table.cells);
+            let grid = table_to_grid(table);
             push_content_node(
                 &mut doc,
                 &section_stack,
@@ -127,6 +173,11 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
                 );
             }
         }
+
+        // Running headers and footers stripped from the content
+        for furniture in page_furniture(result) {
+            push_furniture_node(&mut doc, furniture);
+        }
     }
 
     // Validation — debug assert in dev, silent in release
@@ -139,6 +190,53 @@ pub fn transform_to_document_structure(result: &ExtractionResult) -> DocumentStr
     doc
 }
 
+/// Outline (bookmark) entries of a PDF result, empty for other formats.
+#[cfg(feature = "pdf")]
+fn outline_entries(result: &ExtractionResult) -> &[PdfOutlineEntry] {
+    match &result.metadata.format {
+        Some(crate::types::FormatMetadata::Pdf(pdf)) => pdf.outline.as_deref().unwrap_or_default(),
+        _ => &[],
+    }
+}
+
+#[cfg(not(feature = "pdf"))]
+fn outline_entries(_result: &ExtractionResult) -> &[PdfOutlineEntry] {
+    &[]
+}
+
+/// Running headers and footers stripped from a PDF result, empty for other formats.
+#[cfg(feature = "pdf")]
+fn page_furniture(result: &ExtractionResult) -> &[PageFurniture] {
+    match &result.metadata.format {
+        Some(crate::types::FormatMetadata::Pdf(pdf)) => pdf.page_furniture.as_deref().unwrap_or_default(),
+        _ => &[],
+    }
+}
+
+#[cfg(not(feature = "pdf"))]
+fn page_furniture(_result: &ExtractionResult) -> &[PageFurniture] {
+    &[]
+}
+
+/// Push a running header or footer as a root-level paragraph in the furniture layer.
+fn push_furniture_node(doc: &mut DocumentStructure, furniture: &PageFurniture) {
+    let page = Some(furniture.page_number as u32);
+    let index = doc.len() as u32;
+    doc.push_node(DocumentNode {
+        id: NodeId::generate("paragraph", &furniture.text, page, index),
+        content: NodeContent::Paragraph {
+            text: furniture.text.clone(),
+        },
+        parent: None,
+        children: vec![],
+        content_layer: furniture.layer,
+        page,
+        page_end: None,
+        bbox: furniture.bounding_box,
+        annotations: vec![],
+    });
+}
+
 // ============================================================================
 // Section Nesting
 // ============================================================================
@@ -229,7 +327,8 @@ fn push_content_node(
 /// Process text content into paragraphs and list items.
 fn process_text_content(
     doc: &mut DocumentStructure,
-    section_stack: &[(u8, NodeIndex)],
+    section_stack: &mut Vec<(u8, NodeIndex)>,
+    outline: &mut OutlineHeadings<'_>,
     content: &str,
     page: Option<u32>,
 ) {
@@ -241,7 +340,7 @@ fn process_text_content(
 
     if list_items.is_empty() {
         // No list items — split into paragraphs
-        add_paragraphs(doc, section_stack, content, page);
+        add_paragraphs(doc, section_stack, outline, content, page);
         return;
     }
 
@@ -254,7 +353,7 @@ fn process_text_content(
         // Add paragraphs before list items
         if current_offset < item.byte_start {
             let text_before = &content[current_offset..item.byte_start];
-            add_paragraphs(doc, section_stack, text_before, page);
+            add_paragraphs(doc, section_stack, outline, text_before, page);
         }
 
         // Group consecutive same-type list items
@@ -323,17 +422,39 @@ fn process_text_content(
     // Add remaining text after last list item
     if current_offset < content.len() {
         let text_after = &content[current_offset..];
-        add_paragraphs(doc, section_stack, text_after, page);
+        add_paragraphs(doc, section_stack, outline, text_after, page);
     }
 }
 
 /// Add paragraphs split on double newlines.
-fn add_paragraphs(doc: &mut DocumentStructure, section_stack: &[(u8, NodeIndex)], text: &str, page: Option<u32>) {
+///
+/// Paragraphs matching an outline title become heading groups; when only the
+/// first line matches, it is split off as the heading.
+fn add_paragraphs(
+    doc: &mut DocumentStructure,
+    section_stack: &mut Vec<(u8, NodeIndex)>,
+    outline: &mut OutlineHeadings<'_>,
+    text: &str,
+    page: Option<u32>,
+) {
+    let outline_page = page.map(|p| p as usize);
     for paragraph in text.split("\n\n").filter(|p| !p.trim().is_empty()) {
-        let para_text = paragraph.trim();
+        let mut para_text = paragraph.trim();
         if para_text.is_empty() {
             continue;
         }
+
+        if let Some(level) = outline.match_heading(para_text, outline_page) {
+            push_heading_group(doc, section_stack, level, para_text, page, None);
+            continue;
+        }
+        if let Some((first_line, rest)) = para_text.split_once('\n')
+            && let Some(level) = outline.match_heading(first_line, outline_page)
+        {
+            push_heading_group(doc, section_stack, level, first_line.trim(), page, None);
+            para_text = rest.trim();
+        }
+
         push_content_node(
             doc,
             section_stack,
@@ -350,20 +471,30 @@ fn add_paragraphs(doc: &mut DocumentStructure, section_stack: &[(u8, NodeIndex)]
 // Table Conversion
 // ============================================================================
 
-/// Convert a `Vec<Vec<String>>` cell grid into a `TableGrid`.
-fn table_cells_to_grid(cells: &[Vec<String>]) -> TableGrid {
+/// Convert a table's cell grid into a `TableGrid`.
+///
+/// Merged cells keep their spans; the positions they cover are left out.
+pub(crate) fn table_to_grid(table: &Table) -> TableGrid {
+    let cells = &table.cells;
     let rows = cells.len() as u32;
     let cols = cells.iter().map(|r| r.len()).max().unwrap_or(0) as u32;
+    let covered = table.covered_positions();
 
     let mut grid_cells = Vec::new();
     for (row_idx, row) in cells.iter().enumerate() {
         for (col_idx, cell_content) in row.iter().enumerate() {
+            if covered.contains(&(row_idx, col_idx)) {
+                continue;
+            }
+            let (row_span, col_span) = table
+                .span_at(row_idx, col_idx)
+                .map_or((1, 1), |span| (span.row_span as u32, span.col_span as u32));
             grid_cells.push(GridCell {
                 content: cell_content.clone(),
                 row: row_idx as u32,
                 col: col_idx as u32,
-                row_span: 1,
-                col_span: 1,
+                row_span,
+                col_span,
                 is_header: row_idx == 0, // First row assumed header
                 bbox: None,
             });
@@ -395,7 +526,7 @@ fn parse_heading_level(level: &str) -> Option<u8> {
 }
 
 /// Strip list marker from text (e.g., "- item" → "item", "1. item" → "item").
-fn strip_list_marker(text: &str) -> &str {
+pub(crate) fn strip_list_marker(text: &str) -> &str {
     let trimmed = text.trim_start();
     // Bullet markers
     for prefix in &["- ", "* ", "• "] {
@@ -448,7 +579,7 @@ fn estimate_node_count(result: &ExtractionResult) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{ExtractionResult, HierarchicalBlock, Metadata, PageContent, PageHierarchy, Table};
+    use crate::types::{ExtractionResult, HierarchicalBlock, Metadata, PageContent, PageHierarchy};
     use std::borrow::Cow;
 
     fn test_metadata() -> Metadata {
@@ -565,6 +696,7 @@ mod tests {
                     ],
                 }),
                 is_blank: None,
+                annotations: Vec::new(),
             }]),
             ..test_result("")
         };
@@ -592,6 +724,204 @@ mod tests {
         assert!(!h1_children.is_empty());
     }
 
+    #[test]
+    fn test_page_annotations_become_nodes() {
+        let annotation = crate::types::PdfAnnotation {
+            page_number: 1,
+            annotation_type: crate::types::PdfAnnotationType::Highlight,
+            author: Some("Alice".to_string()),
+            created_at: None,
+            modified_at: None,
+            contents: Some("Double-check this figure".to_string()),
+            highlighted_text: Some("revenue grew 12%".to_string()),
+            url: None,
+            target_page: None,
+            bounding_box: Some(BoundingBox {
+                x0: 72.0,
+                y0: 700.0,
+                x1: 200.0,
+                y1: 712.0,
+            }),
+        };
+        let result = ExtractionResult {
+            pages: Some(vec![PageContent {
+                page_number: 1,
+                content: "Last year revenue grew 12%.".to_string(),
+                tables: vec![],
+                images: vec![],
+                hierarchy: None,
+                is_blank: None,
+                annotations: vec![annotation],
+            }]),
+            ..test_result("")
+        };
+
+        let doc = transform_to_document_structure(&result);
+        assert!(doc.validate().is_ok());
+
+        let node = doc
+            .nodes
+            .iter()
+            .find(|node| matches!(node.content, NodeContent::Annotation { .. }))
+            .expect("annotation node");
+        assert_eq!(node.page, Some(1));
+        assert_eq!(node.bbox.map(|bbox| bbox.x0), Some(72.0));
+        match &node.content {
+            NodeContent::Annotation {
+                text, highlighted_text, ..
+            } => {
+                assert_eq!(text.as_deref(), Some("Double-check this figure"));
+                assert_eq!(highlighted_text.as_deref(), Some("revenue grew 12%"));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_pdf_outline_overrides_clustered_headings() {
+        use crate::pdf::metadata::PdfMetadata;
+        use crate::types::FormatMetadata;
+
+        let block = |text: &str, level: &str| HierarchicalBlock {
+            text: text.to_string(),
+            font_size: 12.0,
+            level: level.to_string(),
+            bbox: None,
+        };
+        let outline = vec![
+            PdfOutlineEntry {
+                title: "1 Overview".to_string(),
+                level: 1,
+                page: Some(1),
+            },
+            PdfOutlineEntry {
+                title: "Installation".to_string(),
+                level: 2,
+                page: Some(2),
+            },
+        ];
+        let result = ExtractionResult {
+            metadata: Metadata {
+                format: Some(FormatMetadata::Pdf(PdfMetadata {
+                    outline: Some(outline),
+                    ..Default::default()
+                })),
+                ..test_metadata()
+            },
+            pages: Some(vec![
+                PageContent {
+                    page_number: 1,
+                    content: String::new(),
+                    tables: vec![],
+                    images: vec![],
+                    hierarchy: Some(PageHierarchy {
+                        block_count: 3,
+                        blocks: vec![
+                            block("Company Confidential", "h1"),
+                            block("1. Overview", "body"),
+                            block("The tool converts documents.", "body"),
+                        ],
+                    }),
+                    is_blank: None,
+                    annotations: vec![],
+                },
+                PageContent {
+                    page_number: 2,
+                    content: "Installation\nRun the installer.".to_string(),
+                    tables: vec![],
+                    images: vec![],
+                    hierarchy: None,
+                    is_blank: None,
+                    annotations: vec![],
+                },
+            ]),
+            ..test_result("")
+        };
+
+        let doc = transform_to_document_structure(&result);
+        assert!(doc.validate().is_ok());
+
+        let headings: Vec<(Option<u8>, Option<&str>)> = doc
+            .nodes
+            .iter()
+            .filter_map(|node| match &node.content {
+                NodeContent::Group {
+                    heading_level,
+                    heading_text,
+                    ..
+                } => Some((*heading_level, heading_text.as_deref())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            headings,
+            vec![
+                (Some(3), Some("Company Confidential")),
+                (Some(1), Some("1. Overview")),
+                (Some(2), Some("Installation")),
+            ]
+        );
+        assert!(doc.nodes.iter().any(|node| matches!(
+            &node.content,
+            NodeContent::Paragraph { text } if text == "Run the installer."
+        )));
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_pdf_page_furniture_becomes_header_and_footer_nodes() {
+        use crate::pdf::metadata::PdfMetadata;
+        use crate::types::FormatMetadata;
+
+        let furniture = |layer: ContentLayer, text: &str| PageFurniture {
+            page_number: 1,
+            layer,
+            text: text.to_string(),
+            bounding_box: None,
+        };
+        let result = ExtractionResult {
+            metadata: Metadata {
+                format: Some(FormatMetadata::Pdf(PdfMetadata {
+                    page_furniture: Some(vec![
+                        furniture(ContentLayer::Header, "ACME Annual Report"),
+                        furniture(ContentLayer::Footer, "Page 1 of 2"),
+                    ]),
+                    ..Default::default()
+                })),
+                ..test_metadata()
+            },
+            pages: Some(vec![PageContent {
+                page_number: 1,
+                content: "Revenue grew in every region.".to_string(),
+                tables: vec![],
+                images: vec![],
+                hierarchy: None,
+                is_blank: None,
+                annotations: vec![],
+            }]),
+            ..test_result("")
+        };
+
+        let doc = transform_to_document_structure(&result);
+        assert!(doc.validate().is_ok());
+
+        let nodes: Vec<(ContentLayer, Option<&str>)> = doc
+            .nodes
+            .iter()
+            .map(|node| (node.content_layer, node.content.text()))
+            .collect();
+        assert_eq!(
+            nodes,
+            vec![
+                (ContentLayer::Header, Some("ACME Annual Report")),
+                (ContentLayer::Body, Some("Revenue grew in every region.")),
+                (ContentLayer::Footer, Some("Page 1 of 2")),
+            ]
+        );
+        assert!(doc.nodes.iter().all(|node| node.parent.is_none()));
+    }
+
     #[test]
     fn test_multiple_h1_sections() {
         let result = ExtractionResult {
@@ -618,6 +948,7 @@ mod tests {
                     ],
                 }),
                 is_blank: None,
+                annotations: Vec::new(),
             }]),
             ..test_result("")
         };
@@ -657,6 +988,7 @@ mod tests {
                     ],
                 }),
                 is_blank: None,
+                annotations: Vec::new(),
             }]),
             ..test_result("")
         };
@@ -695,6 +1027,8 @@ mod tests {
                 markdown: "| Name | Age |\n|---|---|\n| Alice | 30 |".to_string(),
                 page_number: 1,
                 bounding_box: None,
+                end_page_number: None,
+                spans: vec![],
             }],
             ..test_result("Some content")
         };
@@ -718,6 +1052,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_table_grid_conversion_keeps_spans() {
+        let result = ExtractionResult {
+            tables: vec![Table {
+                cells: vec![
+                    vec!["Region".to_string(), "Sales".to_string(), String::new()],
+                    vec!["North".to_string(), "10".to_string(), "12".to_string()],
+                ],
+                markdown: String::new(),
+                page_number: 1,
+                bounding_box: None,
+                end_page_number: None,
+                spans: vec![crate::types::CellSpan {
+                    row: 0,
+                    col: 1,
+                    row_span: 1,
+                    col_span: 2,
+                }],
+            }],
+            ..test_result("Some content")
+        };
+
+        let doc = transform_to_document_structure(&result);
+        let grid = doc
+            .nodes
+            .iter()
+            .find_map(|n| match &n.content {
+                NodeContent::Table { grid } => Some(grid),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(grid.cols, 3);
+        assert_eq!(grid.cells.len(), 5);
+        assert_eq!(grid.cells[1].content, "Sales");
+        assert_eq!(grid.cells[1].col_span, 2);
+        assert_eq!((grid.cells[2].row, grid.cells[2].col), (1, 0));
+    }
+
     #[test]
     fn test_serde_roundtrip() {
         let result = test_result("Hello world.\n\n- Item 1\n- Item 2");
@@ -304,6 +304,7 @@ mod tests {
                         ],
                     }),
                     is_blank: None,
+                    annotations: Vec::new(),
                 },
                 PageContent {
                     page_number: 2,
@@ -312,6 +313,7 @@ mod tests {
                     images: vec![],
                     hierarchy: None,
                     is_blank: None,
+                    annotations: Vec::new(),
                 },
             ]),
             elements: None,
@@ -375,6 +377,8 @@ mod tests {
             markdown: "| Header1 | Header2 |\n| Cell1 | Cell2 |".to_string(),
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let image = ExtractedImage {
@@ -408,6 +412,7 @@ mod tests {
                 images: vec![Arc::new(image)],
                 hierarchy: None,
                 is_blank: None,
+                annotations: Vec::new(),
             }]),
             elements: None,
             ocr_elements: None,
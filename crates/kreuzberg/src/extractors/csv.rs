@@ -92,6 +92,8 @@ impl DocumentExtractor for CsvExtractor {
             markdown,
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let row_count = rows.len();
@@ -57,6 +57,8 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
                         markdown,
                         page_number: idx + 1,
                         bounding_box: None,
+                        end_page_number: None,
+                        spans: vec![],
                     });
                     table_index += 1;
                 }
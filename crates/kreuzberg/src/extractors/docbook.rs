@@ -243,6 +243,8 @@ fn parse_docbook_single_pass(content: &str) -> Result<DocBookParseResult> {
                                 markdown,
                                 page_number: table_index + 1,
                                 bounding_box: None,
+                                end_page_number: None,
+                                spans: vec![],
                             });
                             table_index += 1;
                             current_table.clear();
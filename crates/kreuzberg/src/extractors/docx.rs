@@ -387,6 +387,8 @@ fn convert_docx_table_to_table(docx_table: &crate::extraction::docx::parser::Tab
         markdown,
         page_number: table_index + 1,
         bounding_box: None,
+        end_page_number: None,
+        spans: vec![],
     }
 }
 
@@ -591,6 +593,11 @@ impl DocumentExtractor for DocxExtractor {
                             table_count: None,
                             hidden: None,
                             is_blank: None,
+                            rotation: None,
+                            text_layer: None,
+                            ocr_backend: None,
+                            ocr_language: None,
+                            ocr_confidence: None,
                         })
                         .collect(),
                 ),
@@ -733,6 +740,7 @@ impl DocumentExtractor for DocxExtractor {
                         images: page_images,
                         hierarchy: None,
                         is_blank: Some(is_blank),
+                        annotations: Vec::new(),
                     });
                 }
                 Some(pages)
@@ -745,6 +753,7 @@ impl DocumentExtractor for DocxExtractor {
                     images: arc_images,
                     hierarchy: None,
                     is_blank: Some(text.chars().filter(|c| !c.is_whitespace()).count() < 3),
+                    annotations: Vec::new(),
                 }])
             }
         };
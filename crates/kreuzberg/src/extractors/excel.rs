@@ -47,6 +47,8 @@ impl ExcelExtractor {
                     markdown: sheet.markdown.clone(),
                     page_number: sheet_index + 1,
                     bounding_box: None,
+                    end_page_number: None,
+                    spans: vec![],
                 });
             }
         }
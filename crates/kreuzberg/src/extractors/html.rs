@@ -64,6 +64,8 @@ fn parse_markdown_tables(markdown: &str) -> Vec<Table> {
                 markdown: markdown_table,
                 page_number: table_index + 1,
                 bounding_box: None,
+                end_page_number: None,
+                spans: vec![],
             });
             table_index += 1;
             i = end_idx;
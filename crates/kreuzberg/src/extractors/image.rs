@@ -45,11 +45,57 @@ impl ImageExtractor {
             registry.get(&backend_name)?
         };
 
-        // Thread output_format from ExtractionConfig to OcrConfig
+        // Thread output_format and the cache store from ExtractionConfig to OcrConfig
         let mut ocr_config_with_format = ocr_config.clone();
         ocr_config_with_format.output_format = Some(config.output_format);
+        ocr_config_with_format.cache = config.cache.clone();
+
+        // Multi-frame TIFFs are recognized frame by frame; only when their frames
+        // cannot be decoded is the file handed to the backend as-is ~keep
+        let multi_frame = mime_type.to_lowercase().contains("tiff")
+            && crate::extraction::image::detect_tiff_frame_count(content).unwrap_or(1) > 1;
+        let mut processing_warnings = Vec::new();
+        if multi_frame {
+            match crate::extraction::image::decode_tiff_frames(content) {
+                Ok(frames) => {
+                    return self
+                        .extract_frames_with_ocr(frames, backend.as_ref(), &ocr_config_with_format, config)
+                        .await;
+                }
+                Err(e) => {
+                    if ocr_config.orientation_detection_enabled()
+                        || crate::image::enhance::ocr_preprocessing_config(ocr_config).is_some()
+                    {
+                        processing_warnings.push(crate::types::ProcessingWarning {
+                            source: "image_preprocessing".to_string(),
+                            message: format!(
+                                "TIFF frames could not be decoded ({}); the image was recognized without orientation correction or preprocessing",
+                                e
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+        let (oriented, rotation) = if !multi_frame && ocr_config.orientation_detection_enabled() {
+            crate::ocr::orientation::correct_page_orientation(backend.as_ref(), content, &ocr_config_with_format)
+                .await?
+        } else {
+            (std::borrow::Cow::Borrowed(content), None)
+        };
+        let (ocr_input, mut preprocessing) = if multi_frame {
+            (std::borrow::Cow::Borrowed(content), None)
+        } else {
+            crate::image::apply_ocr_preprocessing(&oriented, &ocr_config_with_format)?
+        };
+        if let Some(metadata) = preprocessing.as_mut() {
+            crate::ocr::orientation::record_rotation(metadata, rotation.as_ref());
+        }
 
-        let ocr_result = backend.process_image(content, &ocr_config_with_format).await?;
+        let crate::ocr::fallback::Recognition {
+            result: ocr_result,
+            source,
+        } = crate::ocr::fallback::recognize(backend.as_ref(), &ocr_input, &ocr_config_with_format).await?;
 
         let ocr_text = ocr_result.content.clone();
         let ocr_extraction_result = crate::extraction::image::extract_text_from_image_with_ocr(
@@ -62,9 +108,197 @@ impl ImageExtractor {
         let mut result = ocr_result;
         result.content = ocr_extraction_result.content;
         result.pages = ocr_extraction_result.page_contents;
+        if preprocessing.is_some() {
+            result.metadata.image_preprocessing = preprocessing;
+        }
+        let dimensions = image_dimensions(content).map(|(width, height)| (width as f64, height as f64));
+        if !multi_frame {
+            let page_info = crate::ocr::orientation::page_info_mut(&mut result.metadata, 1, dimensions);
+            crate::ocr::fallback::record_source(page_info, &source);
+        }
+        if let Some(rotation) = rotation {
+            if let Some(elements) = result.ocr_elements.as_mut() {
+                crate::ocr::orientation::annotate_elements(elements, &rotation);
+            }
+            crate::ocr::orientation::set_page_rotation(&mut result.metadata, 1, dimensions, rotation);
+        } else if config.output_format.requires_ocr_elements()
+            && !multi_frame
+            && result.metadata.image_preprocessing.is_none()
+            && let Some((width, height)) = image_dimensions(content)
+            && let Some(elements) = result.ocr_elements.as_mut()
+        {
+            // hOCR and ALTO pages need the size of the image the coordinates refer to
+            // (with preprocessing, its metadata records the size instead).
+            elements.insert(0, page_element(1, (width, height)));
+        }
+        result.processing_warnings.extend(processing_warnings);
 
         Ok(result)
     }
+
+    /// OCR the frames of a multi-frame TIFF one by one, so every page gets the
+    /// orientation correction and preprocessing a single image gets.
+    ///
+    /// `metadata.image_preprocessing` records the first frame, like it records
+    /// the first page of an OCR'd PDF.
+    #[cfg(feature = "ocr")]
+    async fn extract_frames_with_ocr(
+        &self,
+        frames: Vec<image::DynamicImage>,
+        backend: &dyn crate::plugins::OcrBackend,
+        ocr_config: &crate::core::config::OcrConfig,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let preprocessing = crate::image::enhance::ocr_preprocessing_config(ocr_config);
+        let frame_count = frames.len();
+
+        let mut result = ExtractionResult::default();
+        let mut first_preprocessing = None;
+        let mut page_texts = Vec::with_capacity(frame_count);
+        let mut page_infos = Vec::with_capacity(frame_count);
+
+        for (idx, frame) in frames.into_iter().enumerate() {
+            let page_number = idx + 1;
+            let dimensions = Some((frame.width() as f64, frame.height() as f64));
+            let (frame, rotation) = if ocr_config.orientation_detection_enabled() {
+                let (frame, rotation) =
+                    crate::ocr::orientation::correct_image_orientation(backend, frame, ocr_config).await?;
+                (frame, Some(rotation))
+            } else {
+                (frame, None)
+            };
+
+            let (image_data, image_size) = match &preprocessing {
+                Some(preprocessing) => {
+                    let mut enhanced = crate::image::preprocess_for_ocr(&frame, preprocessing, None)?;
+                    if first_preprocessing.is_none() {
+                        crate::ocr::orientation::record_rotation(&mut enhanced.metadata, rotation.as_ref());
+                        first_preprocessing = Some(enhanced.metadata);
+                    }
+                    let size = enhanced.image.dimensions();
+                    (crate::image::enhance::encode_png(&enhanced.image)?, size)
+                }
+                None => (
+                    crate::ocr::orientation::encode_png(&frame)?,
+                    (frame.width(), frame.height()),
+                ),
+            };
+
+            let crate::ocr::fallback::Recognition {
+                result: mut frame_result,
+                source,
+            } = crate::ocr::fallback::recognize(backend, &image_data, ocr_config).await?;
+
+            if let Some(mut frame_elements) = frame_result.ocr_elements.take() {
+                if let Some(rotation) = &rotation {
+                    crate::ocr::orientation::annotate_elements(&mut frame_elements, rotation);
+                }
+                let elements = result.ocr_elements.get_or_insert_with(Vec::new);
+                if config.output_format.requires_ocr_elements() {
+                    elements.push(page_element(page_number, image_size));
+                }
+                elements.extend(frame_elements.into_iter().map(|mut element| {
+                    element.page_number = page_number;
+                    element
+                }));
+            }
+            result.tables.extend(frame_result.tables.drain(..).map(|mut table| {
+                table.page_number = page_number;
+                table
+            }));
+            page_texts.push(std::mem::take(&mut frame_result.content));
+            page_infos.push((page_number, dimensions, source, rotation));
+
+            if idx == 0 {
+                result.mime_type = frame_result.mime_type;
+                result.metadata = frame_result.metadata;
+                result.detected_languages = frame_result.detected_languages;
+            }
+        }
+
+        for (page_number, dimensions, source, rotation) in page_infos {
+            let page_info = crate::ocr::orientation::page_info_mut(&mut result.metadata, page_number, dimensions);
+            crate::ocr::fallback::record_source(page_info, &source);
+            if let Some(rotation) = rotation {
+                crate::ocr::orientation::set_page_rotation(&mut result.metadata, page_number, dimensions, rotation);
+            }
+        }
+        if first_preprocessing.is_some() {
+            result.metadata.image_preprocessing = first_preprocessing;
+        }
+
+        let mut content = String::new();
+        let mut boundaries = Vec::with_capacity(frame_count);
+        for (idx, text) in page_texts.iter().enumerate() {
+            if idx > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(text);
+            boundaries.push(crate::types::PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: idx + 1,
+            });
+        }
+        if let Some(pages) = result.metadata.pages.as_mut() {
+            pages.total_count = frame_count;
+            if config.pages.is_some() {
+                pages.boundaries = Some(boundaries);
+            }
+        }
+
+        if config.pages.is_some() {
+            result.pages = Some(
+                page_texts
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, text)| crate::types::PageContent {
+                        page_number: idx + 1,
+                        content: text.clone(),
+                        tables: result
+                            .tables
+                            .iter()
+                            .filter(|table| table.page_number == idx + 1)
+                            .map(|table| std::sync::Arc::new(table.clone()))
+                            .collect(),
+                        images: vec![],
+                        hierarchy: None,
+                        is_blank: Some(crate::extraction::blank_detection::is_page_text_blank(text)),
+                        annotations: Vec::new(),
+                    })
+                    .collect(),
+            );
+        }
+        result.content = content;
+
+        Ok(result)
+    }
+}
+
+/// Page-level element recording the size of the image OCR coordinates refer to.
+#[cfg(feature = "ocr")]
+fn page_element(page_number: usize, (width, height): (u32, u32)) -> crate::types::OcrElement {
+    crate::types::OcrElement::new(
+        "",
+        crate::types::OcrBoundingGeometry::Rectangle {
+            left: 0,
+            top: 0,
+            width,
+            height,
+        },
+        crate::types::OcrConfidence::from_tesseract(100.0),
+    )
+    .with_level(crate::types::OcrElementLevel::Page)
+    .with_page_number(page_number)
+}
+
+/// Width and height of an encoded image, read from its header.
+fn image_dimensions(content: &[u8]) -> Option<(u32, u32)> {
+    image::ImageReader::new(std::io::Cursor::new(content))
+        .with_guessed_format()
+        .ok()
+        .and_then(|reader| reader.into_dimensions().ok())
 }
 
 impl Default for ImageExtractor {
@@ -261,4 +495,92 @@ mod tests {
         assert!(supported.contains(&"image/x-tiff"));
         assert!(supported.contains(&"image/x-portable-anymap"));
     }
+
+    #[cfg(feature = "ocr")]
+    mod frames {
+        use super::*;
+        use crate::core::config::{OcrConfig, PageConfig};
+        use crate::plugins::{OcrBackend, OcrBackendType};
+        use crate::types::ImagePreprocessingConfig;
+
+        /// Backend that reports the color type and size of the image it receives.
+        struct ProbeOcrBackend;
+
+        impl Plugin for ProbeOcrBackend {
+            fn name(&self) -> &str {
+                "probe"
+            }
+
+            fn version(&self) -> String {
+                "1.0.0".to_string()
+            }
+
+            fn initialize(&self) -> Result<()> {
+                Ok(())
+            }
+
+            fn shutdown(&self) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        #[async_trait]
+        impl OcrBackend for ProbeOcrBackend {
+            async fn process_image(&self, image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+                let image = image::load_from_memory(image_bytes).unwrap();
+                Ok(ExtractionResult {
+                    content: format!("{:?} {}x{}", image.color(), image.width(), image.height()),
+                    mime_type: "text/plain".into(),
+                    ..Default::default()
+                })
+            }
+
+            fn supports_language(&self, _lang: &str) -> bool {
+                true
+            }
+
+            fn backend_type(&self) -> OcrBackendType {
+                OcrBackendType::Custom
+            }
+        }
+
+        #[tokio::test]
+        async fn test_extract_frames_with_ocr_preprocesses_every_frame() {
+            let frames = vec![
+                image::DynamicImage::new_rgb8(40, 20),
+                image::DynamicImage::new_rgb8(30, 30),
+            ];
+            let ocr_config = OcrConfig {
+                preprocessing: Some(ImagePreprocessingConfig {
+                    auto_rotate: false,
+                    deskew: false,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let config = ExtractionConfig {
+                pages: Some(PageConfig::default()),
+                ..Default::default()
+            };
+
+            let result = ImageExtractor::new()
+                .extract_frames_with_ocr(frames, &ProbeOcrBackend, &ocr_config, &config)
+                .await
+                .unwrap();
+
+            assert_eq!(result.content, "L8 40x20\n\nL8 30x30");
+            assert!(result.metadata.image_preprocessing.is_some());
+            let pages = result.pages.unwrap();
+            assert_eq!(pages.len(), 2);
+            assert_eq!(pages[1].page_number, 2);
+            assert_eq!(pages[1].content, "L8 30x30");
+            let structure = result.metadata.pages.unwrap();
+            assert_eq!(structure.total_count, 2);
+            let boundaries = structure.boundaries.unwrap();
+            assert_eq!(
+                &result.content[boundaries[1].byte_start..boundaries[1].byte_end],
+                "L8 30x30"
+            );
+        }
+    }
 }
@@ -276,6 +276,8 @@ pub(super) fn extract_jats_all_in_one(content: &str) -> Result<(JatsMetadataExtr
                                 markdown,
                                 page_number: table_index + 1,
                                 bounding_box: None,
+                                end_page_number: None,
+                                spans: vec![],
                             });
                             table_index += 1;
                             current_table.clear();
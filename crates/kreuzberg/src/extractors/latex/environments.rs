@@ -130,6 +130,8 @@ pub fn process_table(content: &str, output: &mut String, tables: &mut Vec<Table>
             markdown: markdown.clone(),
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
         tables.push(table);
     }
@@ -257,6 +257,8 @@ impl MarkdownExtractor {
                             markdown,
                             page_number: idx + 1,
                             bounding_box: None,
+                            end_page_number: None,
+                            spans: vec![],
                         });
                         table_index += 1;
                     }
@@ -68,7 +68,7 @@ pub mod text;
 pub mod djot_format;
 pub mod frontmatter_utils;
 
-#[cfg(feature = "archives")]
+#[cfg(any(feature = "archives", feature = "pdf"))]
 pub mod security;
 
 #[cfg(feature = "ocr")]
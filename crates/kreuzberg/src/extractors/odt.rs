@@ -441,6 +441,8 @@ fn parse_odt_table(table_node: roxmltree::Node, table_index: usize) -> Option<Ta
         markdown,
         page_number: table_index + 1,
         bounding_box: None,
+        end_page_number: None,
+        spans: vec![],
     })
 }
 
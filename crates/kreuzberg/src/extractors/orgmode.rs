@@ -184,6 +184,8 @@ impl OrgModeExtractor {
                             markdown,
                             page_number: 1,
                             bounding_box: None,
+                            end_page_number: None,
+                            spans: vec![],
                         });
                         current_table.clear();
                     }
@@ -198,6 +200,8 @@ impl OrgModeExtractor {
                     markdown,
                     page_number: 1,
                     bounding_box: None,
+                    end_page_number: None,
+                    spans: vec![],
                 });
             }
         }
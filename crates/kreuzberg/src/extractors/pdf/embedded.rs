@@ -0,0 +1,233 @@
+//! Recursive extraction of files embedded in PDFs.
+//!
+//! Each attachment is run back through the extractor registry with the MIME
+//! type detected for it. The security limits of the parent extraction bound
+//! the number and total size of the files across all nesting levels: each
+//! level hands only the budget left over to its children, and uses up one
+//! level of `max_nesting_depth`.
+
+use crate::core::config::ExtractionConfig;
+use crate::extractors::security::SecurityLimits;
+use crate::types::{EmbeddedFile, ExtractionResult, FormatMetadata};
+
+/// Extract the files embedded in a PDF.
+///
+/// Files that cannot be extracted are still listed, with the reason in
+/// [`EmbeddedFile::error`]; a failing attachment never fails the PDF itself.
+pub(crate) async fn extract_embedded_files(pdf_bytes: &[u8], config: &ExtractionConfig) -> Vec<EmbeddedFile> {
+    let limits = security_limits(config);
+    let files = crate::pdf::embedded::read_embedded_files(pdf_bytes, &limits);
+    if files.is_empty() {
+        return Vec::new();
+    }
+
+    // Files read at this level count against the budget before any child
+    // starts, and each child's own attachments before the next sibling ~keep
+    let mut used_bytes: usize = files
+        .iter()
+        .filter_map(|(_, content)| content.as_ref())
+        .map(Vec::len)
+        .sum();
+    let mut used_files = files.len();
+
+    let mut extracted = Vec::with_capacity(files.len());
+    for (mut file, content) in files {
+        if let Some(content) = content {
+            let child_config = (limits.max_nesting_depth > 0)
+                .then(|| child_config(config, &remaining_limits(&limits, used_bytes, used_files)));
+            match (&child_config, file.mime_type.as_deref()) {
+                (None, _) => {
+                    file.error = Some(format!(
+                        "Embedded file nesting exceeds the maximum depth of {}",
+                        limits.max_nesting_depth
+                    ));
+                }
+                (Some(_), None) => {
+                    file.error = Some("Could not determine the MIME type of the embedded file".to_string());
+                }
+                (Some(child_config), Some(mime_type)) => {
+                    match crate::core::extractor::extract_bytes(&content, mime_type, child_config).await {
+                        Ok(result) => {
+                            let (nested_bytes, nested_files) = nested_usage(&result);
+                            used_bytes = used_bytes.saturating_add(nested_bytes);
+                            used_files = used_files.saturating_add(nested_files);
+                            file.result = Some(Box::new(result));
+                        }
+                        Err(e) => {
+                            tracing::debug!("Embedded file '{}' could not be extracted: {}", file.filename, e);
+                            file.error = Some(e.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        extracted.push(file);
+    }
+
+    extracted
+}
+
+/// The limits left after `used_bytes` and `used_files` of the budget are spent.
+fn remaining_limits(limits: &SecurityLimits, used_bytes: usize, used_files: usize) -> SecurityLimits {
+    SecurityLimits {
+        max_archive_size: limits.max_archive_size.saturating_sub(used_bytes),
+        max_files_in_archive: limits.max_files_in_archive.saturating_sub(used_files),
+        ..limits.clone()
+    }
+}
+
+/// Total size and number of the files embedded below an extracted attachment.
+fn nested_usage(result: &ExtractionResult) -> (usize, usize) {
+    let Some(FormatMetadata::Pdf(pdf)) = &result.metadata.format else {
+        return (0, 0);
+    };
+    pdf.embedded_files
+        .iter()
+        .flatten()
+        .fold((0, 0), |(bytes, files), file| {
+            let (nested_bytes, nested_files) = file.result.as_deref().map_or((0, 0), nested_usage);
+            (
+                bytes.saturating_add(file.size).saturating_add(nested_bytes),
+                files.saturating_add(1).saturating_add(nested_files),
+            )
+        })
+}
+
+fn security_limits(config: &ExtractionConfig) -> SecurityLimits {
+    #[cfg(feature = "archives")]
+    {
+        config.security_limits.clone().unwrap_or_default()
+    }
+    #[cfg(not(feature = "archives"))]
+    {
+        let _ = config;
+        SecurityLimits::default()
+    }
+}
+
+/// Configuration for extracting the embedded files, one nesting level deeper.
+fn child_config(config: &ExtractionConfig, limits: &SecurityLimits) -> ExtractionConfig {
+    let mut child = config.clone();
+    #[cfg(feature = "archives")]
+    {
+        child.security_limits = Some(SecurityLimits {
+            max_nesting_depth: limits.max_nesting_depth - 1,
+            ..limits.clone()
+        });
+    }
+    // Without configurable limits there is no depth to carry over, so nested
+    // PDFs only list their attachments ~keep
+    #[cfg(not(feature = "archives"))]
+    {
+        let _ = limits;
+        if let Some(pdf_options) = child.pdf_options.as_mut() {
+            pdf_options.extract_embedded_files = false;
+        }
+    }
+    child
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::PdfConfig;
+    use crate::pdf::embedded::tests::pdf_with_attachments;
+
+    fn config() -> ExtractionConfig {
+        ExtractionConfig {
+            pdf_options: Some(PdfConfig {
+                extract_images: false,
+                passwords: None,
+                extract_metadata: true,
+                extract_annotations: false,
+                extract_form_fields: false,
+                render_form_fields: false,
+                extract_embedded_files: true,
+                strip_page_furniture: false,
+                ocr_image_regions: false,
+                extract_figures: false,
+                detect_formulas: false,
+                text_layer_threshold: 0.5,
+                text_elements: None,
+                hierarchy: None,
+                table_detection: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extract_embedded_files_through_registry() {
+        let pdf = pdf_with_attachments(&[("notes.txt", b"Quarterly notes"), ("setup.exe", b"MZ\x90\x00")]);
+
+        let files = extract_embedded_files(&pdf, &config()).await;
+        assert_eq!(files.len(), 2);
+
+        let notes = &files[0];
+        assert_eq!(notes.mime_type.as_deref(), Some("text/plain"));
+        assert!(notes.error.is_none(), "unexpected error: {:?}", notes.error);
+        assert!(notes.result.as_ref().unwrap().content.contains("Quarterly notes"));
+
+        assert!(files[1].result.is_none());
+        assert!(files[1].error.is_some());
+    }
+
+    #[cfg(feature = "archives")]
+    #[tokio::test]
+    async fn test_extract_embedded_files_respects_nesting_depth() {
+        let pdf = pdf_with_attachments(&[("notes.txt", b"Quarterly notes")]);
+        let mut config = config();
+        config.security_limits = Some(SecurityLimits {
+            max_nesting_depth: 0,
+            ..Default::default()
+        });
+
+        let files = extract_embedded_files(&pdf, &config).await;
+        assert!(files[0].result.is_none());
+        assert!(files[0].error.as_deref().unwrap().contains("maximum depth"));
+
+        let child = child_config(&self::config(), &SecurityLimits::default());
+        assert_eq!(child.security_limits.unwrap().max_nesting_depth, 99);
+    }
+
+    #[cfg(feature = "archives")]
+    #[test]
+    fn test_child_config_carries_remaining_budget() {
+        let file = |size, result: Option<ExtractionResult>| EmbeddedFile {
+            filename: "inner.txt".to_string(),
+            mime_type: Some("text/plain".to_string()),
+            size,
+            description: None,
+            result: result.map(Box::new),
+            error: None,
+        };
+        let with_files = |files: Vec<EmbeddedFile>| ExtractionResult {
+            metadata: crate::types::Metadata {
+                format: Some(FormatMetadata::Pdf(crate::pdf::metadata::PdfMetadata {
+                    embedded_files: Some(files),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let nested = with_files(vec![file(10, Some(with_files(vec![file(5, None)]))), file(20, None)]);
+        assert_eq!(nested_usage(&nested), (35, 3));
+        assert_eq!(nested_usage(&ExtractionResult::default()), (0, 0));
+
+        let limits = remaining_limits(
+            &SecurityLimits {
+                max_archive_size: 100,
+                max_files_in_archive: 3,
+                ..Default::default()
+            },
+            35,
+            5,
+        );
+        let child = child_config(&self::config(), &limits).security_limits.unwrap();
+        assert_eq!(child.max_archive_size, 65);
+        assert_eq!(child.max_files_in_archive, 0);
+        assert_eq!(child.max_nesting_depth, 99);
+    }
+}
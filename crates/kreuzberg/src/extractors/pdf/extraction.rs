@@ -7,19 +7,42 @@ use crate::core::config::{ExtractionConfig, OutputFormat};
 use crate::types::{PageBoundary, PageContent};
 
 #[cfg(feature = "pdf")]
-use crate::types::Table;
+use crate::pdf::figures::Figure;
+#[cfg(feature = "pdf")]
+use crate::pdf::regions::ImageRegion;
+#[cfg(feature = "pdf")]
+use crate::pdf::text_layer::PageTextLayer;
+#[cfg(feature = "pdf")]
+use crate::types::{DocumentStructure, OcrElement, PdfAnnotation, PdfAnnotationSummary, Table};
 #[cfg(feature = "pdf")]
 use pdfium_render::prelude::*;
 
+/// Everything extracted from a loaded PDF document in one pass.
 #[cfg(feature = "pdf")]
-pub(crate) type PdfExtractionPhaseResult = (
-    crate::pdf::metadata::PdfExtractionMetadata,
-    String,
-    Vec<Table>,
-    Option<Vec<PageContent>>,
-    Option<Vec<PageBoundary>>,
-    Option<String>, // pre-rendered markdown (when output_format == Markdown)
-);
+pub(crate) struct PdfExtractionPhaseResult {
+    /// PDF metadata (title, authors, dates, page structure, etc.)
+    pub(crate) pdf_metadata: crate::pdf::metadata::PdfExtractionMetadata,
+    /// Native extracted text (or empty if using OCR)
+    pub(crate) native_text: String,
+    /// Extracted tables, stitched across pages
+    pub(crate) tables: Vec<Table>,
+    /// Per-page content (if page extraction configured)
+    pub(crate) page_contents: Option<Vec<PageContent>>,
+    /// Page boundaries for per-page OCR evaluation
+    pub(crate) boundaries: Option<Vec<PageBoundary>>,
+    /// Pre-rendered markdown (when output_format == Markdown)
+    pub(crate) pre_rendered_markdown: Option<String>,
+    /// Native document tree (when include_document_structure is set)
+    pub(crate) document_structure: Option<DocumentStructure>,
+    /// Image regions without a text layer (when ocr_image_regions is set)
+    pub(crate) image_regions: Vec<ImageRegion>,
+    /// Pages whose text layer scored below text_layer_threshold
+    pub(crate) broken_text_layers: Vec<PageTextLayer>,
+    /// Word and line geometry (when text_elements is set)
+    pub(crate) text_elements: Option<Vec<OcrElement>>,
+    /// Vector figures, to render (when extract_figures is set)
+    pub(crate) figures: Vec<Figure>,
+}
 
 /// Extract text, metadata, and tables from a PDF document using a single shared instance.
 ///
@@ -38,26 +61,95 @@ pub(crate) type PdfExtractionPhaseResult = (
 ///
 /// # Returns
 ///
-/// A tuple containing:
-/// - PDF metadata (title, authors, dates, page structure, etc.)
-/// - Native extracted text (or empty if using OCR)
-/// - Extracted tables (if OCR feature enabled)
-/// - Per-page content (if page extraction configured)
-/// - Page boundaries for per-page OCR evaluation
-/// - Pre-rendered markdown (if output_format == Markdown, None otherwise)
+/// A [`PdfExtractionPhaseResult`] with the text, metadata, tables and the
+/// optional outputs requested by the configuration.
+///
+/// When `PdfConfig::extract_annotations` is set, page annotations are summarized in
+/// the PDF metadata and attached to the per-page content. AcroForm fields are read
+/// into the PDF metadata when `PdfConfig::extract_form_fields` is set. With
+/// `PdfConfig::strip_page_furniture`, running headers and footers are removed from
+/// the text and Markdown and kept in the PDF metadata instead. With
+/// `ExtractionConfig::include_document_structure`, the document tree is built from
+/// the same layout analysis as the Markdown output. The text layer of each page is
+/// scored when pages are tracked or OCR is configured, and recorded in the page
+/// metadata. `PdfConfig::text_elements` exports the geometry and fonts of the
+/// text layer's words and lines.
+///
+/// When Markdown or the document structure is requested, tagged PDFs whose
+/// structure tree covers their text take the reading order, headings, lists,
+/// tables and figure descriptions from the tree instead of from layout analysis.
+/// Tables are still detected geometrically when the tree has none.
 #[cfg(feature = "pdf")]
 pub(crate) fn extract_all_from_document(
     document: &PdfDocument,
     config: &ExtractionConfig,
 ) -> Result<PdfExtractionPhaseResult> {
-    let (native_text, boundaries, page_contents, pdf_metadata) =
-        crate::pdf::text::extract_text_and_metadata_from_pdf_document(document, Some(config))?;
+    let furniture = if config
+        .pdf_options
+        .as_ref()
+        .is_some_and(|opts| opts.strip_page_furniture)
+    {
+        crate::pdf::furniture::detect_page_furniture(document)?
+    } else {
+        Vec::new()
+    };
 
-    let tables = extract_tables_from_document(document, &pdf_metadata)?;
+    let (native_text, boundaries, mut page_contents, mut pdf_metadata) =
+        crate::pdf::text::extract_text_and_metadata_with_furniture(document, Some(config), &furniture)?;
 
-    // If markdown output is requested, render it while we have the document loaded.
-    // Skip when force_ocr is set since OCR results produce their own markdown via hOCR.
-    let pre_rendered_markdown = if config.output_format == OutputFormat::Markdown && !config.force_ocr {
+    let annotations = if config.pdf_options.as_ref().is_some_and(|opts| opts.extract_annotations) {
+        crate::pdf::annotations::extract_annotations_from_document(document)?
+    } else {
+        Vec::new()
+    };
+    attach_annotations(&mut pdf_metadata, page_contents.as_deref_mut(), &annotations);
+
+    if config.pdf_options.as_ref().is_some_and(|opts| opts.extract_form_fields) {
+        let form_fields = crate::pdf::forms::extract_form_fields(document);
+        if !form_fields.is_empty() {
+            pdf_metadata.pdf_specific.form_fields = Some(form_fields);
+        }
+    }
+
+    // Markdown output and the document structure share one layout analysis, run while
+    // the document is loaded. Skip when force_ocr is set since OCR results produce their
+    // own markdown via hOCR.
+    let render_markdown = config.output_format == OutputFormat::Markdown && !config.force_ocr;
+    let build_structure = config.include_document_structure && !config.force_ocr;
+
+    // The structure tree of a tagged PDF gives the reading order and roles directly;
+    // untagged documents go through table detection and layout analysis. The tree is
+    // only read when one of the two outputs needs it.
+    let tagged = if render_markdown || build_structure {
+        match crate::pdf::tagged::read_tagged_document(document, &furniture) {
+            Ok(tagged) => tagged,
+            Err(e) => {
+                tracing::warn!(
+                    "PDF structure tree could not be read: {:?}, will use layout analysis",
+                    e
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Tag trees often mark tables up as plain paragraphs; fall back to table detection
+    // and stitching when the tree yields none.
+    let tagged_tables = tagged
+        .as_deref()
+        .map(crate::pdf::tagged::tagged_tables)
+        .unwrap_or_default();
+    let (page_tables, tables) = if tagged_tables.is_empty() {
+        let page_tables = extract_tables_from_document(document, config)?;
+        let tables = stitch_tables(document, &page_tables, config);
+        (page_tables, tables)
+    } else {
+        (Vec::new(), tagged_tables)
+    };
+
+    let layout = if tagged.is_none() && (render_markdown || build_structure) {
         let k = config
             .pdf_options
             .as_ref()
@@ -65,14 +157,11 @@ pub(crate) fn extract_all_from_document(
             .map(|h| h.k_clusters)
             .unwrap_or(4);
 
-        match crate::pdf::markdown::render_document_as_markdown_with_tables(document, k, &tables) {
-            Ok(md) if !md.trim().is_empty() => Some(md),
-            Ok(_) => {
-                tracing::warn!("Markdown rendering produced empty output, will fall back to plain text");
-                None
-            }
+        let detect_formulas = config.pdf_options.as_ref().is_some_and(|opts| opts.detect_formulas);
+        match crate::pdf::markdown::layout_document(document, k, &page_tables, &furniture, detect_formulas) {
+            Ok(layout) => Some(layout),
             Err(e) => {
-                tracing::warn!("Markdown rendering failed: {:?}, will fall back to plain text", e);
+                tracing::warn!("PDF layout analysis failed: {:?}, will fall back to plain text", e);
                 None
             }
         }
@@ -80,110 +169,407 @@ pub(crate) fn extract_all_from_document(
         None
     };
 
-    Ok((
+    let pre_rendered_markdown = match (tagged.as_deref(), layout.as_deref()) {
+        (Some(pages), _) if render_markdown => {
+            Some(crate::pdf::tagged::render_markdown(pages)).filter(|md| !md.trim().is_empty())
+        }
+        (None, Some(pages)) if render_markdown => {
+            let md = crate::pdf::markdown::assemble_markdown_with_tables(pages, &tables);
+            if md.trim().is_empty() {
+                tracing::warn!("Markdown rendering produced empty output, will fall back to plain text");
+                None
+            } else {
+                Some(md)
+            }
+        }
+        _ => None,
+    };
+
+    let document_structure = match (tagged.as_deref(), layout.as_deref()) {
+        (Some(pages), _) if build_structure => Some(crate::pdf::structure::build_tagged_document_structure(
+            pages,
+            &annotations,
+            &furniture,
+        )),
+        (None, Some(pages)) if build_structure => {
+            match crate::pdf::structure::build_document_structure(document, pages, &tables, &annotations, &furniture) {
+                Ok(structure) => Some(structure),
+                Err(e) => {
+                    tracing::warn!(
+                        "PDF document structure failed: {:?}, will rebuild it from the content",
+                        e
+                    );
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let image_regions = if cfg!(feature = "ocr")
+        && config.ocr.is_some()
+        && !config.force_ocr
+        && config.pdf_options.as_ref().is_some_and(|opts| opts.ocr_image_regions)
+    {
+        crate::pdf::regions::find_image_regions(document)?
+    } else {
+        Vec::new()
+    };
+
+    let broken_text_layers = assess_text_layers(
+        document,
+        config,
+        &native_text,
+        boundaries.as_deref(),
+        &furniture,
+        &mut pdf_metadata,
+    )?;
+
+    let text_elements =
+        match config.pdf_options.as_ref().and_then(|opts| opts.text_elements.as_ref()) {
+            Some(element_config) if element_config.include_elements => Some(
+                crate::pdf::elements::extract_text_elements(document, element_config, &furniture)?,
+            ),
+            // hOCR and ALTO output render word geometry even when elements were not requested.
+            _ if config.output_format.requires_ocr_elements() => Some(crate::pdf::elements::extract_text_elements(
+                document,
+                &crate::types::OcrElementConfig {
+                    include_elements: true,
+                    min_level: crate::types::OcrElementLevel::Word,
+                    min_confidence: 0.0,
+                    build_hierarchy: true,
+                },
+                &furniture,
+            )?),
+            _ => None,
+        };
+
+    let figures = if config.pdf_options.as_ref().is_some_and(|opts| opts.extract_figures) {
+        crate::pdf::figures::find_figures(document)?
+    } else {
+        Vec::new()
+    };
+
+    if !furniture.is_empty() {
+        pdf_metadata.pdf_specific.page_furniture = Some(furniture);
+    }
+
+    Ok(PdfExtractionPhaseResult {
         pdf_metadata,
         native_text,
         tables,
         page_contents,
         boundaries,
         pre_rendered_markdown,
-    ))
+        document_structure,
+        image_regions,
+        broken_text_layers,
+        text_elements,
+        figures,
+    })
+}
+
+/// Score the text layer of each page and record it in the page metadata.
+///
+/// Returns the pages scoring below `PdfConfig::text_layer_threshold` when OCR is
+/// configured, so that they can be re-extracted with OCR.
+#[cfg(feature = "pdf")]
+fn assess_text_layers(
+    document: &PdfDocument,
+    config: &ExtractionConfig,
+    native_text: &str,
+    boundaries: Option<&[PageBoundary]>,
+    furniture: &[crate::types::PageFurniture],
+    pdf_metadata: &mut crate::pdf::metadata::PdfExtractionMetadata,
+) -> Result<Vec<PageTextLayer>> {
+    let threshold = config
+        .pdf_options
+        .as_ref()
+        .map_or(0.5, |opts| opts.text_layer_threshold);
+    let reocr = cfg!(feature = "ocr") && config.ocr.is_some() && !config.force_ocr && threshold > 0.0;
+    if boundaries.is_none() && !reocr {
+        return Ok(Vec::new());
+    }
+
+    let pages = crate::pdf::text_layer::assess_pages(document, native_text, boundaries, furniture)?;
+
+    if let Some(page_infos) = pdf_metadata
+        .page_structure
+        .as_mut()
+        .and_then(|page_structure| page_structure.pages.as_mut())
+    {
+        for page in &pages {
+            if let Some(page_info) = page_infos.iter_mut().find(|info| info.number == page.page_number) {
+                page_info.text_layer = Some(page.health.clone());
+            }
+        }
+    }
+
+    Ok(if reocr {
+        pages.into_iter().filter(|page| page.health.score < threshold).collect()
+    } else {
+        Vec::new()
+    })
+}
+
+/// Summarize annotations in the PDF metadata and distribute them to their pages.
+#[cfg(feature = "pdf")]
+fn attach_annotations(
+    pdf_metadata: &mut crate::pdf::metadata::PdfExtractionMetadata,
+    page_contents: Option<&mut [PageContent]>,
+    annotations: &[PdfAnnotation],
+) {
+    if annotations.is_empty() {
+        return;
+    }
+
+    pdf_metadata.pdf_specific.annotations = Some(PdfAnnotationSummary::from_annotations(annotations));
+
+    if let Some(pages) = page_contents {
+        for annotation in annotations {
+            if let Some(page) = pages.iter_mut().find(|page| page.page_number == annotation.page_number) {
+                page.annotations.push(annotation.clone());
+            }
+        }
+    }
 }
 
 /// Extract tables from PDF document using native text positions.
 ///
-/// This function converts PDF character positions to HocrWord format,
-/// then uses the existing table reconstruction logic to detect tables.
+/// Each page goes through [`extract_page_tables`]: with a `table_detection`
+/// config ruled tables come from the line-based table finder, otherwise (and on
+/// pages without ruled tables) word positions are clustered into a table.
 ///
 /// Uses the shared PdfDocument reference (wrapped in Arc<RwLock<>> for thread-safety).
 #[cfg(all(feature = "pdf", feature = "ocr"))]
-fn extract_tables_from_document(
-    document: &PdfDocument,
-    _metadata: &crate::pdf::metadata::PdfExtractionMetadata,
-) -> Result<Vec<Table>> {
-    use crate::ocr::table::{reconstruct_table, table_to_markdown};
-    use crate::pdf::table::extract_words_from_page;
-
+fn extract_tables_from_document(document: &PdfDocument, config: &ExtractionConfig) -> Result<Vec<Table>> {
+    let detection = config
+        .pdf_options
+        .as_ref()
+        .and_then(|opts| opts.table_detection.as_ref());
     let mut all_tables = Vec::new();
 
     for (page_index, page) in document.pages().iter().enumerate() {
-        let words = extract_words_from_page(&page, 0.0)?;
+        all_tables.extend(extract_page_tables(&page, page_index + 1, detection)?);
+    }
 
-        if words.is_empty() {
-            continue;
+    Ok(all_tables)
+}
+
+/// Detect the tables on a single page from its native text positions.
+///
+/// Without a `detection` config the page's words are clustered into rows and
+/// columns, treating the whole page as one table candidate.
+///
+/// With one, tables drawn with ruling lines are found first by the line-based
+/// table finder ([`crate::pdf::find_tables`]) and converted with
+/// [`crate::pdf::extract_table`], so merged cells are kept in `Table::spans`.
+/// Pages without such a table fall back to clustering. Returns no tables when
+/// `PdfTableDetectionConfig::enabled` is off.
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+pub(crate) fn extract_page_tables(
+    page: &PdfPage,
+    page_number: usize,
+    detection: Option<&crate::core::config::pdf::PdfTableDetectionConfig>,
+) -> Result<Vec<Table>> {
+    let Some(detection) = detection else {
+        let defaults = crate::core::config::pdf::PdfTableDetectionConfig::default();
+        return Ok(cluster_page_table(page, page_number, &defaults)?.into_iter().collect());
+    };
+    if !detection.enabled {
+        return Ok(Vec::new());
+    }
+
+    let settings = detection.to_table_settings();
+    let found = crate::pdf::find_tables(page, &settings, None, None)?;
+    let mut tables = Vec::new();
+    for detected in &found.tables {
+        let table = crate::pdf::extract_table(detected, page, page_number, Some(&settings))?;
+        if is_plausible_table(&table.cells) {
+            tables.push(table);
         }
+    }
+    if !tables.is_empty() {
+        return Ok(tables);
+    }
 
-        let column_threshold = 50;
-        let row_threshold_ratio = 0.5;
+    Ok(cluster_page_table(page, page_number, detection)?.into_iter().collect())
+}
 
-        let table_cells = reconstruct_table(&words, column_threshold, row_threshold_ratio);
+/// A real table must have at least 2 rows AND 2 columns.
+/// Single-column or single-row "tables" are almost always regular text lines.
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+fn is_plausible_table(cells: &[Vec<String>]) -> bool {
+    let min_rows = 2;
+    let min_cols = cells.iter().map(|r| r.len()).min().unwrap_or(0);
+    cells.len() >= min_rows && min_cols >= 2
+}
 
-        // Validate table: reject false positives.
-        // A real table must have at least 2 rows AND 2 columns.
-        // Single-column or single-row "tables" are almost always regular text lines.
-        let min_rows = 2;
-        let min_cols = table_cells.iter().map(|r| r.len()).min().unwrap_or(0);
-        if table_cells.len() < min_rows || min_cols < 2 {
-            continue;
-        }
+/// Detect a table on a page without ruling lines by clustering its word positions.
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+fn cluster_page_table(
+    page: &PdfPage,
+    page_number: usize,
+    detection: &crate::core::config::pdf::PdfTableDetectionConfig,
+) -> Result<Option<Table>> {
+    use crate::ocr::table::{reconstruct_table, table_to_markdown};
+    use crate::pdf::table::extract_words_from_page;
 
-        let markdown = table_to_markdown(&table_cells);
-
-        // Compute table bounding box from word positions.
-        // Note: The table detector (reconstruct_table) treats ALL words on the page as
-        // potential table content, so the bbox covers all page words. This is correct:
-        // if the page passes the 2x2 validation, the entire page IS the table.
-        // For pages with mixed content (table + body text), the detector would either
-        // reject the page (not 2x2) or include everything (the full page is tabular).
-        let page_height = page.height().value as f64;
-
-        // HocrWord coordinates are in image space (y=0 at top, from table.rs:finalize_word).
-        // Convert back to PDF coordinates (y=0 at bottom) for the BoundingBox.
-        let img_left = words.iter().map(|w| w.left as f64).fold(f64::INFINITY, f64::min);
-        let img_top = words.iter().map(|w| w.top as f64).fold(f64::INFINITY, f64::min);
-        let img_right = words
-            .iter()
-            .map(|w| (w.left + w.width) as f64)
-            .fold(f64::NEG_INFINITY, f64::max);
-        let img_bottom = words
-            .iter()
-            .map(|w| (w.top + w.height) as f64)
-            .fold(f64::NEG_INFINITY, f64::max);
-
-        let bounding_box = if img_left.is_finite() {
-            Some(crate::types::BoundingBox {
-                x0: img_left,
-                y0: page_height - img_bottom, // bottom in PDF coords
-                x1: img_right,
-                y1: page_height - img_top, // top in PDF coords
-            })
-        } else {
-            None
-        };
+    let words = extract_words_from_page(page, 0.0)?;
 
-        all_tables.push(Table {
-            cells: table_cells,
-            markdown,
-            page_number: page_index + 1,
-            bounding_box,
-        });
+    if words.is_empty() {
+        return Ok(None);
     }
 
-    Ok(all_tables)
+    let table_cells = reconstruct_table(
+        &words,
+        detection.fallback_column_threshold,
+        detection.fallback_row_threshold_ratio,
+    );
+
+    // Validate table: reject false positives.
+    if !is_plausible_table(&table_cells) {
+        return Ok(None);
+    }
+
+    let markdown = table_to_markdown(&table_cells);
+
+    // Compute table bounding box from word positions.
+    // Note: The table detector (reconstruct_table) treats ALL words on the page as
+    // potential table content, so the bbox covers all page words. This is correct:
+    // if the page passes the 2x2 validation, the entire page IS the table.
+    // For pages with mixed content (table + body text), the detector would either
+    // reject the page (not 2x2) or include everything (the full page is tabular).
+    let page_height = page.height().value as f64;
+
+    // HocrWord coordinates are in image space (y=0 at top, from table.rs:finalize_word).
+    // Convert back to PDF coordinates (y=0 at bottom) for the BoundingBox.
+    let img_left = words.iter().map(|w| w.left as f64).fold(f64::INFINITY, f64::min);
+    let img_top = words.iter().map(|w| w.top as f64).fold(f64::INFINITY, f64::min);
+    let img_right = words
+        .iter()
+        .map(|w| (w.left + w.width) as f64)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let img_bottom = words
+        .iter()
+        .map(|w| (w.top + w.height) as f64)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let bounding_box = if img_left.is_finite() {
+        Some(crate::types::BoundingBox {
+            x0: img_left,
+            y0: page_height - img_bottom, // bottom in PDF coords
+            x1: img_right,
+            y1: page_height - img_top, // top in PDF coords
+        })
+    } else {
+        None
+    };
+
+    Ok(Some(Table {
+        cells: table_cells,
+        markdown,
+        page_number,
+        bounding_box,
+        end_page_number: None,
+        spans: vec![],
+    }))
+}
+
+/// Merge tables continued across pages unless disabled in
+/// `PdfTableDetectionConfig::stitch_multi_page_tables`.
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+fn stitch_tables(document: &PdfDocument, page_tables: &[Table], config: &ExtractionConfig) -> Vec<Table> {
+    let enabled = config
+        .pdf_options
+        .as_ref()
+        .and_then(|opts| opts.table_detection.as_ref())
+        .is_none_or(|detection| detection.stitch_multi_page_tables);
+    if enabled {
+        let page_heights: Vec<f64> = document.pages().iter().map(|page| page.height().value as f64).collect();
+        crate::pdf::table_stitching::stitch_multi_page_tables(page_tables.to_vec(), &page_heights)
+    } else {
+        page_tables.to_vec()
+    }
+}
+
+#[cfg(all(feature = "pdf", not(feature = "ocr")))]
+fn stitch_tables(_document: &PdfDocument, page_tables: &[Table], _config: &ExtractionConfig) -> Vec<Table> {
+    page_tables.to_vec()
 }
 
 /// Fallback for when OCR feature is not enabled - returns empty tables.
 #[cfg(all(feature = "pdf", not(feature = "ocr")))]
 fn extract_tables_from_document(
     _document: &PdfDocument,
-    _metadata: &crate::pdf::metadata::PdfExtractionMetadata,
+    _config: &ExtractionConfig,
 ) -> Result<Vec<crate::types::Table>> {
     Ok(vec![])
 }
 
+/// Fallback for when OCR feature is not enabled - detects no tables.
+#[cfg(all(feature = "pdf", feature = "tokio-runtime", not(feature = "ocr")))]
+pub(crate) fn extract_page_tables(
+    _page: &PdfPage,
+    _page_number: usize,
+    _detection: Option<&crate::core::config::pdf::PdfTableDetectionConfig>,
+) -> Result<Vec<Table>> {
+    Ok(Vec::new())
+}
+
 #[cfg(test)]
 mod tests {
 
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_attach_annotations_summarizes_and_assigns_pages() {
+        use super::*;
+        use crate::types::PdfAnnotationType;
+
+        let mut pdf_metadata = crate::pdf::metadata::PdfExtractionMetadata {
+            title: None,
+            subject: None,
+            authors: None,
+            keywords: None,
+            created_at: None,
+            modified_at: None,
+            created_by: None,
+            pdf_specific: Default::default(),
+            page_structure: None,
+        };
+        let mut pages: Vec<PageContent> = (1..=2)
+            .map(|page_number| PageContent {
+                page_number,
+                content: String::new(),
+                tables: Vec::new(),
+                images: Vec::new(),
+                hierarchy: None,
+                is_blank: None,
+                annotations: Vec::new(),
+            })
+            .collect();
+        let annotation = PdfAnnotation {
+            page_number: 2,
+            annotation_type: PdfAnnotationType::Text,
+            author: Some("Alice".to_string()),
+            created_at: None,
+            modified_at: None,
+            contents: Some("Please verify".to_string()),
+            highlighted_text: None,
+            url: None,
+            target_page: None,
+            bounding_box: None,
+        };
+
+        attach_annotations(&mut pdf_metadata, Some(&mut pages), &[annotation]);
+
+        let summary = pdf_metadata.pdf_specific.annotations.unwrap();
+        assert_eq!(summary.total_count, 1);
+        assert_eq!(summary.pages, vec![2]);
+        assert!(pages[0].annotations.is_empty());
+        assert_eq!(pages[1].annotations[0].contents.as_deref(), Some("Please verify"));
+    }
+
     #[test]
     fn test_bounding_box_coordinate_conversion() {
         // Test the bounding box computation logic independently
@@ -280,4 +666,140 @@ mod tests {
         assert_eq!(deserialized.x1, 100.75);
         assert_eq!(deserialized.y1, 200.5);
     }
+
+    /// Build a one-page PDF with a ruled 3x3 table whose "Sales" header spans
+    /// the last two columns (the vertical rule between them stops below it).
+    #[cfg(all(feature = "pdf", feature = "ocr"))]
+    fn pdf_with_merged_header() -> Vec<u8> {
+        use lopdf::{Document, Object, Stream, dictionary};
+
+        let mut ops = String::from("0.5 w\n");
+        for y in [700, 670, 640, 610] {
+            ops.push_str(&format!("72 {y} m 522 {y} l S\n"));
+        }
+        for x in [72, 222, 522] {
+            ops.push_str(&format!("{x} 610 m {x} 700 l S\n"));
+        }
+        ops.push_str("372 610 m 372 670 l S\n");
+        let rows: [(i32, [&str; 3]); 3] = [
+            (680, ["Region", "Sales", ""]),
+            (650, ["Year", "H1", "H2"]),
+            (620, ["North", "10", "20"]),
+        ];
+        for (y, cells) in rows {
+            for (x, text) in [80, 230, 380].into_iter().zip(cells) {
+                if !text.is_empty() {
+                    ops.push_str(&format!("BT /F1 10 Tf {x} {y} Td ({text}) Tj ET\n"));
+                }
+            }
+        }
+
+        let mut doc = Document::with_version("1.4");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+        let content_id = doc.add_object(Stream::new(dictionary! {}, ops.into_bytes()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            "Contents" => content_id,
+            "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![Object::from(page_id)],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[cfg(all(feature = "pdf", feature = "ocr"))]
+    #[tokio::test]
+    async fn test_ruled_table_keeps_merged_header() {
+        use crate::core::config::ExtractionConfig;
+        use crate::types::CellSpan;
+
+        let pdf = pdf_with_merged_header();
+        let config = ExtractionConfig {
+            output_format: crate::core::config::OutputFormat::Markdown,
+            pdf_options: Some(crate::core::config::PdfConfig {
+                extract_images: false,
+                passwords: None,
+                extract_metadata: true,
+                extract_annotations: false,
+                extract_form_fields: false,
+                render_form_fields: false,
+                extract_embedded_files: false,
+                strip_page_furniture: false,
+                ocr_image_regions: false,
+                extract_figures: false,
+                detect_formulas: false,
+                text_layer_threshold: 0.5,
+                text_elements: None,
+                hierarchy: None,
+                table_detection: Some(Default::default()),
+            }),
+            ..Default::default()
+        };
+        let result = crate::core::extractor::extract_bytes(&pdf, "application/pdf", &config)
+            .await
+            .unwrap();
+
+        assert_eq!(result.tables.len(), 1);
+        let table = &result.tables[0];
+        assert_eq!(
+            table.cells,
+            vec![
+                vec!["Region", "Sales", ""],
+                vec!["Year", "H1", "H2"],
+                vec!["North", "10", "20"],
+            ]
+        );
+        assert_eq!(
+            table.spans,
+            vec![CellSpan {
+                row: 0,
+                col: 1,
+                row_span: 1,
+                col_span: 2,
+            }]
+        );
+        assert_eq!(
+            table.markdown,
+            "| Region | Sales |  |\n| --- | --- | --- |\n| Year | H1 | H2 |\n| North | 10 | 20 |\n"
+        );
+        assert!(table.to_html().contains("<th colspan=\"2\">Sales</th>"));
+        assert!(result.content.contains("| Region | Sales |  |"));
+    }
+
+    #[cfg(all(feature = "pdf", feature = "ocr"))]
+    #[tokio::test]
+    async fn test_default_config_keeps_clustered_tables() {
+        let pdf = pdf_with_merged_header();
+        let result = crate::core::extractor::extract_bytes(
+            &pdf,
+            "application/pdf",
+            &crate::core::config::ExtractionConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        // Without `table_detection` the line-based finder is not used, so no spans appear.
+        assert!(result.tables.iter().all(|table| table.spans.is_empty()));
+    }
 }
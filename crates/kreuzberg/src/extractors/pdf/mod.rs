@@ -3,9 +3,13 @@
 //! Provides extraction of text, metadata, tables, and images from PDF documents
 //! using pypdfium2 and playa-pdf. Supports both native text extraction and OCR fallback.
 
+#[cfg(feature = "pdf")]
+mod embedded;
 mod extraction;
 mod ocr;
 mod pages;
+#[cfg(all(feature = "pdf", feature = "tokio-runtime", not(target_arch = "wasm32")))]
+mod stream;
 
 use bytes::Bytes;
 
@@ -24,10 +28,12 @@ use crate::pdf::error::PdfError;
 #[cfg(feature = "ocr")]
 pub use ocr::{NativeTextStats, OcrFallbackDecision, evaluate_native_text_for_ocr, evaluate_per_page_ocr};
 
-use extraction::extract_all_from_document;
+use extraction::{PdfExtractionPhaseResult, extract_all_from_document};
 #[cfg(feature = "ocr")]
 use ocr::extract_with_ocr;
 use pages::assign_tables_and_images_to_pages;
+#[cfg(all(feature = "pdf", feature = "tokio-runtime", not(target_arch = "wasm32")))]
+pub(crate) use stream::stream_pdf_pages;
 
 /// PDF document extractor using pypdfium2 and playa-pdf.
 pub struct PdfExtractor;
@@ -77,6 +83,21 @@ impl DocumentExtractor for PdfExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
+        // Extract a page selection as a document of just those pages, then give the
+        // pages their original numbers back.
+        #[cfg(feature = "pdf")]
+        if let Some(selected) = pages::select_pages(content, config)? {
+            let mut subset_config = config.clone();
+            if let Some(page_config) = subset_config.pages.as_mut() {
+                page_config.selection = None;
+            }
+            let mut result = self.extract_bytes(&selected.content, mime_type, &subset_config).await?;
+            if let Some(page_config) = subset_config.pages.as_ref() {
+                pages::renumber_selected_pages(&mut result, &selected.page_numbers, selected.page_count, page_config);
+            }
+            return Ok(result);
+        }
+
         // Strip /Rotate from page dicts to work around pdfium text extraction bug
         // where FPDFText_CountChars returns 0 for 90°/270° rotated pages.
         #[cfg(feature = "pdf")]
@@ -86,7 +107,19 @@ impl DocumentExtractor for PdfExtractor {
 
         #[cfg(feature = "pdf")]
         #[allow(unused_variables)]
-        let (mut pdf_metadata, native_text, tables, page_contents, boundaries, pre_rendered_markdown) = {
+        let PdfExtractionPhaseResult {
+            mut pdf_metadata,
+            native_text,
+            tables,
+            page_contents,
+            boundaries,
+            pre_rendered_markdown,
+            document_structure,
+            image_regions,
+            broken_text_layers,
+            text_elements,
+            figures,
+        } = {
             #[cfg(target_arch = "wasm32")]
             {
                 let pdfium = crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")
@@ -139,13 +172,12 @@ impl DocumentExtractor for PdfExtractor {
                             }
                         };
 
-                        let (pdf_metadata, native_text, tables, page_contents, boundaries, pre_rendered_markdown) =
-                            extract_all_from_document(&document, &config_owned)
-                                .map_err(|e| PdfError::ExtractionFailed(e.to_string()))?;
+                        let phase = extract_all_from_document(&document, &config_owned)
+                            .map_err(|e| PdfError::ExtractionFailed(e.to_string()))?;
 
                         if let Some(page_cfg) = config_owned.pages.as_ref()
                             && page_cfg.extract_pages
-                            && page_contents.is_none()
+                            && phase.page_contents.is_none()
                         {
                             return Err(PdfError::ExtractionFailed(
                                 "Page extraction was configured but no page data was extracted in batch mode"
@@ -153,20 +185,13 @@ impl DocumentExtractor for PdfExtractor {
                             ));
                         }
 
-                        Ok::<_, crate::pdf::error::PdfError>((
-                            pdf_metadata,
-                            native_text,
-                            tables,
-                            page_contents,
-                            boundaries,
-                            pre_rendered_markdown,
-                        ))
+                        Ok::<_, crate::pdf::error::PdfError>(phase)
                     })
                     .await
                     .map_err(|e| crate::error::KreuzbergError::Other(format!("PDF extraction task failed: {}", e)))?;
 
                     match result {
-                        Ok(tuple) => tuple,
+                        Ok(phase) => phase,
                         Err(e) => return Err(e.into()),
                     }
                 } else {
@@ -203,45 +228,197 @@ impl DocumentExtractor for PdfExtractor {
             }
         };
 
-        #[cfg(feature = "ocr")]
-        let (text, used_ocr) = if config.force_ocr {
-            if config.ocr.is_some() {
-                (extract_with_ocr(content, config).await?, true)
-            } else {
-                (native_text, false)
+        // Full XFA forms have no AcroForm widgets; read their values from the XFA data instead.
+        #[cfg(feature = "pdf")]
+        if config.pdf_options.as_ref().is_some_and(|opts| opts.extract_form_fields)
+            && pdf_metadata.pdf_specific.form_fields.is_none()
+        {
+            let xfa_fields = crate::pdf::forms::extract_xfa_fields(content);
+            if !xfa_fields.is_empty() {
+                pdf_metadata.pdf_specific.form_fields = Some(xfa_fields);
             }
-        } else if config.ocr.is_some() {
-            let decision = ocr::evaluate_per_page_ocr(
-                &native_text,
-                boundaries.as_deref(),
-                pdf_metadata.pdf_specific.page_count,
-            );
+        }
 
-            if std::env::var("KREUZBERG_DEBUG_OCR").is_ok() {
-                eprintln!(
-                    "[kreuzberg::pdf::ocr] fallback={} non_whitespace={} alnum={} meaningful_words={} \
-                     avg_non_whitespace={:.2} avg_alnum={:.2} alnum_ratio={:.3}",
-                    decision.fallback,
-                    decision.stats.non_whitespace,
-                    decision.stats.alnum,
-                    decision.stats.meaningful_words,
-                    decision.avg_non_whitespace,
-                    decision.avg_alnum,
-                    decision.stats.alnum_ratio
-                );
+        // Run the attached files back through the extractor registry when requested.
+        #[cfg(feature = "pdf")]
+        if config
+            .pdf_options
+            .as_ref()
+            .is_some_and(|opts| opts.extract_embedded_files)
+        {
+            let embedded_files = embedded::extract_embedded_files(content, config).await;
+            if !embedded_files.is_empty() {
+                pdf_metadata.pdf_specific.embedded_files = Some(embedded_files);
             }
+        }
+
+        #[cfg(feature = "ocr")]
+        let (text, used_ocr, ocr_preprocessing, page_rotations, page_sources, mut ocr_page_elements, mut ocr_tables) =
+            if config.force_ocr {
+                if config.ocr.is_some() {
+                    let output = extract_with_ocr(content, config).await?;
+                    (
+                        output.text,
+                        true,
+                        output.preprocessing,
+                        output.page_rotations,
+                        output.page_sources,
+                        output.elements,
+                        output.tables,
+                    )
+                } else {
+                    (native_text, false, None, Vec::new(), Vec::new(), Vec::new(), Vec::new())
+                }
+            } else if config.ocr.is_some() {
+                let decision = ocr::evaluate_per_page_ocr(
+                    &native_text,
+                    boundaries.as_deref(),
+                    pdf_metadata.pdf_specific.page_count,
+                );
+
+                if std::env::var("KREUZBERG_DEBUG_OCR").is_ok() {
+                    eprintln!(
+                        "[kreuzberg::pdf::ocr] fallback={} non_whitespace={} alnum={} meaningful_words={} \
+                     avg_non_whitespace={:.2} avg_alnum={:.2} alnum_ratio={:.3}",
+                        decision.fallback,
+                        decision.stats.non_whitespace,
+                        decision.stats.alnum,
+                        decision.stats.meaningful_words,
+                        decision.avg_non_whitespace,
+                        decision.avg_alnum,
+                        decision.stats.alnum_ratio
+                    );
+                }
 
-            if decision.fallback {
-                (extract_with_ocr(content, config).await?, true)
+                if decision.fallback {
+                    let output = extract_with_ocr(content, config).await?;
+                    (
+                        output.text,
+                        true,
+                        output.preprocessing,
+                        output.page_rotations,
+                        output.page_sources,
+                        output.elements,
+                        output.tables,
+                    )
+                } else {
+                    (native_text, false, None, Vec::new(), Vec::new(), Vec::new(), Vec::new())
+                }
             } else {
-                (native_text, false)
-            }
+                (native_text, false, None, Vec::new(), Vec::new(), Vec::new(), Vec::new())
+            };
+
+        #[cfg(not(feature = "ocr"))]
+        let (text, used_ocr, ocr_preprocessing, page_rotations) =
+            (native_text, false, None, Vec::<crate::types::OcrRotation>::new());
+        #[cfg(not(feature = "ocr"))]
+        let mut ocr_page_elements = Vec::<crate::types::OcrElement>::new();
+        #[cfg(not(feature = "ocr"))]
+        let ocr_tables = Vec::<crate::types::Table>::new();
+
+        // Pages whose text layer scored below `text_layer_threshold` (fonts without
+        // ToUnicode maps) are re-extracted with OCR and spliced back into the content.
+        #[allow(unused_mut)]
+        let mut processing_warnings = Vec::new();
+        #[cfg(feature = "ocr")]
+        let (text, page_contents, reocr_pages) = if used_ocr || broken_text_layers.is_empty() {
+            (text, page_contents, Vec::new())
         } else {
-            (native_text, false)
-        };
+            let page_numbers: Vec<usize> = broken_text_layers.iter().map(|page| page.page_number).collect();
+            match ocr::extract_pages_with_ocr(content, config, Some(&page_numbers)).await {
+                Ok(output) => {
+                    ocr_page_elements.extend(output.elements);
+                    ocr_tables.extend(output.tables);
+                    let mut text = text;
+                    let mut page_contents = page_contents;
+                    let mut cursor = 0;
+                    for (idx, (broken, ocr_text)) in broken_text_layers.iter().zip(&output.page_texts).enumerate() {
+                        let ocr_text = ocr_text.trim();
+                        let boundaries = pdf_metadata
+                            .page_structure
+                            .as_mut()
+                            .and_then(|page_structure| page_structure.boundaries.as_deref_mut());
+                        if let Some(end) = crate::pdf::text_layer::replace_page_text(
+                            &mut text,
+                            boundaries,
+                            broken.page_number,
+                            &broken.text,
+                            ocr_text,
+                            cursor,
+                        ) {
+                            cursor = end;
+                        }
+
+                        if let Some(page) = page_contents
+                            .as_mut()
+                            .and_then(|pages| pages.iter_mut().find(|page| page.page_number == broken.page_number))
+                        {
+                            page.content = ocr_text.to_string();
+                            page.hierarchy = None;
+                            page.is_blank = Some(crate::extraction::blank_detection::is_page_text_blank(ocr_text));
+                        }
+
+                        if let Some(page_info) = pdf_metadata
+                            .page_structure
+                            .as_mut()
+                            .and_then(|page_structure| page_structure.pages.as_mut())
+                            .and_then(|pages| pages.iter_mut().find(|info| info.number == broken.page_number))
+                        {
+                            let mut health = broken.health.clone();
+                            health.ocr_fallback = true;
+                            page_info.text_layer = Some(health);
+                            page_info.rotation = output.page_rotations.get(idx).cloned();
+                            if let Some(source) = output.page_sources.get(idx) {
+                                crate::ocr::fallback::record_source(page_info, source);
+                            }
+                        }
 
+                        processing_warnings.push(crate::types::ProcessingWarning {
+                            source: "pdf_text_layer".to_string(),
+                            message: format!(
+                                "Page {} has a broken text layer (health score {:.2}); its text was re-extracted with OCR",
+                                broken.page_number, broken.health.score
+                            ),
+                        });
+                    }
+                    (text, page_contents, page_numbers)
+                }
+                Err(e) => {
+                    for broken in &broken_text_layers {
+                        processing_warnings.push(crate::types::ProcessingWarning {
+                            source: "pdf_text_layer".to_string(),
+                            message: format!(
+                                "Page {} has a broken text layer (health score {:.2}), but OCR failed: {}; keeping the native text",
+                                broken.page_number, broken.health.score, e
+                            ),
+                        });
+                    }
+                    (text, page_contents, Vec::new())
+                }
+            }
+        };
         #[cfg(not(feature = "ocr"))]
-        let (text, used_ocr) = (native_text, false);
+        let reocr_pages: Vec<usize> = Vec::new();
+
+        // Word and line geometry of the text layer, without the pages that were OCR'd instead,
+        // followed by the elements of the OCR'd pages when those were collected.
+        #[cfg(feature = "pdf")]
+        let text_layer_elements = text_elements.filter(|_| !used_ocr).map(|elements| {
+            elements
+                .into_iter()
+                .filter(|element| !reocr_pages.contains(&element.page_number))
+                .collect::<Vec<_>>()
+        });
+        #[cfg(not(feature = "pdf"))]
+        let text_layer_elements: Option<Vec<crate::types::OcrElement>> = None;
+        let ocr_elements = if ocr_page_elements.is_empty() {
+            text_layer_elements
+        } else {
+            let mut elements = text_layer_elements.unwrap_or_default();
+            elements.append(&mut ocr_page_elements);
+            elements.sort_by_key(|element| element.page_number);
+            Some(elements)
+        };
 
         // Post-processing: use pre-rendered markdown from initial document load if available.
         // The markdown was rendered during the first document load to avoid redundant PDF parsing.
@@ -250,7 +427,7 @@ impl DocumentExtractor for PdfExtractor {
         // Note: we defer consumption of pre_rendered_markdown until after images are available
         // so that we can inject image placeholders into it before finalizing the text.
         #[cfg(feature = "pdf")]
-        let use_pdf_markdown = !used_ocr && pre_rendered_markdown.is_some();
+        let use_pdf_markdown = !used_ocr && reocr_pages.is_empty() && pre_rendered_markdown.is_some();
 
         #[cfg(not(feature = "pdf"))]
         let use_pdf_markdown = false;
@@ -325,6 +502,26 @@ impl DocumentExtractor for PdfExtractor {
             None
         };
 
+        // Vector figures are rendered from their page and added to the images, after
+        // the image OCR so that charts are not OCR'd as text.
+        #[cfg(feature = "pdf")]
+        let images = if figures.is_empty() {
+            images
+        } else {
+            let dpi = config
+                .images
+                .as_ref()
+                .map_or(crate::pdf::figures::DEFAULT_FIGURE_DPI, |images| images.target_dpi);
+            let first_index = images.as_ref().map_or(0, Vec::len);
+            match crate::pdf::figures::render_figures(content, &figures, dpi, first_index) {
+                Ok(rendered) => Some(images.unwrap_or_default().into_iter().chain(rendered).collect()),
+                Err(e) => {
+                    tracing::warn!("Rendering PDF figures failed: {:?}, continuing without them", e);
+                    images
+                }
+            }
+        };
+
         // Finalize text: apply pre-rendered markdown (with image placeholder injection) if available.
         // Images (including OCR) are now fully resolved, so we can inject placeholders.
         #[cfg(feature = "pdf")]
@@ -350,6 +547,106 @@ impl DocumentExtractor for PdfExtractor {
         #[cfg(not(feature = "pdf"))]
         let used_pdf_markdown = false;
 
+        // OCR the image regions without a text layer (stamps, signatures, screenshots) and
+        // merge their text after the line above them.
+        #[cfg(feature = "ocr")]
+        let image_regions: Vec<_> = image_regions
+            .into_iter()
+            .filter(|region| !reocr_pages.contains(&region.page_number))
+            .collect();
+        #[cfg(feature = "ocr")]
+        let (text, page_contents) = if !used_ocr && !image_regions.is_empty() {
+            match ocr::ocr_image_regions(content, &image_regions, config).await {
+                Ok(texts) => {
+                    let recognized: Vec<(&crate::pdf::regions::ImageRegion, String)> = image_regions
+                        .iter()
+                        .zip(texts)
+                        .filter(|(_, text)| !text.is_empty())
+                        .collect();
+
+                    let boundaries = pdf_metadata
+                        .page_structure
+                        .as_mut()
+                        .and_then(|page_structure| page_structure.boundaries.as_deref_mut())
+                        .filter(|_| !used_pdf_markdown);
+                    let text = crate::pdf::regions::merge_region_texts(&text, boundaries, &recognized);
+
+                    let page_contents = page_contents.map(|mut pages| {
+                        for page in pages.iter_mut() {
+                            let on_page: Vec<_> = recognized
+                                .iter()
+                                .filter(|(region, _)| region.page_number == page.page_number)
+                                .cloned()
+                                .collect();
+                            if !on_page.is_empty() {
+                                page.content = crate::pdf::regions::merge_region_texts(&page.content, None, &on_page);
+                            }
+                        }
+                        pages
+                    });
+
+                    if !recognized.is_empty() {
+                        pdf_metadata.pdf_specific.ocr_regions = Some(
+                            recognized
+                                .into_iter()
+                                .map(|(region, text)| crate::types::OcrRegion {
+                                    page_number: region.page_number,
+                                    bounding_box: region.bbox,
+                                    text,
+                                })
+                                .collect(),
+                        );
+                    }
+                    (text, page_contents)
+                }
+                Err(e) => {
+                    tracing::warn!("OCR of image regions failed: {:?}, continuing without it", e);
+                    (text, page_contents)
+                }
+            }
+        } else {
+            (text, page_contents)
+        };
+
+        // Append the filled-in form fields as a "Field: Value" section when requested.
+        #[cfg(feature = "pdf")]
+        let text = match (
+            config.pdf_options.as_ref().is_some_and(|opts| opts.render_form_fields),
+            &pdf_metadata.pdf_specific.form_fields,
+        ) {
+            (true, Some(fields)) => match crate::pdf::forms::render_form_fields(fields, used_pdf_markdown) {
+                Some(section) if text.trim().is_empty() => section,
+                Some(section) => format!("{}\n\n{}", text.trim_end(), section),
+                None => text,
+            },
+            _ => text,
+        };
+
+        // The native document tree describes the text layer; drop it when OCR replaced the
+        // text of any page so that the pipeline rebuilds the tree from the OCR content.
+        #[cfg(feature = "pdf")]
+        let document_structure =
+            document_structure
+                .filter(|_| !used_ocr && reocr_pages.is_empty())
+                .map(|mut structure| {
+                    if let Some(ref imgs) = images {
+                        crate::pdf::structure::link_image_nodes(&mut structure, imgs);
+                    }
+                    if let Some(ref regions) = pdf_metadata.pdf_specific.ocr_regions {
+                        crate::pdf::structure::attach_region_texts(&mut structure, regions);
+                    }
+                    structure
+                });
+        #[cfg(not(feature = "pdf"))]
+        let document_structure = None;
+
+        // Tables reconstructed on OCR'd pages (`OcrConfig.table_detection`)
+        let mut tables = tables;
+        if !ocr_tables.is_empty() {
+            tables.extend(ocr_tables);
+            tables.sort_by_key(|table| table.page_number);
+        }
+
         let final_pages = assign_tables_and_images_to_pages(page_contents, &tables, images.as_deref().unwrap_or(&[]));
 
         // Refine PageInfo.is_blank in page_structure to match PageContent refinement
@@ -363,6 +660,30 @@ impl DocumentExtractor for PdfExtractor {
             }
         }
 
+        // Report the orientation correction applied to each OCR'd page
+        if !page_rotations.is_empty()
+            && let Some(page_infos) = pdf_metadata
+                .page_structure
+                .as_mut()
+                .and_then(|page_structure| page_structure.pages.as_mut())
+        {
+            for (page_info, rotation) in page_infos.iter_mut().zip(page_rotations) {
+                page_info.rotation = Some(rotation);
+            }
+        }
+
+        // Report the backend that recognized each OCR'd page
+        #[cfg(feature = "ocr")]
+        if let Some(page_infos) = pdf_metadata
+            .page_structure
+            .as_mut()
+            .and_then(|page_structure| page_structure.pages.as_mut())
+        {
+            for (page_info, source) in page_infos.iter_mut().zip(&page_sources) {
+                crate::ocr::fallback::record_source(page_info, source);
+            }
+        }
+
         // Always preserve the original document MIME type (e.g. application/pdf).
         // The output format is tracked separately in metadata.output_format.
         let effective_mime_type = mime_type.to_string();
@@ -400,6 +721,7 @@ impl DocumentExtractor for PdfExtractor {
                 pages: pdf_metadata.page_structure.clone(),
                 #[cfg(feature = "pdf")]
                 format: Some(crate::types::FormatMetadata::Pdf(pdf_metadata.pdf_specific)),
+                image_preprocessing: ocr_preprocessing,
                 ..Default::default()
             },
             pages: final_pages,
@@ -409,12 +731,12 @@ impl DocumentExtractor for PdfExtractor {
             images,
             djot_content: None,
             elements: None,
-            ocr_elements: None,
-            document: None,
+            ocr_elements,
+            document: document_structure,
             #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
             extracted_keywords: None,
             quality_score: None,
-            processing_warnings: Vec::new(),
+            processing_warnings,
         })
     }
 
@@ -644,6 +966,7 @@ mod tests {
                 extract_pages: true,
                 insert_page_markers: false,
                 marker_format: "<!-- PAGE {page_num} -->".to_string(),
+                selection: None,
             }),
             ..Default::default()
         };
@@ -702,6 +1025,7 @@ mod tests {
                 extract_pages: true,
                 insert_page_markers: true,
                 marker_format: "\n\n<!-- PAGE {page_num} -->\n\n".to_string(),
+                selection: None,
             }),
             ..Default::default()
         };
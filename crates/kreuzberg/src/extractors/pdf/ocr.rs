@@ -166,77 +166,332 @@ pub fn evaluate_per_page_ocr(
     document_decision
 }
 
+/// Output of [`extract_with_ocr`].
+#[cfg(feature = "ocr")]
+pub(crate) struct PdfOcrOutput {
+    /// Text from all pages, separated by double newlines
+    pub text: String,
+    /// Text of each page, in page order
+    pub page_texts: Vec<String>,
+    /// Preprocessing record of the first page only (if preprocessing ran);
+    /// every page is preprocessed, but later pages' records are not kept
+    pub preprocessing: Option<crate::types::ImagePreprocessingMetadata>,
+    /// Orientation correction per page, in page order (empty when detection is off)
+    pub page_rotations: Vec<crate::types::OcrRotation>,
+    /// Backend and confidence of each page, in page order
+    pub page_sources: Vec<crate::ocr::fallback::OcrSource>,
+    /// OCR elements of all pages, in the pixel space of each rendered page. Every
+    /// page starts with a page-level element spanning the whole image. Only
+    /// collected when the elements or an hOCR/ALTO rendering of them were requested.
+    pub elements: Vec<crate::types::OcrElement>,
+    /// Tables recognized on the pages. Only collected when `table_detection` is set.
+    pub tables: Vec<crate::types::Table>,
+}
+
 /// Extract text from PDF using OCR.
 ///
-/// Renders all pages to images and processes them with OCR backend.
+/// Renders all pages to images and processes them with OCR backend. When
+/// orientation detection is enabled, each page is rotated upright first. When
+/// image preprocessing is configured, pages are rendered at its `target_dpi`
+/// and enhanced before being handed to the backend.
 ///
 /// # Arguments
 ///
 /// * `content` - Raw PDF bytes
 /// * `config` - Extraction configuration including OCR settings
-///
-/// # Returns
-///
-/// Concatenated text from all pages, separated by double newlines
 #[cfg(feature = "ocr")]
-pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig) -> crate::Result<String> {
-    use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
-    use crate::plugins::registry::get_ocr_backend_registry;
-    use image::ImageEncoder;
-    use image::codecs::png::PngEncoder;
-    use std::io::Cursor;
+pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig) -> crate::Result<PdfOcrOutput> {
+    extract_pages_with_ocr(content, config, None).await
+}
+
+/// Like [`extract_with_ocr`], limited to the given pages (1-indexed) when
+/// `page_numbers` is set.
+#[cfg(feature = "ocr")]
+pub(crate) async fn extract_pages_with_ocr(
+    content: &[u8],
+    config: &ExtractionConfig,
+    page_numbers: Option<&[usize]>,
+) -> crate::Result<PdfOcrOutput> {
+    use crate::pdf::rendering::PdfRenderer;
+
+    let render_options = ocr_render_options(required_ocr_config(config)?);
+    let images = {
+        let renderer = PdfRenderer::new().map_err(|e| crate::KreuzbergError::Parsing {
+            message: format!("Failed to initialize PDF renderer: {}", e),
+            source: None,
+        })?;
+
+        match page_numbers {
+            None => renderer
+                .render_all_pages(content, &render_options)
+                .map_err(|e| crate::KreuzbergError::Parsing {
+                    message: format!("Failed to render PDF pages: {}", e),
+                    source: None,
+                })?
+                .into_iter()
+                .enumerate()
+                .map(|(idx, image)| (idx + 1, image))
+                .collect(),
+            Some(page_numbers) => page_numbers
+                .iter()
+                .map(|&page_number| {
+                    renderer
+                        .render_page_to_image(content, page_number - 1, &render_options)
+                        .map(|image| (page_number, image))
+                        .map_err(|e| crate::KreuzbergError::Parsing {
+                            message: format!("Failed to render PDF page {}: {}", page_number, e),
+                            source: None,
+                        })
+                })
+                .collect::<crate::Result<Vec<_>>>()?,
+        }
+    };
+
+    ocr_page_images(images, config).await
+}
+
+/// Render options for OCR'ing PDF pages, at the preprocessing `target_dpi` when configured.
+#[cfg(feature = "ocr")]
+pub(crate) fn ocr_render_options(
+    ocr_config: &crate::core::config::OcrConfig,
+) -> crate::pdf::rendering::PageRenderOptions {
+    let mut render_options = crate::pdf::rendering::PageRenderOptions::default();
+    if let Some(preprocessing) = crate::image::enhance::ocr_preprocessing_config(ocr_config) {
+        render_options.target_dpi = preprocessing.target_dpi;
+    }
+    render_options
+}
+
+/// OCR pages that were already rendered with [`ocr_render_options`], given as
+/// (1-indexed page number, image) pairs.
+#[cfg(feature = "ocr")]
+pub(crate) async fn ocr_page_images(
+    images: Vec<(usize, image::DynamicImage)>,
+    config: &ExtractionConfig,
+) -> crate::Result<PdfOcrOutput> {
+    let ocr_config = required_ocr_config(config)?;
+
+    let backend = ocr_backend(ocr_config)?;
+
+    let collect_elements = config.output_format.requires_ocr_elements()
+        || ocr_config.element_config.as_ref().is_some_and(|ec| ec.include_elements);
+    let mut ocr_config = crate::core::config::OcrConfig {
+        cache: config.cache.clone(),
+        ..ocr_config.clone()
+    };
+    if config.output_format.requires_ocr_elements() && ocr_config.output_format.is_none() {
+        // Tells the backend to produce elements for the requested rendering.
+        ocr_config.output_format = Some(config.output_format);
+    }
+    let ocr_config = &ocr_config;
+
+    let preprocessing = crate::image::enhance::ocr_preprocessing_config(ocr_config);
+    let detect_orientation = ocr_config.orientation_detection_enabled();
+
+    let mut page_texts = Vec::with_capacity(images.len());
+    let mut page_rotations = Vec::new();
+    let mut page_sources = Vec::with_capacity(images.len());
+    let mut first_page_preprocessing = None;
+    let mut elements = Vec::new();
+    let mut tables = Vec::new();
+
+    for (page_number, image) in images {
+        let (image, rotation) = if detect_orientation {
+            let (image, rotation) =
+                crate::ocr::orientation::correct_image_orientation(backend.as_ref(), image, ocr_config).await?;
+            (image, Some(rotation))
+        } else {
+            (image, None)
+        };
+
+        let (image_data, image_size) = match &preprocessing {
+            Some(preprocessing) => {
+                let mut enhanced = crate::image::preprocess_for_ocr(&image, preprocessing, None)?;
+                if first_page_preprocessing.is_none() {
+                    crate::ocr::orientation::record_rotation(&mut enhanced.metadata, rotation.as_ref());
+                    first_page_preprocessing = Some(enhanced.metadata);
+                }
+                let size = enhanced.image.dimensions();
+                (crate::image::enhance::encode_png(&enhanced.image)?, size)
+            }
+            None => (encode_rgb_png(&image)?, (image.width(), image.height())),
+        };
 
-    let ocr_config = config.ocr.as_ref().ok_or_else(|| crate::KreuzbergError::Parsing {
+        let crate::ocr::fallback::Recognition {
+            result: ocr_result,
+            source,
+        } = crate::ocr::fallback::recognize(backend.as_ref(), &image_data, ocr_config).await?;
+
+        if ocr_config.table_detection.is_some() {
+            tables.extend(ocr_result.tables.into_iter().map(|mut table| {
+                table.page_number = page_number;
+                table
+            }));
+        }
+        if collect_elements {
+            elements.push(page_element(page_number, image_size));
+            elements.extend(ocr_result.ocr_elements.into_iter().flatten().map(|mut element| {
+                element.page_number = page_number;
+                element
+            }));
+        }
+        page_texts.push(ocr_result.content);
+        page_rotations.extend(rotation);
+        page_sources.push(source);
+    }
+
+    Ok(PdfOcrOutput {
+        text: page_texts.join("\n\n"),
+        page_texts,
+        preprocessing: first_page_preprocessing,
+        page_rotations,
+        page_sources,
+        elements,
+        tables,
+    })
+}
+
+#[cfg(feature = "ocr")]
+fn required_ocr_config(config: &ExtractionConfig) -> crate::Result<&crate::core::config::OcrConfig> {
+    config.ocr.as_ref().ok_or_else(|| crate::KreuzbergError::Parsing {
         message: "OCR config required for force_ocr".to_string(),
         source: None,
-    })?;
+    })
+}
 
-    let backend = {
-        let registry = get_ocr_backend_registry();
-        let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
-            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
-            plugin_name: "ocr-registry".to_string(),
-        })?;
-        let backend_name = crate::core::config::canonical_ocr_backend_name(&ocr_config.backend);
-        registry.get(&backend_name)?
+/// Page-level element recording the size of a rendered page.
+#[cfg(feature = "ocr")]
+fn page_element(page_number: usize, (width, height): (u32, u32)) -> crate::types::OcrElement {
+    crate::types::OcrElement::new(
+        "",
+        crate::types::OcrBoundingGeometry::Rectangle {
+            left: 0,
+            top: 0,
+            width,
+            height,
+        },
+        crate::types::OcrConfidence::from_tesseract(100.0),
+    )
+    .with_level(crate::types::OcrElementLevel::Page)
+    .with_page_number(page_number)
+}
+
+/// OCR the image regions of native pages that have no text layer.
+///
+/// Each page with regions is rendered once; every region is cropped from it and
+/// recognized on its own (with image preprocessing when configured). Returns the
+/// recognized text of each region, in the order of `regions`.
+#[cfg(feature = "ocr")]
+pub(crate) async fn ocr_image_regions(
+    content: &[u8],
+    regions: &[crate::pdf::regions::ImageRegion],
+    config: &ExtractionConfig,
+) -> crate::Result<Vec<String>> {
+    use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
+
+    let Some(ocr_config) = config.ocr.as_ref() else {
+        return Ok(Vec::new());
+    };
+    let ocr_config = &crate::core::config::OcrConfig {
+        cache: config.cache.clone(),
+        ..ocr_config.clone()
     };
+    let backend = ocr_backend(ocr_config)?;
+    let preprocessing = crate::image::enhance::ocr_preprocessing_config(ocr_config);
 
-    let images = {
-        let render_options = PageRenderOptions::default();
+    let mut render_options = PageRenderOptions::default();
+    if let Some(preprocessing) = &preprocessing {
+        render_options.target_dpi = preprocessing.target_dpi;
+    }
+
+    // Crop every region before OCR'ing: the renderer must not be held across an await
+    let crops = {
         let renderer = PdfRenderer::new().map_err(|e| crate::KreuzbergError::Parsing {
             message: format!("Failed to initialize PDF renderer: {}", e),
             source: None,
         })?;
 
-        renderer
-            .render_all_pages(content, &render_options)
-            .map_err(|e| crate::KreuzbergError::Parsing {
-                message: format!("Failed to render PDF pages: {}", e),
-                source: None,
-            })?
+        let mut crops = Vec::with_capacity(regions.len());
+        let mut rendered: Option<(usize, image::DynamicImage)> = None;
+        for region in regions {
+            if rendered.as_ref().is_none_or(|(page, _)| *page != region.page_number) {
+                let page_image = renderer
+                    .render_page_to_image(content, region.page_number - 1, &render_options)
+                    .map_err(|e| crate::KreuzbergError::Parsing {
+                        message: format!("Failed to render PDF page {}: {}", region.page_number, e),
+                        source: None,
+                    })?;
+                rendered = Some((region.page_number, page_image));
+            }
+            let Some((_, page_image)) = rendered.as_ref() else {
+                crops.push(None);
+                continue;
+            };
+
+            crops.push(crate::pdf::rendering::crop_to_bbox(
+                page_image,
+                region.page_width,
+                region.page_height,
+                &region.bbox,
+            ));
+        }
+        crops
     };
 
-    let mut page_texts = Vec::with_capacity(images.len());
+    let mut texts = Vec::with_capacity(crops.len());
+    for crop in crops {
+        let Some(crop) = crop else {
+            texts.push(String::new());
+            continue;
+        };
+        let image_data = match &preprocessing {
+            Some(preprocessing) => {
+                let enhanced = crate::image::preprocess_for_ocr(&crop, preprocessing, None)?;
+                crate::image::enhance::encode_png(&enhanced.image)?
+            }
+            None => encode_rgb_png(&crop)?,
+        };
+        let ocr_result = crate::ocr::fallback::recognize(backend.as_ref(), &image_data, ocr_config)
+            .await?
+            .result;
+        texts.push(ocr_result.content.trim().to_string());
+    }
 
-    for image in images {
-        let rgb_image = image.to_rgb8();
-        let (width, height) = rgb_image.dimensions();
+    Ok(texts)
+}
 
-        let mut image_bytes = Cursor::new(Vec::new());
-        let encoder = PngEncoder::new(&mut image_bytes);
-        encoder
-            .write_image(&rgb_image, width, height, image::ColorType::Rgb8.into())
-            .map_err(|e| crate::KreuzbergError::Parsing {
-                message: format!("Failed to encode image: {}", e),
-                source: None,
-            })?;
+/// Look up the OCR backend selected in the OCR configuration.
+#[cfg(feature = "ocr")]
+fn ocr_backend(
+    ocr_config: &crate::core::config::OcrConfig,
+) -> crate::Result<std::sync::Arc<dyn crate::plugins::OcrBackend>> {
+    let registry = crate::plugins::registry::get_ocr_backend_registry();
+    let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
+        message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+        plugin_name: "ocr-registry".to_string(),
+    })?;
+    let backend_name = crate::core::config::canonical_ocr_backend_name(&ocr_config.backend);
+    registry.get(&backend_name)
+}
 
-        let image_data = image_bytes.into_inner();
+/// Encode an image as an RGB PNG for the OCR backend.
+#[cfg(feature = "ocr")]
+fn encode_rgb_png(image: &image::DynamicImage) -> crate::Result<Vec<u8>> {
+    use image::ImageEncoder;
+    use image::codecs::png::PngEncoder;
+    use std::io::Cursor;
 
-        let ocr_result = backend.process_image(&image_data, ocr_config).await?;
+    let rgb_image = image.to_rgb8();
+    let (width, height) = rgb_image.dimensions();
 
-        page_texts.push(ocr_result.content);
-    }
+    let mut image_bytes = Cursor::new(Vec::new());
+    let encoder = PngEncoder::new(&mut image_bytes);
+    encoder
+        .write_image(&rgb_image, width, height, image::ColorType::Rgb8.into())
+        .map_err(|e| crate::KreuzbergError::Parsing {
+            message: format!("Failed to encode image: {}", e),
+            source: None,
+        })?;
 
-    Ok(page_texts.join("\n\n"))
+    Ok(image_bytes.into_inner())
 }
@@ -1,8 +1,13 @@
 //! Page content management for PDF extraction.
 //!
-//! Handles assignment of tables and images to specific pages.
+//! Handles assignment of tables and images to specific pages, and maps the page
+//! numbers of a page selection back to the original document.
 
 use crate::types::PageContent;
+#[cfg(feature = "pdf")]
+use crate::types::{ExtractionResult, FormatMetadata};
+#[cfg(feature = "pdf")]
+use std::sync::Arc;
 
 /// Helper function to assign tables and images to pages.
 ///
@@ -56,3 +61,244 @@ pub(crate) fn assign_tables_and_images_to_pages(
 
     Some(updated_pages)
 }
+
+/// The selected pages of a PDF, as a document of their own.
+#[cfg(feature = "pdf")]
+pub(crate) struct SelectedPages {
+    /// The PDF with only the selected pages.
+    pub(crate) content: Vec<u8>,
+    /// Original number of each page of `content`.
+    pub(crate) page_numbers: Vec<usize>,
+    /// Number of pages of the original document.
+    pub(crate) page_count: usize,
+}
+
+/// Cut the pages of `config.pages.selection` out of the PDF.
+///
+/// Returns `None` when no selection is configured or it covers the whole document.
+#[cfg(feature = "pdf")]
+pub(crate) fn select_pages(
+    content: &[u8],
+    config: &crate::core::config::ExtractionConfig,
+) -> crate::Result<Option<SelectedPages>> {
+    let Some(selection) = config.pages.as_ref().and_then(|pages| pages.selection.as_ref()) else {
+        return Ok(None);
+    };
+    let passwords = config
+        .pdf_options
+        .as_ref()
+        .and_then(|opts| opts.passwords.as_deref())
+        .unwrap_or_default();
+
+    let mut document = crate::pdf::split::load_document_with_passwords(content, passwords)?;
+    let page_count = document.get_pages().len();
+    let selected = selection.resolve(page_count)?;
+    if selected.len() == page_count {
+        return Ok(None);
+    }
+
+    let keep: Vec<u32> = selected.iter().map(|&page| page as u32).collect();
+    Ok(Some(SelectedPages {
+        content: crate::pdf::split::extract_pages(&mut document, &keep)?,
+        page_numbers: selected,
+        page_count,
+    }))
+}
+
+/// Map the page numbers of an extraction of selected pages back to the original document.
+///
+/// `selected[i]` is the original number of page `i + 1` of the extracted subset, and
+/// `page_count` the length of the original document. Page markers in the content are
+/// rewritten with the original numbers and the page boundaries moved accordingly.
+#[cfg(feature = "pdf")]
+pub(crate) fn renumber_selected_pages(
+    result: &mut ExtractionResult,
+    selected: &[usize],
+    page_count: usize,
+    page_config: &crate::core::config::PageConfig,
+) {
+    let original = |page: usize| selected.get(page.wrapping_sub(1)).copied().unwrap_or(page);
+
+    if let Some(page_structure) = result.metadata.pages.as_mut() {
+        page_structure.total_count = page_count;
+        if let Some(boundaries) = page_structure.boundaries.as_mut() {
+            if page_config.insert_page_markers {
+                renumber_page_markers(&mut result.content, boundaries, &page_config.marker_format, selected);
+            }
+            for boundary in boundaries.iter_mut() {
+                boundary.page_number = original(boundary.page_number);
+            }
+        }
+        for info in page_structure.pages.iter_mut().flatten() {
+            info.number = original(info.number);
+        }
+    }
+
+    if let Some(FormatMetadata::Pdf(pdf)) = result.metadata.format.as_mut() {
+        pdf.page_count = Some(page_count);
+        if let Some(summary) = pdf.annotations.as_mut() {
+            for page in summary.pages.iter_mut() {
+                *page = original(*page);
+            }
+        }
+        for entry in pdf.outline.iter_mut().flatten() {
+            entry.page = entry.page.map(original);
+        }
+        for field in pdf.form_fields.iter_mut().flatten() {
+            field.page_number = field.page_number.map(original);
+        }
+        for furniture in pdf.page_furniture.iter_mut().flatten() {
+            furniture.page_number = original(furniture.page_number);
+        }
+        for region in pdf.ocr_regions.iter_mut().flatten() {
+            region.page_number = original(region.page_number);
+        }
+    }
+
+    for table in result.tables.iter_mut() {
+        table.page_number = original(table.page_number);
+        table.end_page_number = table.end_page_number.map(original);
+    }
+    for image in result.images.iter_mut().flatten() {
+        image.page_number = image.page_number.map(original);
+    }
+    for page in result.pages.iter_mut().flatten() {
+        page.page_number = original(page.page_number);
+        for table in page.tables.iter_mut() {
+            let table = Arc::make_mut(table);
+            table.page_number = original(table.page_number);
+            table.end_page_number = table.end_page_number.map(original);
+        }
+        for image in page.images.iter_mut() {
+            let image = Arc::make_mut(image);
+            image.page_number = image.page_number.map(original);
+        }
+        for annotation in page.annotations.iter_mut() {
+            annotation.page_number = original(annotation.page_number);
+        }
+    }
+    for element in result.ocr_elements.iter_mut().flatten() {
+        element.page_number = original(element.page_number);
+    }
+    for node in result
+        .document
+        .iter_mut()
+        .flat_map(|document| document.nodes.iter_mut())
+    {
+        node.page = node.page.map(|page| original(page as usize) as u32);
+        node.page_end = node.page_end.map(|page| original(page as usize) as u32);
+    }
+}
+
+/// Rewrite the page markers of the subset pages with their original numbers.
+///
+/// Markers are found in page order, so a marker is never mistaken for one that was
+/// already rewritten. Boundaries after a rewritten marker move by the change in length.
+#[cfg(feature = "pdf")]
+fn renumber_page_markers(
+    content: &mut String,
+    boundaries: &mut [crate::types::PageBoundary],
+    marker_format: &str,
+    selected: &[usize],
+) {
+    let mut cursor = 0;
+    for (idx, &original) in selected.iter().enumerate() {
+        let marker = marker_format.replace("{page_num}", &(idx + 1).to_string());
+        let Some(offset) = content[cursor..].find(&marker) else {
+            continue;
+        };
+        let start = cursor + offset;
+        let end = start + marker.len();
+        let renumbered = marker_format.replace("{page_num}", &original.to_string());
+        content.replace_range(start..end, &renumbered);
+
+        for boundary in boundaries.iter_mut() {
+            if boundary.byte_start >= end {
+                boundary.byte_start = boundary.byte_start - marker.len() + renumbered.len();
+            }
+            if boundary.byte_end >= end {
+                boundary.byte_end = boundary.byte_end - marker.len() + renumbered.len();
+            }
+        }
+        cursor = start + renumbered.len();
+    }
+}
+
+#[cfg(all(test, feature = "pdf"))]
+mod tests {
+    use super::*;
+    use crate::core::config::PageConfig;
+    use crate::types::{Metadata, PageBoundary, PageStructure, PageUnitType, Table};
+
+    fn table(page_number: usize) -> Table {
+        Table {
+            cells: vec![vec!["A".to_string()]],
+            markdown: "| A |".to_string(),
+            page_number,
+            bounding_box: None,
+            end_page_number: None,
+            spans: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_renumber_selected_pages() {
+        let mut result = ExtractionResult {
+            content: "first\n\nsecond".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: 2,
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(vec![
+                        PageBoundary {
+                            byte_start: 0,
+                            byte_end: 5,
+                            page_number: 1,
+                        },
+                        PageBoundary {
+                            byte_start: 7,
+                            byte_end: 13,
+                            page_number: 2,
+                        },
+                    ]),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![table(2)],
+            ..Default::default()
+        };
+
+        renumber_selected_pages(&mut result, &[40, 55], 60, &PageConfig::default());
+
+        let pages = result.metadata.pages.unwrap();
+        assert_eq!(pages.total_count, 60);
+        let numbers: Vec<_> = pages.boundaries.unwrap().iter().map(|b| b.page_number).collect();
+        assert_eq!(numbers, vec![40, 55]);
+        assert_eq!(result.tables[0].page_number, 55);
+    }
+
+    #[test]
+    fn test_renumber_page_markers_moves_boundaries() {
+        let format = "[PAGE {page_num}]";
+        let mut content = "[PAGE 1]one[PAGE 2]two".to_string();
+        let mut boundaries = vec![
+            PageBoundary {
+                byte_start: 8,
+                byte_end: 11,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 19,
+                byte_end: 22,
+                page_number: 2,
+            },
+        ];
+
+        renumber_page_markers(&mut content, &mut boundaries, format, &[2, 140]);
+
+        assert_eq!(content, "[PAGE 2]one[PAGE 140]two");
+        assert_eq!(&content[boundaries[0].byte_start..boundaries[0].byte_end], "one");
+        assert_eq!(&content[boundaries[1].byte_start..boundaries[1].byte_end], "two");
+    }
+}
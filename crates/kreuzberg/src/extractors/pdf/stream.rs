@@ -0,0 +1,200 @@
+//! Page-by-page PDF extraction for the streaming API.
+//!
+//! A blocking task reads the document page by page and hands the pages over
+//! through a bounded channel, so a 3,000-page PDF never has more than a few pages
+//! in memory and the consumer sees the first page right away. The task loads the
+//! document once and holds the global PDFium lock until it is done or the stream
+//! is dropped; pages without usable native text are rendered there as well, so
+//! the consumer OCRs the rendering without touching PDFium again. Other PDF
+//! extractions wait for the stream to finish, so consume or drop it promptly.
+
+use super::extraction::extract_page_tables;
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::core::extractor::PageStream;
+use crate::pdf::error::PdfError;
+use crate::types::{PageContent, PageFurniture};
+use futures::StreamExt;
+use pdfium_render::prelude::*;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Pages buffered ahead of the consumer.
+const PAGES_BUFFERED: usize = 8;
+
+/// A page read by the producer, with its rendering when it needs OCR.
+struct ProducedPage {
+    page: PageContent,
+    #[cfg(feature = "ocr")]
+    ocr_image: Option<image::DynamicImage>,
+}
+
+/// Stream the pages of a PDF.
+///
+/// Only the pages of `PageConfig::selection` are read, when set. Each page
+/// carries its native text (without running headers and footers when
+/// `PdfConfig::strip_page_furniture` is set), its table, annotations and text
+/// hierarchy as configured. With OCR configured, pages whose text fails the OCR
+/// fallback check (or every page with `force_ocr`) are recognized before they
+/// are yielded. Post-processors run on every page. Errors are yielded in place;
+/// when the document itself cannot be read, the error is the last item.
+pub(crate) fn stream_pdf_pages(content: Vec<u8>, config: ExtractionConfig) -> PageStream {
+    // Strip /Rotate for the same pdfium text extraction bug as the whole-document path
+    let derotated = match crate::pdf::text::strip_page_rotation(&content) {
+        std::borrow::Cow::Owned(derotated) => Some(derotated),
+        std::borrow::Cow::Borrowed(_) => None,
+    };
+    let content: Arc<[u8]> = derotated.unwrap_or(content).into();
+    let config = Arc::new(config);
+
+    let (tx, rx) = mpsc::channel::<Result<ProducedPage>>(PAGES_BUFFERED);
+    let producer_config = Arc::clone(&config);
+    let span = tracing::Span::current();
+    tokio::task::spawn_blocking(move || {
+        let _guard = span.entered();
+        if let Err(e) = produce_pages(&content, &producer_config, &tx) {
+            let _ = tx.blocking_send(Err(e));
+        }
+    });
+
+    futures::stream::unfold(rx, |mut rx| async move { rx.recv().await.map(|page| (page, rx)) })
+        .then(move |page| {
+            let config = Arc::clone(&config);
+            async move { finish_page(page?, &config).await }
+        })
+        .boxed()
+}
+
+/// Extract the selected pages one by one, sending each page as soon as it is read.
+///
+/// Returns early without an error when the consumer dropped the stream.
+fn produce_pages(content: &[u8], config: &ExtractionConfig, tx: &mpsc::Sender<Result<ProducedPage>>) -> Result<()> {
+    let strip_furniture = config
+        .pdf_options
+        .as_ref()
+        .is_some_and(|opts| opts.strip_page_furniture);
+
+    let pdfium = crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
+    let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
+        let err_msg = crate::pdf::error::format_pdfium_error(e);
+        if err_msg.contains("password") || err_msg.contains("Password") {
+            PdfError::PasswordRequired
+        } else {
+            PdfError::InvalidPdf(err_msg)
+        }
+    })?;
+
+    // Running headers and footers are found by comparing pages, so they need one
+    // pass over the whole document before the first page can be cleaned.
+    let furniture = if strip_furniture {
+        crate::pdf::furniture::detect_page_furniture(&document)?
+    } else {
+        Vec::new()
+    };
+    let page_count = document.pages().len() as usize;
+
+    let selected = match config.pages.as_ref().and_then(|pages| pages.selection.as_ref()) {
+        Some(selection) => selection.resolve(page_count)?,
+        None => (1..=page_count).collect(),
+    };
+
+    for page_number in selected {
+        let page = produce_page(&document, page_number - 1, config, &furniture)?;
+        if tx.blocking_send(Ok(page)).is_err() {
+            return Ok(());
+        }
+    }
+
+    Ok(())
+}
+
+/// Extract the native content of one page, rendering it when it needs OCR.
+fn produce_page(
+    document: &PdfDocument<'_>,
+    page_idx: usize,
+    config: &ExtractionConfig,
+    furniture: &[PageFurniture],
+) -> Result<ProducedPage> {
+    let page_number = page_idx + 1;
+    let pdf_page = document
+        .pages()
+        .get(page_idx as PdfPageIndex)
+        .map_err(|e| PdfError::TextExtractionFailed(format!("Failed to load page {}: {}", page_number, e)))?;
+    let page = extract_page(&pdf_page, page_number, config, furniture)?;
+
+    #[cfg(feature = "ocr")]
+    {
+        let ocr_image = match config.ocr.as_ref() {
+            Some(ocr_config)
+                if config.force_ocr || super::ocr::evaluate_native_text_for_ocr(&page.content, Some(1)).fallback =>
+            {
+                Some(crate::pdf::rendering::render_page(
+                    &pdf_page,
+                    &super::ocr::ocr_render_options(ocr_config),
+                )?)
+            }
+            _ => None,
+        };
+        Ok(ProducedPage { page, ocr_image })
+    }
+    #[cfg(not(feature = "ocr"))]
+    Ok(ProducedPage { page })
+}
+
+/// Extract the native content of one page.
+fn extract_page(
+    page: &PdfPage<'_>,
+    page_number: usize,
+    config: &ExtractionConfig,
+    furniture: &[PageFurniture],
+) -> Result<PageContent> {
+    let text = page
+        .text()
+        .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
+    let content = crate::pdf::text::page_text_without_furniture(text.all(), furniture, page_number);
+
+    let pdf_options = config.pdf_options.as_ref();
+    let hierarchy = match pdf_options.and_then(|opts| opts.hierarchy.as_ref()) {
+        Some(hierarchy_config) if hierarchy_config.enabled => {
+            crate::pdf::text::page_hierarchy_without_furniture(page, Some(hierarchy_config), furniture, page_number)?
+        }
+        _ => None,
+    };
+    let annotations = if pdf_options.is_some_and(|opts| opts.extract_annotations) {
+        crate::pdf::annotations::extract_page_annotations(page, page_number)?
+    } else {
+        Vec::new()
+    };
+    let table_detection = pdf_options.and_then(|opts| opts.table_detection.as_ref());
+    let tables: Vec<_> = extract_page_tables(page, page_number, table_detection)?
+        .into_iter()
+        .map(Arc::new)
+        .collect();
+
+    Ok(PageContent {
+        page_number,
+        is_blank: Some(tables.is_empty() && crate::extraction::blank_detection::is_page_text_blank(&content)),
+        content,
+        tables,
+        images: Vec::new(),
+        hierarchy,
+        annotations,
+    })
+}
+
+/// OCR the page when the producer rendered it for OCR, then run the post-processors.
+async fn finish_page(produced: ProducedPage, config: &ExtractionConfig) -> Result<PageContent> {
+    let mut page = produced.page;
+
+    #[cfg(feature = "ocr")]
+    if let Some(image) = produced.ocr_image {
+        let output = super::ocr::ocr_page_images(vec![(page.page_number, image)], config).await?;
+        page.content = output.text;
+        page.hierarchy = None;
+        page.is_blank =
+            Some(page.tables.is_empty() && crate::extraction::blank_detection::is_page_text_blank(&page.content));
+    }
+
+    crate::core::pipeline::run_page_processors(&mut page, "application/pdf", config).await?;
+    Ok(page)
+}
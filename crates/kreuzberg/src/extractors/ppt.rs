@@ -126,6 +126,11 @@ impl DocumentExtractor for PptExtractor {
                             table_count: None,
                             hidden: None,
                             is_blank: None,
+                            rotation: None,
+                            text_layer: None,
+                            ocr_backend: None,
+                            ocr_language: None,
+                            ocr_confidence: None,
                         })
                         .collect(),
                 ),
@@ -348,6 +348,8 @@ impl RstExtractor {
             markdown,
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         })
     }
 
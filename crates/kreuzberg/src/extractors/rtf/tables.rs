@@ -73,6 +73,8 @@ impl TableState {
             markdown,
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         })
     }
 }
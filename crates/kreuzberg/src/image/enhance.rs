@@ -0,0 +1,843 @@
+//! Image enhancement applied before OCR.
+//!
+//! Implements the steps configured by [`ImagePreprocessingConfig`]: color
+//! inversion, contrast stretching, median denoising, 90° auto-rotation,
+//! deskewing and binarization (Otsu, Sauvola or adaptive mean). Everything
+//! operates on 8-bit grayscale and is backend independent, so Tesseract,
+//! PaddleOCR and the VLM backends all receive the same cleaned-up image.
+//!
+//! The pipeline runs in a fixed order:
+//!
+//! 1. invert colors (`invert_colors`)
+//! 2. contrast stretch (`contrast_enhance`)
+//! 3. 3×3 median filter (`denoise`)
+//! 4. 90°/180°/270° orientation correction (`auto_rotate`)
+//! 5. small-angle skew correction (`deskew`)
+//! 6. binarization (`binarize`, `binarization_method`)
+//!
+//! Every applied step is recorded in [`ImagePreprocessingMetadata`].
+
+use crate::core::config::OcrConfig;
+use crate::error::{KreuzbergError, Result};
+use crate::types::{ImagePreprocessingConfig, ImagePreprocessingMetadata};
+use image::{DynamicImage, GrayImage, ImageEncoder, Luma, imageops};
+use std::borrow::Cow;
+use std::io::Cursor;
+
+/// Largest skew angle (degrees) searched by deskewing.
+const MAX_SKEW_DEGREES: f64 = 15.0;
+
+/// Skew angles below this (degrees) are left uncorrected.
+const MIN_SKEW_DEGREES: f64 = 0.1;
+
+/// Upper bound on the number of ink pixels sampled for skew detection.
+const MAX_SKEW_SAMPLES: usize = 200_000;
+
+/// Sauvola sensitivity `k` and dynamic range `R`.
+const SAUVOLA_K: f64 = 0.34;
+const SAUVOLA_R: f64 = 128.0;
+
+/// Offset subtracted from the local mean by adaptive thresholding.
+const ADAPTIVE_MEAN_OFFSET: f64 = 10.0;
+
+/// Binarization algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinarizationMethod {
+    /// Global threshold maximizing between-class variance.
+    Otsu,
+    /// Local threshold from window mean and standard deviation.
+    Sauvola,
+    /// Local threshold from window mean minus a constant.
+    Adaptive,
+}
+
+impl BinarizationMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinarizationMethod::Otsu => "otsu",
+            BinarizationMethod::Sauvola => "sauvola",
+            BinarizationMethod::Adaptive => "adaptive",
+        }
+    }
+}
+
+impl std::str::FromStr for BinarizationMethod {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "otsu" => Ok(BinarizationMethod::Otsu),
+            "sauvola" => Ok(BinarizationMethod::Sauvola),
+            "adaptive" => Ok(BinarizationMethod::Adaptive),
+            other => Err(KreuzbergError::validation(format!(
+                "Invalid binarization method '{}'. Valid options are: otsu, adaptive, sauvola",
+                other
+            ))),
+        }
+    }
+}
+
+/// Result of [`preprocess_for_ocr`].
+pub struct EnhanceResult {
+    /// Grayscale image ready for OCR, binarized unless `binarize` is off
+    pub image: GrayImage,
+    /// Record of the applied steps
+    pub metadata: ImagePreprocessingMetadata,
+}
+
+/// Run the configured preprocessing steps on an image.
+///
+/// # Arguments
+/// * `image` - Decoded source image
+/// * `config` - Preprocessing configuration
+/// * `source_dpi` - Resolution of `image` if known; otherwise it is assumed to
+///   already match `config.target_dpi` (no resampling happens at this stage)
+pub fn preprocess_for_ocr(
+    image: &DynamicImage,
+    config: &ImagePreprocessingConfig,
+    source_dpi: Option<f64>,
+) -> Result<EnhanceResult> {
+    let method: BinarizationMethod = config.binarization_method.parse()?;
+
+    let mut gray = image.to_luma8();
+    let original_dimensions = (gray.width() as usize, gray.height() as usize);
+    let mut operations = Vec::new();
+
+    if config.invert_colors {
+        imageops::invert(&mut gray);
+        operations.push("invert".to_string());
+    }
+
+    if config.contrast_enhance && stretch_contrast(&mut gray) {
+        operations.push("contrast_enhance".to_string());
+    }
+
+    if config.denoise {
+        gray = median_filter_3x3(&gray);
+        operations.push("denoise".to_string());
+    }
+
+    let mut rotation_degrees = 0;
+    if config.auto_rotate {
+        let (analysis, _) = binarize(&gray, BinarizationMethod::Otsu);
+        rotation_degrees = detect_orientation(&analysis);
+        if rotation_degrees != 0 {
+            gray = rotate_quadrant(&gray, rotation_degrees);
+            operations.push("auto_rotate".to_string());
+        }
+    }
+
+    let mut deskew_angle = None;
+    if config.deskew {
+        let (analysis, _) = binarize(&gray, BinarizationMethod::Otsu);
+        if let Some(angle) = detect_skew(&analysis)
+            && angle.abs() >= MIN_SKEW_DEGREES
+        {
+            gray = deskew(&gray, angle);
+            deskew_angle = Some(angle);
+            operations.push("deskew".to_string());
+        }
+    }
+
+    let (image, threshold, binarization_method) = if config.binarize {
+        let (binary, threshold) = binarize(&gray, method);
+        operations.push("binarize".to_string());
+        (binary, threshold, Some(method.as_str().to_string()))
+    } else {
+        (gray, None, None)
+    };
+
+    let dpi = source_dpi.unwrap_or_else(|| f64::from(config.target_dpi));
+    let new_dimensions = (image.width() as usize, image.height() as usize);
+
+    let metadata = ImagePreprocessingMetadata {
+        original_dimensions,
+        original_dpi: (dpi, dpi),
+        target_dpi: config.target_dpi,
+        scale_factor: 1.0,
+        auto_adjusted: false,
+        final_dpi: dpi.round() as i32,
+        new_dimensions: (new_dimensions != original_dimensions).then_some(new_dimensions),
+        resample_method: "NONE".to_string(),
+        dimension_clamped: false,
+        calculated_dpi: None,
+        skipped_resize: true,
+        resize_error: None,
+        operations,
+        rotation_degrees,
+        deskew_angle,
+        binarization_method,
+        binarization_threshold: threshold,
+    };
+
+    Ok(EnhanceResult { image, metadata })
+}
+
+/// Decode encoded image bytes, preprocess them and re-encode as PNG.
+///
+/// This is the entry point used in front of every OCR backend.
+pub fn preprocess_image_bytes(
+    bytes: &[u8],
+    config: &ImagePreprocessingConfig,
+) -> Result<(Vec<u8>, ImagePreprocessingMetadata)> {
+    let image = image::load_from_memory(bytes)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to decode image for preprocessing: {}", e)))?;
+
+    let result = preprocess_for_ocr(&image, config, None)?;
+    Ok((encode_png(&result.image)?, result.metadata))
+}
+
+/// Apply the preprocessing configured on `ocr_config` to encoded image bytes.
+///
+/// Returns the input unchanged (and no metadata) when preprocessing is not
+/// configured. Images the `image` crate cannot decode (JPEG 2000, JBIG2, ...)
+/// are passed through with a warning so the OCR backend can still try them.
+///
+/// Orientation is corrected by the OCR pipeline beforehand (see
+/// [`crate::ocr::orientation`]), so `auto_rotate` is not applied here.
+pub fn apply_ocr_preprocessing<'a>(
+    bytes: &'a [u8],
+    ocr_config: &OcrConfig,
+) -> Result<(Cow<'a, [u8]>, Option<ImagePreprocessingMetadata>)> {
+    let Some(config) = ocr_preprocessing_config(ocr_config) else {
+        return Ok((Cow::Borrowed(bytes), None));
+    };
+    config.binarization_method.parse::<BinarizationMethod>()?;
+
+    match preprocess_image_bytes(bytes, &config) {
+        Ok((processed, metadata)) => Ok((Cow::Owned(processed), Some(metadata))),
+        Err(e) => {
+            tracing::warn!("Image preprocessing skipped, passing original image to OCR: {}", e);
+            Ok((Cow::Borrowed(bytes), None))
+        }
+    }
+}
+
+/// Preprocessing configured on `ocr_config` minus the orientation step, which the
+/// OCR pipeline runs itself with the backend's detector.
+pub fn ocr_preprocessing_config(ocr_config: &OcrConfig) -> Option<ImagePreprocessingConfig> {
+    ocr_config
+        .effective_preprocessing()
+        .map(|config| ImagePreprocessingConfig {
+            auto_rotate: false,
+            ..config.clone()
+        })
+}
+
+/// Encode a grayscale image as PNG.
+pub fn encode_png(image: &GrayImage) -> Result<Vec<u8>> {
+    let mut buffer = Cursor::new(Vec::new());
+    image::codecs::png::PngEncoder::new(&mut buffer)
+        .write_image(
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            image::ColorType::L8.into(),
+        )
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to encode preprocessed image: {}", e)))?;
+    Ok(buffer.into_inner())
+}
+
+/// Binarize an image, returning the result and the global threshold if one was used.
+///
+/// Ink becomes 0 (black), background 255 (white).
+pub fn binarize(image: &GrayImage, method: BinarizationMethod) -> (GrayImage, Option<u8>) {
+    match method {
+        BinarizationMethod::Otsu => {
+            let threshold = otsu_threshold(image);
+            let binary = GrayImage::from_fn(image.width(), image.height(), |x, y| {
+                Luma([if image.get_pixel(x, y)[0] > threshold { 255 } else { 0 }])
+            });
+            (binary, Some(threshold))
+        }
+        BinarizationMethod::Sauvola => (
+            local_threshold(image, |mean, std_dev| {
+                mean * (1.0 + SAUVOLA_K * (std_dev / SAUVOLA_R - 1.0))
+            }),
+            None,
+        ),
+        BinarizationMethod::Adaptive => (local_threshold(image, |mean, _| mean - ADAPTIVE_MEAN_OFFSET), None),
+    }
+}
+
+/// Otsu's global threshold: pixels `<= threshold` are ink.
+pub fn otsu_threshold(image: &GrayImage) -> u8 {
+    let histogram = histogram(image);
+    let total = image.width() as f64 * image.height() as f64;
+    if total == 0.0 {
+        return 127;
+    }
+
+    let weighted_total: f64 = histogram.iter().enumerate().map(|(i, &c)| i as f64 * c as f64).sum();
+
+    let mut background_weight = 0.0;
+    let mut background_sum = 0.0;
+    let mut best_variance = -1.0;
+    let mut best_threshold = 0u8;
+
+    for (level, &count) in histogram.iter().enumerate() {
+        background_weight += count as f64;
+        if background_weight == 0.0 {
+            continue;
+        }
+        let foreground_weight = total - background_weight;
+        if foreground_weight == 0.0 {
+            break;
+        }
+
+        background_sum += level as f64 * count as f64;
+        let background_mean = background_sum / background_weight;
+        let foreground_mean = (weighted_total - background_sum) / foreground_weight;
+        let variance = background_weight * foreground_weight * (background_mean - foreground_mean).powi(2);
+
+        if variance > best_variance {
+            best_variance = variance;
+            best_threshold = level as u8;
+        }
+    }
+
+    best_threshold
+}
+
+fn histogram(image: &GrayImage) -> [u64; 256] {
+    let mut histogram = [0u64; 256];
+    for pixel in image.as_raw() {
+        histogram[*pixel as usize] += 1;
+    }
+    histogram
+}
+
+/// Window-based thresholding using integral images.
+///
+/// `threshold(mean, std_dev)` gives the local threshold; pixels at or below it are ink.
+fn local_threshold(image: &GrayImage, threshold: impl Fn(f64, f64) -> f64) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let (w, h) = (width as usize, height as usize);
+    let radius = local_window_size(width, height) / 2;
+
+    // Integral images with a zero row/column on top/left ~keep
+    let stride = w + 1;
+    let mut sum = vec![0u64; stride * (h + 1)];
+    let mut sum_sq = vec![0u64; stride * (h + 1)];
+    let raw = image.as_raw();
+    for y in 0..h {
+        let mut row_sum = 0u64;
+        let mut row_sum_sq = 0u64;
+        for x in 0..w {
+            let v = u64::from(raw[y * w + x]);
+            row_sum += v;
+            row_sum_sq += v * v;
+            sum[(y + 1) * stride + x + 1] = sum[y * stride + x + 1] + row_sum;
+            sum_sq[(y + 1) * stride + x + 1] = sum_sq[y * stride + x + 1] + row_sum_sq;
+        }
+    }
+
+    let area_sum = |table: &[u64], x0: usize, y0: usize, x1: usize, y1: usize| -> f64 {
+        (table[y1 * stride + x1] + table[y0 * stride + x0] - table[y0 * stride + x1] - table[y1 * stride + x0]) as f64
+    };
+
+    GrayImage::from_fn(width, height, |x, y| {
+        let (x, y) = (x as usize, y as usize);
+        let x0 = x.saturating_sub(radius);
+        let y0 = y.saturating_sub(radius);
+        let x1 = (x + radius + 1).min(w);
+        let y1 = (y + radius + 1).min(h);
+        let count = ((x1 - x0) * (y1 - y0)) as f64;
+
+        let mean = area_sum(&sum, x0, y0, x1, y1) / count;
+        let variance = (area_sum(&sum_sq, x0, y0, x1, y1) / count - mean * mean).max(0.0);
+        let value = f64::from(raw[y * w + x]);
+
+        Luma([if value > threshold(mean, variance.sqrt()) {
+            255
+        } else {
+            0
+        }])
+    })
+}
+
+/// Odd window size for local thresholding, roughly two text lines at 300 DPI.
+fn local_window_size(width: u32, height: u32) -> usize {
+    let size = (width.min(height) as usize / 40).clamp(15, 75);
+    size | 1
+}
+
+/// Linearly stretch the 1st–99th percentile range to the full 0–255 range.
+///
+/// Returns `false` when the image already spans the full range or is flat.
+fn stretch_contrast(image: &mut GrayImage) -> bool {
+    let histogram = histogram(image);
+    let total: u64 = histogram.iter().sum();
+    let cutoff = total / 100;
+
+    let percentile = |from_top: bool| -> u8 {
+        let mut seen = 0u64;
+        let levels: Box<dyn Iterator<Item = usize>> = if from_top {
+            Box::new((0..256).rev())
+        } else {
+            Box::new(0..256)
+        };
+        for level in levels {
+            seen += histogram[level];
+            if seen > cutoff {
+                return level as u8;
+            }
+        }
+        if from_top { 0 } else { 255 }
+    };
+
+    let low = percentile(false);
+    let high = percentile(true);
+    if high <= low || (low == 0 && high == 255) {
+        return false;
+    }
+
+    let scale = 255.0 / f64::from(high - low);
+    for pixel in image.pixels_mut() {
+        let stretched = (f64::from(pixel[0].saturating_sub(low)) * scale).round();
+        pixel[0] = stretched.min(255.0) as u8;
+    }
+    true
+}
+
+/// 3×3 median filter; removes salt-and-pepper noise while keeping stroke edges.
+fn median_filter_3x3(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    if width < 3 || height < 3 {
+        return image.clone();
+    }
+
+    GrayImage::from_fn(width, height, |x, y| {
+        let mut window = [0u8; 9];
+        let mut i = 0;
+        for dy in -1i64..=1 {
+            for dx in -1i64..=1 {
+                let sx = (i64::from(x) + dx).clamp(0, i64::from(width) - 1) as u32;
+                let sy = (i64::from(y) + dy).clamp(0, i64::from(height) - 1) as u32;
+                window[i] = image.get_pixel(sx, sy)[0];
+                i += 1;
+            }
+        }
+        let (_, median, _) = window.select_nth_unstable(4);
+        Luma([*median])
+    })
+}
+
+/// Rotate clockwise by a multiple of 90 degrees.
+fn rotate_quadrant(image: &GrayImage, degrees: i32) -> GrayImage {
+    match degrees.rem_euclid(360) {
+        90 => imageops::rotate90(image),
+        180 => imageops::rotate180(image),
+        270 => imageops::rotate270(image),
+        _ => image.clone(),
+    }
+}
+
+/// Detect the clockwise rotation (0, 90, 180 or 270) that makes text upright.
+///
+/// Horizontal text lines produce a strongly modulated row profile, so the
+/// axis with the stronger modulation gives the line direction. Upside-down
+/// text is recognized because in Latin-like scripts ascenders are far more
+/// common than descenders: upright lines carry more ink above their x-height
+/// band than below it.
+pub fn detect_orientation(binary: &GrayImage) -> i32 {
+    let rows = ink_profile(binary, true);
+    let columns = ink_profile(binary, false);
+
+    let horizontal = profile_modulation(&rows);
+    let vertical = profile_modulation(&columns);
+
+    if vertical > horizontal * 1.5 {
+        // Lines run vertically; rotate clockwise and decide between 90 and 270 ~keep
+        let rotated = imageops::rotate90(binary);
+        if is_upside_down(&ink_profile(&rotated, true)) {
+            270
+        } else {
+            90
+        }
+    } else if is_upside_down(&rows) {
+        180
+    } else {
+        0
+    }
+}
+
+/// Count of ink pixels per row (`by_row`) or per column.
+fn ink_profile(binary: &GrayImage, by_row: bool) -> Vec<u32> {
+    let (width, height) = binary.dimensions();
+    let mut profile = vec![0u32; if by_row { height } else { width } as usize];
+    for (x, y, pixel) in binary.enumerate_pixels() {
+        if pixel[0] == 0 {
+            profile[if by_row { y } else { x } as usize] += 1;
+        }
+    }
+    profile
+}
+
+/// Mean squared difference between adjacent profile bins, normalized by the
+/// squared mean so that the measure is independent of ink density.
+fn profile_modulation(profile: &[u32]) -> f64 {
+    if profile.len() < 2 {
+        return 0.0;
+    }
+    let mean = profile.iter().map(|&v| f64::from(v)).sum::<f64>() / profile.len() as f64;
+    if mean == 0.0 {
+        return 0.0;
+    }
+    let diff_sq: f64 = profile
+        .windows(2)
+        .map(|w| (f64::from(w[1]) - f64::from(w[0])).powi(2))
+        .sum();
+    diff_sq / (profile.len() - 1) as f64 / (mean * mean)
+}
+
+/// Compare ink above and below the x-height band of each text line.
+fn is_upside_down(row_profile: &[u32]) -> bool {
+    let max = row_profile.iter().copied().max().unwrap_or(0);
+    if max == 0 {
+        return false;
+    }
+    let min_line_ink = (max / 20).max(1);
+
+    let mut above = 0u64;
+    let mut below = 0u64;
+    let mut lines = 0;
+
+    let mut y = 0;
+    while y < row_profile.len() {
+        if row_profile[y] < min_line_ink {
+            y += 1;
+            continue;
+        }
+        let start = y;
+        while y < row_profile.len() && row_profile[y] >= min_line_ink {
+            y += 1;
+        }
+        let line = &row_profile[start..y];
+        if line.len() < 4 {
+            continue;
+        }
+
+        // x-height band: rows holding at least half of the line's peak ink ~keep
+        let peak = line.iter().copied().max().unwrap_or(0);
+        let core_start = line.iter().position(|&v| v * 2 >= peak).unwrap_or(0);
+        let core_end = line.iter().rposition(|&v| v * 2 >= peak).unwrap_or(line.len() - 1);
+
+        above += line[..core_start].iter().map(|&v| u64::from(v)).sum::<u64>();
+        below += line[core_end + 1..].iter().map(|&v| u64::from(v)).sum::<u64>();
+        lines += 1;
+    }
+
+    lines >= 2 && below as f64 > above as f64 * 1.5 && below > 0
+}
+
+/// Estimate the skew angle (degrees, positive when lines descend to the right).
+///
+/// Projection-profile search: ink pixels are projected along candidate
+/// angles and the angle giving the sharpest row histogram wins. Returns
+/// `None` when there is too little ink to decide.
+pub fn detect_skew(binary: &GrayImage) -> Option<f64> {
+    let (width, height) = binary.dimensions();
+    let ink = binary.as_raw().iter().filter(|&&v| v == 0).count();
+    if ink < 100 {
+        return None;
+    }
+
+    let step = ((ink as f64 / MAX_SKEW_SAMPLES as f64).sqrt().ceil() as u32).max(1);
+    let mut points = Vec::with_capacity(ink.min(MAX_SKEW_SAMPLES * 2));
+    for y in (0..height).step_by(step as usize) {
+        for x in (0..width).step_by(step as usize) {
+            if binary.get_pixel(x, y)[0] == 0 {
+                points.push((f64::from(x), f64::from(y)));
+            }
+        }
+    }
+    if points.len() < 100 {
+        return None;
+    }
+
+    // Projections stay within [0, 2 * width + height) for |angle| <= 15° ~keep
+    let offset = f64::from(width);
+    let bins = (2 * width + height) as usize / step as usize + 2;
+    let bin_size = f64::from(step);
+    let score = |degrees: f64| -> f64 {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let mut histogram = vec![0u32; bins];
+        for &(x, y) in &points {
+            let projected = y * cos - x * sin + offset;
+            let bin = (projected / bin_size) as usize;
+            if let Some(count) = histogram.get_mut(bin) {
+                *count += 1;
+            }
+        }
+        histogram.iter().map(|&c| f64::from(c) * f64::from(c)).sum()
+    };
+
+    let search = |from: f64, to: f64, increment: f64| -> f64 {
+        let mut best = (0.0, f64::MIN);
+        let mut angle = from;
+        while angle <= to + 1e-9 {
+            let s = score(angle);
+            if s > best.1 {
+                best = (angle, s);
+            }
+            angle += increment;
+        }
+        best.0
+    };
+
+    let coarse = search(-MAX_SKEW_DEGREES, MAX_SKEW_DEGREES, 0.5);
+    let fine = search(coarse - 0.5, coarse + 0.5, 0.05);
+    Some((fine * 100.0).round() / 100.0)
+}
+
+/// Rotate the image content by `-skew_degrees` around its center so that
+/// lines with the detected skew become horizontal. Uncovered areas are white.
+fn deskew(image: &GrayImage, skew_degrees: f64) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let (sin, cos) = skew_degrees.to_radians().sin_cos();
+    let cx = f64::from(width) / 2.0;
+    let cy = f64::from(height) / 2.0;
+
+    GrayImage::from_fn(width, height, |x, y| {
+        let u = f64::from(x) - cx;
+        let v = f64::from(y) - cy;
+        let sx = u * cos - v * sin + cx;
+        let sy = u * sin + v * cos + cy;
+        Luma([sample_bilinear(image, sx, sy)])
+    })
+}
+
+fn sample_bilinear(image: &GrayImage, x: f64, y: f64) -> u8 {
+    let (width, height) = image.dimensions();
+    if x < 0.0 || y < 0.0 || x > f64::from(width - 1) || y > f64::from(height - 1) {
+        return 255;
+    }
+
+    let x0 = x.floor() as u32;
+    let y0 = y.floor() as u32;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+    let fx = x - f64::from(x0);
+    let fy = y - f64::from(y0);
+
+    let p = |px: u32, py: u32| f64::from(image.get_pixel(px, py)[0]);
+    let top = p(x0, y0) * (1.0 - fx) + p(x1, y0) * fx;
+    let bottom = p(x0, y1) * (1.0 - fx) + p(x1, y1) * fx;
+    (top * (1.0 - fy) + bottom * fy).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Synthetic page: text lines made of x-height blocks with frequent
+    /// ascenders and rare descenders, optionally skewed by `skew_degrees`.
+    fn synthetic_page(width: u32, height: u32, skew_degrees: f64) -> GrayImage {
+        let tan = skew_degrees.to_radians().tan();
+        let mut page = GrayImage::from_pixel(width, height, Luma([255]));
+
+        for line in 0..(height / 40).saturating_sub(1) {
+            let baseline = 30 + line * 40;
+            for x in 20..width.saturating_sub(20) {
+                // Vary glyph positions per line so columns do not line up ~keep
+                let word_gap = ((x + line * 13) / 30) % 5 == 4;
+                if word_gap {
+                    continue;
+                }
+                let shift = (f64::from(x) * tan).round() as i64;
+                let glyph = (x + line * 7) % 9;
+                let top = if glyph < 2 { 18 } else { 10 };
+                let bottom = if glyph == 5 { -5 } else { 0 };
+                for dy in bottom..top {
+                    let y = i64::from(baseline) - i64::from(dy) + shift;
+                    if (0..i64::from(height)).contains(&y) {
+                        page.put_pixel(x, y as u32, Luma([20]));
+                    }
+                }
+            }
+        }
+        page
+    }
+
+    fn config_only(step: impl FnOnce(&mut ImagePreprocessingConfig)) -> ImagePreprocessingConfig {
+        let mut config = ImagePreprocessingConfig::default();
+        step(&mut config);
+        config
+    }
+
+    #[test]
+    fn test_otsu_threshold_separates_bimodal_image() {
+        let image = GrayImage::from_fn(100, 10, |x, _| Luma([if x < 30 { 40 } else { 220 }]));
+        let threshold = otsu_threshold(&image);
+        assert!((40..220).contains(&threshold));
+
+        let (binary, reported) = binarize(&image, BinarizationMethod::Otsu);
+        assert_eq!(reported, Some(threshold));
+        assert_eq!(binary.get_pixel(10, 5)[0], 0);
+        assert_eq!(binary.get_pixel(80, 5)[0], 255);
+    }
+
+    #[test]
+    fn test_local_binarization_handles_uneven_background() {
+        // Background fades from light to mid gray; text is always darker than its surroundings ~keep
+        let image = GrayImage::from_fn(200, 100, |x, y| {
+            let background = 250 - (x as u8 / 2);
+            let is_text = (40..60).contains(&y) && x % 20 < 6;
+            Luma([if is_text { background - 150 } else { background }])
+        });
+
+        for method in [BinarizationMethod::Sauvola, BinarizationMethod::Adaptive] {
+            let (binary, threshold) = binarize(&image, method);
+            assert!(threshold.is_none());
+            assert!(binary.pixels().all(|p| p[0] == 0 || p[0] == 255));
+            assert_eq!(binary.get_pixel(182, 50)[0], 0, "{:?} lost text on dark side", method);
+            assert_eq!(binary.get_pixel(190, 10)[0], 255, "{:?} blackened background", method);
+            assert_eq!(binary.get_pixel(2, 50)[0], 0, "{:?} lost text on light side", method);
+        }
+    }
+
+    #[test]
+    fn test_median_filter_removes_salt_noise() {
+        let mut image = GrayImage::from_pixel(20, 20, Luma([255]));
+        image.put_pixel(10, 10, Luma([0]));
+        let filtered = median_filter_3x3(&image);
+        assert_eq!(filtered.get_pixel(10, 10)[0], 255);
+    }
+
+    #[test]
+    fn test_stretch_contrast_expands_range() {
+        let mut image = GrayImage::from_fn(100, 1, |x, _| Luma([100 + (x as u8 / 2)]));
+        assert!(stretch_contrast(&mut image));
+        assert!(image.get_pixel(0, 0)[0] < 10);
+        assert!(image.get_pixel(99, 0)[0] > 245);
+
+        let mut full = GrayImage::from_fn(100, 1, |x, _| Luma([if x < 50 { 0 } else { 255 }]));
+        assert!(!stretch_contrast(&mut full));
+    }
+
+    #[test]
+    fn test_detect_skew() {
+        let page = synthetic_page(600, 400, 3.0);
+        let (binary, _) = binarize(&page, BinarizationMethod::Otsu);
+        let angle = detect_skew(&binary).unwrap();
+        assert!((angle - 3.0).abs() < 0.3, "detected {}", angle);
+
+        let straight = synthetic_page(600, 400, 0.0);
+        let (binary, _) = binarize(&straight, BinarizationMethod::Otsu);
+        assert!(detect_skew(&binary).unwrap().abs() < MIN_SKEW_DEGREES);
+    }
+
+    #[test]
+    fn test_deskew_straightens_lines() {
+        let page = synthetic_page(600, 400, -4.0);
+        let config = config_only(|c| c.deskew = true);
+        let result = preprocess_for_ocr(&DynamicImage::ImageLuma8(page), &config, None).unwrap();
+
+        let angle = result.metadata.deskew_angle.unwrap();
+        assert!((angle + 4.0).abs() < 0.3, "detected {}", angle);
+        assert!(result.metadata.operations.contains(&"deskew".to_string()));
+        assert!(detect_skew(&result.image).unwrap().abs() < 0.5);
+    }
+
+    #[test]
+    fn test_detect_orientation() {
+        let page = synthetic_page(400, 400, 0.0);
+        let (binary, _) = binarize(&page, BinarizationMethod::Otsu);
+
+        assert_eq!(detect_orientation(&binary), 0);
+        assert_eq!(detect_orientation(&imageops::rotate180(&binary)), 180);
+        assert_eq!(detect_orientation(&imageops::rotate90(&binary)), 270);
+        assert_eq!(detect_orientation(&imageops::rotate270(&binary)), 90);
+    }
+
+    #[test]
+    fn test_auto_rotate_restores_upright_page() {
+        let page = synthetic_page(400, 400, 0.0);
+        let config = config_only(|c| c.auto_rotate = true);
+        let rotated = DynamicImage::ImageLuma8(imageops::rotate180(&page));
+        let result = preprocess_for_ocr(&rotated, &config, None).unwrap();
+
+        assert_eq!(result.metadata.rotation_degrees, 180);
+        assert_eq!(detect_orientation(&result.image), 0);
+    }
+
+    #[test]
+    fn test_preprocess_records_operations() {
+        let page = synthetic_page(300, 200, 0.0);
+        let config = config_only(|c| {
+            c.invert_colors = true;
+            c.denoise = true;
+            c.binarization_method = "Sauvola".to_string();
+        });
+        let result = preprocess_for_ocr(&DynamicImage::ImageLuma8(page), &config, Some(150.0)).unwrap();
+
+        assert_eq!(result.metadata.operations, vec!["invert", "denoise", "binarize"]);
+        assert_eq!(result.metadata.binarization_method.as_deref(), Some("sauvola"));
+        assert_eq!(result.metadata.binarization_threshold, None);
+        assert_eq!(result.metadata.original_dpi, (150.0, 150.0));
+        assert_eq!(result.metadata.rotation_degrees, 0);
+        assert!(result.metadata.new_dimensions.is_none());
+    }
+
+    #[test]
+    fn test_default_config_only_binarizes() {
+        let page = synthetic_page(300, 200, 3.0);
+        let image = DynamicImage::ImageLuma8(page.clone());
+
+        let result = preprocess_for_ocr(&image, &ImagePreprocessingConfig::default(), None).unwrap();
+        assert_eq!(result.metadata.operations, vec!["binarize"]);
+        assert!(result.metadata.deskew_angle.is_none());
+
+        let config = config_only(|c| c.binarize = false);
+        let result = preprocess_for_ocr(&image, &config, None).unwrap();
+        assert!(result.metadata.operations.is_empty());
+        assert!(result.metadata.binarization_method.is_none());
+        assert_eq!(result.image, page);
+    }
+
+    #[test]
+    fn test_preprocess_image_bytes_round_trip() {
+        let page = DynamicImage::ImageLuma8(synthetic_page(300, 200, 0.0));
+        let mut png = Cursor::new(Vec::new());
+        page.write_to(&mut png, image::ImageFormat::Png).unwrap();
+
+        let (bytes, metadata) = preprocess_image_bytes(png.get_ref(), &ImagePreprocessingConfig::default()).unwrap();
+        let decoded = image::load_from_memory(&bytes).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (300, 200));
+        assert_eq!(metadata.original_dimensions, (300, 200));
+        assert!(metadata.binarization_threshold.is_some());
+    }
+
+    #[test]
+    fn test_apply_ocr_preprocessing() {
+        let page = DynamicImage::ImageLuma8(synthetic_page(120, 80, 0.0));
+        let mut png = Cursor::new(Vec::new());
+        page.write_to(&mut png, image::ImageFormat::Png).unwrap();
+        let png = png.into_inner();
+
+        let (bytes, metadata) = apply_ocr_preprocessing(&png, &OcrConfig::default()).unwrap();
+        assert!(matches!(bytes, Cow::Borrowed(_)));
+        assert!(metadata.is_none());
+
+        let config = OcrConfig {
+            preprocessing: Some(ImagePreprocessingConfig::default()),
+            ..Default::default()
+        };
+        let (bytes, metadata) = apply_ocr_preprocessing(&png, &config).unwrap();
+        assert!(matches!(bytes, Cow::Owned(_)));
+        assert!(metadata.is_some());
+
+        let (bytes, metadata) = apply_ocr_preprocessing(b"undecodable", &config).unwrap();
+        assert_eq!(bytes.as_ref(), b"undecodable");
+        assert!(metadata.is_none());
+    }
+
+    #[test]
+    fn test_invalid_binarization_method() {
+        let config = config_only(|c| c.binarization_method = "niblack".to_string());
+        let image = DynamicImage::ImageLuma8(GrayImage::new(10, 10));
+        assert!(preprocess_for_ocr(&image, &config, None).is_err());
+        assert!(preprocess_image_bytes(b"not an image", &ImagePreprocessingConfig::default()).is_err());
+    }
+}
@@ -1,6 +1,10 @@
 pub mod dpi;
+pub mod enhance;
 pub mod preprocessing;
 pub mod resize;
 
 pub use dpi::calculate_optimal_dpi;
+pub use enhance::{
+    BinarizationMethod, EnhanceResult, apply_ocr_preprocessing, preprocess_for_ocr, preprocess_image_bytes,
+};
 pub use preprocessing::{NormalizeResult, normalize_image_dpi};
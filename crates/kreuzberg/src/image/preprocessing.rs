@@ -185,6 +185,11 @@ fn create_skip_result(
             calculated_dpi,
             skipped_resize: true,
             resize_error: None,
+            operations: Vec::new(),
+            rotation_degrees: 0,
+            deskew_angle: None,
+            binarization_method: None,
+            binarization_threshold: None,
         },
     }
 }
@@ -233,6 +238,11 @@ fn perform_resize(
         calculated_dpi,
         skipped_resize: false,
         resize_error: None,
+        operations: Vec::new(),
+        rotation_degrees: 0,
+        deskew_angle: None,
+        binarization_method: None,
+        binarization_threshold: None,
     };
 
     Ok(NormalizeResult {
@@ -90,6 +90,8 @@ pub mod pdf;
 pub use error::{KreuzbergError, Result};
 pub use types::*;
 
+#[cfg(feature = "tokio-runtime")]
+pub use core::extractor::{PageStream, extract_bytes_pages, extract_file_pages};
 #[cfg(feature = "tokio-runtime")]
 pub use core::extractor::{batch_extract_bytes, batch_extract_file};
 pub use core::extractor::{extract_bytes, extract_file};
@@ -100,8 +102,9 @@ pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync};
 pub use core::extractor::{batch_extract_file_sync, extract_file_sync};
 
 pub use core::config::{
-    ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig, TokenReductionConfig,
+    CacheBackendType, CacheConfig, ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig,
+    ImageExtractionConfig, LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PageSelection,
+    PostProcessorConfig, TokenReductionConfig,
 };
 
 #[cfg(feature = "api")]
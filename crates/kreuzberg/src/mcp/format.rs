@@ -330,6 +330,8 @@ mod tests {
                 page_number: 1,
                 markdown: "| Col1 | Col2 |\n|------|------|\n| A    | B    |".to_string(),
                 bounding_box: None,
+                end_page_number: None,
+                spans: vec![],
             }],
             detected_languages: None,
             chunks: None,
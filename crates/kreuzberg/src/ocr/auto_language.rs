@@ -0,0 +1,318 @@
+//! Automatic OCR language selection (`OcrConfig.language = "auto"`).
+//!
+//! Every page is first classified by script. Backends implementing
+//! [`OcrBackend::detect_script`] (Tesseract, through OSD) report the dominant
+//! script of the page image directly. Scripts that map to a single language
+//! (Han, Hangul, Greek, Thai, ...) decide the language on their own.
+//!
+//! Scripts shared by many languages (Latin, Cyrillic, Arabic, Devanagari), and
+//! pages whose script is unknown, get a fast first recognition pass with the
+//! script's default language. With the `language-detection` feature its text is
+//! classified with whatlang, and the languages found make up the page language.
+//!
+//! Languages are kept as Tesseract codes and mapped to the recognizing backend by
+//! [`backend_language`]: Tesseract combines up to three installed languages
+//! (`eng+deu`), other backends get the first language they support (PaddleOCR
+//! picks its recognition model from it).
+
+use crate::Result;
+use crate::core::config::OcrConfig;
+use crate::plugins::{OcrBackend, OcrBackendType};
+
+/// Maximum number of languages combined for one Tesseract pass.
+const MAX_LANGUAGES: usize = 3;
+
+/// Language used when neither the script nor the text identifies one.
+const DEFAULT_LANGUAGE: &str = "eng";
+
+/// Script and languages detected on a page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedLanguages {
+    /// Dominant script as named by Tesseract OSD (`Latin`, `Cyrillic`, `Han`, ...)
+    pub script: Option<String>,
+    /// Tesseract language codes, most frequent first (never empty)
+    pub languages: Vec<String>,
+}
+
+/// Detect the script and languages of a page image.
+///
+/// Script detection failures are logged and treated as an unknown script;
+/// errors of the first recognition pass are returned.
+pub(crate) async fn detect_languages(
+    backend: &dyn OcrBackend,
+    image: &[u8],
+    config: &OcrConfig,
+) -> Result<DetectedLanguages> {
+    let script = match backend.detect_script(image, config).await {
+        Ok(script) => script,
+        Err(e) => {
+            tracing::warn!("Script detection failed, detecting the language from text: {}", e);
+            None
+        }
+    };
+
+    let default_language = script
+        .as_deref()
+        .and_then(script_language)
+        .unwrap_or(DEFAULT_LANGUAGE)
+        .to_string();
+    let mut languages = vec![default_language];
+
+    if script.as_deref().is_none_or(is_shared_script) {
+        let first_pass_config = OcrConfig {
+            language: backend_language(backend, &languages),
+            tesseract_config: config
+                .tesseract_config
+                .clone()
+                .map(|tesseract| crate::types::TesseractConfig {
+                    enable_table_detection: false,
+                    ..tesseract
+                }),
+            output_format: None,
+            element_config: None,
+            fallback: None,
+            table_detection: None,
+            ..config.clone()
+        };
+        let first_pass = backend.process_image(image, &first_pass_config).await?;
+        let detected = text_languages(&first_pass.content);
+        if !detected.is_empty() {
+            languages = detected;
+        }
+    }
+
+    tracing::debug!(?script, ?languages, "Detected OCR page languages");
+    Ok(DetectedLanguages { script, languages })
+}
+
+/// The language value to pass to `backend` for the detected languages.
+pub(crate) fn backend_language(backend: &dyn OcrBackend, languages: &[String]) -> String {
+    let supported: Vec<&str> = languages
+        .iter()
+        .map(String::as_str)
+        .filter(|language| backend.supports_language(language))
+        .collect();
+
+    match backend.backend_type() {
+        OcrBackendType::Tesseract if supported.is_empty() => DEFAULT_LANGUAGE.to_string(),
+        OcrBackendType::Tesseract => supported.into_iter().take(MAX_LANGUAGES).collect::<Vec<_>>().join("+"),
+        _ => supported
+            .first()
+            .copied()
+            .or(languages.first().map(String::as_str))
+            .unwrap_or(DEFAULT_LANGUAGE)
+            .to_string(),
+    }
+}
+
+/// Default Tesseract language of a Tesseract OSD script.
+fn script_language(script: &str) -> Option<&'static str> {
+    Some(match script {
+        "Latin" => "eng",
+        "Cyrillic" => "rus",
+        "Arabic" => "ara",
+        "Devanagari" => "hin",
+        "Han" | "HanS" => "chi_sim",
+        "HanT" => "chi_tra",
+        "Japanese" | "Hiragana" | "Katakana" => "jpn",
+        "Korean" | "Hangul" => "kor",
+        "Greek" => "ell",
+        "Hebrew" => "heb",
+        "Thai" => "tha",
+        "Tamil" => "tam",
+        "Telugu" => "tel",
+        "Georgian" => "kat",
+        "Armenian" => "hye",
+        "Bengali" => "ben",
+        "Gujarati" => "guj",
+        "Gurmukhi" => "pan",
+        "Kannada" => "kan",
+        "Malayalam" => "mal",
+        "Oriya" => "ori",
+        "Sinhala" => "sin",
+        "Khmer" => "khm",
+        "Lao" => "lao",
+        "Myanmar" => "mya",
+        "Ethiopic" => "amh",
+        "Tibetan" => "bod",
+        "Fraktur" => "deu",
+        _ => return None,
+    })
+}
+
+/// Scripts written in many languages, whose language is detected from text.
+fn is_shared_script(script: &str) -> bool {
+    matches!(script, "Latin" | "Cyrillic" | "Arabic" | "Devanagari")
+}
+
+/// Languages of first-pass OCR text as Tesseract codes, most frequent first.
+#[cfg(feature = "language-detection")]
+fn text_languages(text: &str) -> Vec<String> {
+    let config = crate::core::config::LanguageDetectionConfig {
+        enabled: true,
+        min_confidence: 0.5,
+        detect_multiple: true,
+    };
+    match crate::language_detection::detect_languages(text, &config) {
+        Ok(languages) => languages
+            .unwrap_or_default()
+            .into_iter()
+            .map(|language| tesseract_code(&language).to_string())
+            .collect(),
+        Err(e) => {
+            tracing::warn!("Language detection of OCR text failed: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(not(feature = "language-detection"))]
+fn text_languages(_text: &str) -> Vec<String> {
+    Vec::new()
+}
+
+/// Tesseract code of an ISO 639-3 code from language detection.
+#[cfg_attr(not(feature = "language-detection"), allow(dead_code))]
+fn tesseract_code(iso639_3: &str) -> &str {
+    match iso639_3 {
+        "cmn" => "chi_sim",
+        "nob" => "nor",
+        "pes" => "fas",
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::Plugin;
+    use crate::types::ExtractionResult;
+    use async_trait::async_trait;
+
+    /// Backend with a fixed script, recognizing every image as `text`.
+    struct ScriptOcrBackend {
+        backend_type: OcrBackendType,
+        script: Option<&'static str>,
+        text: &'static str,
+        languages: &'static [&'static str],
+    }
+
+    impl Plugin for ScriptOcrBackend {
+        fn name(&self) -> &str {
+            "script-test"
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl OcrBackend for ScriptOcrBackend {
+        async fn process_image(&self, _image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+            Ok(ExtractionResult {
+                content: self.text.to_string(),
+                ..Default::default()
+            })
+        }
+
+        async fn detect_script(&self, _image_bytes: &[u8], _config: &OcrConfig) -> Result<Option<String>> {
+            Ok(self.script.map(str::to_string))
+        }
+
+        fn supports_language(&self, lang: &str) -> bool {
+            self.languages.contains(&lang)
+        }
+
+        fn backend_type(&self) -> OcrBackendType {
+            self.backend_type
+        }
+    }
+
+    fn tesseract(script: Option<&'static str>, text: &'static str) -> ScriptOcrBackend {
+        ScriptOcrBackend {
+            backend_type: OcrBackendType::Tesseract,
+            script,
+            text,
+            languages: &["eng", "deu", "fra", "rus", "chi_sim"],
+        }
+    }
+
+    fn languages(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|code| code.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_single_language_script_skips_first_pass() {
+        let backend = tesseract(Some("Han"), "this text would be detected as English");
+        let detected = detect_languages(&backend, b"image", &OcrConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(detected.script.as_deref(), Some("Han"));
+        assert_eq!(detected.languages, languages(&["chi_sim"]));
+    }
+
+    #[cfg(feature = "language-detection")]
+    #[tokio::test]
+    async fn test_shared_script_detects_language_from_text() {
+        let backend = tesseract(
+            Some("Latin"),
+            "Die Rechnung wurde am Montag verschickt und muss innerhalb von vierzehn Tagen bezahlt werden. \
+             Bei Fragen wenden Sie sich bitte an unsere Buchhaltung.",
+        );
+        let detected = detect_languages(&backend, b"image", &OcrConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(detected.languages.first().map(String::as_str), Some("deu"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_script_without_text_uses_default() {
+        let backend = tesseract(None, "");
+        let detected = detect_languages(&backend, b"image", &OcrConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(detected.script, None);
+        assert_eq!(detected.languages, languages(&["eng"]));
+    }
+
+    #[test]
+    fn test_backend_language_combines_installed_tesseract_languages() {
+        let backend = tesseract(None, "");
+        assert_eq!(
+            backend_language(&backend, &languages(&["eng", "ita", "deu", "fra", "rus"])),
+            "eng+deu+fra"
+        );
+        assert_eq!(backend_language(&backend, &languages(&["ita"])), "eng");
+    }
+
+    #[test]
+    fn test_backend_language_picks_first_supported_for_other_backends() {
+        let backend = ScriptOcrBackend {
+            backend_type: OcrBackendType::PaddleOCR,
+            script: None,
+            text: "",
+            languages: &["deu", "chi_sim"],
+        };
+        assert_eq!(backend_language(&backend, &languages(&["eng", "deu"])), "deu");
+        assert_eq!(backend_language(&backend, &languages(&["kor"])), "kor");
+    }
+
+    #[test]
+    fn test_script_language() {
+        assert_eq!(script_language("Latin"), Some("eng"));
+        assert_eq!(script_language("HanT"), Some("chi_tra"));
+        assert_eq!(script_language("Hangul"), Some("kor"));
+        assert_eq!(script_language("Unknown"), None);
+        assert_eq!(tesseract_code("cmn"), "chi_sim");
+        assert_eq!(tesseract_code("deu"), "deu");
+    }
+}
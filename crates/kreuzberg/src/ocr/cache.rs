@@ -1,30 +1,63 @@
+//! OCR result cache.
+//!
+//! Entries live in the `ocr` namespace of a [`CacheBackend`], like every other
+//! Kreuzberg cache. By default that is the process-wide filesystem backend at
+//! the cache root (`KREUZBERG_CACHE_DIR` or `.kreuzberg`), i.e. `<root>/ocr`.
+
 use super::error::OcrError;
 use super::utils::compute_hash;
+use crate::cache::{CacheBackend, FilesystemBackend, GenericCache, OCR_CACHE_TYPE, shared_backend};
+use crate::core::config::CacheConfig;
 use crate::types::OcrExtractionResult;
-use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 pub struct OcrCache {
-    cache_dir: PathBuf,
+    inner: GenericCache,
 }
 
 impl OcrCache {
+    /// OCR cache in `cache_dir`, or in the `ocr` namespace of the default cache
+    /// root when `None`.
+    ///
+    /// An explicit directory holds the entries directly (`<cache_dir>/<key>.msgpack`).
     pub fn new(cache_dir: Option<PathBuf>) -> Result<Self, OcrError> {
-        let cache_dir = cache_dir.unwrap_or_else(|| {
-            let mut path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-            path.push(".kreuzberg");
-            path.push("ocr");
-            path
-        });
-
-        fs::create_dir_all(&cache_dir)
+        let Some(cache_dir) = cache_dir else {
+            return Self::from_config(&CacheConfig::default());
+        };
+
+        // The directory is the namespace below its parent, so the layout is unchanged ~keep
+        let (root, namespace) = match (cache_dir.parent(), cache_dir.file_name()) {
+            (Some(parent), Some(name)) => (parent.to_path_buf(), name.to_string_lossy().to_string()),
+            _ => (cache_dir.clone(), OCR_CACHE_TYPE.to_string()),
+        };
+        let defaults = CacheConfig::default();
+        let backend = FilesystemBackend::new(
+            root,
+            defaults.max_age_days,
+            defaults.max_size_mb,
+            defaults.min_free_space_mb,
+        )
+        .map_err(cache_error)?;
+        std::fs::create_dir_all(&cache_dir)
             .map_err(|e| OcrError::CacheError(format!("Failed to create cache directory: {}", e)))?;
 
-        Ok(Self { cache_dir })
+        Ok(Self {
+            inner: GenericCache::with_backend(namespace, Arc::new(backend)),
+        })
     }
 
-    fn get_cache_path(&self, cache_key: &str) -> PathBuf {
-        self.cache_dir.join(format!("{}.msgpack", cache_key))
+    /// OCR cache in the `ocr` namespace of the store configured by `cache_config`.
+    pub fn from_config(cache_config: &CacheConfig) -> Result<Self, OcrError> {
+        let backend = shared_backend(cache_config).map_err(cache_error)?;
+        Ok(Self::with_backend(backend))
+    }
+
+    /// OCR cache in the `ocr` namespace of `backend`.
+    pub fn with_backend(backend: Arc<dyn CacheBackend>) -> Self {
+        Self {
+            inner: GenericCache::with_backend(OCR_CACHE_TYPE.to_string(), backend),
+        }
     }
 
     pub fn get_cached_result(
@@ -34,14 +67,9 @@ impl OcrCache {
         config: &str,
     ) -> Result<Option<OcrExtractionResult>, OcrError> {
         let cache_key = self.generate_cache_key(image_hash, backend, config);
-        let cache_path = self.get_cache_path(&cache_key);
-
-        if !cache_path.exists() {
+        let Some(cached_bytes) = self.inner.get(&cache_key, None).map_err(cache_error)? else {
             return Ok(None);
-        }
-
-        let cached_bytes =
-            fs::read(&cache_path).map_err(|e| OcrError::CacheError(format!("Failed to read cache file: {}", e)))?;
+        };
 
         let result: OcrExtractionResult = rmp_serde::from_slice(&cached_bytes)
             .map_err(|e| OcrError::CacheError(format!("Failed to deserialize cache: {}", e)))?;
@@ -57,29 +85,11 @@ impl OcrCache {
         result: &OcrExtractionResult,
     ) -> Result<(), OcrError> {
         let cache_key = self.generate_cache_key(image_hash, backend, config);
-        let cache_path = self.get_cache_path(&cache_key);
 
         let serialized = rmp_serde::to_vec(result)
             .map_err(|e| OcrError::CacheError(format!("Failed to serialize result: {}", e)))?;
 
-        let pid = std::process::id();
-        let thread_id = std::thread::current().id();
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos();
-        let temp_name = format!("{}.tmp.{}.{:?}.{}", cache_key, pid, thread_id, timestamp);
-        let temp_path = self.cache_dir.join(temp_name);
-
-        fs::write(&temp_path, &serialized)
-            .map_err(|e| OcrError::CacheError(format!("Failed to write temp cache file: {}", e)))?;
-
-        fs::rename(&temp_path, &cache_path).map_err(|e| {
-            let _ = fs::remove_file(&temp_path);
-            OcrError::CacheError(format!("Failed to rename cache file: {}", e))
-        })?;
-
-        Ok(())
+        self.inner.set(&cache_key, serialized, None).map_err(cache_error)
     }
 
     fn generate_cache_key(&self, image_hash: &str, backend: &str, config: &str) -> String {
@@ -92,51 +102,26 @@ impl OcrCache {
     }
 
     pub fn clear(&self) -> Result<(), OcrError> {
-        if !self.cache_dir.exists() {
-            return Ok(());
-        }
-
-        let entries = fs::read_dir(&self.cache_dir)
-            .map_err(|e| OcrError::CacheError(format!("Failed to read cache directory: {}", e)))?;
-
-        for entry in entries.flatten() {
-            if let Some(ext) = entry.path().extension()
-                && ext == "msgpack"
-            {
-                let _ = fs::remove_file(entry.path());
-            }
-        }
-
+        self.inner.clear().map_err(cache_error)?;
         Ok(())
     }
 
     pub fn get_stats(&self) -> Result<OcrCacheStats, OcrError> {
-        if !self.cache_dir.exists() {
-            return Ok(OcrCacheStats::default());
-        }
-
-        let entries = fs::read_dir(&self.cache_dir)
-            .map_err(|e| OcrError::CacheError(format!("Failed to read cache directory: {}", e)))?;
-
-        let mut total_files = 0;
-        let mut total_size_bytes = 0u64;
-
-        for entry in entries.flatten() {
-            if let Some(ext) = entry.path().extension()
-                && ext == "msgpack"
-            {
-                total_files += 1;
-                if let Ok(metadata) = entry.metadata() {
-                    total_size_bytes += metadata.len();
-                }
-            }
-        }
-
+        let stats = self.inner.get_stats().map_err(cache_error)?;
         Ok(OcrCacheStats {
-            total_files,
-            total_size_mb: total_size_bytes as f64 / 1024.0 / 1024.0,
+            total_files: stats.total_files,
+            total_size_mb: stats.total_size_mb,
         })
     }
+
+    /// Location of the cache (directory for the filesystem backend).
+    pub fn cache_dir(&self) -> &std::path::Path {
+        self.inner.cache_dir()
+    }
+}
+
+fn cache_error(e: crate::KreuzbergError) -> OcrError {
+    OcrError::CacheError(e.to_string())
 }
 
 #[derive(Debug, Clone, Default)]
@@ -149,6 +134,7 @@ pub struct OcrCacheStats {
 mod tests {
     use super::*;
     use std::collections::HashMap;
+    use std::fs;
 
     #[test]
     fn test_cache_get_set() {
@@ -171,6 +157,36 @@ mod tests {
         assert_eq!(cached.unwrap().content, "Test OCR result");
     }
 
+    #[test]
+    fn test_cache_uses_configured_memory_backend() {
+        use crate::core::config::CacheBackendType;
+
+        let cache_config = CacheConfig {
+            backend: CacheBackendType::Memory,
+            max_size_mb: 3.0,
+            ..Default::default()
+        };
+        let processor_cache = OcrCache::from_config(&cache_config).unwrap();
+        let result = OcrExtractionResult {
+            content: "Shared OCR result".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: HashMap::new(),
+            tables: Vec::new(),
+            ocr_elements: None,
+        };
+        processor_cache
+            .set_cached_result("img", "tesseract", "eng", &result)
+            .unwrap();
+
+        let backend = shared_backend(&cache_config).unwrap();
+        assert_eq!(backend.name(), "memory");
+        assert_eq!(backend.stats(Some(OCR_CACHE_TYPE)).unwrap().total_files, 1);
+
+        let other_cache = OcrCache::from_config(&cache_config).unwrap();
+        let cached = other_cache.get_cached_result("img", "tesseract", "eng").unwrap();
+        assert_eq!(cached.unwrap().content, "Shared OCR result");
+    }
+
     #[test]
     fn test_cache_miss() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -402,34 +418,54 @@ mod tests {
     }
 
     #[test]
-    fn test_cache_stats_nonexistent_dir() {
-        let temp_dir = tempfile::tempdir().unwrap();
-        let cache_path = temp_dir.path().join("nonexistent");
-        let cache = OcrCache { cache_dir: cache_path };
+    fn test_cache_on_backend_namespace() {
+        let backend: Arc<dyn CacheBackend> = Arc::new(crate::cache::MemoryBackend::new(30.0, 100.0));
+        let cache = OcrCache::with_backend(Arc::clone(&backend));
 
         let stats = cache.get_stats().unwrap();
         assert_eq!(stats.total_files, 0);
-        assert_eq!(stats.total_size_mb, 0.0);
-    }
 
-    #[test]
-    fn test_cache_clear_nonexistent_dir() {
-        let temp_dir = tempfile::tempdir().unwrap();
-        let cache_path = temp_dir.path().join("nonexistent");
-        let cache = OcrCache { cache_dir: cache_path };
+        let result = OcrExtractionResult {
+            content: "Test".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: HashMap::new(),
+            tables: Vec::new(),
+            ocr_elements: None,
+        };
+        cache.set_cached_result("test", "tesseract", "eng", &result).unwrap();
 
-        assert!(cache.clear().is_ok());
+        assert_eq!(backend.stats(Some(OCR_CACHE_TYPE)).unwrap().total_files, 1);
+        assert_eq!(
+            cache
+                .get_cached_result("test", "tesseract", "eng")
+                .unwrap()
+                .unwrap()
+                .content,
+            "Test"
+        );
+
+        cache.clear().unwrap();
+        assert_eq!(backend.stats(None).unwrap().total_files, 0);
     }
 
     #[test]
-    fn test_cache_get_path() {
+    fn test_cache_dir_keeps_entries_in_directory() {
         let temp_dir = tempfile::tempdir().unwrap();
-        let cache = OcrCache::new(Some(temp_dir.path().to_path_buf())).unwrap();
+        let cache_path = temp_dir.path().join("ocr-cache");
+        let cache = OcrCache::new(Some(cache_path.clone())).unwrap();
+        assert_eq!(cache.cache_dir(), cache_path);
 
-        let path = cache.get_cache_path("abc123");
+        let result = OcrExtractionResult {
+            content: "Test".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: HashMap::new(),
+            tables: Vec::new(),
+            ocr_elements: None,
+        };
+        cache.set_cached_result("abc123", "tesseract", "eng", &result).unwrap();
 
-        assert!(path.to_string_lossy().contains("abc123.msgpack"));
-        assert_eq!(path.parent().unwrap(), temp_dir.path());
+        let key = cache.generate_cache_key("abc123", "tesseract", "eng");
+        assert!(cache_path.join(format!("{}.msgpack", key)).exists());
     }
 
     #[test]
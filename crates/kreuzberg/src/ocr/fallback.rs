@@ -0,0 +1,516 @@
+//! Confidence-driven fallback to a second OCR backend.
+//!
+//! With `OcrConfig.fallback` set, every page (or image region) is recognized by
+//! the configured backend first. When its mean recognition confidence is below
+//! `fallback.min_confidence`, the image is recognized again with the fallback
+//! backend and the result with the higher confidence is kept.
+//!
+//! Page confidence is the mean [`OcrConfidence::recognition`] of the result's
+//! word elements (line elements for line-level backends such as PaddleOCR),
+//! weighted by text length. Backends that report no elements fall back to the
+//! `mean_confidence` Tesseract records in the result metadata. A result without
+//! any text counts as confidence 0.
+
+use crate::Result;
+use crate::core::config::{OcrConfig, OcrFallbackConfig, canonical_ocr_backend_name, is_auto_language};
+use crate::ocr::auto_language::{DetectedLanguages, backend_language, detect_languages};
+use crate::ocr::table::reconstruct_tables_from_elements;
+use crate::plugins::OcrBackend;
+use crate::types::{ExtractionResult, OcrConfidence, OcrElementConfig, OcrElementLevel, PageInfo};
+use std::sync::Arc;
+
+/// The backend that recognized a page and how confident it was.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrSource {
+    /// Name of the OCR backend.
+    pub backend: String,
+    /// Language the backend recognized the page with (e.g. `eng+deu`).
+    pub language: String,
+    /// Mean recognition confidence (0.0-1.0), when the backend reports one.
+    pub confidence: Option<f64>,
+}
+
+/// An OCR result together with its [`OcrSource`].
+#[derive(Debug)]
+pub(crate) struct Recognition {
+    pub result: ExtractionResult,
+    pub source: OcrSource,
+}
+
+/// Recognize an image, retrying with the fallback backend on low confidence.
+///
+/// With `language = "auto"` the page languages are detected first (see
+/// [`crate::ocr::auto_language`]) and mapped to each backend's language codes.
+///
+/// With `table_detection` set, results without tables get the tables
+/// reconstructed from their OCR elements.
+///
+/// Errors of the primary backend are returned; a missing or failing fallback
+/// backend only logs a warning and keeps the primary result.
+pub(crate) async fn recognize(backend: &dyn OcrBackend, image: &[u8], config: &OcrConfig) -> Result<Recognition> {
+    let detected = if config.auto_language() {
+        Some(detect_languages(backend, image, config).await?)
+    } else {
+        None
+    };
+    let detected_config;
+    let config = match &detected {
+        Some(detected) => {
+            detected_config = OcrConfig {
+                language: backend_language(backend, &detected.languages),
+                ..config.clone()
+            };
+            &detected_config
+        }
+        None => config,
+    };
+
+    // Confidence and table geometry come from the elements, so they are requested
+    // whenever a fallback or table reconstruction needs them
+    let scoring_config = if config.fallback.is_some() || config.table_detection.is_some() {
+        OcrConfig {
+            element_config: Some(OcrElementConfig {
+                include_elements: true,
+                ..config.element_config.clone().unwrap_or_default()
+            }),
+            ..config.clone()
+        }
+    } else {
+        config.clone()
+    };
+    let result = backend.process_image(image, &scoring_config).await?;
+    let mut best = Recognition {
+        source: source(backend, config, &result),
+        result,
+    };
+
+    if let Some(fallback) = config.fallback.as_ref() {
+        best = with_fallback(
+            backend,
+            image,
+            config,
+            &scoring_config,
+            fallback,
+            detected.as_ref(),
+            best,
+        )
+        .await;
+    }
+
+    if let Some(table_config) = config.table_detection.as_ref()
+        && best.result.tables.is_empty()
+        && let Some(elements) = best.result.ocr_elements.as_deref()
+    {
+        best.result.tables = reconstruct_tables_from_elements(elements, table_config);
+    }
+
+    let elements_requested = config.element_config.as_ref().is_some_and(|ec| ec.include_elements)
+        || config
+            .output_format
+            .is_some_and(|format| format.requires_ocr_elements());
+    if !elements_requested {
+        best.result.ocr_elements = None;
+    }
+    Ok(best)
+}
+
+/// Retry a low-confidence recognition with the fallback backend.
+async fn with_fallback(
+    backend: &dyn OcrBackend,
+    image: &[u8],
+    config: &OcrConfig,
+    scoring_config: &OcrConfig,
+    fallback: &OcrFallbackConfig,
+    detected: Option<&DetectedLanguages>,
+    best: Recognition,
+) -> Recognition {
+    let low_confidence = best
+        .source
+        .confidence
+        .is_some_and(|confidence| confidence < fallback.min_confidence);
+    let fallback_name = canonical_ocr_backend_name(&fallback.backend);
+    if !low_confidence || fallback_name == backend.name() {
+        return best;
+    }
+
+    let attempt = async {
+        let fallback_backend = fallback_backend(&fallback_name)?;
+        let language = match fallback.language.as_deref() {
+            Some(language) if !is_auto_language(language) => language.to_string(),
+            None if detected.is_none() => config.language.clone(),
+            _ => {
+                let detected = match detected {
+                    Some(detected) => detected.clone(),
+                    None => detect_languages(fallback_backend.as_ref(), image, scoring_config).await?,
+                };
+                backend_language(fallback_backend.as_ref(), &detected.languages)
+            }
+        };
+        let fallback_config = OcrConfig {
+            backend: fallback_name.clone(),
+            language,
+            ..scoring_config.clone()
+        };
+        let result = fallback_backend.process_image(image, &fallback_config).await?;
+        Ok::<_, crate::KreuzbergError>(Recognition {
+            source: source(fallback_backend.as_ref(), &fallback_config, &result),
+            result,
+        })
+    };
+    match attempt.await {
+        Ok(candidate) if is_better(&candidate.source, &best.source) => candidate,
+        Ok(_) => best,
+        Err(e) => {
+            tracing::warn!("Fallback OCR backend '{}' failed: {}", fallback_name, e);
+            best
+        }
+    }
+}
+
+/// Mean recognition confidence (0.0-1.0) of an OCR result.
+pub fn page_confidence(result: &ExtractionResult) -> Option<f64> {
+    if let Some(elements) = result.ocr_elements.as_deref() {
+        let level = if elements.iter().any(|element| element.level == OcrElementLevel::Word) {
+            OcrElementLevel::Word
+        } else {
+            OcrElementLevel::Line
+        };
+        let (weighted, total) = elements
+            .iter()
+            .filter(|element| element.level == level)
+            .map(|element| (element.confidence.recognition, element.text.trim().chars().count()))
+            .filter(|(_, length)| *length > 0)
+            .fold((0.0, 0usize), |(weighted, total), (confidence, length)| {
+                (weighted + confidence * length as f64, total + length)
+            });
+        if total > 0 {
+            return Some(weighted / total as f64);
+        }
+    }
+
+    if let Some(mean) = result
+        .metadata
+        .additional
+        .get("mean_confidence")
+        .and_then(|value| value.as_f64())
+    {
+        return Some(OcrConfidence::from_tesseract(mean).recognition);
+    }
+
+    result.content.trim().is_empty().then_some(0.0)
+}
+
+/// Report the backend and confidence of an OCR'd page in its `PageInfo`.
+pub(crate) fn record_source(page_info: &mut PageInfo, source: &OcrSource) {
+    page_info.ocr_backend = Some(source.backend.clone());
+    page_info.ocr_language = Some(source.language.clone());
+    page_info.ocr_confidence = source.confidence;
+}
+
+fn source(backend: &dyn OcrBackend, config: &OcrConfig, result: &ExtractionResult) -> OcrSource {
+    OcrSource {
+        backend: backend.name().to_string(),
+        language: config.language.clone(),
+        confidence: page_confidence(result),
+    }
+}
+
+/// A candidate replaces the current result only when it is known to be more confident.
+fn is_better(candidate: &OcrSource, current: &OcrSource) -> bool {
+    match (candidate.confidence, current.confidence) {
+        (Some(candidate), Some(current)) => candidate > current,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+fn fallback_backend(name: &str) -> Result<Arc<dyn OcrBackend>> {
+    let registry = crate::plugins::registry::get_ocr_backend_registry();
+    let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
+        message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+        plugin_name: "ocr-registry".to_string(),
+    })?;
+    registry.get(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::OcrFallbackConfig;
+    use crate::plugins::{OcrBackendType, Plugin};
+    use crate::types::{OcrBoundingGeometry, OcrElement};
+    use async_trait::async_trait;
+
+    /// Backend that recognizes every image as `text` with a fixed word confidence.
+    struct FixedOcrBackend {
+        name: &'static str,
+        text: &'static str,
+        confidence: f64,
+    }
+
+    impl Plugin for FixedOcrBackend {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl OcrBackend for FixedOcrBackend {
+        async fn process_image(&self, _image_bytes: &[u8], config: &OcrConfig) -> Result<ExtractionResult> {
+            let include_elements = config.element_config.as_ref().is_some_and(|ec| ec.include_elements);
+            Ok(result_with(
+                self.text,
+                include_elements.then(|| vec![element(self.text, OcrElementLevel::Word, self.confidence)]),
+            ))
+        }
+
+        fn supports_language(&self, _lang: &str) -> bool {
+            true
+        }
+
+        fn backend_type(&self) -> OcrBackendType {
+            OcrBackendType::Custom
+        }
+    }
+
+    fn fallback_config(backend: &str, min_confidence: f64) -> OcrConfig {
+        OcrConfig {
+            fallback: Some(OcrFallbackConfig {
+                backend: backend.to_string(),
+                language: None,
+                min_confidence,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recognize_keeps_more_confident_fallback() {
+        let registry = crate::plugins::registry::get_ocr_backend_registry();
+        registry
+            .write()
+            .unwrap()
+            .register(Arc::new(FixedOcrBackend {
+                name: "fallback-test-good",
+                text: "good",
+                confidence: 0.95,
+            }))
+            .unwrap();
+        let primary = FixedOcrBackend {
+            name: "fallback-test-primary",
+            text: "bad",
+            confidence: 0.3,
+        };
+
+        let recognition = recognize(&primary, b"image", &fallback_config("fallback-test-good", 0.6))
+            .await
+            .unwrap();
+        assert_eq!(recognition.result.content, "good");
+        assert_eq!(recognition.source.backend, "fallback-test-good");
+        assert_eq!(recognition.source.confidence, Some(0.95));
+        // Elements were only requested for scoring
+        assert!(recognition.result.ocr_elements.is_none());
+
+        // Confident enough: the fallback is not consulted
+        let recognition = recognize(&primary, b"image", &fallback_config("fallback-test-good", 0.2))
+            .await
+            .unwrap();
+        assert_eq!(recognition.source.backend, "fallback-test-primary");
+    }
+
+    #[tokio::test]
+    async fn test_recognize_resolves_auto_language() {
+        let backend = FixedOcrBackend {
+            name: "fallback-test-primary",
+            text: "",
+            confidence: 0.9,
+        };
+        let config = OcrConfig {
+            language: "auto".to_string(),
+            ..Default::default()
+        };
+        let recognition = recognize(&backend, b"image", &config).await.unwrap();
+        assert_eq!(recognition.source.language, "eng");
+    }
+
+    #[tokio::test]
+    async fn test_recognize_keeps_primary_without_fallback_backend() {
+        let primary = FixedOcrBackend {
+            name: "fallback-test-primary",
+            text: "bad",
+            confidence: 0.3,
+        };
+        let recognition = recognize(&primary, b"image", &fallback_config("fallback-test-missing", 0.6))
+            .await
+            .unwrap();
+        assert_eq!(recognition.result.content, "bad");
+        assert_eq!(recognition.source.confidence, Some(0.3));
+    }
+
+    /// Backend recognizing every image as a 2x2 grid of words, without tables of its own.
+    struct GridOcrBackend;
+
+    impl Plugin for GridOcrBackend {
+        fn name(&self) -> &str {
+            "fallback-test-grid"
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl OcrBackend for GridOcrBackend {
+        async fn process_image(&self, _image_bytes: &[u8], config: &OcrConfig) -> Result<ExtractionResult> {
+            let include_elements = config.element_config.as_ref().is_some_and(|ec| ec.include_elements);
+            let word = |text: &str, left: u32, top: u32| {
+                OcrElement::new(
+                    text,
+                    OcrBoundingGeometry::Rectangle {
+                        left,
+                        top,
+                        width: 40,
+                        height: 20,
+                    },
+                    OcrConfidence::from_tesseract(90.0),
+                )
+                .with_level(OcrElementLevel::Word)
+            };
+            Ok(result_with(
+                "Name Qty\nApples 3",
+                include_elements.then(|| {
+                    vec![
+                        word("Name", 0, 0),
+                        word("Qty", 300, 0),
+                        word("Apples", 0, 50),
+                        word("3", 300, 50),
+                    ]
+                }),
+            ))
+        }
+
+        fn supports_language(&self, _lang: &str) -> bool {
+            true
+        }
+
+        fn backend_type(&self) -> OcrBackendType {
+            OcrBackendType::Custom
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recognize_reconstructs_tables_from_elements() {
+        let recognition = recognize(&GridOcrBackend, b"image", &OcrConfig::default())
+            .await
+            .unwrap();
+        assert!(recognition.result.tables.is_empty());
+
+        let config = OcrConfig {
+            table_detection: Some(crate::core::config::OcrTableConfig::default()),
+            ..Default::default()
+        };
+        let recognition = recognize(&GridOcrBackend, b"image", &config).await.unwrap();
+        assert_eq!(recognition.result.tables.len(), 1);
+        assert_eq!(
+            recognition.result.tables[0].cells,
+            vec![vec!["Name", "Qty"], vec!["Apples", "3"]]
+        );
+        // Elements were only requested for table reconstruction
+        assert!(recognition.result.ocr_elements.is_none());
+    }
+
+    fn element(text: &str, level: OcrElementLevel, confidence: f64) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Rectangle {
+                left: 0,
+                top: 0,
+                width: 10,
+                height: 10,
+            },
+            OcrConfidence {
+                detection: None,
+                recognition: confidence,
+            },
+        )
+        .with_level(level)
+    }
+
+    fn result_with(content: &str, elements: Option<Vec<OcrElement>>) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            ocr_elements: elements,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_page_confidence_weights_words_by_length() {
+        let result = result_with(
+            "abc d",
+            Some(vec![
+                element("abc", OcrElementLevel::Word, 0.9),
+                element("d", OcrElementLevel::Word, 0.5),
+                element("abc d", OcrElementLevel::Line, 0.1),
+            ]),
+        );
+        let confidence = page_confidence(&result).unwrap();
+        assert!((confidence - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_page_confidence_uses_lines_without_words() {
+        let result = result_with("ab", Some(vec![element("ab", OcrElementLevel::Line, 0.7)]));
+        assert_eq!(page_confidence(&result), Some(0.7));
+    }
+
+    #[test]
+    fn test_page_confidence_from_tesseract_metadata() {
+        let mut result = result_with("text", None);
+        result
+            .metadata
+            .additional
+            .insert("mean_confidence".into(), serde_json::json!(42));
+        assert_eq!(page_confidence(&result), Some(0.42));
+    }
+
+    #[test]
+    fn test_page_confidence_empty_and_unknown() {
+        assert_eq!(page_confidence(&result_with("  ", None)), Some(0.0));
+        assert_eq!(page_confidence(&result_with("text", None)), None);
+    }
+
+    #[test]
+    fn test_is_better() {
+        let source = |confidence| OcrSource {
+            backend: "x".to_string(),
+            language: "eng".to_string(),
+            confidence,
+        };
+        assert!(is_better(&source(Some(0.9)), &source(Some(0.5))));
+        assert!(!is_better(&source(Some(0.5)), &source(Some(0.5))));
+        assert!(is_better(&source(Some(0.1)), &source(None)));
+        assert!(!is_better(&source(None), &source(Some(0.0))));
+    }
+}
@@ -12,6 +12,7 @@
 //! - **Batch processing**: Process multiple images efficiently
 //! - **Language support**: Validate and configure Tesseract languages
 //! - **PSM modes**: Support for all Tesseract Page Segmentation Modes
+//! - **Backend fallback**: Retry low-confidence pages with a second OCR backend
 //!
 //! # Example
 //!
@@ -37,12 +38,15 @@
 //! [dependencies]
 //! kreuzberg = { version = "4.0", features = ["ocr"] }
 //! ```
+pub mod auto_language;
 mod backends;
 pub mod cache;
 pub mod conversion;
 pub mod error;
+pub mod fallback;
 pub mod hocr;
 pub mod language_registry;
+pub mod orientation;
 pub mod processor;
 pub mod table;
 pub mod tesseract_backend;
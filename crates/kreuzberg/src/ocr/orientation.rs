@@ -0,0 +1,301 @@
+//! Page orientation correction ahead of OCR.
+//!
+//! Scanned pages are often fed sideways or upside down. Before recognition the
+//! active backend is asked for the page orientation ([`OcrBackend::detect_orientation`]:
+//! Tesseract OSD, PaddleOCR's angle classifier). When the backend has no detector,
+//! cannot decide or fails, the projection-profile heuristic from
+//! [`crate::image::enhance::detect_orientation`] is used instead.
+//!
+//! The page is then rotated upright and the applied rotation is returned so it can
+//! be reported in `PageInfo::rotation` and on the OCR elements.
+
+use crate::Result;
+use crate::core::config::OcrConfig;
+use crate::error::KreuzbergError;
+use crate::image::enhance::{BinarizationMethod, binarize, detect_orientation};
+use crate::plugins::OcrBackend;
+use crate::types::{
+    ImagePreprocessingMetadata, Metadata, OcrElement, OcrRotation, PageInfo, PageStructure, PageUnitType,
+};
+use image::DynamicImage;
+use std::borrow::Cow;
+use std::io::Cursor;
+
+/// Detect the page orientation and rotate encoded image bytes upright.
+///
+/// Returns the input unchanged when the page is already upright or cannot be
+/// decoded (e.g. JPEG 2000); the rotation is `None` when nothing could be determined.
+pub async fn correct_page_orientation<'a>(
+    backend: &dyn OcrBackend,
+    image_bytes: &'a [u8],
+    config: &OcrConfig,
+) -> Result<(Cow<'a, [u8]>, Option<OcrRotation>)> {
+    let detected = detect_with_backend(backend, image_bytes, config).await;
+    apply_orientation(image_bytes, detected)
+}
+
+/// Detect the page orientation of a decoded image and rotate it upright.
+pub async fn correct_image_orientation(
+    backend: &dyn OcrBackend,
+    image: DynamicImage,
+    config: &OcrConfig,
+) -> Result<(DynamicImage, OcrRotation)> {
+    let encoded = encode_png(&DynamicImage::ImageRgb8(image.to_rgb8()))?;
+    let rotation = match detect_with_backend(backend, &encoded, config).await {
+        Some(rotation) => rotation,
+        None => heuristic_orientation(&image),
+    };
+
+    let rotated = match quadrant(&rotation) {
+        0 => image,
+        _ => rotate_page(&image, &rotation),
+    };
+    Ok((rotated, rotation))
+}
+
+/// Rotate encoded image bytes by a detected orientation.
+///
+/// `detected` is the backend's answer; when it is `None` the heuristic decides.
+pub(crate) fn apply_orientation(
+    image_bytes: &[u8],
+    detected: Option<OcrRotation>,
+) -> Result<(Cow<'_, [u8]>, Option<OcrRotation>)> {
+    if let Some(rotation) = &detected
+        && quadrant(rotation) == 0
+    {
+        return Ok((Cow::Borrowed(image_bytes), detected));
+    }
+
+    let image = match image::load_from_memory(image_bytes) {
+        Ok(image) => image,
+        Err(e) => {
+            tracing::warn!("Orientation correction skipped, image could not be decoded: {}", e);
+            return Ok((Cow::Borrowed(image_bytes), None));
+        }
+    };
+
+    let rotation = detected.unwrap_or_else(|| heuristic_orientation(&image));
+    if quadrant(&rotation) == 0 {
+        return Ok((Cow::Borrowed(image_bytes), Some(rotation)));
+    }
+
+    let rotated = encode_png(&rotate_page(&image, &rotation))?;
+    Ok((Cow::Owned(rotated), Some(rotation)))
+}
+
+/// Orientation from the projection-profile heuristic (no confidence).
+pub fn heuristic_orientation(image: &DynamicImage) -> OcrRotation {
+    let (binary, _) = binarize(&image.to_luma8(), BinarizationMethod::Otsu);
+    OcrRotation {
+        angle_degrees: detect_orientation(&binary) as f64,
+        confidence: None,
+    }
+}
+
+/// Rotate an image clockwise by `rotation` (multiples of 90 degrees).
+pub fn rotate_page(image: &DynamicImage, rotation: &OcrRotation) -> DynamicImage {
+    match quadrant(rotation) {
+        90 => image.rotate90(),
+        180 => image.rotate180(),
+        270 => image.rotate270(),
+        _ => image.clone(),
+    }
+}
+
+/// Record a page rotation in the preprocessing metadata.
+///
+/// Orientation runs before the other preprocessing steps, so it is listed first.
+pub(crate) fn record_rotation(metadata: &mut ImagePreprocessingMetadata, rotation: Option<&OcrRotation>) {
+    let Some(rotation) = rotation else {
+        return;
+    };
+    let degrees = quadrant(rotation);
+    if degrees != 0 {
+        metadata.rotation_degrees = degrees;
+        metadata.operations.insert(0, "auto_rotate".to_string());
+    }
+}
+
+/// Attach the page rotation to OCR elements that carry none of their own.
+pub(crate) fn annotate_elements(elements: &mut [OcrElement], rotation: &OcrRotation) {
+    for element in elements.iter_mut().filter(|element| element.rotation.is_none()) {
+        element.rotation = Some(rotation.clone());
+    }
+}
+
+/// Report the orientation correction of a page in `metadata.pages`.
+///
+/// `dimensions` are those of the original (unrotated) page and are only used
+/// when the page has no `PageInfo` yet.
+pub(crate) fn set_page_rotation(
+    metadata: &mut Metadata,
+    page_number: usize,
+    dimensions: Option<(f64, f64)>,
+    rotation: OcrRotation,
+) {
+    page_info_mut(metadata, page_number, dimensions).rotation = Some(rotation);
+}
+
+/// The `PageInfo` of a page in `metadata.pages`, created when missing.
+pub(crate) fn page_info_mut(
+    metadata: &mut Metadata,
+    page_number: usize,
+    dimensions: Option<(f64, f64)>,
+) -> &mut PageInfo {
+    let pages = metadata.pages.get_or_insert(PageStructure {
+        total_count: page_number,
+        unit_type: PageUnitType::Page,
+        boundaries: None,
+        pages: None,
+    });
+    let page_infos = pages.pages.get_or_insert_with(Vec::new);
+    let idx = match page_infos.iter().position(|page| page.number == page_number) {
+        Some(idx) => idx,
+        None => {
+            page_infos.push(PageInfo {
+                number: page_number,
+                title: None,
+                dimensions,
+                image_count: None,
+                table_count: None,
+                hidden: None,
+                is_blank: None,
+                rotation: None,
+                text_layer: None,
+                ocr_backend: None,
+                ocr_language: None,
+                ocr_confidence: None,
+            });
+            page_infos.len() - 1
+        }
+    };
+    &mut page_infos[idx]
+}
+
+async fn detect_with_backend(backend: &dyn OcrBackend, image_bytes: &[u8], config: &OcrConfig) -> Option<OcrRotation> {
+    match backend.detect_orientation(image_bytes, config).await {
+        Ok(rotation) => rotation,
+        Err(e) => {
+            tracing::warn!("Orientation detection failed, falling back to heuristic: {}", e);
+            None
+        }
+    }
+}
+
+fn quadrant(rotation: &OcrRotation) -> i32 {
+    match (rotation.angle_degrees.round() as i32).rem_euclid(360) {
+        degrees @ (90 | 180 | 270) => degrees,
+        _ => 0,
+    }
+}
+
+/// Encode an image as PNG.
+pub(crate) fn encode_png(image: &DynamicImage) -> Result<Vec<u8>> {
+    let mut buffer = Cursor::new(Vec::new());
+    image
+        .write_to(&mut buffer, image::ImageFormat::Png)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to encode image as PNG: {}", e)))?;
+    Ok(buffer.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    /// White page with dark horizontal "text lines" that carry ascenders.
+    fn upright_page() -> DynamicImage {
+        let mut page = GrayImage::from_pixel(400, 300, Luma([255]));
+        for line in 0..6 {
+            let top = 30 + line * 40;
+            for x in 30..370 {
+                for y in top + 8..top + 20 {
+                    page.put_pixel(x, y, Luma([0]));
+                }
+                // Ascender strokes above the x-height band ~keep
+                if x % 12 < 3 {
+                    for y in top..top + 8 {
+                        page.put_pixel(x, y, Luma([0]));
+                    }
+                }
+            }
+        }
+        DynamicImage::ImageLuma8(page)
+    }
+
+    fn rotation(angle_degrees: f64) -> OcrRotation {
+        OcrRotation {
+            angle_degrees,
+            confidence: Some(0.9),
+        }
+    }
+
+    #[test]
+    fn test_apply_orientation_uses_detected_rotation() {
+        let sideways = upright_page().rotate270();
+        let bytes = encode_png(&sideways).unwrap();
+
+        let (corrected, applied) = apply_orientation(&bytes, Some(rotation(90.0))).unwrap();
+        assert!(matches!(corrected, Cow::Owned(_)));
+        assert_eq!(applied.unwrap().angle_degrees, 90.0);
+
+        let decoded = image::load_from_memory(&corrected).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (400, 300));
+    }
+
+    #[test]
+    fn test_apply_orientation_keeps_upright_pages() {
+        let bytes = encode_png(&upright_page()).unwrap();
+        let (corrected, applied) = apply_orientation(&bytes, Some(rotation(0.0))).unwrap();
+        assert!(matches!(corrected, Cow::Borrowed(_)));
+        assert_eq!(applied.unwrap().angle_degrees, 0.0);
+    }
+
+    #[test]
+    fn test_apply_orientation_falls_back_to_heuristic() {
+        let upside_down = upright_page().rotate180();
+        let bytes = encode_png(&upside_down).unwrap();
+
+        let (_, applied) = apply_orientation(&bytes, None).unwrap();
+        let applied = applied.unwrap();
+        assert_eq!(applied.angle_degrees, 180.0);
+        assert!(applied.confidence.is_none());
+    }
+
+    #[test]
+    fn test_apply_orientation_passes_through_undecodable_bytes() {
+        let (corrected, applied) = apply_orientation(b"not an image", Some(rotation(90.0))).unwrap();
+        assert_eq!(&*corrected, b"not an image");
+        assert!(applied.is_none());
+    }
+
+    #[test]
+    fn test_record_rotation_prepends_operation() {
+        let config = crate::types::ImagePreprocessingConfig {
+            auto_rotate: false,
+            deskew: false,
+            ..Default::default()
+        };
+        let mut metadata = crate::image::preprocess_for_ocr(&upright_page(), &config, None)
+            .unwrap()
+            .metadata;
+        record_rotation(&mut metadata, Some(&rotation(270.0)));
+        assert_eq!(metadata.rotation_degrees, 270);
+        assert_eq!(metadata.operations, vec!["auto_rotate", "binarize"]);
+
+        record_rotation(&mut metadata, Some(&rotation(0.0)));
+        assert_eq!(metadata.operations.len(), 2);
+    }
+
+    #[test]
+    fn test_set_page_rotation_creates_page_info() {
+        let mut metadata = Metadata::default();
+        set_page_rotation(&mut metadata, 1, Some((300.0, 400.0)), rotation(90.0));
+
+        let pages = metadata.pages.unwrap();
+        assert_eq!(pages.total_count, 1);
+        let page = &pages.pages.unwrap()[0];
+        assert_eq!(page.dimensions, Some((300.0, 400.0)));
+        assert_eq!(page.rotation.as_ref().unwrap().angle_degrees, 90.0);
+    }
+}
@@ -20,10 +20,10 @@ use kreuzberg_tesseract::TesseractAPI;
 ///
 /// Hexadecimal string representation of the configuration hash
 pub(super) fn hash_config(config: &TesseractConfig) -> String {
-    use ahash::AHasher;
+    use crate::cache::StableHasher;
     use std::hash::{Hash, Hasher};
 
-    let mut hasher = AHasher::default();
+    let mut hasher = StableHasher::new();
     config.language.hash(&mut hasher);
     config.psm.hash(&mut hasher);
     config.output_format.hash(&mut hasher);
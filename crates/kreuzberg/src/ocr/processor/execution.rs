@@ -44,8 +44,9 @@ fn parse_tsv_to_elements(tsv_data: &str, min_confidence: f64) -> Vec<OcrElement>
             continue;
         }
 
-        // Parse fields
-        let level = fields[0].parse::<i32>().unwrap_or(0);
+        // Parse fields. TSV levels count from the page (1=page .. 5=word), while
+        // `TsvRow` counts from the block (1=block .. 4=word).
+        let level = fields[0].parse::<i32>().unwrap_or(0) - 1;
         let page_num = fields[1].parse::<i32>().unwrap_or(1);
         let block_num = fields[2].parse::<i32>().unwrap_or(0);
         let par_num = fields[3].parse::<i32>().unwrap_or(0);
@@ -109,6 +110,22 @@ where
     tracing::debug!("[ci-debug][ocr::processor::{stage}] {timestamp:.3}s {}", details());
 }
 
+/// Decode image bytes, including JPEG 2000 and JBIG2 which the `image` crate cannot read.
+pub(super) fn decode_image(image_bytes: &[u8]) -> Result<image::DynamicImage, OcrError> {
+    if crate::extraction::image::is_jp2(image_bytes) || crate::extraction::image::is_j2k(image_bytes) {
+        crate::extraction::image::decode_jp2_to_rgb(image_bytes)
+            .map(image::DynamicImage::ImageRgb8)
+            .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode JP2 image: {}", e)))
+    } else if crate::extraction::image::is_jbig2(image_bytes) {
+        crate::extraction::image::decode_jbig2_to_gray(image_bytes)
+            .map(image::DynamicImage::ImageLuma8)
+            .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode JBIG2 image: {}", e)))
+    } else {
+        image::load_from_memory(image_bytes)
+            .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode image: {}", e)))
+    }
+}
+
 /// Perform OCR on an image using Tesseract.
 ///
 /// This function handles the complete OCR pipeline:
@@ -143,21 +160,7 @@ pub(super) fn perform_ocr(
         )
     });
 
-    let img = {
-        // Check for JPEG 2000 format which the image crate doesn't support
-        if crate::extraction::image::is_jp2(image_bytes) || crate::extraction::image::is_j2k(image_bytes) {
-            crate::extraction::image::decode_jp2_to_rgb(image_bytes)
-                .map(image::DynamicImage::ImageRgb8)
-                .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode JP2 image: {}", e)))?
-        } else if crate::extraction::image::is_jbig2(image_bytes) {
-            crate::extraction::image::decode_jbig2_to_gray(image_bytes)
-                .map(image::DynamicImage::ImageLuma8)
-                .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode JBIG2 image: {}", e)))?
-        } else {
-            image::load_from_memory(image_bytes)
-                .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode image: {}", e)))?
-        }
-    };
+    let img = decode_image(image_bytes)?;
 
     let rgb_image = img.to_rgb8();
     let (width, height) = rgb_image.dimensions();
@@ -264,7 +267,8 @@ pub(super) fn perform_ocr(
 
     log_ci_debug(ci_debug_enabled, "recognize", || "completed".to_string());
 
-    let tsv_data_for_tables = if config.enable_table_detection || config.output_format == "tsv" {
+    let needs_elements = extraction_config.is_some_and(|c| c.output_format.requires_ocr_elements());
+    let tsv_data_for_tables = if config.enable_table_detection || config.output_format == "tsv" || needs_elements {
         Some(
             api.get_tsv_text(0)
                 .map_err(|e| OcrError::ProcessingFailed(format!("Failed to extract TSV: {}", e)))?,
@@ -326,6 +330,10 @@ pub(super) fn perform_ocr(
         serde_json::Value::String(config.language.clone()),
     );
     metadata.insert("psm".to_string(), serde_json::Value::String(config.psm.to_string()));
+    // Mean word confidence (0-100), used to decide on OCR backend fallback
+    if let Ok(confidence) = api.mean_text_conf() {
+        metadata.insert("mean_confidence".to_string(), serde_json::Value::from(confidence));
+    }
     metadata.insert(
         "output_format".to_string(),
         serde_json::Value::String(config.output_format.clone()),
@@ -484,12 +492,17 @@ fn process_image_resolved(
     cache: &OcrCache,
     output_format: Option<crate::core::config::OutputFormat>,
 ) -> Result<OcrExtractionResult, OcrError> {
-    let mut hasher = ahash::AHasher::default();
+    let mut hasher = crate::cache::StableHasher::new();
     use std::hash::{Hash, Hasher};
     image_bytes.hash(&mut hasher);
     let image_hash = format!("{:016x}", hasher.finish());
 
-    let config_str = hash_config(config);
+    // Results without TSV carry no elements, so cache them apart from results that need them.
+    let config_str = if output_format.is_some_and(|fmt| fmt.requires_ocr_elements()) {
+        format!("{}_elements", hash_config(config))
+    } else {
+        hash_config(config)
+    };
 
     if config.use_cache
         && let Some(cached_result) = cache.get_cached_result(&image_hash, "tesseract", &config_str)?
@@ -598,6 +611,22 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    #[test]
+    fn test_parse_tsv_to_elements_levels() {
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+                   1\t1\t0\t0\t0\t0\t0\t0\t200\t100\t-1\t\n\
+                   4\t1\t1\t1\t1\t0\t10\t10\t90\t20\t-1\t\n\
+                   5\t1\t1\t1\t1\t1\t10\t10\t40\t20\t96.5\tHello\n\
+                   5\t1\t1\t1\t1\t2\t60\t10\t40\t20\t12.0\tworld\n";
+
+        let elements = parse_tsv_to_elements(tsv, 50.0);
+
+        assert_eq!(elements.len(), 1);
+        assert_eq!(elements[0].text, "Hello");
+        assert_eq!(elements[0].level, crate::types::OcrElementLevel::Word);
+        assert_eq!(elements[0].parent_id.as_deref(), Some("p1_b1_par1_l1"));
+    }
+
     #[test]
     fn test_is_all_languages() {
         assert!(is_all_languages("all"));
@@ -621,16 +650,16 @@ mod tests {
 
     #[test]
     fn test_compute_image_hash_deterministic() {
-        use ahash::AHasher;
+        use crate::cache::StableHasher;
         use std::hash::{Hash, Hasher};
 
         let image_bytes = vec![1, 2, 3, 4, 5];
 
-        let mut hasher1 = AHasher::default();
+        let mut hasher1 = StableHasher::new();
         image_bytes.hash(&mut hasher1);
         let hash1 = format!("{:016x}", hasher1.finish());
 
-        let mut hasher2 = AHasher::default();
+        let mut hasher2 = StableHasher::new();
         image_bytes.hash(&mut hasher2);
         let hash2 = format!("{:016x}", hasher2.finish());
 
@@ -640,17 +669,17 @@ mod tests {
 
     #[test]
     fn test_compute_image_hash_different_data() {
-        use ahash::AHasher;
+        use crate::cache::StableHasher;
         use std::hash::{Hash, Hasher};
 
         let image_bytes1 = vec![1, 2, 3, 4, 5];
         let image_bytes2 = vec![5, 4, 3, 2, 1];
 
-        let mut hasher1 = AHasher::default();
+        let mut hasher1 = StableHasher::new();
         image_bytes1.hash(&mut hasher1);
         let hash1 = format!("{:016x}", hasher1.finish());
 
-        let mut hasher2 = AHasher::default();
+        let mut hasher2 = StableHasher::new();
         image_bytes2.hash(&mut hasher2);
         let hash2 = format!("{:016x}", hasher2.finish());
 
@@ -4,15 +4,17 @@
 //! - `validation` - Image and configuration validation
 //! - `config` - Configuration hashing and Tesseract variables
 //! - `execution` - Core OCR execution logic
+//! - `orientation` - Page orientation detection (Tesseract OSD)
 
 mod config;
 mod execution;
+mod orientation;
 mod validation;
 
 use crate::ocr::cache::OcrCache;
 use crate::ocr::error::OcrError;
 use crate::ocr::types::{BatchItemResult, TesseractConfig};
-use crate::types::OcrExtractionResult;
+use crate::types::{OcrExtractionResult, OcrRotation};
 
 pub struct OcrProcessor {
     cache: OcrCache,
@@ -24,6 +26,16 @@ impl OcrProcessor {
         Ok(Self { cache })
     }
 
+    /// Processor caching results in the store configured by `cache_config`, or in
+    /// the default filesystem cache when `None`.
+    pub fn with_cache_config(cache_config: Option<&crate::core::config::CacheConfig>) -> Result<Self, OcrError> {
+        let cache = match cache_config {
+            Some(cache_config) => OcrCache::from_config(cache_config)?,
+            None => OcrCache::new(None)?,
+        };
+        Ok(Self { cache })
+    }
+
     #[cfg_attr(feature = "otel", tracing::instrument(
         skip(self, image_bytes),
         fields(
@@ -57,6 +69,36 @@ impl OcrProcessor {
         execution::process_image_with_cache(image_bytes, config, &self.cache, Some(output_format))
     }
 
+    /// Detect the orientation of a page image with Tesseract OSD.
+    ///
+    /// Returns the clockwise rotation that makes the page upright, or `None` when
+    /// `osd.traineddata` is not installed or the page has too little text.
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, image_bytes),
+        fields(
+            ocr.backend = "tesseract",
+            image.size_bytes = image_bytes.len(),
+        )
+    ))]
+    pub fn detect_orientation(&self, image_bytes: &[u8]) -> Result<Option<OcrRotation>, OcrError> {
+        orientation::detect_orientation(image_bytes)
+    }
+
+    /// Detect the dominant script of a page image with Tesseract OSD.
+    ///
+    /// Returns the OSD script name (e.g. `Latin`, `Cyrillic`, `Han`), or `None`
+    /// when `osd.traineddata` is not installed or the script is uncertain.
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, image_bytes),
+        fields(
+            ocr.backend = "tesseract",
+            image.size_bytes = image_bytes.len(),
+        )
+    ))]
+    pub fn detect_script(&self, image_bytes: &[u8]) -> Result<Option<String>, OcrError> {
+        orientation::detect_script(image_bytes)
+    }
+
     pub fn clear_cache(&self) -> Result<(), OcrError> {
         self.cache.clear()
     }
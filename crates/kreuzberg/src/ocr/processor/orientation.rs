@@ -0,0 +1,129 @@
+//! Page orientation and script detection via Tesseract's OSD (orientation and
+//! script detection).
+//!
+//! OSD needs `osd.traineddata` next to the language models. When it is missing,
+//! or Tesseract finds too little text to decide, detection reports no result and
+//! callers fall back to their own heuristics.
+
+use super::execution::decode_image;
+use super::validation::resolve_tessdata_path;
+use crate::ocr::error::OcrError;
+use crate::types::OcrRotation;
+use kreuzberg_tesseract::{TessPageSegMode, TesseractAPI};
+use std::path::Path;
+
+/// OSD confidence below which the page is left as it is.
+///
+/// Tesseract's orientation confidence is unbounded; values under ~2 are
+/// frequently wrong on sparse or noisy pages.
+const MIN_OSD_CONFIDENCE: f32 = 2.0;
+
+/// OSD script confidence below which the script is reported as unknown.
+const MIN_SCRIPT_CONFIDENCE: f32 = 1.0;
+
+/// Result of a Tesseract OSD pass.
+struct Osd {
+    orientation: i32,
+    orientation_confidence: f32,
+    script: String,
+    script_confidence: f32,
+}
+
+/// Detect page orientation with Tesseract OSD using the resolved tessdata directory.
+pub(super) fn detect_orientation(image_bytes: &[u8]) -> Result<Option<OcrRotation>, OcrError> {
+    detect_orientation_with_tessdata(image_bytes, &resolve_tessdata_path())
+}
+
+/// Detect the dominant script with Tesseract OSD using the resolved tessdata directory.
+pub(super) fn detect_script(image_bytes: &[u8]) -> Result<Option<String>, OcrError> {
+    Ok(run_osd(image_bytes, &resolve_tessdata_path())?
+        .filter(|osd| osd.script_confidence >= MIN_SCRIPT_CONFIDENCE && !osd.script.is_empty())
+        .map(|osd| osd.script))
+}
+
+fn detect_orientation_with_tessdata(image_bytes: &[u8], tessdata_path: &str) -> Result<Option<OcrRotation>, OcrError> {
+    Ok(run_osd(image_bytes, tessdata_path)?.map(|osd| osd_rotation(osd.orientation, osd.orientation_confidence)))
+}
+
+fn run_osd(image_bytes: &[u8], tessdata_path: &str) -> Result<Option<Osd>, OcrError> {
+    // Initializing with a missing traineddata file can segfault ~keep
+    if tessdata_path.is_empty() || !Path::new(tessdata_path).join("osd.traineddata").exists() {
+        tracing::debug!("osd.traineddata not found, skipping Tesseract OSD");
+        return Ok(None);
+    }
+
+    let rgb_image = decode_image(image_bytes)?.to_rgb8();
+    let (width, height) = rgb_image.dimensions();
+
+    let api = TesseractAPI::new();
+    api.init(tessdata_path, "osd")
+        .map_err(|e| OcrError::TesseractInitializationFailed(format!("Failed to initialize OSD: {}", e)))?;
+    api.set_page_seg_mode(TessPageSegMode::PSM_OSD_ONLY)
+        .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to set PSM mode: {}", e)))?;
+    api.set_image(rgb_image.as_raw(), width as i32, height as i32, 3, 3 * width as i32)
+        .map_err(|e| OcrError::ProcessingFailed(format!("Failed to set image: {}", e)))?;
+
+    match api.detect_os() {
+        Ok((orientation, orientation_confidence, script, script_confidence)) => {
+            tracing::debug!(
+                orientation,
+                orientation_confidence,
+                script,
+                script_confidence,
+                "Tesseract OSD completed"
+            );
+            Ok(Some(Osd {
+                orientation,
+                orientation_confidence,
+                script,
+                script_confidence,
+            }))
+        }
+        Err(e) => {
+            // OSD fails on pages with too few characters ~keep
+            tracing::debug!("Tesseract OSD found no orientation or script: {}", e);
+            Ok(None)
+        }
+    }
+}
+
+/// Convert OSD output into the clockwise rotation that makes the page upright.
+fn osd_rotation(orientation: i32, confidence: f32) -> OcrRotation {
+    let angle_degrees = if confidence < MIN_OSD_CONFIDENCE {
+        0
+    } else {
+        (360 - orientation.rem_euclid(360)) % 360
+    };
+
+    OcrRotation {
+        angle_degrees: angle_degrees as f64,
+        confidence: Some(confidence as f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osd_rotation_inverts_detected_orientation() {
+        assert_eq!(osd_rotation(0, 10.0).angle_degrees, 0.0);
+        assert_eq!(osd_rotation(90, 10.0).angle_degrees, 270.0);
+        assert_eq!(osd_rotation(180, 10.0).angle_degrees, 180.0);
+        assert_eq!(osd_rotation(270, 10.0).angle_degrees, 90.0);
+    }
+
+    #[test]
+    fn test_osd_rotation_ignores_low_confidence() {
+        let rotation = osd_rotation(180, 0.4);
+        assert_eq!(rotation.angle_degrees, 0.0);
+        assert_eq!(rotation.confidence, Some(0.4f32 as f64));
+    }
+
+    #[test]
+    fn test_detect_orientation_without_osd_data() {
+        let tessdata = tempfile::tempdir().unwrap();
+        let result = detect_orientation_with_tessdata(b"not an image", tessdata.path().to_str().unwrap());
+        assert!(matches!(result, Ok(None)));
+    }
+}
@@ -0,0 +1,400 @@
+//! Table reconstruction from OCR element geometry.
+//!
+//! Backend-agnostic counterpart of the Tesseract TSV path: it only needs the
+//! bounding geometry of [`OcrElement`]s, so PaddleOCR (quadrilaterals) and custom
+//! backends get tables as well.
+//!
+//! 1. Word elements (line elements for line-level backends) are clustered into
+//!    rows by their vertical center.
+//! 2. Within a row, elements separated by less than a text height are joined into
+//!    one cell fragment.
+//! 3. Runs of consecutive rows with at least `min_columns` fragments form a table.
+//! 4. Columns are the merged horizontal extents of the fragments in the rows with
+//!    the most common fragment count; every fragment goes to the column it
+//!    overlaps most, so headers spanning several columns do not split the grid.
+
+use super::table_to_markdown;
+use crate::core::config::OcrTableConfig;
+use crate::types::{OcrElement, OcrElementLevel, Table};
+use crate::utils::clustering::cluster_objects_by;
+
+/// Rows are elements whose vertical centers lie within this share of the median height.
+const ROW_TOLERANCE: f64 = 0.5;
+
+/// Horizontal gap (in median text heights) up to which elements belong to one cell.
+const CELL_GAP: f64 = 1.0;
+
+/// Mean cell length above which a "table" is taken to be columns of running text.
+const MAX_MEAN_CELL_CHARS: f64 = 40.0;
+
+/// An element or a run of elements within a row.
+#[derive(Debug, Clone)]
+struct Fragment {
+    text: String,
+    x0: f64,
+    x1: f64,
+    top: f64,
+    bottom: f64,
+}
+
+impl Fragment {
+    fn from_element(element: &OcrElement) -> Self {
+        let (left, top, width, height) = element.geometry.to_aabb();
+        Self {
+            text: element.text.trim().to_string(),
+            x0: left as f64,
+            x1: (left + width) as f64,
+            top: top as f64,
+            bottom: (top + height) as f64,
+        }
+    }
+
+    fn center_y(&self) -> f64 {
+        (self.top + self.bottom) / 2.0
+    }
+
+    fn height(&self) -> f64 {
+        self.bottom - self.top
+    }
+
+    fn overlap(&self, (x0, x1): (f64, f64)) -> f64 {
+        (self.x1.min(x1) - self.x0.max(x0)).max(0.0)
+    }
+}
+
+/// Reconstruct the tables of OCR'd pages from their elements.
+///
+/// Elements of different pages are handled separately; every table takes the
+/// page number of its elements. Returns no tables when the elements carry no
+/// positions that line up in rows and columns.
+pub fn reconstruct_tables_from_elements(elements: &[OcrElement], config: &OcrTableConfig) -> Vec<Table> {
+    let mut page_numbers: Vec<usize> = elements.iter().map(|element| element.page_number).collect();
+    page_numbers.sort_unstable();
+    page_numbers.dedup();
+
+    page_numbers
+        .into_iter()
+        .flat_map(|page_number| {
+            let page_elements: Vec<&OcrElement> = elements
+                .iter()
+                .filter(|element| element.page_number == page_number)
+                .collect();
+            page_tables(&page_elements, page_number, config)
+        })
+        .collect()
+}
+
+fn page_tables(elements: &[&OcrElement], page_number: usize, config: &OcrTableConfig) -> Vec<Table> {
+    let usable: Vec<&OcrElement> = elements
+        .iter()
+        .copied()
+        .filter(|element| element.confidence.recognition >= config.min_confidence && !element.text.trim().is_empty())
+        .collect();
+    let level = if usable.iter().any(|element| element.level == OcrElementLevel::Word) {
+        OcrElementLevel::Word
+    } else {
+        OcrElementLevel::Line
+    };
+    let fragments: Vec<Fragment> = usable
+        .iter()
+        .filter(|element| element.level == level)
+        .map(|element| Fragment::from_element(element))
+        .filter(|fragment| fragment.height() > 0.0)
+        .collect();
+    if fragments.len() < config.min_rows.max(1) * config.min_columns.max(1) {
+        return Vec::new();
+    }
+
+    let text_height = median(fragments.iter().map(Fragment::height).collect());
+    let rows = rows(&fragments, text_height);
+
+    let mut tables = Vec::new();
+    let mut start = 0;
+    while start < rows.len() {
+        if rows[start].len() < config.min_columns.max(2) {
+            start += 1;
+            continue;
+        }
+        let end = (start..rows.len())
+            .find(|&idx| rows[idx].len() < config.min_columns.max(2))
+            .unwrap_or(rows.len());
+        if end - start >= config.min_rows.max(1)
+            && let Some(table) = build_table(&rows[start..end], page_number, config)
+        {
+            tables.push(table);
+        }
+        start = end;
+    }
+    tables
+}
+
+/// Group fragments into rows (top to bottom) of cell fragments (left to right).
+fn rows(fragments: &[Fragment], text_height: f64) -> Vec<Vec<Fragment>> {
+    let centers: Vec<f64> = fragments.iter().map(Fragment::center_y).collect();
+    cluster_objects_by(&centers, |center| center, text_height * ROW_TOLERANCE)
+        .into_iter()
+        .map(|indices| {
+            let mut row: Vec<&Fragment> = indices.iter().map(|&idx| &fragments[idx]).collect();
+            row.sort_by(|a, b| a.x0.total_cmp(&b.x0));
+
+            let mut cells: Vec<Fragment> = Vec::new();
+            for fragment in row {
+                match cells.last_mut() {
+                    Some(cell) if fragment.x0 - cell.x1 <= text_height * CELL_GAP => {
+                        cell.text.push(' ');
+                        cell.text.push_str(&fragment.text);
+                        cell.x1 = cell.x1.max(fragment.x1);
+                        cell.top = cell.top.min(fragment.top);
+                        cell.bottom = cell.bottom.max(fragment.bottom);
+                    }
+                    _ => cells.push(fragment.clone()),
+                }
+            }
+            cells
+        })
+        .collect()
+}
+
+fn build_table(rows: &[Vec<Fragment>], page_number: usize, config: &OcrTableConfig) -> Option<Table> {
+    let cell_count: usize = rows.iter().map(Vec::len).sum();
+    let mean_chars = rows
+        .iter()
+        .flatten()
+        .map(|cell| cell.text.chars().count())
+        .sum::<usize>() as f64
+        / cell_count as f64;
+    if mean_chars > MAX_MEAN_CELL_CHARS {
+        return None;
+    }
+
+    let columns = columns(rows);
+    if columns.len() < config.min_columns.max(2) {
+        return None;
+    }
+
+    let mut cells = vec![vec![String::new(); columns.len()]; rows.len()];
+    for (row_idx, row) in rows.iter().enumerate() {
+        for fragment in row {
+            let column = column_of(fragment, &columns);
+            let cell = &mut cells[row_idx][column];
+            if !cell.is_empty() {
+                cell.push(' ');
+            }
+            cell.push_str(&fragment.text);
+        }
+    }
+
+    Some(Table {
+        markdown: table_to_markdown(&cells),
+        cells,
+        page_number,
+        bounding_box: None,
+        end_page_number: None,
+        spans: vec![],
+    })
+}
+
+/// Column extents from the rows with the most common cell count (the widest on ties).
+fn columns(rows: &[Vec<Fragment>]) -> Vec<(f64, f64)> {
+    let mut counts: Vec<usize> = rows.iter().map(Vec::len).collect();
+    counts.sort_unstable();
+    let mut reference = 0;
+    let mut best_frequency = 0;
+    for count in counts.iter().copied() {
+        let frequency = counts.iter().filter(|&&other| other == count).count();
+        if frequency >= best_frequency {
+            best_frequency = frequency;
+            reference = count;
+        }
+    }
+
+    let mut extents: Vec<(f64, f64)> = rows
+        .iter()
+        .filter(|row| row.len() == reference)
+        .flatten()
+        .map(|fragment| (fragment.x0, fragment.x1))
+        .collect();
+    extents.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut columns: Vec<(f64, f64)> = Vec::new();
+    for (x0, x1) in extents {
+        match columns.last_mut() {
+            Some(column) if x0 < column.1 => column.1 = column.1.max(x1),
+            _ => columns.push((x0, x1)),
+        }
+    }
+    columns
+}
+
+/// Index of the column a fragment overlaps most (the nearest one without overlap).
+fn column_of(fragment: &Fragment, columns: &[(f64, f64)]) -> usize {
+    let center = (fragment.x0 + fragment.x1) / 2.0;
+    let distance = |&(x0, x1): &(f64, f64)| (center - (x0 + x1) / 2.0).abs();
+    columns
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| {
+            fragment
+                .overlap(**a)
+                .total_cmp(&fragment.overlap(**b))
+                .then_with(|| distance(b).total_cmp(&distance(a)))
+        })
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    values.get(values.len() / 2).copied().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrBoundingGeometry, OcrConfidence};
+
+    fn word(text: &str, left: u32, top: u32, width: u32) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Rectangle {
+                left,
+                top,
+                width,
+                height: 20,
+            },
+            OcrConfidence::from_tesseract(95.0),
+        )
+        .with_level(OcrElementLevel::Word)
+    }
+
+    fn line(text: &str, left: u32, top: u32, width: u32) -> OcrElement {
+        let (right, bottom) = (left + width, top + 20);
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Quadrilateral {
+                points: [(left, top), (right, top), (right, bottom), (left, bottom)],
+            },
+            OcrConfidence {
+                detection: Some(0.9),
+                recognition: 0.9,
+            },
+        )
+        .with_level(OcrElementLevel::Line)
+    }
+
+    #[test]
+    fn test_reconstructs_word_grid() {
+        let elements = vec![
+            word("Item", 100, 100, 60),
+            word("Unit", 400, 102, 60),
+            word("price", 470, 102, 70),
+            word("Qty", 700, 99, 50),
+            word("Apples", 100, 150, 90),
+            word("1.20", 420, 151, 60),
+            word("3", 710, 150, 20),
+            word("Pears", 100, 200, 80),
+            word("0.80", 420, 200, 60),
+            word("12", 705, 201, 30),
+        ];
+        let tables = reconstruct_tables_from_elements(&elements, &OcrTableConfig::default());
+        assert_eq!(tables.len(), 1);
+        assert_eq!(
+            tables[0].cells,
+            vec![
+                vec!["Item", "Unit price", "Qty"],
+                vec!["Apples", "1.20", "3"],
+                vec!["Pears", "0.80", "12"],
+            ]
+        );
+        assert_eq!(tables[0].page_number, 1);
+        assert!(tables[0].markdown.contains("Unit price"));
+    }
+
+    #[test]
+    fn test_reconstructs_quadrilateral_lines_and_spanning_header() {
+        let elements = vec![
+            line("Quarterly results", 100, 50, 500),
+            line("Region", 100, 100, 100),
+            line("Revenue", 400, 100, 120),
+            line("North", 100, 150, 80),
+            line("1,200", 430, 150, 90),
+            line("South", 100, 200, 80),
+            line("950", 460, 200, 60),
+        ];
+        let tables = reconstruct_tables_from_elements(&elements, &OcrTableConfig::default());
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].cells.len(), 3);
+        assert_eq!(tables[0].cells[2], vec!["South", "950"]);
+    }
+
+    #[test]
+    fn test_ignores_running_text() {
+        let elements = vec![
+            line(
+                "This paragraph is a long line of running text in the left column",
+                100,
+                100,
+                800,
+            ),
+            line(
+                "and this one continues the right column with more running text",
+                1000,
+                100,
+                800,
+            ),
+            line(
+                "another long line of prose in the left column of the page layout",
+                100,
+                130,
+                800,
+            ),
+            line(
+                "another long line of prose in the right column of the page layout",
+                1000,
+                130,
+                800,
+            ),
+        ];
+        assert!(reconstruct_tables_from_elements(&elements, &OcrTableConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_separates_pages_and_skips_single_column_rows() {
+        let mut elements = vec![
+            word("Heading", 100, 10, 200),
+            word("a", 100, 100, 20),
+            word("b", 400, 100, 20),
+            word("c", 100, 150, 20),
+            word("d", 400, 150, 20),
+        ];
+        elements.extend(
+            [word("e", 100, 100, 20), word("f", 400, 100, 20)]
+                .into_iter()
+                .map(|element| element.with_page_number(2)),
+        );
+        let tables = reconstruct_tables_from_elements(&elements, &OcrTableConfig::default());
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].cells, vec![vec!["a", "b"], vec!["c", "d"]]);
+
+        let single_row = OcrTableConfig {
+            min_rows: 1,
+            ..Default::default()
+        };
+        let tables = reconstruct_tables_from_elements(&elements, &single_row);
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[1].page_number, 2);
+    }
+
+    #[test]
+    fn test_low_confidence_elements_are_ignored() {
+        let mut elements = vec![
+            word("a", 100, 100, 20),
+            word("b", 400, 100, 20),
+            word("c", 100, 150, 20),
+            word("d", 400, 150, 20),
+        ];
+        elements[3].confidence.recognition = 0.1;
+        let tables = reconstruct_tables_from_elements(&elements, &OcrTableConfig::default());
+        assert!(tables.is_empty());
+    }
+}
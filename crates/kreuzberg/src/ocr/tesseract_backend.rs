@@ -7,7 +7,7 @@ use crate::Result;
 use crate::core::config::OcrConfig;
 use crate::ocr::processor::OcrProcessor;
 use crate::plugins::{OcrBackend, OcrBackendType, Plugin};
-use crate::types::ExtractionResult;
+use crate::types::{ExtractionResult, OcrRotation};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
@@ -54,6 +54,21 @@ impl TesseractBackend {
         })
     }
 
+    /// Processor caching into `config.cache`, or this backend's own processor when unset.
+    fn processor_for(&self, config: &OcrConfig) -> Result<Arc<OcrProcessor>> {
+        match &config.cache {
+            Some(cache_config) => {
+                let processor =
+                    OcrProcessor::with_cache_config(Some(cache_config)).map_err(|e| crate::KreuzbergError::Ocr {
+                        message: format!("Failed to open OCR cache: {}", e),
+                        source: Some(Box::new(e)),
+                    })?;
+                Ok(Arc::new(processor))
+            }
+            None => Ok(Arc::clone(&self.processor)),
+        }
+    }
+
     /// Convert public API TesseractConfig to internal TesseractConfig.
     ///
     /// The public API types (crate::types) use i32 for compatibility with PyO3,
@@ -181,7 +196,7 @@ impl OcrBackend for TesseractBackend {
         let tess_config_clone = tess_config.clone();
         let output_format = config.output_format;
 
-        let processor = Arc::clone(&self.processor);
+        let processor = self.processor_for(config)?;
         let image_bytes = image_bytes.to_vec();
 
         let ocr_result = tokio::task::spawn_blocking(move || match output_format {
@@ -241,6 +256,8 @@ impl OcrBackend for TesseractBackend {
                     markdown: t.markdown,
                     page_number: t.page_number,
                     bounding_box: None,
+                    end_page_number: None,
+                    spans: vec![],
                 })
                 .collect(),
             detected_languages: None,
@@ -262,7 +279,7 @@ impl OcrBackend for TesseractBackend {
         let tess_config_clone = tess_config.clone();
         let output_format = config.output_format;
 
-        let processor = Arc::clone(&self.processor);
+        let processor = self.processor_for(config)?;
         let path_str = path.to_string_lossy().to_string();
 
         let ocr_result = tokio::task::spawn_blocking(move || match output_format {
@@ -322,6 +339,8 @@ impl OcrBackend for TesseractBackend {
                     markdown: t.markdown,
                     page_number: t.page_number,
                     bounding_box: None,
+                    end_page_number: None,
+                    spans: vec![],
                 })
                 .collect(),
             detected_languages: None,
@@ -338,6 +357,38 @@ impl OcrBackend for TesseractBackend {
         })
     }
 
+    async fn detect_orientation(&self, image_bytes: &[u8], _config: &OcrConfig) -> Result<Option<OcrRotation>> {
+        let processor = Arc::clone(&self.processor);
+        let image_bytes = image_bytes.to_vec();
+
+        tokio::task::spawn_blocking(move || processor.detect_orientation(&image_bytes))
+            .await
+            .map_err(|e| crate::KreuzbergError::Plugin {
+                message: format!("Tesseract task panicked: {}", e),
+                plugin_name: "tesseract".to_string(),
+            })?
+            .map_err(|e| crate::KreuzbergError::Ocr {
+                message: format!("Tesseract orientation detection failed: {}", e),
+                source: Some(Box::new(e)),
+            })
+    }
+
+    async fn detect_script(&self, image_bytes: &[u8], _config: &OcrConfig) -> Result<Option<String>> {
+        let processor = Arc::clone(&self.processor);
+        let image_bytes = image_bytes.to_vec();
+
+        tokio::task::spawn_blocking(move || processor.detect_script(&image_bytes))
+            .await
+            .map_err(|e| crate::KreuzbergError::Plugin {
+                message: format!("Tesseract task panicked: {}", e),
+                plugin_name: "tesseract".to_string(),
+            })?
+            .map_err(|e| crate::KreuzbergError::Ocr {
+                message: format!("Tesseract script detection failed: {}", e),
+                source: Some(Box::new(e)),
+            })
+    }
+
     fn supports_language(&self, lang: &str) -> bool {
         self.get_cached_languages().contains(&lang.to_string())
     }
@@ -458,6 +509,7 @@ mod tests {
             deskew: true,
             denoise: true,
             contrast_enhance: true,
+            binarize: true,
             binarization_method: "adaptive".to_string(),
             invert_colors: false,
         };
@@ -1,4 +1,4 @@
-use ahash::AHasher;
+use crate::cache::StableHasher;
 use std::hash::{Hash, Hasher};
 
 /// Minimal supported Tesseract version
@@ -13,7 +13,7 @@ pub const MIN_COLUMN_WIDTH: usize = 3;
 
 /// Compute a hash string from input data
 pub fn compute_hash(data: &str) -> String {
-    let mut hasher = AHasher::default();
+    let mut hasher = StableHasher::new();
     data.hash(&mut hasher);
     let hash = hasher.finish();
     format!("{:016x}", hash)
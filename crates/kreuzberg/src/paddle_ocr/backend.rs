@@ -17,10 +17,10 @@ use std::sync::{Arc, Mutex};
 
 use crate::Result;
 use crate::core::config::OcrConfig;
-use crate::ocr::conversion::{elements_to_hocr_words, text_block_to_element};
-use crate::ocr::table::{reconstruct_table, table_to_markdown};
+use crate::ocr::conversion::text_block_to_element;
+use crate::ocr::table::reconstruct_tables_from_elements;
 use crate::plugins::{OcrBackend, OcrBackendType, Plugin};
-use crate::types::{ExtractionResult, FormatMetadata, Metadata, OcrElement, OcrMetadata, Table};
+use crate::types::{ExtractionResult, FormatMetadata, Metadata, OcrElement, OcrMetadata, OcrRotation};
 
 use super::config::PaddleOcrConfig;
 use super::model_manager::{ModelManager, SharedModelPaths};
@@ -242,6 +242,59 @@ impl PaddleOcrBackend {
         Ok((text, ocr_elements))
     }
 
+    /// Resolve the effective PaddleOCR configuration for a request.
+    fn effective_config(&self, config: &OcrConfig) -> Result<Arc<PaddleOcrConfig>> {
+        match config.paddle_ocr_config {
+            Some(ref paddle_json) => {
+                let overridden: PaddleOcrConfig =
+                    serde_json::from_value(paddle_json.clone()).map_err(|e| crate::KreuzbergError::Validation {
+                        message: format!("Failed to deserialize paddle_ocr_config: {}", e),
+                        source: None,
+                    })?;
+                Ok(Arc::new(overridden))
+            }
+            None => Ok(Arc::clone(&self.config)),
+        }
+    }
+
+    /// Detect page orientation with the detection and angle classification models.
+    fn perform_orientation_detection(
+        image_bytes: &[u8],
+        ocr_engine: &Arc<Mutex<OcrLite>>,
+        config: &PaddleOcrConfig,
+    ) -> Result<Option<OcrRotation>> {
+        let img = image::load_from_memory(image_bytes)
+            .map_err(|e| crate::KreuzbergError::Ocr {
+                message: format!("Failed to decode image: {}", e),
+                source: None,
+            })?
+            .to_rgb8();
+
+        let mut engine_guard = ocr_engine.lock().map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("Failed to acquire OCR engine lock: {}", e),
+            plugin_name: "paddle-ocr".to_string(),
+        })?;
+
+        let orientation = engine_guard
+            .detect_orientation(
+                &img,
+                config.padding,
+                config.det_limit_side_len,
+                config.det_db_thresh,
+                config.det_db_box_thresh,
+                config.det_db_unclip_ratio,
+            )
+            .map_err(|e| crate::KreuzbergError::Ocr {
+                message: format!("PaddleOCR orientation detection failed: {}", e),
+                source: None,
+            })?;
+
+        Ok(orientation.map(|(angle, agreement)| OcrRotation {
+            angle_degrees: angle as f64,
+            confidence: Some((agreement as f64).clamp(0.0, 1.0)),
+        }))
+    }
+
     /// Perform actual OCR inference (runs in blocking context).
     fn perform_ocr(
         image_bytes: &[u8],
@@ -322,16 +375,7 @@ impl OcrBackend for PaddleOcrBackend {
             });
         }
 
-        let effective_config: Arc<PaddleOcrConfig> = if let Some(ref paddle_json) = config.paddle_ocr_config {
-            let overridden: PaddleOcrConfig =
-                serde_json::from_value(paddle_json.clone()).map_err(|e| crate::KreuzbergError::Validation {
-                    message: format!("Failed to deserialize paddle_ocr_config: {}", e),
-                    source: None,
-                })?;
-            Arc::new(overridden)
-        } else {
-            Arc::clone(&self.config)
-        };
+        let effective_config = self.effective_config(config)?;
 
         // Map language code to PaddleOCR language, then use it for engine selection
         let paddle_lang = map_language_code(&config.language).unwrap_or("en");
@@ -341,33 +385,18 @@ impl OcrBackend for PaddleOcrBackend {
             .await?;
 
         // Table detection
-        let mut tables: Vec<Table> = vec![];
-        let mut table_count = 0;
-        let mut table_rows: Option<usize> = None;
-        let mut table_cols: Option<usize> = None;
-
-        if effective_config.enable_table_detection && !ocr_elements.is_empty() {
-            let words = elements_to_hocr_words(&ocr_elements, 0.3);
-
-            if !words.is_empty() {
-                let cells = reconstruct_table(&words, 20, 0.5);
-
-                if !cells.is_empty() {
-                    table_count = 1;
-                    table_rows = Some(cells.len());
-                    table_cols = cells.first().map(|row| row.len());
-
-                    let table_markdown = table_to_markdown(&cells);
-
-                    tables.push(Table {
-                        cells,
-                        markdown: table_markdown,
-                        page_number: 1,
-                        bounding_box: None,
-                    });
-                }
-            }
-        }
+        let tables = if effective_config.enable_table_detection && !ocr_elements.is_empty() {
+            let table_config = config.table_detection.clone().unwrap_or_default();
+            reconstruct_tables_from_elements(&ocr_elements, &table_config)
+        } else {
+            vec![]
+        };
+        let table_count = tables.len();
+        let table_rows = tables.first().map(|table| table.cells.len());
+        let table_cols = tables
+            .first()
+            .and_then(|table| table.cells.first())
+            .map(|row| row.len());
 
         let mut additional = AHashMap::new();
         additional.insert(Cow::Borrowed("backend"), serde_json::json!("paddle-ocr"));
@@ -385,7 +414,8 @@ impl OcrBackend for PaddleOcrBackend {
             ..Default::default()
         };
 
-        let include_elements = config.element_config.as_ref().is_some_and(|ec| ec.include_elements);
+        let include_elements = config.element_config.as_ref().is_some_and(|ec| ec.include_elements)
+            || config.output_format.is_some_and(|fmt| fmt.requires_ocr_elements());
 
         let ocr_elements_opt = if include_elements && !ocr_elements.is_empty() {
             Some(ocr_elements)
@@ -418,6 +448,28 @@ impl OcrBackend for PaddleOcrBackend {
         self.process_image(&bytes, config).await
     }
 
+    async fn detect_orientation(&self, image_bytes: &[u8], config: &OcrConfig) -> Result<Option<OcrRotation>> {
+        let effective_config = self.effective_config(config)?;
+        let paddle_lang = map_language_code(&config.language).unwrap_or("en");
+        let engine = self.get_or_init_engine_for_family(language_to_script_family(paddle_lang))?;
+        let image_bytes = image_bytes.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            catch_unwind(std::panic::AssertUnwindSafe(|| {
+                Self::perform_orientation_detection(&image_bytes, &engine, &effective_config)
+            }))
+            .map_err(|_| crate::KreuzbergError::Plugin {
+                message: "PaddleOCR inference panicked (ONNX Runtime error)".to_string(),
+                plugin_name: "paddle-ocr".to_string(),
+            })?
+        })
+        .await
+        .map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("PaddleOCR task panicked: {}", e),
+            plugin_name: "paddle-ocr".to_string(),
+        })?
+    }
+
     fn supports_language(&self, lang: &str) -> bool {
         is_language_supported(lang) || map_language_code(lang).is_some()
     }
@@ -0,0 +1,198 @@
+//! PDF page annotation extraction.
+//!
+//! Reads the annotation layer that sits on top of the page content: reviewer
+//! comments, highlights and other text markup, sticky notes, stamps and link
+//! areas. For text markup annotations the covered page text is read from the
+//! quad points, which follow the marked lines more tightly than the annotation
+//! rectangle.
+
+use super::error::{PdfError, Result};
+use super::metadata::parse_pdf_date;
+use crate::types::{BoundingBox, PdfAnnotation, PdfAnnotationType};
+use pdfium_render::prelude::*;
+
+/// Extract the annotations of all pages in a document.
+pub fn extract_annotations_from_document(document: &PdfDocument<'_>) -> Result<Vec<PdfAnnotation>> {
+    let mut annotations = Vec::new();
+    for (page_index, page) in document.pages().iter().enumerate() {
+        annotations.extend(extract_page_annotations(&page, page_index + 1)?);
+    }
+    Ok(annotations)
+}
+
+/// Extract the annotations attached to a single page.
+///
+/// Popups and form field widgets are skipped; see [`PdfAnnotationType`].
+pub fn extract_page_annotations(page: &PdfPage<'_>, page_number: usize) -> Result<Vec<PdfAnnotation>> {
+    let page_annotations = page.annotations();
+    if page_annotations.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let text = page
+        .text()
+        .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
+
+    let mut annotations = Vec::with_capacity(page_annotations.len());
+    for annotation in page_annotations.iter() {
+        let Some(annotation_type) = map_annotation_type(annotation.annotation_type()) else {
+            continue;
+        };
+
+        let bounds = annotation.bounds().ok();
+        let highlighted_text = if annotation_type.is_text_markup() {
+            covered_text(&text, &annotation, bounds)
+        } else {
+            None
+        };
+        let (url, target_page) = annotation.as_link_annotation().map(link_target).unwrap_or_default();
+
+        annotations.push(PdfAnnotation {
+            page_number,
+            annotation_type,
+            author: non_empty(annotation.creator()),
+            created_at: non_empty(annotation.creation_date()).map(|date| parse_pdf_date(&date)),
+            modified_at: non_empty(annotation.modification_date()).map(|date| parse_pdf_date(&date)),
+            contents: non_empty(annotation.contents()),
+            highlighted_text,
+            url,
+            target_page,
+            bounding_box: bounds.map(|rect| BoundingBox {
+                x0: rect.left().value as f64,
+                y0: rect.bottom().value as f64,
+                x1: rect.right().value as f64,
+                y1: rect.top().value as f64,
+            }),
+        });
+    }
+
+    Ok(annotations)
+}
+
+fn map_annotation_type(annotation_type: PdfPageAnnotationType) -> Option<PdfAnnotationType> {
+    let mapped = match annotation_type {
+        PdfPageAnnotationType::Popup | PdfPageAnnotationType::Widget | PdfPageAnnotationType::XfaWidget => {
+            return None;
+        }
+        PdfPageAnnotationType::Text => PdfAnnotationType::Text,
+        PdfPageAnnotationType::FreeText => PdfAnnotationType::FreeText,
+        PdfPageAnnotationType::Highlight => PdfAnnotationType::Highlight,
+        PdfPageAnnotationType::Underline => PdfAnnotationType::Underline,
+        PdfPageAnnotationType::Squiggly => PdfAnnotationType::Squiggly,
+        PdfPageAnnotationType::Strikeout => PdfAnnotationType::Strikeout,
+        PdfPageAnnotationType::Link => PdfAnnotationType::Link,
+        PdfPageAnnotationType::Stamp => PdfAnnotationType::Stamp,
+        PdfPageAnnotationType::Ink => PdfAnnotationType::Ink,
+        PdfPageAnnotationType::Square => PdfAnnotationType::Square,
+        PdfPageAnnotationType::Circle => PdfAnnotationType::Circle,
+        PdfPageAnnotationType::Line => PdfAnnotationType::Line,
+        PdfPageAnnotationType::Polygon => PdfAnnotationType::Polygon,
+        PdfPageAnnotationType::Polyline => PdfAnnotationType::Polyline,
+        PdfPageAnnotationType::Caret => PdfAnnotationType::Caret,
+        PdfPageAnnotationType::FileAttachment => PdfAnnotationType::FileAttachment,
+        PdfPageAnnotationType::Redacted => PdfAnnotationType::Redact,
+        _ => PdfAnnotationType::Other,
+    };
+    Some(mapped)
+}
+
+/// Page text under a text markup annotation.
+///
+/// Each quad covers one marked line; annotations without quad points fall back
+/// to their rectangle.
+fn covered_text(text: &PdfPageText<'_>, annotation: &PdfPageAnnotation<'_>, bounds: Option<PdfRect>) -> Option<String> {
+    let attachment_points = annotation.attachment_points();
+    let fragments: Vec<String> = if attachment_points.is_empty() {
+        bounds.map(|rect| text.inside_rect(rect)).into_iter().collect()
+    } else {
+        attachment_points
+            .iter()
+            .map(|quad| text.inside_rect(quad.to_rect()))
+            .collect()
+    };
+    join_fragments(&fragments)
+}
+
+/// Join per-line text fragments, collapsing the line breaks and runs of
+/// whitespace pdfium reports inside the bounded text.
+fn join_fragments(fragments: &[String]) -> Option<String> {
+    let joined = fragments
+        .iter()
+        .flat_map(|fragment| fragment.split_whitespace())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if joined.is_empty() { None } else { Some(joined) }
+}
+
+/// URI or internal target page (1-indexed) of a link annotation.
+fn link_target(annotation: &PdfPageLinkAnnotation<'_>) -> (Option<String>, Option<usize>) {
+    let Ok(link) = annotation.link() else {
+        return (None, None);
+    };
+
+    let action = link.action();
+    if let Some(uri) = action.as_ref().and_then(|action| action.as_uri_action())
+        && let Ok(url) = uri.uri()
+        && !url.is_empty()
+    {
+        return (Some(url), None);
+    }
+
+    let destination = link.destination().or_else(|| {
+        action
+            .as_ref()
+            .and_then(|action| action.as_local_destination_action())
+            .and_then(|local| local.destination().ok())
+    });
+    let target_page = destination
+        .and_then(|destination| destination.page_index().ok())
+        .and_then(|index| usize::try_from(index).ok())
+        .map(|index| index + 1);
+
+    (None, target_page)
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_annotation_type_skips_popups_and_widgets() {
+        assert_eq!(map_annotation_type(PdfPageAnnotationType::Popup), None);
+        assert_eq!(map_annotation_type(PdfPageAnnotationType::Widget), None);
+        assert_eq!(map_annotation_type(PdfPageAnnotationType::XfaWidget), None);
+        assert_eq!(
+            map_annotation_type(PdfPageAnnotationType::Text),
+            Some(PdfAnnotationType::Text)
+        );
+        assert_eq!(
+            map_annotation_type(PdfPageAnnotationType::Redacted),
+            Some(PdfAnnotationType::Redact)
+        );
+        assert_eq!(
+            map_annotation_type(PdfPageAnnotationType::Sound),
+            Some(PdfAnnotationType::Other)
+        );
+    }
+
+    #[test]
+    fn test_join_fragments_collapses_whitespace() {
+        let fragments = vec!["quarterly\r\n".to_string(), "  revenue  grew ".to_string()];
+        assert_eq!(join_fragments(&fragments).as_deref(), Some("quarterly revenue grew"));
+        assert_eq!(join_fragments(&[" \n".to_string()]), None);
+        assert_eq!(join_fragments(&[]), None);
+    }
+
+    #[test]
+    fn test_non_empty_trims() {
+        assert_eq!(non_empty(Some("  Alice ".to_string())).as_deref(), Some("Alice"));
+        assert_eq!(non_empty(Some("   ".to_string())), None);
+        assert_eq!(non_empty(None), None);
+    }
+}
@@ -0,0 +1,376 @@
+//! Word and line geometry of native PDF text.
+//!
+//! OCR backends report positioned [`OcrElement`]s, while the text layer of a
+//! digital PDF only yields strings. This module builds the same elements from
+//! a page's characters, so highlight overlays and citation UIs work alike for
+//! digital and scanned documents. Geometry is in PDF points measured from the
+//! top-left corner of the page, i.e. the pixel grid of a page rendered at 72 DPI.
+
+use super::error::Result;
+use super::furniture::{furniture_on_page, is_on_furniture};
+use super::hierarchy::{CharData, extract_chars_with_font_names};
+use super::markdown::is_cjk_char;
+use crate::types::{OcrBoundingGeometry, OcrConfidence, OcrElement, OcrElementConfig, OcrElementLevel, PageFurniture};
+use pdfium_render::prelude::*;
+
+/// Value of the `backend` metadata key of elements built from the text layer.
+pub const TEXT_LAYER_BACKEND: &str = "pdf_text_layer";
+
+/// Maximum baseline difference between characters of one line, as a fraction of the font size.
+const BASELINE_TOLERANCE_FRACTION: f32 = 0.5;
+/// Horizontal gap that starts a new word, as a fraction of the font size.
+const WORD_GAP_FRACTION: f32 = 0.3;
+/// Horizontal gap that starts a new line on the same baseline (column gutters,
+/// table cells), as a multiple of the font size.
+const LINE_GAP_FRACTION: f32 = 2.0;
+
+/// A word built from the characters of the text layer, in PDF coordinates.
+#[derive(Debug, Clone)]
+struct TextWord {
+    text: String,
+    x0: f32,
+    y0: f32,
+    x1: f32,
+    y1: f32,
+    baseline_y: f32,
+    font_name: String,
+    font_size: f32,
+    is_bold: bool,
+    is_italic: bool,
+}
+
+/// Build word and line elements for every page of `document`.
+///
+/// Characters on `furniture` lines are skipped, so the elements cover the same
+/// text as the extracted content when running headers and footers are stripped.
+/// Which levels are emitted follows `config.min_level`: `Word` yields lines and
+/// their words, `Line` only lines. Blocks and pages are not built from the text layer.
+pub fn extract_text_elements(
+    document: &PdfDocument<'_>,
+    config: &OcrElementConfig,
+    furniture: &[PageFurniture],
+) -> Result<Vec<OcrElement>> {
+    let mut elements = Vec::new();
+    for (page_idx, page) in document.pages().iter().enumerate() {
+        let page_number = page_idx + 1;
+        let mut chars = extract_chars_with_font_names(&page)?;
+
+        let page_furniture = furniture_on_page(furniture, page_number);
+        if !page_furniture.is_empty() {
+            chars.retain(|(ch, _)| !is_on_furniture(&page_furniture, ch.x + ch.width / 2.0, ch.baseline_y));
+        }
+
+        elements.extend(page_elements(&chars, page_number, page.height().value, config));
+    }
+    Ok(elements)
+}
+
+/// Build the elements of one page from its characters in content-stream order.
+fn page_elements(
+    chars: &[(CharData, String)],
+    page_number: usize,
+    page_height: f32,
+    config: &OcrElementConfig,
+) -> Vec<OcrElement> {
+    let include_words = config.min_level == OcrElementLevel::Word;
+    let include_lines = matches!(config.min_level, OcrElementLevel::Word | OcrElementLevel::Line);
+    if !include_lines {
+        return Vec::new();
+    }
+
+    let words = chars_to_words(chars);
+    let mut elements = Vec::new();
+    for (line_idx, line) in words_to_lines(&words).into_iter().enumerate() {
+        let line_num = line_idx + 1;
+        let line_word = merge_words(line);
+        elements.push(
+            element(&line_word, page_number, page_height)
+                .with_level(OcrElementLevel::Line)
+                .with_metadata("line_num", serde_json::json!(line_num)),
+        );
+
+        if include_words {
+            for (word_idx, word) in line.iter().enumerate() {
+                let mut word_element = element(word, page_number, page_height)
+                    .with_level(OcrElementLevel::Word)
+                    .with_metadata("line_num", serde_json::json!(line_num))
+                    .with_metadata("word_num", serde_json::json!(word_idx + 1));
+                if config.build_hierarchy {
+                    word_element = word_element.with_parent_id(format!("p{}_l{}", page_number, line_num));
+                }
+                elements.push(word_element);
+            }
+        }
+    }
+    elements
+}
+
+/// An element with the geometry and font of `word`.
+fn element(word: &TextWord, page_number: usize, page_height: f32) -> OcrElement {
+    let left = word.x0.max(0.0).floor();
+    let top = (page_height - word.y1).max(0.0).floor();
+    let right = word.x1.max(left).ceil();
+    let bottom = (page_height - word.y0).max(top).ceil();
+    let geometry = OcrBoundingGeometry::Rectangle {
+        left: left as u32,
+        top: top as u32,
+        width: (right - left) as u32,
+        height: (bottom - top) as u32,
+    };
+    let confidence = OcrConfidence {
+        detection: None,
+        recognition: 1.0,
+    };
+
+    OcrElement::new(word.text.clone(), geometry, confidence)
+        .with_page_number(page_number)
+        .with_metadata("backend", serde_json::json!(TEXT_LAYER_BACKEND))
+        .with_metadata("font_name", serde_json::json!(word.font_name))
+        .with_metadata("font_size", serde_json::json!((word.font_size * 100.0).round() / 100.0))
+        .with_metadata("bold", serde_json::json!(word.is_bold))
+        .with_metadata("italic", serde_json::json!(word.is_italic))
+}
+
+/// Split characters into words at whitespace, baseline changes, CJK characters
+/// and horizontal gaps.
+fn chars_to_words(chars: &[(CharData, String)]) -> Vec<TextWord> {
+    let mut words = Vec::new();
+    let mut current: Vec<&(CharData, String)> = Vec::new();
+
+    for entry in chars {
+        let ch = &entry.0;
+        if ch.text.trim().is_empty() || ch.text.chars().any(char::is_control) {
+            if !current.is_empty() {
+                words.push(finalize_word(&current));
+                current.clear();
+            }
+            continue;
+        }
+
+        if let Some((prev, _)) = current.last().copied() {
+            let is_cjk = prev.text.chars().any(is_cjk_char) || ch.text.chars().any(is_cjk_char);
+            if is_cjk || !continues_word(prev, ch) {
+                words.push(finalize_word(&current));
+                current.clear();
+            }
+        }
+        current.push(entry);
+    }
+    if !current.is_empty() {
+        words.push(finalize_word(&current));
+    }
+    words
+}
+
+/// Whether `ch` directly follows `prev` on the same baseline.
+fn continues_word(prev: &CharData, ch: &CharData) -> bool {
+    let min_font_size = prev.font_size.min(ch.font_size).max(1.0);
+    if (prev.baseline_y - ch.baseline_y).abs() >= BASELINE_TOLERANCE_FRACTION * min_font_size {
+        return false;
+    }
+    let avg_font_size = ((prev.font_size + ch.font_size) / 2.0).max(1.0);
+    let gap = ch.x - (prev.x + prev.width);
+    gap <= WORD_GAP_FRACTION * avg_font_size && gap >= -avg_font_size
+}
+
+fn finalize_word(chars: &[&(CharData, String)]) -> TextWord {
+    let count = chars.len() as f32;
+    let majority = chars.len() / 2;
+    TextWord {
+        text: chars.iter().map(|(c, _)| c.text.as_str()).collect(),
+        x0: chars.iter().map(|(c, _)| c.x).fold(f32::INFINITY, f32::min),
+        y0: chars.iter().map(|(c, _)| c.y).fold(f32::INFINITY, f32::min),
+        x1: chars
+            .iter()
+            .map(|(c, _)| c.x + c.width)
+            .fold(f32::NEG_INFINITY, f32::max),
+        y1: chars
+            .iter()
+            .map(|(c, _)| c.y + c.height)
+            .fold(f32::NEG_INFINITY, f32::max),
+        baseline_y: chars.iter().map(|(c, _)| c.baseline_y).sum::<f32>() / count,
+        font_name: most_common(chars.iter().map(|(_, font_name)| font_name.as_str())),
+        font_size: chars.iter().map(|(c, _)| c.font_size).sum::<f32>() / count,
+        is_bold: chars.iter().filter(|(c, _)| c.is_bold).count() > majority,
+        is_italic: chars.iter().filter(|(c, _)| c.is_italic).count() > majority,
+    }
+}
+
+/// Group consecutive words into lines. A word starts a new line when its
+/// baseline differs, when it starts left of the previous word, or after a wide gap.
+fn words_to_lines(words: &[TextWord]) -> Vec<&[TextWord]> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for idx in 1..words.len() {
+        let (prev, word) = (&words[idx - 1], &words[idx]);
+        let min_font_size = prev.font_size.min(word.font_size).max(1.0);
+        let same_baseline = (prev.baseline_y - word.baseline_y).abs() < BASELINE_TOLERANCE_FRACTION * min_font_size;
+        let gap = word.x0 - prev.x1;
+        if !same_baseline || gap < 0.0 || gap > LINE_GAP_FRACTION * min_font_size {
+            lines.push(&words[start..idx]);
+            start = idx;
+        }
+    }
+    if start < words.len() {
+        lines.push(&words[start..]);
+    }
+    lines
+}
+
+/// A line as a single word: the text of its words, their union box,
+/// and the font used by most of its words.
+fn merge_words(words: &[TextWord]) -> TextWord {
+    let majority = words.len() / 2;
+    TextWord {
+        text: join_words(words),
+        x0: words.iter().map(|w| w.x0).fold(f32::INFINITY, f32::min),
+        y0: words.iter().map(|w| w.y0).fold(f32::INFINITY, f32::min),
+        x1: words.iter().map(|w| w.x1).fold(f32::NEG_INFINITY, f32::max),
+        y1: words.iter().map(|w| w.y1).fold(f32::NEG_INFINITY, f32::max),
+        baseline_y: words.iter().map(|w| w.baseline_y).sum::<f32>() / words.len() as f32,
+        font_name: most_common(words.iter().map(|w| w.font_name.as_str())),
+        font_size: words.iter().map(|w| w.font_size).sum::<f32>() / words.len() as f32,
+        is_bold: words.iter().filter(|w| w.is_bold).count() > majority,
+        is_italic: words.iter().filter(|w| w.is_italic).count() > majority,
+    }
+}
+
+/// The most frequent value, the first one seen on ties.
+fn most_common<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for value in values {
+        match counts.iter_mut().find(|(v, _)| *v == value) {
+            Some(entry) => entry.1 += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+    let mut best: Option<(&str, usize)> = None;
+    for (value, count) in counts {
+        if best.is_none_or(|(_, best_count)| count > best_count) {
+            best = Some((value, count));
+        }
+    }
+    best.map(|(value, _)| value.to_string()).unwrap_or_default()
+}
+
+/// Words joined with spaces, except between CJK characters.
+fn join_words(words: &[TextWord]) -> String {
+    let mut text = String::new();
+    for word in words {
+        let cjk_boundary =
+            text.chars().next_back().is_some_and(is_cjk_char) && word.text.chars().next().is_some_and(is_cjk_char);
+        if !text.is_empty() && !cjk_boundary {
+            text.push(' ');
+        }
+        text.push_str(&word.text);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Characters of `text` set in a 10pt font, 6pt wide, starting at `x` on `baseline_y`.
+    fn chars(text: &str, x: f32, baseline_y: f32, font_name: &str, is_bold: bool) -> Vec<(CharData, String)> {
+        text.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                (
+                    CharData {
+                        text: c.to_string(),
+                        x: x + i as f32 * 6.0,
+                        y: baseline_y - 2.0,
+                        font_size: 10.0,
+                        width: 6.0,
+                        height: 10.0,
+                        is_bold,
+                        is_italic: false,
+                        baseline_y,
+                    },
+                    font_name.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn config(min_level: OcrElementLevel) -> OcrElementConfig {
+        OcrElementConfig {
+            include_elements: true,
+            min_level,
+            min_confidence: 0.0,
+            build_hierarchy: true,
+        }
+    }
+
+    #[test]
+    fn test_words_and_lines_with_geometry_and_fonts() {
+        let mut page = chars("Total due", 72.0, 700.0, "Helvetica", false);
+        page.extend(chars("42", 200.0, 700.0, "Helvetica-Bold", true));
+        page.extend(chars("Thanks", 72.0, 680.0, "Helvetica", false));
+
+        let elements = page_elements(&page, 2, 792.0, &config(OcrElementLevel::Word));
+        let texts: Vec<(&str, OcrElementLevel)> = elements.iter().map(|e| (e.text.as_str(), e.level)).collect();
+        assert_eq!(
+            texts,
+            vec![
+                ("Total due", OcrElementLevel::Line),
+                ("Total", OcrElementLevel::Word),
+                ("due", OcrElementLevel::Word),
+                // 76pt gutter: a separate line on the same baseline
+                ("42", OcrElementLevel::Line),
+                ("42", OcrElementLevel::Word),
+                ("Thanks", OcrElementLevel::Line),
+                ("Thanks", OcrElementLevel::Word),
+            ]
+        );
+
+        let total = &elements[1];
+        assert_eq!(total.page_number, 2);
+        assert_eq!(
+            total.geometry,
+            OcrBoundingGeometry::Rectangle {
+                left: 72,
+                top: 84,
+                width: 30,
+                height: 10
+            }
+        );
+        assert_eq!(total.confidence.recognition, 1.0);
+        assert_eq!(total.parent_id.as_deref(), Some("p2_l1"));
+        assert_eq!(total.backend_metadata["backend"], TEXT_LAYER_BACKEND);
+        assert_eq!(total.backend_metadata["font_name"], "Helvetica");
+        assert_eq!(total.backend_metadata["font_size"], 10.0);
+        assert_eq!(total.backend_metadata["bold"], false);
+
+        let amount = &elements[4];
+        assert_eq!(amount.backend_metadata["font_name"], "Helvetica-Bold");
+        assert_eq!(amount.backend_metadata["bold"], true);
+        assert_eq!(amount.parent_id.as_deref(), Some("p2_l2"));
+    }
+
+    #[test]
+    fn test_line_level_omits_words() {
+        let page = chars("Hello world", 72.0, 700.0, "Times", false);
+        let elements = page_elements(&page, 1, 792.0, &config(OcrElementLevel::Line));
+        assert_eq!(elements.len(), 1);
+        assert_eq!(elements[0].text, "Hello world");
+        assert_eq!(elements[0].level, OcrElementLevel::Line);
+        assert!(elements[0].parent_id.is_none());
+
+        assert!(page_elements(&page, 1, 792.0, &config(OcrElementLevel::Block)).is_empty());
+    }
+
+    #[test]
+    fn test_cjk_characters_are_separate_words() {
+        let page = chars("東京駅", 72.0, 700.0, "MS-Mincho", false);
+        let elements = page_elements(&page, 1, 792.0, &config(OcrElementLevel::Word));
+        let words: Vec<&str> = elements
+            .iter()
+            .filter(|e| e.level == OcrElementLevel::Word)
+            .map(|e| e.text.as_str())
+            .collect();
+        assert_eq!(words, vec!["東", "京", "駅"]);
+        assert_eq!(elements[0].text, "東京駅");
+    }
+}
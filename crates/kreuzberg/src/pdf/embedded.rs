@@ -0,0 +1,378 @@
+//! Embedded file (attachment) extraction.
+//!
+//! PDF portfolios and documents with attachments keep complete files in the
+//! `/EmbeddedFiles` name tree of the document catalog. The listing is read with
+//! pdfium; the file contents are read with `lopdf` and inflated incrementally,
+//! so that decoding stops as soon as a file exceeds the [`SecurityLimits`].
+
+use crate::core::mime::{detect_mime_type, detect_mime_type_from_bytes};
+use crate::extractors::security::SecurityLimits;
+use crate::types::EmbeddedFile;
+use flate2::read::ZlibDecoder;
+use lopdf::{Dictionary, Document, Object, ObjectId, Stream, decode_text_string};
+use pdfium_render::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::Read;
+
+/// Deepest name tree level that is followed.
+const MAX_NAME_TREE_DEPTH: usize = 32;
+
+/// List the files embedded in a document, without their contents.
+pub fn list_embedded_files(document: &PdfDocument<'_>) -> Vec<EmbeddedFile> {
+    document
+        .attachments()
+        .iter()
+        .map(|attachment| {
+            let filename = attachment.name();
+            EmbeddedFile {
+                mime_type: detect_mime_type(&filename, false).ok(),
+                size: attachment.len(),
+                filename,
+                description: None,
+                result: None,
+                error: None,
+            }
+        })
+        .collect()
+}
+
+/// Read the embedded files of a document together with their contents.
+///
+/// Files beyond `max_files_in_archive`, or that would take the total size of
+/// all embedded files past `max_archive_size`, are listed with an error and
+/// without content. Returns an empty list when the document cannot be parsed.
+pub fn read_embedded_files(pdf_bytes: &[u8], limits: &SecurityLimits) -> Vec<(EmbeddedFile, Option<Vec<u8>>)> {
+    let document = match Document::load_mem(pdf_bytes) {
+        Ok(document) => document,
+        Err(e) => {
+            tracing::debug!("Embedded file extraction skipped, document could not be parsed: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut files = Vec::new();
+    let mut total_size = 0usize;
+    for (index, (key, file_spec)) in file_specs(&document).into_iter().enumerate() {
+        let filename = text(file_spec, b"UF", &document)
+            .or_else(|| text(file_spec, b"F", &document))
+            .unwrap_or(key);
+        let mut file = EmbeddedFile {
+            mime_type: detect_mime_type(&filename, false).ok(),
+            filename,
+            size: 0,
+            description: text(file_spec, b"Desc", &document),
+            result: None,
+            error: None,
+        };
+
+        if index >= limits.max_files_in_archive {
+            file.error = Some(format!(
+                "Too many embedded files (max: {})",
+                limits.max_files_in_archive
+            ));
+            files.push((file, None));
+            continue;
+        }
+
+        let Some(stream) = file_stream(file_spec, &document) else {
+            file.error = Some("Embedded file has no content stream".to_string());
+            files.push((file, None));
+            continue;
+        };
+
+        let budget = limits.max_archive_size.saturating_sub(total_size);
+        let declared_size = declared_size(&stream.dict, &document).unwrap_or(stream.content.len());
+        file.size = declared_size;
+        if declared_size > budget {
+            file.error = Some(size_error(limits));
+            files.push((file, None));
+            continue;
+        }
+
+        let content = match decode_within(stream, budget) {
+            Ok(Some(content)) => content,
+            Ok(None) => {
+                file.error = Some(size_error(limits));
+                files.push((file, None));
+                continue;
+            }
+            Err(e) => {
+                file.error = Some(format!("Failed to decode embedded file: {}", e));
+                files.push((file, None));
+                continue;
+            }
+        };
+
+        file.size = content.len();
+        total_size += content.len();
+
+        if file.mime_type.is_none() {
+            file.mime_type = detect_mime_type_from_bytes(&content).ok();
+        }
+        files.push((file, Some(content)));
+    }
+
+    files
+}
+
+fn size_error(limits: &SecurityLimits) -> String {
+    format!(
+        "Embedded files exceed the maximum total size of {} bytes",
+        limits.max_archive_size
+    )
+}
+
+/// File specifications of the `/EmbeddedFiles` name tree in key order, with
+/// their name tree keys.
+fn file_specs(document: &Document) -> Vec<(String, &Dictionary)> {
+    let mut specs = Vec::new();
+    let Some(root) = document
+        .catalog()
+        .ok()
+        .and_then(|catalog| catalog.get_deref(b"Names", document).ok())
+        .and_then(|names| names.as_dict().ok())
+        .and_then(|names| names.get_deref(b"EmbeddedFiles", document).ok())
+        .and_then(|tree| tree.as_dict().ok())
+    else {
+        return specs;
+    };
+
+    // Depth-first over the name tree; kids are pushed in reverse to keep key order ~keep
+    let mut visited: HashSet<ObjectId> = HashSet::new();
+    let mut stack = vec![(root, 0usize)];
+    while let Some((node, depth)) = stack.pop() {
+        if let Ok(names) = node.get_deref(b"Names", document).and_then(Object::as_array) {
+            for pair in names.chunks(2) {
+                let [key, value] = pair else {
+                    continue;
+                };
+                if let Ok((_, value)) = document.dereference(value)
+                    && let Ok(file_spec) = value.as_dict()
+                {
+                    specs.push((decode_text_string(key).unwrap_or_default(), file_spec));
+                }
+            }
+        }
+
+        if depth >= MAX_NAME_TREE_DEPTH {
+            continue;
+        }
+        if let Ok(kids) = node.get_deref(b"Kids", document).and_then(Object::as_array) {
+            for kid in kids.iter().rev() {
+                if let Ok(id) = kid.as_reference()
+                    && !visited.insert(id)
+                {
+                    continue;
+                }
+                if let Ok((_, kid)) = document.dereference(kid)
+                    && let Ok(kid) = kid.as_dict()
+                {
+                    stack.push((kid, depth + 1));
+                }
+            }
+        }
+    }
+
+    specs
+}
+
+/// The embedded file stream of a file specification (`/EF` entry).
+fn file_stream<'a>(file_spec: &'a Dictionary, document: &'a Document) -> Option<&'a lopdf::Stream> {
+    let ef = file_spec.get_deref(b"EF", document).ok()?.as_dict().ok()?;
+    ef.get_deref(b"UF", document)
+        .or_else(|_| ef.get_deref(b"F", document))
+        .ok()?
+        .as_stream()
+        .ok()
+}
+
+/// Decode an embedded file stream, giving up once it grows past `budget` bytes.
+///
+/// Returns `Ok(None)` when the decoded content would exceed `budget`.
+/// `FlateDecode` is inflated incrementally, so a compression bomb never takes
+/// more than `budget` bytes. `ASCII85Decode` cannot grow its input and is
+/// decoded by lopdf; any other filter is rejected.
+fn decode_within(stream: &Stream, budget: usize) -> Result<Option<Vec<u8>>, String> {
+    if !stream.dict.has(b"Filter") {
+        return Ok((stream.content.len() <= budget).then(|| stream.content.clone()));
+    }
+
+    let filters = stream.filters().map_err(|e| e.to_string())?;
+    let params = stream.dict.get(b"DecodeParms").ok();
+    let mut content = Cow::Borrowed(stream.content.as_slice());
+    for filter in filters {
+        let decoded = match filter {
+            b"FlateDecode" => {
+                let Some(inflated) = inflate_within(&content, budget) else {
+                    return Ok(None);
+                };
+                match params {
+                    // Predictors never grow the inflated data, so lopdf can redo it in full ~keep
+                    Some(params) => decode_with_lopdf(filter, Some(params), &content)?,
+                    None => inflated,
+                }
+            }
+            b"ASCII85Decode" => decode_with_lopdf(filter, None, &content)?,
+            other => return Err(format!("unsupported filter {}", String::from_utf8_lossy(other))),
+        };
+        content = Cow::Owned(decoded);
+    }
+
+    Ok((content.len() <= budget).then(|| content.into_owned()))
+}
+
+/// Inflate zlib data, or `None` once the output exceeds `budget` bytes.
+///
+/// Like lopdf, a corrupt stream keeps whatever was inflated before the error.
+fn inflate_within(input: &[u8], budget: usize) -> Option<Vec<u8>> {
+    let mut output = Vec::new();
+    let limit = u64::try_from(budget).unwrap_or(u64::MAX).saturating_add(1);
+    if let Err(e) = ZlibDecoder::new(input).take(limit).read_to_end(&mut output) {
+        tracing::debug!("Embedded file stream is truncated or corrupt: {}", e);
+    }
+    (output.len() <= budget).then_some(output)
+}
+
+/// Apply a single filter with lopdf.
+fn decode_with_lopdf(filter: &[u8], params: Option<&Object>, input: &[u8]) -> Result<Vec<u8>, String> {
+    let mut dict = Dictionary::new();
+    dict.set("Filter", Object::Name(filter.to_vec()));
+    if let Some(params) = params {
+        dict.set("DecodeParms", params.clone());
+    }
+    Stream::new(dict, input.to_vec())
+        .decompressed_content()
+        .map_err(|e| e.to_string())
+}
+
+/// Uncompressed size recorded in the stream's `/Params` dictionary.
+fn declared_size(stream_dict: &Dictionary, document: &Document) -> Option<usize> {
+    let params = stream_dict.get_deref(b"Params", document).ok()?.as_dict().ok()?;
+    let size = params.get_deref(b"Size", document).ok()?.as_i64().ok()?;
+    usize::try_from(size).ok()
+}
+
+fn text(dict: &Dictionary, key: &[u8], document: &Document) -> Option<String> {
+    let value = dict.get_deref(key, document).ok()?;
+    decode_text_string(value)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use lopdf::dictionary;
+
+    /// Build a PDF whose `/EmbeddedFiles` name tree holds the given files.
+    pub(crate) fn pdf_with_attachments(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.add_object(dictionary! {
+            "Type" => "Pages",
+            "Kids" => Vec::<Object>::new(),
+            "Count" => 0,
+        });
+
+        let mut names = Vec::new();
+        for (name, content) in files {
+            let mut stream = Stream::new(dictionary! { "Type" => "EmbeddedFile" }, content.to_vec());
+            stream.compress().unwrap();
+            let stream_id = doc.add_object(stream);
+            let file_spec_id = doc.add_object(dictionary! {
+                "Type" => "Filespec",
+                "F" => Object::string_literal(*name),
+                "Desc" => Object::string_literal(format!("Attached {}", name)),
+                "EF" => dictionary! { "F" => stream_id },
+            });
+            names.push(Object::string_literal(*name));
+            names.push(file_spec_id.into());
+        }
+
+        let tree_id = doc.add_object(dictionary! { "Names" => names });
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+            "Names" => dictionary! { "EmbeddedFiles" => tree_id },
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_read_embedded_files() {
+        let pdf = pdf_with_attachments(&[
+            ("totals.csv", b"region,total\nnorth,42\n"),
+            ("notes.txt", b"see totals"),
+        ]);
+
+        let files = read_embedded_files(&pdf, &SecurityLimits::default());
+        assert_eq!(files.len(), 2);
+
+        let (file, content) = &files[0];
+        assert_eq!(file.filename, "totals.csv");
+        assert_eq!(file.mime_type.as_deref(), Some("text/csv"));
+        assert_eq!(file.description.as_deref(), Some("Attached totals.csv"));
+        assert_eq!(file.size, 22);
+        assert_eq!(content.as_deref(), Some(&b"region,total\nnorth,42\n"[..]));
+        assert_eq!(files[1].0.filename, "notes.txt");
+        assert!(files.iter().all(|(file, _)| file.error.is_none()));
+
+        assert!(read_embedded_files(b"not a pdf", &SecurityLimits::default()).is_empty());
+    }
+
+    #[test]
+    fn test_read_embedded_files_applies_security_limits() {
+        let pdf = pdf_with_attachments(&[("a.txt", b"first file"), ("b.txt", b"second file"), ("c.txt", b"c")]);
+        let limits = SecurityLimits {
+            max_files_in_archive: 2,
+            max_archive_size: 15,
+            ..Default::default()
+        };
+
+        let files = read_embedded_files(&pdf, &limits);
+        assert_eq!(files.len(), 3);
+        assert!(files[0].0.error.is_none());
+        assert!(files[0].1.is_some());
+        assert!(files[1].0.error.as_deref().unwrap().contains("maximum total size"));
+        assert!(files[1].1.is_none());
+        assert!(files[2].0.error.as_deref().unwrap().contains("Too many embedded files"));
+        assert!(files[2].1.is_none());
+    }
+
+    #[test]
+    fn test_read_embedded_files_stops_inflating_at_the_size_limit() {
+        // 16 MiB of zeros compress to about 16 KiB and declare no /Params /Size
+        let pdf = pdf_with_attachments(&[("zeros.bin", &vec![0u8; 16 * 1024 * 1024]), ("b.txt", b"small")]);
+        let limits = SecurityLimits {
+            max_archive_size: 1024 * 1024,
+            ..Default::default()
+        };
+
+        let files = read_embedded_files(&pdf, &limits);
+        assert!(files[0].0.error.as_deref().unwrap().contains("maximum total size"));
+        assert!(files[0].1.is_none());
+        assert_eq!(files[1].1.as_deref(), Some(&b"small"[..]));
+    }
+
+    #[test]
+    fn test_decode_within() {
+        let mut stream = Stream::new(dictionary! {}, b"embedded".to_vec());
+        assert_eq!(decode_within(&stream, 8).unwrap().as_deref(), Some(&b"embedded"[..]));
+        assert_eq!(decode_within(&stream, 7).unwrap(), None);
+
+        stream.compress().unwrap();
+        assert_eq!(decode_within(&stream, 8).unwrap().as_deref(), Some(&b"embedded"[..]));
+        assert_eq!(decode_within(&stream, 7).unwrap(), None);
+
+        let ascii85 = Stream::new(dictionary! { "Filter" => "ASCII85Decode" }, b"BP@~>".to_vec());
+        assert_eq!(decode_within(&ascii85, 8).unwrap().as_deref(), Some(&b"hi"[..]));
+
+        let lzw = Stream::new(dictionary! { "Filter" => "LZWDecode" }, b"\x80".to_vec());
+        assert!(decode_within(&lzw, 8).is_err());
+    }
+}
@@ -0,0 +1,396 @@
+//! Figure detection on native PDF pages.
+//!
+//! Charts and diagrams drawn with vector paths have no image object, so they
+//! never show up among the embedded images. Figures are found here as dense
+//! clusters of path objects, grown by the images placed among them, and rendered
+//! to crops of the page. The nearest "Figure N" caption line (with the lines
+//! continuing it) becomes the figure's description.
+
+use super::error::{PdfError, Result};
+use super::furniture::{TextLine, page_text_lines};
+use super::structure::image_boxes;
+use crate::types::{BoundingBox, ExtractedImage};
+use bytes::Bytes;
+use once_cell::sync::Lazy;
+use pdfium_render::prelude::*;
+use regex::Regex;
+
+/// Minimum number of path objects in a cluster for it to be a figure.
+const MIN_FIGURE_PATHS: usize = 8;
+/// Minimum width and height (in points) of a figure.
+const MIN_FIGURE_SIDE: f64 = 36.0;
+/// Minimum area (in square points) of a figure.
+const MIN_FIGURE_AREA: f64 = 5000.0;
+/// Paths (and images) closer than this (in points) belong to the same cluster.
+const CLUSTER_GAP: f64 = 6.0;
+/// Paths covering more of the page than this are backgrounds or page frames.
+const MAX_PATH_PAGE_COVERAGE: f64 = 0.8;
+/// Clusters whose area is covered by text lines beyond this are ruled tables or
+/// boxed text, not figures.
+const MAX_TEXT_COVERAGE: f64 = 0.3;
+/// Maximum distance (in points) between a figure and its caption.
+const MAX_CAPTION_GAP: f64 = 48.0;
+/// Maximum number of lines in a caption.
+const MAX_CAPTION_LINES: usize = 4;
+/// Rendering resolution of figure crops when no image configuration is given.
+pub(crate) const DEFAULT_FIGURE_DPI: i32 = 150;
+
+/// A caption line: "Figure 3", "Fig. 2a:", "Chart 1 -", "Abbildung 4".
+static CAPTION_START: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?i:figure|fig\.?|chart|diagram|graph|plate|illustration|exhibit|abbildung|abb\.|figura)\s*([0-9]+|[IVXLC]+\b)")
+        .expect("valid caption regex")
+});
+
+/// A vector figure on a page.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Figure {
+    pub page_number: usize,
+    /// Page size in points, to map the figure onto a rendered page image.
+    pub page_width: f64,
+    pub page_height: f64,
+    /// PDF coordinates (y=0 at the bottom of the page).
+    pub bbox: BoundingBox,
+    /// Text of the caption next to the figure.
+    pub caption: Option<String>,
+}
+
+/// Find the vector figures of every page.
+pub(crate) fn find_figures(document: &PdfDocument<'_>) -> Result<Vec<Figure>> {
+    let mut figures = Vec::new();
+    for (page_idx, page) in document.pages().iter().enumerate() {
+        let (page_width, page_height) = (page.width().value as f64, page.height().value as f64);
+        let paths = path_boxes(&page, page_width * page_height);
+        if paths.len() < MIN_FIGURE_PATHS {
+            continue;
+        }
+        let images = image_boxes(&page);
+        let mut lines = page_text_lines(&page)?;
+        lines.sort_by(|a, b| b.bbox.y1.total_cmp(&a.bbox.y1));
+
+        let regions = figure_regions(&paths, &images, &lines);
+        let mut used = vec![false; lines.len()];
+        for bbox in regions {
+            let caption = find_caption(&bbox, &lines, &mut used);
+            figures.push(Figure {
+                page_number: page_idx + 1,
+                page_width,
+                page_height,
+                bbox,
+                caption,
+            });
+        }
+    }
+    Ok(figures)
+}
+
+/// Render each figure to a PNG crop of its page.
+///
+/// Each page with figures is rendered once. `first_index` is the `image_index`
+/// of the first figure, so that figures continue the numbering of the embedded images.
+pub(crate) fn render_figures(
+    content: &[u8],
+    figures: &[Figure],
+    dpi: i32,
+    first_index: usize,
+) -> Result<Vec<ExtractedImage>> {
+    use super::rendering::{PageRenderOptions, PdfRenderer, crop_to_bbox};
+
+    let renderer = PdfRenderer::new()?;
+    let render_options = PageRenderOptions {
+        target_dpi: dpi,
+        ..Default::default()
+    };
+
+    let mut images = Vec::with_capacity(figures.len());
+    let mut rendered: Option<(usize, image::DynamicImage)> = None;
+    for figure in figures {
+        if rendered.as_ref().is_none_or(|(page, _)| *page != figure.page_number) {
+            let page_image = renderer.render_page_to_image(content, figure.page_number - 1, &render_options)?;
+            rendered = Some((figure.page_number, page_image));
+        }
+        let Some((_, page_image)) = rendered.as_ref() else {
+            continue;
+        };
+        let Some(crop) = crop_to_bbox(page_image, figure.page_width, figure.page_height, &figure.bbox) else {
+            continue;
+        };
+
+        let mut data = std::io::Cursor::new(Vec::new());
+        crop.write_to(&mut data, image::ImageFormat::Png)
+            .map_err(|e| PdfError::RenderingFailed(format!("Failed to encode figure: {}", e)))?;
+        images.push(ExtractedImage {
+            data: Bytes::from(data.into_inner()),
+            format: std::borrow::Cow::Borrowed("png"),
+            image_index: first_index + images.len(),
+            page_number: Some(figure.page_number),
+            width: Some(crop.width()),
+            height: Some(crop.height()),
+            colorspace: Some("RGB".to_string()),
+            bits_per_component: Some(8),
+            is_mask: false,
+            description: figure.caption.clone(),
+            ocr_result: None,
+            bounding_box: Some(figure.bbox),
+        });
+    }
+    Ok(images)
+}
+
+/// Bounds of the path objects of a page, without page-sized backgrounds and frames.
+fn path_boxes(page: &PdfPage<'_>, page_area: f64) -> Vec<BoundingBox> {
+    page.objects()
+        .iter()
+        .filter(|object| object.as_path_object().is_some())
+        .filter_map(|object| object.bounds().ok())
+        .map(|bounds| BoundingBox {
+            x0: bounds.left().value as f64,
+            y0: bounds.bottom().value as f64,
+            x1: bounds.right().value as f64,
+            y1: bounds.top().value as f64,
+        })
+        .filter(|bbox| area(bbox) < page_area * MAX_PATH_PAGE_COVERAGE)
+        .collect()
+}
+
+/// The figure regions of a page: clusters of enough paths, grown by the images
+/// they touch, that are large enough and not mostly text.
+fn figure_regions(paths: &[BoundingBox], images: &[BoundingBox], lines: &[TextLine]) -> Vec<BoundingBox> {
+    cluster_boxes(paths)
+        .into_iter()
+        .filter(|(_, count)| *count >= MIN_FIGURE_PATHS)
+        .map(|(mut bbox, _)| {
+            for image in images {
+                if near(&bbox, image) {
+                    bbox = union(&bbox, image);
+                }
+            }
+            bbox
+        })
+        .filter(|bbox| {
+            let (width, height) = (bbox.x1 - bbox.x0, bbox.y1 - bbox.y0);
+            width >= MIN_FIGURE_SIDE && height >= MIN_FIGURE_SIDE && width * height >= MIN_FIGURE_AREA
+        })
+        .filter(|bbox| text_coverage(bbox, lines) <= MAX_TEXT_COVERAGE)
+        .collect()
+}
+
+/// Group boxes that touch (within `CLUSTER_GAP`) into clusters, with the number
+/// of boxes in each.
+fn cluster_boxes(boxes: &[BoundingBox]) -> Vec<(BoundingBox, usize)> {
+    let mut clusters: Vec<(BoundingBox, usize)> = Vec::new();
+    for bbox in boxes {
+        let mut merged = (*bbox, 1);
+        // A growing cluster can reach clusters it did not touch before
+        loop {
+            let before = clusters.len();
+            clusters.retain(|(other, count)| {
+                if near(&merged.0, other) {
+                    merged = (union(&merged.0, other), merged.1 + count);
+                    false
+                } else {
+                    true
+                }
+            });
+            if clusters.len() == before {
+                break;
+            }
+        }
+        clusters.push(merged);
+    }
+    clusters
+}
+
+/// The caption of a figure: the nearest unused caption line just above or below
+/// it, with the lines continuing it. `lines` are sorted top to bottom.
+fn find_caption(figure: &BoundingBox, lines: &[TextLine], used: &mut [bool]) -> Option<String> {
+    let (idx, _) = lines
+        .iter()
+        .enumerate()
+        .filter(|(idx, line)| !used[*idx] && !contains_center(figure, &line.bbox))
+        .filter(|(_, line)| line.bbox.x0 < figure.x1 && line.bbox.x1 > figure.x0)
+        .filter(|(_, line)| CAPTION_START.is_match(line.text.trim()))
+        .filter_map(|(idx, line)| {
+            let gap = if line.bbox.y1 <= figure.y0 + CLUSTER_GAP {
+                figure.y0 - line.bbox.y1
+            } else {
+                line.bbox.y0 - figure.y1
+            };
+            (-CLUSTER_GAP..=MAX_CAPTION_GAP).contains(&gap).then_some((idx, gap))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    used[idx] = true;
+    let mut caption = lines[idx].text.trim().to_string();
+    let mut previous = &lines[idx].bbox;
+    for (next_idx, line) in lines.iter().enumerate().skip(idx + 1).take(MAX_CAPTION_LINES - 1) {
+        let line_height = previous.y1 - previous.y0;
+        let continues = !used[next_idx]
+            && !contains_center(figure, &line.bbox)
+            && previous.y0 - line.bbox.y1 <= line_height * 0.8
+            && line.bbox.x0 < previous.x1
+            && line.bbox.x1 > previous.x0
+            && !CAPTION_START.is_match(line.text.trim());
+        if !continues {
+            break;
+        }
+        used[next_idx] = true;
+        caption.push(' ');
+        caption.push_str(line.text.trim());
+        previous = &line.bbox;
+    }
+    Some(caption)
+}
+
+/// Share of the area of `bbox` covered by text lines.
+fn text_coverage(bbox: &BoundingBox, lines: &[TextLine]) -> f64 {
+    let covered: f64 = lines
+        .iter()
+        .map(|line| {
+            let width = line.bbox.x1.min(bbox.x1) - line.bbox.x0.max(bbox.x0);
+            let height = line.bbox.y1.min(bbox.y1) - line.bbox.y0.max(bbox.y0);
+            width.max(0.0) * height.max(0.0)
+        })
+        .sum();
+    covered / area(bbox).max(1.0)
+}
+
+fn area(bbox: &BoundingBox) -> f64 {
+    (bbox.x1 - bbox.x0) * (bbox.y1 - bbox.y0)
+}
+
+fn near(a: &BoundingBox, b: &BoundingBox) -> bool {
+    a.x0 - CLUSTER_GAP <= b.x1 && b.x0 <= a.x1 + CLUSTER_GAP && a.y0 - CLUSTER_GAP <= b.y1 && b.y0 <= a.y1 + CLUSTER_GAP
+}
+
+fn union(a: &BoundingBox, b: &BoundingBox) -> BoundingBox {
+    BoundingBox {
+        x0: a.x0.min(b.x0),
+        y0: a.y0.min(b.y0),
+        x1: a.x1.max(b.x1),
+        y1: a.y1.max(b.y1),
+    }
+}
+
+fn contains_center(outer: &BoundingBox, inner: &BoundingBox) -> bool {
+    let (x, y) = ((inner.x0 + inner.x1) / 2.0, (inner.y0 + inner.y1) / 2.0);
+    x >= outer.x0 && x <= outer.x1 && y >= outer.y0 && y <= outer.y1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bbox(x0: f64, y0: f64, x1: f64, y1: f64) -> BoundingBox {
+        BoundingBox { x0, y0, x1, y1 }
+    }
+
+    fn line(text: &str, x0: f64, y0: f64, x1: f64, y1: f64) -> TextLine {
+        TextLine {
+            text: text.to_string(),
+            bbox: bbox(x0, y0, x1, y1),
+        }
+    }
+
+    /// A bar chart: an axis and bars standing on it.
+    fn chart_paths() -> Vec<BoundingBox> {
+        let mut paths = vec![bbox(100.0, 400.0, 100.0, 600.0), bbox(100.0, 400.0, 400.0, 400.0)];
+        for i in 0..8 {
+            let x = 110.0 + i as f64 * 35.0;
+            paths.push(bbox(x, 400.0, x + 25.0, 450.0 + i as f64 * 15.0));
+        }
+        paths
+    }
+
+    #[test]
+    fn test_cluster_boxes_merges_touching_boxes() {
+        let boxes = vec![
+            bbox(0.0, 0.0, 10.0, 10.0),
+            bbox(100.0, 100.0, 110.0, 110.0),
+            bbox(12.0, 0.0, 20.0, 10.0),
+            // Bridges the two clusters once the first one has grown
+            bbox(22.0, 8.0, 98.0, 102.0),
+        ];
+        let clusters = cluster_boxes(&boxes);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].1, 4);
+        assert_eq!(clusters[0].0, bbox(0.0, 0.0, 110.0, 110.0));
+    }
+
+    #[test]
+    fn test_figure_regions_finds_chart() {
+        let lines = vec![line("Some body text above the chart", 72.0, 650.0, 540.0, 662.0)];
+        let regions = figure_regions(&chart_paths(), &[], &lines);
+        assert_eq!(regions, vec![bbox(100.0, 400.0, 400.0, 600.0)]);
+    }
+
+    #[test]
+    fn test_figure_regions_grows_by_adjacent_images() {
+        let images = vec![bbox(402.0, 500.0, 480.0, 580.0), bbox(500.0, 100.0, 540.0, 140.0)];
+        let regions = figure_regions(&chart_paths(), &images, &[]);
+        assert_eq!(regions, vec![bbox(100.0, 400.0, 480.0, 600.0)]);
+    }
+
+    #[test]
+    fn test_figure_regions_skips_ruled_tables() {
+        // Row and column rules of a table whose cells are full of text
+        let mut paths: Vec<_> = (0..10)
+            .map(|i| bbox(72.0, 400.0 + i as f64 * 14.0, 540.0, 400.0 + i as f64 * 14.0))
+            .collect();
+        paths.push(bbox(72.0, 400.0, 72.0, 526.0));
+        paths.push(bbox(540.0, 400.0, 540.0, 526.0));
+        let lines: Vec<_> = (0..9)
+            .map(|i| {
+                let y = 402.0 + i as f64 * 14.0;
+                line("Cell  Cell  Cell", 74.0, y, 538.0, y + 10.0)
+            })
+            .collect();
+        assert!(figure_regions(&paths, &[], &lines).is_empty());
+    }
+
+    #[test]
+    fn test_figure_regions_skips_sparse_paths() {
+        assert!(figure_regions(&chart_paths()[..5], &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_find_caption_below_figure_with_continuation() {
+        let figure = bbox(100.0, 400.0, 400.0, 600.0);
+        let lines = vec![
+            line("Figure 2: Unrelated caption far away", 100.0, 100.0, 400.0, 110.0),
+            line("Figure 3: Revenue by quarter, in millions", 100.0, 380.0, 400.0, 390.0),
+            line("of euros, for all regions.", 100.0, 368.0, 300.0, 378.0),
+            line("The next paragraph starts here after a gap.", 72.0, 330.0, 540.0, 340.0),
+        ];
+        let mut sorted = lines.clone();
+        sorted.sort_by(|a, b| b.bbox.y1.total_cmp(&a.bbox.y1));
+        let mut used = vec![false; sorted.len()];
+
+        let caption = find_caption(&figure, &sorted, &mut used);
+        assert_eq!(
+            caption.as_deref(),
+            Some("Figure 3: Revenue by quarter, in millions of euros, for all regions.")
+        );
+    }
+
+    #[test]
+    fn test_find_caption_above_figure() {
+        let figure = bbox(100.0, 400.0, 400.0, 600.0);
+        let lines = vec![line("Fig. 4 - Network topology", 100.0, 610.0, 300.0, 620.0)];
+        let mut used = vec![false; lines.len()];
+        assert_eq!(
+            find_caption(&figure, &lines, &mut used).as_deref(),
+            Some("Fig. 4 - Network topology")
+        );
+
+        // A caption is only used once
+        assert_eq!(find_caption(&figure, &lines, &mut used), None);
+    }
+
+    #[test]
+    fn test_find_caption_ignores_plain_text() {
+        let figure = bbox(100.0, 400.0, 400.0, 600.0);
+        let lines = vec![line("Figures show a steady increase", 100.0, 380.0, 400.0, 390.0)];
+        let mut used = vec![false; lines.len()];
+        assert_eq!(find_caption(&figure, &lines, &mut used), None);
+    }
+}
@@ -0,0 +1,389 @@
+//! PDF form field extraction.
+//!
+//! AcroForm fields are read through pdfium from the widget annotations on each
+//! page, which gives their type, value, checked state and position. Full XFA
+//! forms have no widgets pdfium can read; their values are taken from the
+//! `datasets` packet of the XFA stream with `lopdf`.
+
+use crate::types::{BoundingBox, FormField, FormFieldSource, FormFieldType};
+use lopdf::{Document, Object};
+use pdfium_render::prelude::*;
+
+/// Extract the AcroForm fields of all pages in a document.
+///
+/// The widgets of a check box or radio button group share one field name and
+/// are merged into a single entry that reports the selected option.
+pub fn extract_form_fields(document: &PdfDocument<'_>) -> Vec<FormField> {
+    let mut fields = Vec::new();
+
+    for (page_index, page) in document.pages().iter().enumerate() {
+        for annotation in page.annotations().iter() {
+            let Some(widget) = annotation.as_form_field() else {
+                continue;
+            };
+            let Some(field) = read_widget(widget, page_index + 1, annotation.bounds().ok()) else {
+                continue;
+            };
+            push_field(&mut fields, field);
+        }
+    }
+
+    fields
+}
+
+fn read_widget(widget: &PdfFormField<'_>, page_number: usize, bounds: Option<PdfRect>) -> Option<FormField> {
+    let name = widget
+        .name()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())?;
+
+    let (field_type, value, is_checked) = match widget.field_type() {
+        // Buttons only trigger actions and hold no value ~keep
+        PdfFormFieldType::PushButton => return None,
+        PdfFormFieldType::Text => (
+            FormFieldType::Text,
+            widget.as_text_field().and_then(|field| field.value()),
+            None,
+        ),
+        PdfFormFieldType::Checkbox => {
+            let field = widget.as_checkbox_field()?;
+            (
+                FormFieldType::Checkbox,
+                export_value(field.group_value()),
+                field.is_checked().ok(),
+            )
+        }
+        PdfFormFieldType::RadioButton => {
+            let field = widget.as_radio_button_field()?;
+            (
+                FormFieldType::RadioButton,
+                export_value(field.group_value()),
+                field.is_checked().ok(),
+            )
+        }
+        PdfFormFieldType::ComboBox => (
+            FormFieldType::ComboBox,
+            widget.as_combo_box_field().and_then(|field| field.value()),
+            None,
+        ),
+        PdfFormFieldType::ListBox => (
+            FormFieldType::ListBox,
+            widget.as_list_box_field().and_then(|field| field.value()),
+            None,
+        ),
+        PdfFormFieldType::Signature => (FormFieldType::Signature, None, None),
+        PdfFormFieldType::Unknown => (FormFieldType::Unknown, None, None),
+    };
+
+    Some(FormField {
+        name,
+        field_type,
+        value,
+        is_checked,
+        is_read_only: widget.is_read_only(),
+        page_number: Some(page_number),
+        bounding_box: bounds.map(|rect| BoundingBox {
+            x0: rect.left().value as f64,
+            y0: rect.bottom().value as f64,
+            x1: rect.right().value as f64,
+            y1: rect.top().value as f64,
+        }),
+        source: FormFieldSource::AcroForm,
+    })
+}
+
+/// Add a field, merging the widgets of a check box or radio button group.
+///
+/// The group keeps the position of its first widget until a checked one is seen.
+fn push_field(fields: &mut Vec<FormField>, field: FormField) {
+    let is_button = matches!(field.field_type, FormFieldType::Checkbox | FormFieldType::RadioButton);
+    if is_button
+        && let Some(existing) = fields
+            .iter_mut()
+            .find(|existing| existing.name == field.name && existing.field_type == field.field_type)
+    {
+        if field.is_checked == Some(true) && existing.is_checked != Some(true) {
+            *existing = field;
+        }
+        return;
+    }
+    fields.push(field);
+}
+
+/// Export value of a button group; `Off` means nothing is selected.
+fn export_value(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty() && value != "Off")
+}
+
+/// Extract the field values of an XFA form from the raw PDF bytes.
+///
+/// Returns an empty list when the document has no XFA stream or it cannot be
+/// read (e.g. encrypted documents).
+pub fn extract_xfa_fields(pdf_bytes: &[u8]) -> Vec<FormField> {
+    let document = match Document::load_mem(pdf_bytes) {
+        Ok(document) => document,
+        Err(e) => {
+            tracing::debug!("XFA form extraction skipped, document could not be parsed: {}", e);
+            return Vec::new();
+        }
+    };
+
+    match xfa_datasets(&document) {
+        Some(xml) => parse_xfa_datasets(&xml),
+        None => Vec::new(),
+    }
+}
+
+/// The XML of the `datasets` packet of the document's XFA form.
+fn xfa_datasets(document: &Document) -> Option<String> {
+    let acro_form = document
+        .catalog()
+        .ok()?
+        .get_deref(b"AcroForm", document)
+        .ok()?
+        .as_dict()
+        .ok()?;
+    let xfa = acro_form.get_deref(b"XFA", document).ok()?;
+
+    match xfa {
+        // Packets as [name stream name stream ...] ~keep
+        Object::Array(packets) => packets.chunks(2).find_map(|packet| {
+            let [name, stream] = packet else {
+                return None;
+            };
+            if name.as_str().ok()? != b"datasets" {
+                return None;
+            }
+            let (_, stream) = document.dereference(stream).ok()?;
+            stream_text(stream)
+        }),
+        // A single stream holding the complete XDP document ~keep
+        stream => stream_text(stream),
+    }
+}
+
+fn stream_text(object: &Object) -> Option<String> {
+    let stream = object.as_stream().ok()?;
+    let bytes = if stream.dict.has(b"Filter") {
+        stream.decompressed_content().ok()?
+    } else {
+        stream.content.clone()
+    };
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Read field values from XFA `datasets` XML.
+///
+/// Every leaf element under `xfa:data` with text becomes a field named after
+/// its element path (`form1.applicant.name`).
+fn parse_xfa_datasets(xml: &str) -> Vec<FormField> {
+    let document = match roxmltree::Document::parse(xml.trim_start_matches('\u{feff}').trim()) {
+        Ok(document) => document,
+        Err(e) => {
+            tracing::debug!("XFA datasets packet could not be parsed: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let Some(data) = document
+        .descendants()
+        .find(|node| node.is_element() && node.tag_name().name() == "datasets")
+        .and_then(|datasets| {
+            datasets
+                .children()
+                .find(|node| node.is_element() && node.tag_name().name() == "data")
+        })
+    else {
+        return Vec::new();
+    };
+
+    let mut fields = Vec::new();
+    let mut path = Vec::new();
+    collect_xfa_values(data, &mut path, &mut fields);
+    fields
+}
+
+fn collect_xfa_values<'a>(node: roxmltree::Node<'a, '_>, path: &mut Vec<&'a str>, fields: &mut Vec<FormField>) {
+    for child in node.children().filter(|child| child.is_element()) {
+        path.push(child.tag_name().name());
+
+        if child.children().any(|grandchild| grandchild.is_element()) {
+            collect_xfa_values(child, path, fields);
+        } else if let Some(text) = child.text().map(str::trim).filter(|text| !text.is_empty()) {
+            fields.push(FormField {
+                name: path.join("."),
+                field_type: FormFieldType::Unknown,
+                value: Some(text.to_string()),
+                is_checked: None,
+                is_read_only: false,
+                page_number: None,
+                bounding_box: None,
+                source: FormFieldSource::Xfa,
+            });
+        }
+
+        path.pop();
+    }
+}
+
+/// Render the filled-in fields as a "Field: Value" section.
+///
+/// Markdown output uses a heading and a bullet list. Returns `None` when no
+/// field has a value.
+pub fn render_form_fields(fields: &[FormField], markdown: bool) -> Option<String> {
+    let lines: Vec<String> = fields
+        .iter()
+        .filter_map(|field| {
+            field.display_value().map(|value| {
+                format!(
+                    "{}: {}",
+                    field.name,
+                    value.split_whitespace().collect::<Vec<_>>().join(" ")
+                )
+            })
+        })
+        .collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    if markdown {
+        let items: Vec<String> = lines.iter().map(|line| format!("- {}", line)).collect();
+        Some(format!("## Form Fields\n\n{}", items.join("\n")))
+    } else {
+        Some(format!("Form Fields\n\n{}", lines.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button(name: &str, value: Option<&str>, is_checked: bool, page_number: usize) -> FormField {
+        FormField {
+            name: name.to_string(),
+            field_type: FormFieldType::RadioButton,
+            value: value.map(str::to_string),
+            is_checked: Some(is_checked),
+            is_read_only: false,
+            page_number: Some(page_number),
+            bounding_box: None,
+            source: FormFieldSource::AcroForm,
+        }
+    }
+
+    #[test]
+    fn test_push_field_merges_button_groups() {
+        let mut fields = Vec::new();
+        push_field(&mut fields, button("status", Some("Single"), false, 1));
+        push_field(&mut fields, button("status", Some("Married"), true, 2));
+        push_field(&mut fields, button("status", Some("Married"), false, 3));
+
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].is_checked, Some(true));
+        assert_eq!(fields[0].page_number, Some(2));
+    }
+
+    #[test]
+    fn test_export_value_treats_off_as_unset() {
+        assert_eq!(export_value(Some("Off".to_string())), None);
+        assert_eq!(export_value(Some(String::new())), None);
+        assert_eq!(export_value(Some("Yes".to_string())).as_deref(), Some("Yes"));
+    }
+
+    #[test]
+    fn test_parse_xfa_datasets() {
+        let xml = r#"<xfa:datasets xmlns:xfa="http://www.xfa.org/schema/xfa-data/1.0/">
+            <xfa:data>
+                <form1>
+                    <applicant>
+                        <name>Jane Doe</name>
+                        <city> Berlin </city>
+                        <phone/>
+                    </applicant>
+                    <total>42.50</total>
+                </form1>
+            </xfa:data>
+        </xfa:datasets>"#;
+
+        let fields = parse_xfa_datasets(xml);
+        let pairs: Vec<(&str, Option<&str>)> = fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.value.as_deref()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("form1.applicant.name", Some("Jane Doe")),
+                ("form1.applicant.city", Some("Berlin")),
+                ("form1.total", Some("42.50")),
+            ]
+        );
+        assert!(fields.iter().all(|field| field.source == FormFieldSource::Xfa));
+    }
+
+    #[test]
+    fn test_extract_xfa_fields_from_pdf() {
+        use lopdf::{Stream, dictionary};
+
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.add_object(dictionary! {
+            "Type" => "Pages",
+            "Kids" => Vec::<Object>::new(),
+            "Count" => 0,
+        });
+        let datasets = Stream::new(
+            dictionary! {},
+            b"<xfa:datasets xmlns:xfa=\"http://www.xfa.org/schema/xfa-data/1.0/\"><xfa:data><f1><tax_id>12-345</tax_id></f1></xfa:data></xfa:datasets>".to_vec(),
+        );
+        let datasets_id = doc.add_object(datasets);
+        let acro_form_id = doc.add_object(dictionary! {
+            "Fields" => Vec::<Object>::new(),
+            "XFA" => vec![Object::string_literal("datasets"), datasets_id.into()],
+        });
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+            "AcroForm" => acro_form_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+
+        let fields = extract_xfa_fields(&bytes);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "f1.tax_id");
+        assert_eq!(fields[0].value.as_deref(), Some("12-345"));
+        assert!(extract_xfa_fields(b"not a pdf").is_empty());
+    }
+
+    #[test]
+    fn test_parse_xfa_datasets_rejects_invalid_xml() {
+        assert!(parse_xfa_datasets("<xfa:datasets><unclosed>").is_empty());
+        assert!(parse_xfa_datasets("").is_empty());
+    }
+
+    #[test]
+    fn test_render_form_fields() {
+        let mut name = button("name", Some("Jane\nDoe"), false, 1);
+        name.field_type = FormFieldType::Text;
+        name.is_checked = None;
+        let fields = vec![
+            name,
+            button("married", Some("Yes"), true, 1),
+            button("newsletter", None, false, 1),
+        ];
+
+        assert_eq!(
+            render_form_fields(&fields, false).as_deref(),
+            Some("Form Fields\n\nname: Jane Doe\nmarried: Yes")
+        );
+        assert_eq!(
+            render_form_fields(&fields, true).as_deref(),
+            Some("## Form Fields\n\n- name: Jane Doe\n- married: Yes")
+        );
+        assert_eq!(render_form_fields(&fields[2..], false), None);
+    }
+}
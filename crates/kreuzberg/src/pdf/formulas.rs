@@ -0,0 +1,386 @@
+//! Mathematical formula detection for the layout analysis of untagged PDFs.
+//!
+//! The text layer of a display equation is a run of symbols in reading order,
+//! with exponents and indices flattened onto the baseline. A text line is taken
+//! as part of a formula when most of its characters use a math font (Computer
+//! Modern math, AMS, STIX, Cambria Math, Symbol), when math symbols make up a
+//! large share of it, or when it is a short line centered in its column with a
+//! relation symbol or an equation number. Formula lines are linearized from
+//! their characters: raised and lowered characters become `^{...}` and `_{...}`
+//! and a trailing equation number becomes `\tag{...}`.
+
+use super::hierarchy::CharData;
+
+/// Share of a line's characters in math fonts that makes it a formula line.
+const MIN_MATH_FONT_RATIO: f32 = 0.5;
+/// Share of math symbols that makes a line a formula line.
+const MIN_SYMBOL_RATIO: f32 = 0.2;
+/// Share of math symbols or math-font characters a centered line needs.
+const MIN_CENTERED_MATH_RATIO: f32 = 0.05;
+/// Maximum distance of a centered line's center from the column center, as a
+/// fraction of the column width.
+const MAX_CENTER_OFFSET: f32 = 0.08;
+/// Maximum width of a centered line, as a fraction of the column width.
+const MAX_CENTERED_WIDTH: f32 = 0.75;
+/// Maximum number of prose words (four or more letters) on a formula line.
+const MAX_PROSE_WORDS: usize = 2;
+/// Horizontal gap between characters, as a fraction of the font size, that
+/// is written as a space.
+const SPACE_GAP_FRACTION: f32 = 0.25;
+/// Baseline offset, as a fraction of the main font size, of a smaller
+/// character written as an exponent or index.
+const SCRIPT_OFFSET_FRACTION: f32 = 0.15;
+/// Baseline offset of a character of any size written as an exponent or index.
+const LARGE_SCRIPT_OFFSET_FRACTION: f32 = 0.3;
+/// Font size ratio below which a character counts as smaller than the main size.
+const SCRIPT_SIZE_RATIO: f32 = 0.85;
+
+/// Substrings of the (lowercased) names of fonts that only set math.
+const MATH_FONT_MARKERS: &[&str] = &[
+    "cmmi",
+    "cmsy",
+    "cmex",
+    "msam",
+    "msbm",
+    "eufm",
+    "rsfs",
+    "stixmath",
+    "stixgeneral",
+    "stixtwomath",
+    "cambriamath",
+    "cambria math",
+    "xitsmath",
+    "mtextra",
+    "mt extra",
+    "mathematicalpi",
+    "euler",
+    "esint",
+    "symbol",
+    "math",
+];
+
+/// Relation symbols that mark an equation rather than an expression in prose.
+const RELATIONS: &[char] = &[
+    '=', '<', '>', '≤', '≥', '≠', '≈', '≡', '∼', '≃', '≅', '∝', '→', '←', '↔', '⇒', '⇔', '∈', '∉', '⊂', '⊆', '⊃', '⊇',
+];
+
+/// A character of a text line, with whether its font is a math font.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Glyph<'a> {
+    pub ch: &'a CharData,
+    pub math_font: bool,
+}
+
+/// Whether a font only sets math, judging by its name.
+pub(crate) fn is_math_font(font_name: &str) -> bool {
+    let name = font_name.to_lowercase();
+    // Subset prefixes ("ABCDEF+CMMI10") do not matter for substring matches.
+    MATH_FONT_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Whether a character is a math operator, relation, arrow or math letter.
+pub(crate) fn is_math_symbol(c: char) -> bool {
+    matches!(c,
+        '=' | '+' | '<' | '>' | '^' | '±' | '×' | '÷' | '·' | '′' | '″'
+        | '\u{2190}'..='\u{21FF}'   // arrows
+        | '\u{2200}'..='\u{22FF}'   // mathematical operators
+        | '\u{2308}'..='\u{230B}'   // ceiling and floor
+        | '\u{27C0}'..='\u{27EF}'   // miscellaneous mathematical symbols-A
+        | '\u{2980}'..='\u{2AFF}'   // symbols-B and supplemental operators
+        | '\u{2102}' | '\u{210E}' | '\u{2115}' | '\u{2119}' | '\u{211A}' | '\u{211D}' | '\u{2124}'
+        | '\u{1D400}'..='\u{1D7FF}' // mathematical alphanumeric symbols
+    )
+}
+
+/// Whether the characters of a text line form a line of a formula.
+///
+/// `column` is the horizontal extent of the text column the line sits in, used
+/// to recognize isolated centered lines.
+pub(crate) fn is_formula_line(glyphs: &[Glyph<'_>], column: (f32, f32)) -> bool {
+    let visible: Vec<&Glyph<'_>> = glyphs.iter().filter(|g| !g.ch.text.trim().is_empty()).collect();
+    if visible.len() < 2 {
+        return false;
+    }
+
+    let count = visible.len() as f32;
+    let math_font_ratio = visible.iter().filter(|g| g.math_font).count() as f32 / count;
+    let symbol_ratio = visible.iter().filter(|g| g.ch.text.chars().any(is_math_symbol)).count() as f32 / count;
+    if math_font_ratio >= MIN_MATH_FONT_RATIO {
+        return true;
+    }
+
+    if prose_words(glyphs) > MAX_PROSE_WORDS {
+        return false;
+    }
+    if symbol_ratio >= MIN_SYMBOL_RATIO {
+        return true;
+    }
+
+    let has_relation = visible
+        .iter()
+        .any(|g| g.ch.text.chars().any(|c| RELATIONS.contains(&c)));
+    let has_number = split_equation_number(&linearize(glyphs)).1.is_some();
+    (has_relation || has_number)
+        && symbol_ratio.max(math_font_ratio) >= MIN_CENTERED_MATH_RATIO
+        && is_centered(&visible, column)
+}
+
+/// Linearize the lines of a formula, one output line per text line.
+pub(crate) fn linearize_formula(lines: &[Vec<Glyph<'_>>]) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            let text = linearize(line);
+            match split_equation_number(&text) {
+                (body, Some(number)) => format!("{} \\tag{{{}}}", body, number),
+                (body, None) => body.to_string(),
+            }
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Words of four or more letters outside math fonts.
+fn prose_words(glyphs: &[Glyph<'_>]) -> usize {
+    let mut count = 0;
+    let mut letters = 0;
+    let mut prev: Option<&Glyph<'_>> = None;
+    for glyph in sorted_by_x(glyphs) {
+        let breaks = glyph.ch.text.trim().is_empty()
+            || glyph.math_font
+            || !glyph.ch.text.chars().all(char::is_alphabetic)
+            || prev.is_some_and(|p| gap(p.ch, glyph.ch) > SPACE_GAP_FRACTION * p.ch.font_size);
+        if breaks {
+            count += usize::from(letters >= 4);
+            letters = 0;
+        }
+        if !glyph.math_font && glyph.ch.text.chars().all(char::is_alphabetic) {
+            letters += glyph.ch.text.chars().count();
+        }
+        prev = Some(glyph);
+    }
+    count + usize::from(letters >= 4)
+}
+
+/// Whether a line is narrow and centered in its column.
+fn is_centered(visible: &[&Glyph<'_>], column: (f32, f32)) -> bool {
+    let column_width = column.1 - column.0;
+    if column_width <= 0.0 {
+        return false;
+    }
+    let x0 = visible.iter().map(|g| g.ch.x).fold(f32::INFINITY, f32::min);
+    let x1 = visible
+        .iter()
+        .map(|g| g.ch.x + g.ch.width)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let offset = ((x0 + x1) / 2.0 - (column.0 + column.1) / 2.0).abs();
+    offset <= MAX_CENTER_OFFSET * column_width && x1 - x0 <= MAX_CENTERED_WIDTH * column_width
+}
+
+/// Script position of a character relative to the main baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Script {
+    Base,
+    Super,
+    Sub,
+}
+
+/// Linearize one line, marking exponents and indices.
+fn linearize(glyphs: &[Glyph<'_>]) -> String {
+    let sorted = sorted_by_x(glyphs);
+    let visible: Vec<&CharData> = sorted
+        .iter()
+        .map(|g| g.ch)
+        .filter(|ch| !ch.text.trim().is_empty())
+        .collect();
+    let Some(main_size) = visible.iter().map(|ch| ch.font_size).reduce(f32::max) else {
+        return String::new();
+    };
+
+    let mut baselines: Vec<f32> = visible
+        .iter()
+        .filter(|ch| ch.font_size >= SCRIPT_SIZE_RATIO * main_size)
+        .map(|ch| ch.baseline_y)
+        .collect();
+    baselines.sort_by(f32::total_cmp);
+    let baseline = baselines[baselines.len() / 2];
+
+    let mut output = String::new();
+    let mut group = String::new();
+    let mut current = Script::Base;
+    let mut prev: Option<&CharData> = None;
+    for ch in visible {
+        let offset = ch.baseline_y - baseline;
+        let smaller = ch.font_size < SCRIPT_SIZE_RATIO * main_size;
+        let script = if (smaller && offset > SCRIPT_OFFSET_FRACTION * main_size)
+            || offset > LARGE_SCRIPT_OFFSET_FRACTION * main_size
+        {
+            Script::Super
+        } else if (smaller && offset < -SCRIPT_OFFSET_FRACTION * main_size)
+            || offset < -LARGE_SCRIPT_OFFSET_FRACTION * main_size
+        {
+            Script::Sub
+        } else {
+            Script::Base
+        };
+
+        if script != current {
+            flush_script(&mut output, &mut group, current);
+            current = script;
+        }
+        let spaced = prev.is_some_and(|p| gap(p, ch) > SPACE_GAP_FRACTION * p.font_size.min(ch.font_size));
+        let target = if current == Script::Base {
+            &mut output
+        } else {
+            &mut group
+        };
+        if spaced && !target.is_empty() && !target.ends_with(' ') {
+            target.push(' ');
+        }
+        target.push_str(&ch.text);
+        prev = Some(ch);
+    }
+    flush_script(&mut output, &mut group, current);
+    output.trim().to_string()
+}
+
+/// Append a pending exponent or index group to the output.
+fn flush_script(output: &mut String, group: &mut String, script: Script) {
+    if group.is_empty() {
+        return;
+    }
+    let marker = if script == Script::Super { '^' } else { '_' };
+    let text = group.trim();
+    if text.chars().count() == 1 {
+        output.push(marker);
+        output.push_str(text);
+    } else {
+        output.push(marker);
+        output.push('{');
+        output.push_str(text);
+        output.push('}');
+    }
+    group.clear();
+}
+
+/// Split a trailing equation number such as "(3)" or "(2.1a)" off a line.
+fn split_equation_number(text: &str) -> (&str, Option<&str>) {
+    let trimmed = text.trim_end();
+    let Some(inner) = trimmed.strip_suffix(')') else {
+        return (trimmed, None);
+    };
+    let Some(open) = inner.rfind('(') else {
+        return (trimmed, None);
+    };
+    let number = &inner[open + 1..];
+    let body = trimmed[..open].trim_end();
+    let is_number = number.starts_with(|c: char| c.is_ascii_digit())
+        && number.len() <= 6
+        && number
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c.is_ascii_lowercase());
+    // The number has to stand apart from the formula, not close a parenthesis of it.
+    if is_number && !body.is_empty() && trimmed[..open].ends_with(' ') {
+        (body, Some(number))
+    } else {
+        (trimmed, None)
+    }
+}
+
+fn sorted_by_x<'a, 'b>(glyphs: &'b [Glyph<'a>]) -> Vec<&'b Glyph<'a>> {
+    let mut sorted: Vec<&Glyph<'a>> = glyphs.iter().collect();
+    sorted.sort_by(|a, b| a.ch.x.total_cmp(&b.ch.x));
+    sorted
+}
+
+fn gap(prev: &CharData, next: &CharData) -> f32 {
+    next.x - (prev.x + prev.width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lay out `text` left to right from `x`, one character per `advance` points.
+    fn chars(text: &str, x: f32, baseline: f32, size: f32) -> Vec<CharData> {
+        let advance = size * 0.5;
+        text.chars()
+            .enumerate()
+            .map(|(i, c)| CharData {
+                text: c.to_string(),
+                x: x + i as f32 * advance,
+                y: baseline,
+                font_size: size,
+                width: if c == ' ' { 0.0 } else { advance },
+                height: size,
+                is_bold: false,
+                is_italic: false,
+                baseline_y: baseline,
+            })
+            .collect()
+    }
+
+    fn glyphs(chars: &[CharData], math_font: bool) -> Vec<Glyph<'_>> {
+        chars.iter().map(|ch| Glyph { ch, math_font }).collect()
+    }
+
+    #[test]
+    fn test_is_math_font() {
+        assert!(is_math_font("ABCDEF+CMMI10"));
+        assert!(is_math_font("CMSY7"));
+        assert!(is_math_font("CambriaMath"));
+        assert!(is_math_font("STIXTwoMath-Regular"));
+        assert!(!is_math_font("TimesNewRomanPS-BoldMT"));
+        assert!(!is_math_font("Helvetica"));
+    }
+
+    #[test]
+    fn test_math_font_line_is_formula() {
+        let line = chars("xyz", 100.0, 500.0, 10.0);
+        assert!(is_formula_line(&glyphs(&line, true), (72.0, 540.0)));
+        assert!(!is_formula_line(&glyphs(&line, false), (72.0, 540.0)));
+    }
+
+    #[test]
+    fn test_symbol_dense_line_is_formula() {
+        let line = chars("∑ α ≤ ∫ f", 72.0, 500.0, 10.0);
+        assert!(is_formula_line(&glyphs(&line, false), (72.0, 540.0)));
+    }
+
+    #[test]
+    fn test_prose_with_symbols_is_not_formula() {
+        let line = chars("the value of x = y holds whenever both terms agree", 72.0, 500.0, 10.0);
+        assert!(!is_formula_line(&glyphs(&line, false), (72.0, 540.0)));
+    }
+
+    #[test]
+    fn test_centered_equation_is_formula_and_left_aligned_is_not() {
+        // Column from 72 to 540, center at 306. The line is 15 chars * 5pt = 75pt wide.
+        let centered = chars("aa = bbbbbb (1)", 306.0 - 37.5, 500.0, 10.0);
+        assert!(is_formula_line(&glyphs(&centered, false), (72.0, 540.0)));
+
+        let left = chars("aa = bbbbbb (1)", 72.0, 500.0, 10.0);
+        assert!(!is_formula_line(&glyphs(&left, false), (72.0, 540.0)));
+    }
+
+    #[test]
+    fn test_linearize_scripts_and_equation_number() {
+        let mut line = chars("E = mc", 200.0, 500.0, 10.0);
+        line.extend(chars("2", 230.0, 504.0, 7.0));
+        line.extend(chars("(4)", 300.0, 500.0, 10.0));
+        let text = linearize_formula(&[glyphs(&line, false)]);
+        assert_eq!(text, "E = mc^2 \\tag{4}");
+
+        let mut indexed = chars("x", 200.0, 500.0, 10.0);
+        indexed.extend(chars("ij", 205.0, 497.0, 7.0));
+        assert_eq!(linearize_formula(&[glyphs(&indexed, true)]), "x_{ij}");
+    }
+
+    #[test]
+    fn test_split_equation_number() {
+        assert_eq!(split_equation_number("a = b (2.1a)"), ("a = b", Some("2.1a")));
+        assert_eq!(split_equation_number("f(x)"), ("f(x)", None));
+        assert_eq!(split_equation_number("a = (b + c)"), ("a = (b + c)", None));
+    }
+}
@@ -0,0 +1,477 @@
+//! Running header and footer ("page furniture") detection.
+//!
+//! Page numbers, running titles and confidentiality notices sit at the same
+//! place on most pages. Lines near the top and bottom edge of every page are
+//! compared across pages; a line whose text (with digits masked, so that page
+//! numbers match) recurs at the same vertical position on enough pages is
+//! furniture. Detected lines can then be removed from the page text and the
+//! Markdown output.
+
+use super::error::{PdfError, Result};
+use crate::types::{BoundingBox, ContentLayer, PageFurniture};
+use pdfium_render::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Height of the header and footer zones as a fraction of the page height.
+const ZONE_FRACTION: f64 = 0.12;
+/// Lines considered per zone, counted from the page edge.
+const MAX_LINES_PER_ZONE: usize = 3;
+/// Maximum difference of the distance to the page edge, as a fraction of the page height.
+const POSITION_TOLERANCE: f64 = 0.02;
+/// Minimum normalized edit similarity for two lines to be the same furniture.
+const MIN_TEXT_SIMILARITY: f64 = 0.8;
+/// Fraction of the pages a line must recur on.
+const MIN_PAGE_FRACTION: f64 = 0.3;
+/// A line must recur on at least this many pages.
+const MIN_PAGES: usize = 2;
+/// Margin (in points) added around furniture lines when filtering characters.
+const CHAR_FILTER_MARGIN: f32 = 1.0;
+
+/// A line of text with its position on the page.
+#[derive(Debug, Clone)]
+pub(crate) struct TextLine {
+    pub text: String,
+    /// PDF coordinates (y=0 at the bottom of the page).
+    pub bbox: BoundingBox,
+}
+
+/// The text lines of one page.
+#[derive(Debug, Clone)]
+pub(crate) struct PageLines {
+    pub page_number: usize,
+    pub height: f64,
+    pub lines: Vec<TextLine>,
+}
+
+/// Detect the running headers and footers of a document.
+pub fn detect_page_furniture(document: &PdfDocument<'_>) -> Result<Vec<PageFurniture>> {
+    let mut pages = Vec::with_capacity(document.pages().len() as usize);
+    for (page_idx, page) in document.pages().iter().enumerate() {
+        pages.push(PageLines {
+            page_number: page_idx + 1,
+            height: page.height().value as f64,
+            lines: page_text_lines(&page)?,
+        });
+    }
+
+    Ok(find_page_furniture(&pages))
+}
+
+/// The text lines of a page with their positions, top to bottom.
+pub(crate) fn page_text_lines(page: &PdfPage<'_>) -> Result<Vec<TextLine>> {
+    let text = page
+        .text()
+        .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
+    let segments: Vec<TextLine> = text
+        .segments()
+        .iter()
+        .filter_map(|segment| {
+            let bounds = segment.bounds();
+            let text = segment.text();
+            (!text.trim().is_empty()).then(|| TextLine {
+                text,
+                bbox: BoundingBox {
+                    x0: bounds.left().value as f64,
+                    y0: bounds.bottom().value as f64,
+                    x1: bounds.right().value as f64,
+                    y1: bounds.top().value as f64,
+                },
+            })
+        })
+        .collect();
+
+    Ok(segments_to_lines(segments))
+}
+
+/// Join text segments sharing a vertical band into lines, top to bottom.
+fn segments_to_lines(mut segments: Vec<TextLine>) -> Vec<TextLine> {
+    segments.sort_by(|a, b| b.bbox.y1.total_cmp(&a.bbox.y1));
+
+    let mut lines: Vec<Vec<TextLine>> = Vec::new();
+    for segment in segments {
+        let center = (segment.bbox.y0 + segment.bbox.y1) / 2.0;
+        match lines.last_mut() {
+            Some(line) if line.iter().any(|s| center >= s.bbox.y0 && center <= s.bbox.y1) => line.push(segment),
+            _ => lines.push(vec![segment]),
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|mut line| {
+            line.sort_by(|a, b| a.bbox.x0.total_cmp(&b.bbox.x0));
+            let bbox = line.iter().skip(1).fold(line[0].bbox, |acc, s| BoundingBox {
+                x0: acc.x0.min(s.bbox.x0),
+                y0: acc.y0.min(s.bbox.y0),
+                x1: acc.x1.max(s.bbox.x1),
+                y1: acc.y1.max(s.bbox.y1),
+            });
+            let text = line.iter().map(|s| s.text.trim()).collect::<Vec<_>>().join(" ");
+            TextLine { text, bbox }
+        })
+        .collect()
+}
+
+/// A header or footer line candidate.
+struct Candidate<'a> {
+    page_number: usize,
+    layer: ContentLayer,
+    line: &'a TextLine,
+    key: String,
+    /// Distance from the page edge of the zone, as a fraction of the page height.
+    offset: f64,
+}
+
+/// Find the lines that repeat near the top or bottom edge across pages.
+pub(crate) fn find_page_furniture(pages: &[PageLines]) -> Vec<PageFurniture> {
+    if pages.len() < MIN_PAGES {
+        return Vec::new();
+    }
+    let min_pages = MIN_PAGES.max((pages.len() as f64 * MIN_PAGE_FRACTION).ceil() as usize);
+
+    let candidates: Vec<Candidate<'_>> = pages.iter().flat_map(zone_candidates).collect();
+
+    // Greedy clustering by layer, position and text similarity. Clusters are indexed
+    // by their first line's key and by vertical band, so a candidate is only compared
+    // with clusters in position range, and edit similarity is only computed when no
+    // cluster has the same key.
+    let band_of = |offset: f64| (offset / POSITION_TOLERANCE).floor() as i64;
+    let mut clusters: Vec<Vec<usize>> = Vec::new();
+    let mut by_key: HashMap<(ContentLayer, &str), Vec<usize>> = HashMap::new();
+    let mut by_band: HashMap<(ContentLayer, i64), Vec<usize>> = HashMap::new();
+    for (idx, candidate) in candidates.iter().enumerate() {
+        let in_range =
+            |&cluster: &usize| (candidates[clusters[cluster][0]].offset - candidate.offset).abs() <= POSITION_TOLERANCE;
+        let candidate_band = band_of(candidate.offset);
+        let cluster = by_key
+            .get(&(candidate.layer, candidate.key.as_str()))
+            .and_then(|same_key| same_key.iter().copied().find(in_range))
+            .or_else(|| {
+                let mut nearby: Vec<usize> = (candidate_band - 1..=candidate_band + 1)
+                    .filter_map(|band| by_band.get(&(candidate.layer, band)))
+                    .flatten()
+                    .copied()
+                    .filter(in_range)
+                    .collect();
+                nearby.sort_unstable();
+                nearby.into_iter().find(|&cluster| {
+                    similarity(&candidates[clusters[cluster][0]].key, &candidate.key) >= MIN_TEXT_SIMILARITY
+                })
+            });
+        match cluster {
+            Some(cluster) => clusters[cluster].push(idx),
+            None => {
+                by_key
+                    .entry((candidate.layer, candidate.key.as_str()))
+                    .or_default()
+                    .push(clusters.len());
+                by_band
+                    .entry((candidate.layer, candidate_band))
+                    .or_default()
+                    .push(clusters.len());
+                clusters.push(vec![idx]);
+            }
+        }
+    }
+
+    let mut furniture: Vec<(f64, PageFurniture)> = clusters
+        .into_iter()
+        .filter(|cluster| {
+            let pages: HashSet<usize> = cluster.iter().map(|&idx| candidates[idx].page_number).collect();
+            pages.len() >= min_pages
+        })
+        .flatten()
+        .map(|idx| {
+            let candidate = &candidates[idx];
+            (
+                -candidate.line.bbox.y1,
+                PageFurniture {
+                    page_number: candidate.page_number,
+                    layer: candidate.layer,
+                    text: candidate.line.text.trim().to_string(),
+                    bounding_box: Some(candidate.line.bbox),
+                },
+            )
+        })
+        .collect();
+
+    furniture.sort_by(|(a_top, a), (b_top, b)| a.page_number.cmp(&b.page_number).then(a_top.total_cmp(b_top)));
+    furniture.into_iter().map(|(_, furniture)| furniture).collect()
+}
+
+/// The lines closest to the top and bottom edge of a page.
+fn zone_candidates(page: &PageLines) -> Vec<Candidate<'_>> {
+    if page.height <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut lines: Vec<&TextLine> = page
+        .lines
+        .iter()
+        .filter(|line| !normalize(&line.text).is_empty())
+        .collect();
+    lines.sort_by(|a, b| b.bbox.y1.total_cmp(&a.bbox.y1));
+
+    let center = |line: &TextLine| (line.bbox.y0 + line.bbox.y1) / 2.0;
+    let headers: Vec<&TextLine> = lines
+        .iter()
+        .copied()
+        .take_while(|line| center(line) >= page.height * (1.0 - ZONE_FRACTION))
+        .take(MAX_LINES_PER_ZONE)
+        .collect();
+    let footers: Vec<&TextLine> = lines
+        .iter()
+        .rev()
+        .copied()
+        .take_while(|line| center(line) <= page.height * ZONE_FRACTION)
+        .take(MAX_LINES_PER_ZONE)
+        .filter(|line| !headers.iter().any(|header| std::ptr::eq(*header, *line)))
+        .collect();
+
+    let header_candidates = headers.into_iter().map(|line| Candidate {
+        page_number: page.page_number,
+        layer: ContentLayer::Header,
+        key: normalize(&line.text),
+        offset: (page.height - line.bbox.y1) / page.height,
+        line,
+    });
+    let footer_candidates = footers.into_iter().map(|line| Candidate {
+        page_number: page.page_number,
+        layer: ContentLayer::Footer,
+        key: normalize(&line.text),
+        offset: line.bbox.y0 / page.height,
+        line,
+    });
+    header_candidates.chain(footer_candidates).collect()
+}
+
+/// Lowercase, collapse whitespace and mask digit runs, so that "Page 3 of 10"
+/// and "Page 4 of 10" compare equal.
+fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut in_digits = false;
+    for word in text.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        for c in word.chars() {
+            if c.is_ascii_digit() {
+                if !in_digits {
+                    normalized.push('#');
+                }
+                in_digits = true;
+            } else {
+                normalized.extend(c.to_lowercase());
+                in_digits = false;
+            }
+        }
+        in_digits = false;
+    }
+    normalized
+}
+
+/// Normalized edit similarity: 1.0 for equal strings, 0.0 for nothing in common.
+fn similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 || a.len().abs_diff(b.len()) as f64 > longest as f64 * (1.0 - MIN_TEXT_SIMILARITY) {
+        return 0.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    1.0 - previous[b.len()] as f64 / longest as f64
+}
+
+/// Remove the furniture lines of one page from its extracted text.
+///
+/// Each furniture entry removes at most one line, compared with whitespace
+/// collapsed.
+pub(crate) fn strip_furniture_lines(page_text: &str, furniture: &[&PageFurniture]) -> String {
+    if furniture.is_empty() {
+        return page_text.to_string();
+    }
+
+    let collapse = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut remaining: Vec<String> = furniture.iter().map(|f| collapse(&f.text)).collect();
+
+    let kept: Vec<&str> = page_text
+        .split('\n')
+        .filter(|line| {
+            let line = collapse(line);
+            match remaining.iter().position(|text| !line.is_empty() && *text == line) {
+                Some(idx) => {
+                    remaining.swap_remove(idx);
+                    false
+                }
+                None => true,
+            }
+        })
+        .collect();
+    kept.join("\n").trim_matches(['\r', '\n']).to_string()
+}
+
+/// Whether a character at (`x`, `baseline_y`) lies on one of the furniture lines.
+pub(crate) fn is_on_furniture(furniture: &[&PageFurniture], x: f32, baseline_y: f32) -> bool {
+    furniture.iter().any(|f| {
+        f.bounding_box.is_some_and(|bbox| {
+            x >= bbox.x0 as f32 - CHAR_FILTER_MARGIN
+                && x <= bbox.x1 as f32 + CHAR_FILTER_MARGIN
+                && baseline_y >= bbox.y0 as f32 - CHAR_FILTER_MARGIN
+                && baseline_y <= bbox.y1 as f32 + CHAR_FILTER_MARGIN
+        })
+    })
+}
+
+/// The furniture entries of one page.
+pub(crate) fn furniture_on_page(furniture: &[PageFurniture], page_number: usize) -> Vec<&PageFurniture> {
+    furniture.iter().filter(|f| f.page_number == page_number).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE_HEIGHT: f64 = 792.0;
+
+    fn line(text: &str, y0: f64) -> TextLine {
+        TextLine {
+            text: text.to_string(),
+            bbox: BoundingBox {
+                x0: 72.0,
+                y0,
+                x1: 300.0,
+                y1: y0 + 10.0,
+            },
+        }
+    }
+
+    fn page(page_number: usize, body: &str) -> PageLines {
+        PageLines {
+            page_number,
+            height: PAGE_HEIGHT,
+            lines: vec![
+                line("ACME Corp Annual Report", 760.0),
+                line(body, 500.0),
+                line("Confidential", 50.0),
+                line(&format!("Page {} of 4", page_number), 30.0),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_find_page_furniture_detects_repeated_headers_and_footers() {
+        let pages: Vec<PageLines> = (1..=4).map(|n| page(n, &format!("Body text {}", n))).collect();
+
+        let furniture = find_page_furniture(&pages);
+        assert_eq!(furniture.len(), 12);
+
+        let first_page: Vec<(&str, ContentLayer)> = furniture
+            .iter()
+            .filter(|f| f.page_number == 1)
+            .map(|f| (f.text.as_str(), f.layer))
+            .collect();
+        assert_eq!(
+            first_page,
+            vec![
+                ("ACME Corp Annual Report", ContentLayer::Header),
+                ("Confidential", ContentLayer::Footer),
+                ("Page 1 of 4", ContentLayer::Footer),
+            ]
+        );
+        assert!(!furniture.iter().any(|f| f.text.starts_with("Body")));
+    }
+
+    #[test]
+    fn test_find_page_furniture_ignores_lines_that_do_not_repeat() {
+        let mut pages: Vec<PageLines> = (1..=4).map(|n| page(n, "Body")).collect();
+        // A title only on the first page, and a header line that moves
+        pages[0].lines.push(line("Quarterly results", 740.0));
+        pages[1].lines[0].bbox.y0 = 600.0;
+        pages[1].lines[0].bbox.y1 = 610.0;
+
+        let furniture = find_page_furniture(&pages);
+        assert!(!furniture.iter().any(|f| f.text == "Quarterly results"));
+        assert!(
+            !furniture
+                .iter()
+                .any(|f| f.page_number == 2 && f.layer == ContentLayer::Header)
+        );
+        assert_eq!(furniture.iter().filter(|f| f.layer == ContentLayer::Header).count(), 3);
+
+        assert!(find_page_furniture(&pages[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_find_page_furniture_matches_similar_lines_across_bands() {
+        // Headers alternate between two positions on either side of a band
+        // boundary, and between two spellings.
+        let pages: Vec<PageLines> = (1..=4)
+            .map(|n| PageLines {
+                page_number: n,
+                height: PAGE_HEIGHT,
+                lines: vec![
+                    if n % 2 == 0 {
+                        line("Chapter one: revenue", 751.0)
+                    } else {
+                        line("Chapter one: revenues", 749.0)
+                    },
+                    line("Body", 500.0),
+                ],
+            })
+            .collect();
+
+        let furniture = find_page_furniture(&pages);
+        assert_eq!(furniture.len(), 4);
+        assert!(furniture.iter().all(|f| f.layer == ContentLayer::Header));
+    }
+
+    #[test]
+    fn test_normalize_and_similarity() {
+        assert_eq!(normalize("  Page 12 of  30 "), "page # of #");
+        assert_eq!(
+            similarity("chapter one: revenue", "chapter one: revenues"),
+            1.0 - 1.0 / 21.0
+        );
+        assert_eq!(similarity("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn test_strip_furniture_lines() {
+        let furniture = PageFurniture {
+            page_number: 1,
+            layer: ContentLayer::Footer,
+            text: "Page 1 of 4".to_string(),
+            bounding_box: None,
+        };
+
+        let text = "ACME\r\nIntro paragraph\r\nPage  1 of 4\r\n";
+        assert_eq!(strip_furniture_lines(text, &[&furniture]), "ACME\r\nIntro paragraph");
+        assert_eq!(strip_furniture_lines(text, &[]), text);
+    }
+
+    #[test]
+    fn test_segments_to_lines_joins_segments_on_the_same_line() {
+        let mut right = line("Confidential", 50.0);
+        right.bbox.x0 = 400.0;
+        right.bbox.x1 = 500.0;
+        let lines = segments_to_lines(vec![right, line("ACME", 51.0), line("Body", 500.0)]);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].text, "Body");
+        assert_eq!(lines[1].text, "ACME Confidential");
+        assert_eq!(lines[1].bbox.x1, 500.0);
+    }
+}
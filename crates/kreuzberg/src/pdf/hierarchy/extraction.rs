@@ -277,6 +277,14 @@ pub fn assign_hierarchy_levels_from_clusters(
 /// # }
 /// ```
 pub fn extract_chars_with_fonts(page: &PdfPage) -> Result<Vec<CharData>> {
+    Ok(extract_chars_with_font_names(page)?
+        .into_iter()
+        .map(|(char_data, _font_name)| char_data)
+        .collect())
+}
+
+/// Extract characters with fonts from a PDF page, each paired with the name of its font.
+pub(crate) fn extract_chars_with_font_names(page: &PdfPage) -> Result<Vec<(CharData, String)>> {
     let page_text = page
         .text()
         .map_err(|e| PdfError::TextExtractionFailed(format!("Failed to get page text: {}", e)))?;
@@ -352,7 +360,7 @@ pub fn extract_chars_with_fonts(page: &PdfPage) -> Result<Vec<CharData>> {
             baseline_y,
         };
 
-        char_data_list.push(char_data);
+        char_data_list.push((char_data, font_name));
     }
 
     Ok(char_data_list)
@@ -12,6 +12,7 @@ mod extraction;
 // Re-export all public types and functions for backward compatibility
 pub use bounding_box::BoundingBox;
 pub use clustering::{FontSizeCluster, assign_heading_levels_smart, cluster_font_sizes};
+pub(crate) use extraction::extract_chars_with_font_names;
 pub use extraction::{
     CharData, HierarchyBlock, HierarchyLevel, KMeansResult, TextBlock, assign_hierarchy_levels,
     assign_hierarchy_levels_from_clusters, extract_chars_with_fonts, merge_chars_into_blocks, should_trigger_ocr,
@@ -2,12 +2,16 @@
 //!
 //! Converts PDF documents into structured markdown by analyzing per-character
 //! font metrics (size, bold, italic, baseline position) to reconstruct headings,
-//! paragraphs, inline formatting, and list items.
+//! paragraphs, inline formatting, and list items. When the document has an
+//! outline (bookmarks), its levels take precedence over font-size clustering.
 
 use crate::pdf::error::Result;
+use crate::pdf::formulas::{Glyph, is_formula_line, is_math_font, linearize_formula};
 use crate::pdf::hierarchy::{
-    BoundingBox, CharData, TextBlock, assign_heading_levels_smart, cluster_font_sizes, extract_chars_with_fonts,
+    BoundingBox, CharData, TextBlock, assign_heading_levels_smart, cluster_font_sizes, extract_chars_with_font_names,
 };
+use crate::types::PdfOutlineEntry;
+use crate::types::outline::OutlineHeadings;
 use pdfium_render::prelude::*;
 
 // Threshold constants for spatial analysis
@@ -33,6 +37,11 @@ const COLUMN_HISTOGRAM_BIN_WIDTH: f32 = 5.0;
 const MAX_LIST_ITEM_LINES: usize = 5;
 /// Maximum distance multiplier relative to average inter-cluster gap for heading assignment.
 const MAX_HEADING_DISTANCE_MULTIPLIER: f32 = 2.0;
+/// Share of the font size below the baseline, used for approximate paragraph boxes.
+const DESCENT_FRACTION: f32 = 0.2;
+/// Vertical distance from a word's baseline, as a fraction of its font size, of
+/// the characters (exponents, indices) that belong to it.
+const WORD_GLYPH_BASELINE_FRACTION: f32 = 0.6;
 
 /// A detected column region on a page.
 #[derive(Debug, Clone)]
@@ -46,7 +55,6 @@ struct ColumnRegion {
 struct PdfWord {
     text: String,
     x_start: f32,
-    #[allow(dead_code)]
     x_end: f32,
     baseline_y: f32,
     font_size: f32,
@@ -70,7 +78,7 @@ struct PdfLine {
 
 /// A paragraph composed of lines, with optional heading classification.
 #[derive(Debug, Clone)]
-struct PdfParagraph {
+pub(crate) struct PdfParagraph {
     lines: Vec<PdfLine>,
     dominant_font_size: f32,
     heading_level: Option<u8>,
@@ -79,6 +87,78 @@ struct PdfParagraph {
     #[allow(dead_code)]
     is_italic: bool,
     is_list_item: bool,
+    /// Linearized text, for paragraphs made of formula lines.
+    formula: Option<String>,
+}
+
+impl PdfParagraph {
+    /// Plain text of the paragraph, lines joined with spaces.
+    pub(crate) fn text(&self) -> String {
+        match &self.formula {
+            Some(formula) => formula.clone(),
+            None => join_line_texts(&self.lines),
+        }
+    }
+
+    /// Linearized text of the formula, for paragraphs detected as formulas.
+    pub(crate) fn formula(&self) -> Option<&str> {
+        self.formula.as_deref()
+    }
+
+    /// Heading level (1-6), `None` for body text.
+    pub(crate) fn heading_level(&self) -> Option<u8> {
+        self.heading_level
+    }
+
+    /// Plain text of each list item, for paragraphs classified as lists.
+    ///
+    /// A line starting with a bullet or number starts a new item; other lines
+    /// continue the previous one. Markers are kept.
+    pub(crate) fn list_items(&self) -> Vec<String> {
+        if !self.is_list_item {
+            return Vec::new();
+        }
+        let mut items: Vec<String> = Vec::new();
+        for line in &self.lines {
+            let text = join_line_texts(std::slice::from_ref(line));
+            let starts_item = line.words.first().is_some_and(|w| is_list_prefix(&w.text));
+            match items.last_mut() {
+                Some(item) if !starts_item => {
+                    item.push(' ');
+                    item.push_str(&text);
+                }
+                _ => items.push(text),
+            }
+        }
+        items
+    }
+
+    /// Top of the paragraph in PDF coordinates (y=0 at the bottom of the page).
+    pub(crate) fn top(&self) -> f32 {
+        self.lines.first().map(|l| l.baseline_y).unwrap_or(0.0)
+    }
+
+    /// Approximate bounding box in PDF coordinates, from the word extents,
+    /// baselines and font sizes.
+    pub(crate) fn bounding_box(&self) -> Option<crate::types::BoundingBox> {
+        let words = self.lines.iter().flat_map(|l| l.words.iter());
+        words
+            .map(|w| {
+                (
+                    w.x_start,
+                    w.baseline_y - w.font_size * DESCENT_FRACTION,
+                    w.x_end,
+                    w.baseline_y + w.font_size * (1.0 - DESCENT_FRACTION),
+                )
+            })
+            .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+            .map(|(x0, y0, x1, y1)| crate::types::BoundingBox {
+                x0: x0 as f64,
+                y0: y0 as f64,
+                x1: x1 as f64,
+                y1: y1 as f64,
+            })
+    }
 }
 
 /// Detect column boundaries by finding vertical gutters in character x-positions.
@@ -261,25 +341,67 @@ pub fn render_document_as_markdown_with_tables(
     k_clusters: usize,
     tables: &[crate::types::Table],
 ) -> Result<String> {
+    render_document_as_markdown_with_table_regions(document, k_clusters, tables, tables, &[])
+}
+
+/// Render a PDF document as markdown, filtering the characters inside
+/// `table_regions` and embedding `tables`.
+///
+/// The two differ for tables stitched across pages: every per-page fragment is
+/// a region to filter, but the stitched table is embedded once, at the position
+/// of its first fragment.
+///
+/// Characters on the lines of `furniture` (running headers and footers) are
+/// left out as well.
+pub(crate) fn render_document_as_markdown_with_table_regions(
+    document: &PdfDocument,
+    k_clusters: usize,
+    table_regions: &[crate::types::Table],
+    tables: &[crate::types::Table],
+    furniture: &[crate::types::PageFurniture],
+) -> Result<String> {
+    let pages = layout_document(document, k_clusters, table_regions, furniture, false)?;
+    Ok(assemble_markdown_with_tables(&pages, tables))
+}
+
+/// Analyze the layout of every page into classified paragraphs, in reading order.
+///
+/// Characters inside `table_regions` and on the lines of `furniture` are left
+/// out. Heading levels come from global font-size clustering, overridden by the
+/// document outline when present. With `detect_formulas`, lines recognized as
+/// math are split into formula paragraphs.
+pub(crate) fn layout_document(
+    document: &PdfDocument,
+    k_clusters: usize,
+    table_regions: &[crate::types::Table],
+    furniture: &[crate::types::PageFurniture],
+    detect_formulas: bool,
+) -> Result<Vec<Vec<PdfParagraph>>> {
     let pages = document.pages();
     let page_count = pages.len();
 
     // Stage 1: Extract chars from all pages
     let mut all_page_chars: Vec<Vec<CharData>> = Vec::with_capacity(page_count as usize);
+    let mut all_page_math_fonts: Vec<Vec<bool>> = Vec::with_capacity(page_count as usize);
     let mut page_dimensions: Vec<(f32, f32)> = Vec::with_capacity(page_count as usize);
     for i in 0..page_count {
         let page = pages.get(i).map_err(|e| {
             crate::pdf::error::PdfError::TextExtractionFailed(format!("Failed to get page {}: {:?}", i, e))
         })?;
-        let mut chars = extract_chars_with_fonts(&page)?;
+        let mut chars: Vec<(CharData, bool)> = extract_chars_with_font_names(&page)?
+            .into_iter()
+            .map(|(ch, font_name)| (ch, detect_formulas && is_math_font(&font_name)))
+            .collect();
         let (page_w, page_h) = (page.width().value, page.height().value);
         page_dimensions.push((page_w, page_h));
 
         // Filter out characters that fall within table bounding boxes on this page
-        let page_tables: Vec<&crate::types::Table> =
-            tables.iter().filter(|t| t.page_number == (i as usize) + 1).collect();
+        let page_tables: Vec<&crate::types::Table> = table_regions
+            .iter()
+            .filter(|t| t.page_number == (i as usize) + 1)
+            .collect();
         if !page_tables.is_empty() {
-            chars.retain(|ch| {
+            chars.retain(|(ch, _)| {
                 !page_tables.iter().any(|t| {
                     if let Some(ref bbox) = t.bounding_box {
                         // CharData uses PDF coordinates (baseline_y with y=0 at bottom).
@@ -298,7 +420,16 @@ pub fn render_document_as_markdown_with_tables(
             });
         }
 
+        let page_furniture = crate::pdf::furniture::furniture_on_page(furniture, (i as usize) + 1);
+        if !page_furniture.is_empty() {
+            chars.retain(|(ch, _)| {
+                !crate::pdf::furniture::is_on_furniture(&page_furniture, ch.x + ch.width / 2.0, ch.baseline_y)
+            });
+        }
+
+        let (chars, math_fonts) = chars.into_iter().unzip();
         all_page_chars.push(chars);
+        all_page_math_fonts.push(math_fonts);
     }
 
     // Stage 2: Global font-size clustering
@@ -337,6 +468,11 @@ pub fn render_document_as_markdown_with_tables(
     for (page_idx, page_chars) in all_page_chars.iter().enumerate() {
         let (page_w, page_h) = page_dimensions[page_idx];
         let columns = detect_columns(page_chars, page_w, page_h);
+        let glyphs = if detect_formulas {
+            page_glyphs(page_chars, &all_page_math_fonts[page_idx])
+        } else {
+            Vec::new()
+        };
 
         let mut page_paragraphs: Vec<PdfParagraph> = Vec::new();
 
@@ -345,6 +481,9 @@ pub fn render_document_as_markdown_with_tables(
             let words = chars_to_words(page_chars);
             let lines = words_to_lines(words);
             let mut paragraphs = lines_to_paragraphs(lines);
+            if detect_formulas {
+                paragraphs = split_formulas(paragraphs, &glyphs);
+            }
             classify_paragraphs(&mut paragraphs, &heading_map);
             page_paragraphs = paragraphs;
         } else {
@@ -358,6 +497,9 @@ pub fn render_document_as_markdown_with_tables(
                 let words = chars_to_words(&owned);
                 let lines = words_to_lines(words);
                 let mut paragraphs = lines_to_paragraphs(lines);
+                if detect_formulas {
+                    paragraphs = split_formulas(paragraphs, &glyphs);
+                }
                 classify_paragraphs(&mut paragraphs, &heading_map);
                 page_paragraphs.extend(paragraphs);
             }
@@ -366,13 +508,105 @@ pub fn render_document_as_markdown_with_tables(
         all_page_paragraphs.push(page_paragraphs);
     }
 
-    // Stage 4: Assemble markdown with inline tables
-    Ok(assemble_markdown_with_tables(all_page_paragraphs, tables))
+    // Stage 3b: The document outline, when present, overrides the clustered heading levels
+    let outline = crate::pdf::outline::extract_outline(document);
+    if !outline.is_empty() {
+        apply_outline_headings(&mut all_page_paragraphs, &outline);
+    }
+
+    Ok(all_page_paragraphs)
+}
+
+/// The characters of a page with their math-font flags, sorted by baseline.
+fn page_glyphs<'a>(chars: &'a [CharData], math_fonts: &[bool]) -> Vec<Glyph<'a>> {
+    let mut glyphs: Vec<Glyph<'a>> = chars
+        .iter()
+        .zip(math_fonts)
+        .map(|(ch, &math_font)| Glyph { ch, math_font })
+        .collect();
+    glyphs.sort_by(|a, b| a.ch.baseline_y.total_cmp(&b.ch.baseline_y));
+    glyphs
+}
+
+/// The characters of a line, found by word extent and baseline in the page's glyphs.
+fn line_glyphs<'a>(line: &PdfLine, glyphs: &[Glyph<'a>]) -> Vec<Glyph<'a>> {
+    let reach = line.words.iter().map(|w| w.font_size).fold(0.0, f32::max) * WORD_GLYPH_BASELINE_FRACTION;
+    let (low, high) = line
+        .words
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), w| {
+            (lo.min(w.baseline_y), hi.max(w.baseline_y))
+        });
+    let start = glyphs.partition_point(|g| g.ch.baseline_y < low - reach);
+    let end = glyphs.partition_point(|g| g.ch.baseline_y <= high + reach);
+    glyphs[start..end]
+        .iter()
+        .filter(|g| {
+            let center = g.ch.x + g.ch.width / 2.0;
+            line.words.iter().any(|w| {
+                center >= w.x_start
+                    && center <= w.x_end
+                    && (g.ch.baseline_y - w.baseline_y).abs() <= w.font_size * WORD_GLYPH_BASELINE_FRACTION
+            })
+        })
+        .copied()
+        .collect()
+}
+
+/// Split the runs of formula lines out of their paragraphs.
+///
+/// Each run becomes a paragraph carrying the linearized formula. The horizontal
+/// extent of all lines stands in for the column, to recognize centered lines.
+fn split_formulas(paragraphs: Vec<PdfParagraph>, glyphs: &[Glyph<'_>]) -> Vec<PdfParagraph> {
+    let words = paragraphs.iter().flat_map(|p| &p.lines).flat_map(|l| &l.words);
+    let column = words.fold((f32::INFINITY, f32::NEG_INFINITY), |(x0, x1), w| {
+        (x0.min(w.x_start), x1.max(w.x_end))
+    });
+
+    let mut result = Vec::with_capacity(paragraphs.len());
+    for para in paragraphs {
+        let lines: Vec<(PdfLine, Vec<Glyph<'_>>)> = para
+            .lines
+            .into_iter()
+            .map(|line| {
+                let line_glyphs = line_glyphs(&line, glyphs);
+                (line, line_glyphs)
+            })
+            .collect();
+
+        let mut run: Vec<(PdfLine, Vec<Glyph<'_>>)> = Vec::new();
+        let mut run_is_formula = false;
+        for (line, line_glyphs) in lines {
+            // Exponents or limits raised far enough become lines of one or two characters.
+            let is_formula =
+                is_formula_line(&line_glyphs, column) || (run_is_formula && !run.is_empty() && line_glyphs.len() <= 2);
+            if !run.is_empty() && is_formula != run_is_formula {
+                result.push(finalize_run(std::mem::take(&mut run), run_is_formula));
+            }
+            run_is_formula = is_formula;
+            run.push((line, line_glyphs));
+        }
+        if !run.is_empty() {
+            result.push(finalize_run(run, run_is_formula));
+        }
+    }
+    result
+}
+
+/// Build a paragraph from a run of lines, linearizing it when it is a formula.
+fn finalize_run(run: Vec<(PdfLine, Vec<Glyph<'_>>)>, is_formula: bool) -> PdfParagraph {
+    let (lines, glyphs): (Vec<PdfLine>, Vec<Vec<Glyph<'_>>>) = run.into_iter().unzip();
+    let mut para = finalize_paragraph(lines);
+    if is_formula {
+        para.formula = Some(linearize_formula(&glyphs)).filter(|formula| !formula.is_empty());
+        para.is_list_item = false;
+    }
+    para
 }
 
 /// Returns true if the character is a CJK ideograph, Hiragana, Katakana, or Hangul.
 /// Used for word boundary detection — CJK characters don't use spaces between words.
-fn is_cjk_char(c: char) -> bool {
+pub(crate) fn is_cjk_char(c: char) -> bool {
     let cp = c as u32;
     matches!(cp,
         0x4E00..=0x9FFF     // CJK Unified Ideographs
@@ -726,6 +960,7 @@ fn finalize_paragraph(lines: Vec<PdfLine>) -> PdfParagraph {
         is_bold: bold_count >= majority,
         is_italic: italic_count >= majority,
         is_list_item,
+        formula: None,
         lines,
     }
 }
@@ -752,7 +987,7 @@ fn is_list_prefix(text: &str) -> bool {
 
 /// Classify paragraphs as headings or body using the global heading map.
 fn classify_paragraphs(paragraphs: &mut [PdfParagraph], heading_map: &[(f32, Option<u8>)]) {
-    for para in paragraphs.iter_mut() {
+    for para in paragraphs.iter_mut().filter(|para| para.formula.is_none()) {
         // Count total words in the paragraph
         let word_count: usize = para.lines.iter().map(|l| l.words.len()).sum();
 
@@ -768,6 +1003,44 @@ fn classify_paragraphs(paragraphs: &mut [PdfParagraph], heading_map: &[(f32, Opt
     }
 }
 
+/// Re-assign heading levels from the document outline (bookmarks).
+///
+/// Paragraphs matching a bookmark title become headings at the bookmark's depth.
+/// When only the first line matches, it is split off as its own heading. Headings
+/// from font-size clustering that match no bookmark are demoted below the outline.
+fn apply_outline_headings(pages: &mut [Vec<PdfParagraph>], outline: &[PdfOutlineEntry]) {
+    let mut headings = OutlineHeadings::new(outline);
+
+    for (page_idx, paragraphs) in pages.iter_mut().enumerate() {
+        let page = Some(page_idx + 1);
+        let mut seeded = Vec::with_capacity(paragraphs.len());
+
+        for mut para in paragraphs.drain(..) {
+            if let Some(level) = headings.match_heading(&join_line_texts(&para.lines), page) {
+                para.heading_level = Some(level);
+                seeded.push(para);
+                continue;
+            }
+
+            if para.lines.len() > 1
+                && let Some(level) = headings.match_heading(&join_line_texts(&para.lines[..1]), page)
+            {
+                let rest = para.lines.split_off(1);
+                let mut heading = finalize_paragraph(para.lines);
+                heading.heading_level = Some(level);
+                seeded.push(heading);
+                seeded.push(finalize_paragraph(rest));
+                continue;
+            }
+
+            para.heading_level = para.heading_level.map(|level| headings.demote(level));
+            seeded.push(para);
+        }
+
+        *paragraphs = seeded;
+    }
+}
+
 /// Find the heading level for a given font size by matching against the cluster centroids.
 fn find_heading_level(font_size: f32, heading_map: &[(f32, Option<u8>)]) -> Option<u8> {
     if heading_map.is_empty() {
@@ -807,7 +1080,7 @@ fn find_heading_level(font_size: f32, heading_map: &[(f32, Option<u8>)]) -> Opti
 }
 
 /// Assemble final markdown string from classified paragraphs across all pages.
-fn assemble_markdown(pages: Vec<Vec<PdfParagraph>>) -> String {
+fn assemble_markdown(pages: &[Vec<PdfParagraph>]) -> String {
     let mut output = String::new();
 
     for (page_idx, paragraphs) in pages.iter().enumerate() {
@@ -820,27 +1093,7 @@ fn assemble_markdown(pages: Vec<Vec<PdfParagraph>>) -> String {
                 output.push_str("\n\n");
             }
 
-            if let Some(level) = para.heading_level {
-                // Heading: prefix with # symbols
-                let prefix = "#".repeat(level as usize);
-                let text = join_line_texts(&para.lines);
-                output.push_str(&prefix);
-                output.push(' ');
-                output.push_str(&text);
-            } else if para.is_list_item {
-                // List items: preserve each line individually
-                for (line_idx, line) in para.lines.iter().enumerate() {
-                    if line_idx > 0 {
-                        output.push('\n');
-                    }
-                    let text = render_line_with_inline_markup(line);
-                    output.push_str(&text);
-                }
-            } else {
-                // Body paragraph: join lines with space, apply inline markup
-                let text = render_paragraph_with_inline_markup(para);
-                output.push_str(&text);
-            }
+            render_paragraph_to_output(para, &mut output);
         }
     }
 
@@ -851,7 +1104,7 @@ fn assemble_markdown(pages: Vec<Vec<PdfParagraph>>) -> String {
 ///
 /// Tables are inserted at their vertical position relative to surrounding paragraphs.
 /// For tables without bounding boxes, they are appended at the end of their page.
-fn assemble_markdown_with_tables(pages: Vec<Vec<PdfParagraph>>, tables: &[crate::types::Table]) -> String {
+pub(crate) fn assemble_markdown_with_tables(pages: &[Vec<PdfParagraph>], tables: &[crate::types::Table]) -> String {
     if tables.is_empty() || tables.iter().all(|t| t.bounding_box.is_none()) {
         // No positioned tables, use simple assembly
         return assemble_markdown(pages);
@@ -943,7 +1196,11 @@ fn assemble_markdown_with_tables(pages: Vec<Vec<PdfParagraph>>, tables: &[crate:
 
 /// Render a single paragraph to the output string.
 fn render_paragraph_to_output(para: &PdfParagraph, output: &mut String) {
-    if let Some(level) = para.heading_level {
+    if let Some(formula) = &para.formula {
+        output.push_str("$$\n");
+        output.push_str(formula);
+        output.push_str("\n$$");
+    } else if let Some(level) = para.heading_level {
         let prefix = "#".repeat(level as usize);
         let text = join_line_texts(&para.lines);
         output.push_str(&prefix);
@@ -1450,6 +1707,7 @@ mod tests {
             is_bold: true,
             is_italic: false,
             is_list_item: false,
+            formula: None,
         };
 
         let body_para = PdfParagraph {
@@ -1505,9 +1763,10 @@ mod tests {
             is_bold: false,
             is_italic: false,
             is_list_item: false,
+            formula: None,
         };
 
-        let markdown = assemble_markdown(vec![vec![heading_para, body_para]]);
+        let markdown = assemble_markdown(&[vec![heading_para, body_para]]);
         assert_eq!(markdown, "# Introduction\n\nThis is body.");
     }
 
@@ -1525,7 +1784,7 @@ mod tests {
     #[test]
     fn test_empty_document() {
         let paragraphs: Vec<Vec<PdfParagraph>> = vec![vec![]];
-        let markdown = assemble_markdown(paragraphs);
+        let markdown = assemble_markdown(&paragraphs);
         assert_eq!(markdown, "");
     }
 
@@ -1932,8 +2191,9 @@ mod tests {
             is_bold: false,
             is_italic: false,
             is_list_item: false,
+            formula: None,
         }]];
-        let result = assemble_markdown_with_tables(paragraphs, &[]);
+        let result = assemble_markdown_with_tables(&paragraphs, &[]);
         assert_eq!(result, "Hello");
     }
 
@@ -1964,6 +2224,7 @@ mod tests {
                 is_bold: false,
                 is_italic: false,
                 is_list_item: false,
+                formula: None,
             },
             PdfParagraph {
                 lines: vec![PdfLine {
@@ -1988,6 +2249,7 @@ mod tests {
                 is_bold: false,
                 is_italic: false,
                 is_list_item: false,
+                formula: None,
             },
         ]];
 
@@ -2001,9 +2263,11 @@ mod tests {
                 x1: 500.0,
                 y1: 500.0, // y1=500 is between Top(700) and Bottom(200)
             }),
+            end_page_number: None,
+            spans: vec![],
         }];
 
-        let result = assemble_markdown_with_tables(paragraphs, &tables);
+        let result = assemble_markdown_with_tables(&paragraphs, &tables);
         // Order should be: Top, Table, Bottom
         let top_pos = result.find("Top").unwrap();
         let table_pos = result.find("| A | B |").unwrap();
@@ -2011,4 +2275,90 @@ mod tests {
         assert!(top_pos < table_pos, "Top should come before table");
         assert!(table_pos < bottom_pos, "Table should come before Bottom");
     }
+
+    #[test]
+    fn test_apply_outline_headings() {
+        fn line(text: &str, baseline: f32, font_size: f32) -> PdfLine {
+            let words: Vec<PdfWord> = text
+                .split_whitespace()
+                .enumerate()
+                .map(|(i, word)| PdfWord {
+                    text: word.to_string(),
+                    x_start: i as f32 * 40.0,
+                    x_end: i as f32 * 40.0 + 35.0,
+                    baseline_y: baseline,
+                    font_size,
+                    is_bold: false,
+                    is_italic: false,
+                })
+                .collect();
+            finalize_line(words)
+        }
+
+        let mut clustered = finalize_paragraph(vec![line("Revision History", 50.0, 18.0)]);
+        clustered.heading_level = Some(1);
+        let merged = finalize_paragraph(vec![
+            line("2.1 Data Sources", 80.0, 12.0),
+            line("We collected samples.", 94.0, 12.0),
+        ]);
+        let mut pages = vec![vec![clustered, merged]];
+        let outline = vec![
+            PdfOutlineEntry {
+                title: "Methods".to_string(),
+                level: 1,
+                page: Some(1),
+            },
+            PdfOutlineEntry {
+                title: "2.1 Data Sources".to_string(),
+                level: 2,
+                page: Some(1),
+            },
+        ];
+
+        apply_outline_headings(&mut pages, &outline);
+
+        let paragraphs = &pages[0];
+        assert_eq!(paragraphs.len(), 3);
+        assert_eq!(paragraphs[0].heading_level, Some(3));
+        assert_eq!(paragraphs[1].heading_level, Some(2));
+        assert_eq!(join_line_texts(&paragraphs[1].lines), "2.1 Data Sources");
+        assert_eq!(paragraphs[2].heading_level, None);
+        assert_eq!(join_line_texts(&paragraphs[2].lines), "We collected samples.");
+    }
+
+    #[test]
+    fn test_split_formulas_separates_equation_lines() {
+        let fs = 12.0;
+        let text_chars = |text: &str, x: f32, baseline_y: f32| -> Vec<CharData> {
+            text.chars()
+                .enumerate()
+                .map(|(i, c)| plain_char(&c.to_string(), x + i as f32 * fs * 0.6, baseline_y, fs))
+                .collect()
+        };
+
+        let mut chars = text_chars("Energy is given by the relation", 72.0, 700.0);
+        let prose_len = chars.len();
+        chars.extend(text_chars("E = mc", 72.0, 686.0));
+        chars.push(plain_char("2", 115.2, 689.5, 8.0));
+        let formula_end = chars.len();
+        chars.extend(text_chars("where m is the mass of the body.", 72.0, 672.0));
+        let math_fonts: Vec<bool> = (0..chars.len()).map(|i| i >= prose_len && i < formula_end).collect();
+
+        let paragraphs = lines_to_paragraphs(words_to_lines(chars_to_words(&chars)));
+        assert_eq!(paragraphs.len(), 1);
+        let glyphs = page_glyphs(&chars, &math_fonts);
+        let split = split_formulas(paragraphs, &glyphs);
+
+        assert_eq!(split.len(), 3);
+        assert_eq!(split[0].formula(), None);
+        assert_eq!(split[1].formula(), Some("E = mc^2"));
+        assert_eq!(split[2].text(), "where m is the mass of the body.");
+
+        let markdown = assemble_markdown(&[split]);
+        assert!(
+            markdown.contains("relation\n\n$$\nE = mc^2\n$$\n\nwhere"),
+            "{}",
+            markdown
+        );
+    }
 }
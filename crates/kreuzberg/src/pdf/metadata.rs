@@ -1,6 +1,9 @@
 use super::bindings::bind_pdfium;
 use super::error::{PdfError, Result};
-use crate::types::{PageBoundary, PageInfo, PageStructure, PageUnitType};
+use crate::types::{
+    EmbeddedFile, FormField, OcrRegion, PageBoundary, PageFurniture, PageInfo, PageStructure, PageUnitType,
+    PdfAnnotationSummary, PdfOutlineEntry,
+};
 use pdfium_render::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -35,6 +38,31 @@ pub struct PdfMetadata {
     /// Total number of pages in the PDF document
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_count: Option<usize>,
+
+    /// Summary of the page annotations (only when annotation extraction is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<PdfAnnotationSummary>,
+
+    /// Document outline (bookmarks) flattened into a table of contents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline: Option<Vec<PdfOutlineEntry>>,
+
+    /// Interactive form fields and their values (only when form field extraction is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_fields: Option<Vec<FormField>>,
+
+    /// Files attached to the document (`/EmbeddedFiles`), e.g. the members of a PDF portfolio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedded_files: Option<Vec<EmbeddedFile>>,
+
+    /// Running headers and footers removed from the content (only when page furniture stripping is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_furniture: Option<Vec<PageFurniture>>,
+
+    /// Image regions without a text layer whose OCR text was merged into the content
+    /// (only when image region OCR is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_regions: Option<Vec<OcrRegion>>,
 }
 
 /// Complete PDF extraction metadata including common and PDF-specific fields.
@@ -213,6 +241,16 @@ fn extract_pdf_specific_metadata(document: &PdfDocument<'_>) -> Result<PdfMetada
     // Always capture page count
     metadata.page_count = Some(document.pages().len() as usize);
 
+    let outline = super::outline::extract_outline(document);
+    if !outline.is_empty() {
+        metadata.outline = Some(outline);
+    }
+
+    let embedded_files = super::embedded::list_embedded_files(document);
+    if !embedded_files.is_empty() {
+        metadata.embedded_files = Some(embedded_files);
+    }
+
     Ok(metadata)
 }
 
@@ -274,6 +312,11 @@ fn build_page_structure(
             table_count: None,
             hidden: None,
             is_blank,
+            rotation: None,
+            text_layer: None,
+            ocr_backend: None,
+            ocr_language: None,
+            ocr_confidence: None,
         });
     }
 
@@ -386,7 +429,7 @@ fn parse_keywords(keywords_str: &str) -> Vec<String> {
         .collect()
 }
 
-fn parse_pdf_date(date_str: &str) -> String {
+pub(crate) fn parse_pdf_date(date_str: &str) -> String {
     let cleaned = date_str.trim();
 
     if cleaned.starts_with("D:") && cleaned.len() >= 10 {
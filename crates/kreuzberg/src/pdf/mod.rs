@@ -8,8 +8,19 @@
 //!
 //! - **Text extraction**: Extract text content from PDFs using `pdfium-render`
 //! - **Metadata extraction**: Parse PDF metadata (title, author, creation date, etc.)
+//! - **Annotation extraction**: Read comments, highlights, sticky notes and links
+//! - **Outline extraction**: Read the bookmark tree as a table of contents
+//! - **Form field extraction**: Read AcroForm and XFA field values
+//! - **Embedded files**: List and read the files attached to a document
+//! - **Page furniture**: Detect running headers and footers
+//! - **Tagged PDFs**: Read reading order and roles from structure trees
+//! - **Text layer health**: Score native text to detect broken font encodings
+//! - **Text geometry**: Export words and lines with positions and fonts
 //! - **Image extraction**: Extract embedded images from PDF pages
+//! - **Figure detection**: Find vector charts and diagrams with their captions
+//! - **Formula detection**: Recognize display equations in untagged documents
 //! - **Page rendering**: Render PDF pages to images for OCR processing
+//! - **Searchable PDFs**: Write scans with an invisible OCR text layer
 //! - **Error handling**: Comprehensive PDF-specific error types
 //!
 //! # Example
@@ -36,14 +47,28 @@
 //! This module requires the `pdf` feature. The `ocr` feature enables additional
 //! functionality in the PDF extractor for rendering pages to images.
 #[cfg(feature = "pdf")]
+pub mod annotations;
+#[cfg(feature = "pdf")]
 pub(crate) mod bindings;
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub mod bundled;
 #[cfg(feature = "pdf")]
+pub mod elements;
+#[cfg(feature = "pdf")]
+pub mod embedded;
+#[cfg(feature = "pdf")]
 pub mod error;
 #[cfg(feature = "pdf")]
+pub(crate) mod figures;
+#[cfg(feature = "pdf")]
 pub mod fonts;
 #[cfg(feature = "pdf")]
+pub mod forms;
+#[cfg(feature = "pdf")]
+pub(crate) mod formulas;
+#[cfg(feature = "pdf")]
+pub mod furniture;
+#[cfg(feature = "pdf")]
 pub mod hierarchy;
 #[cfg(feature = "pdf")]
 pub mod images;
@@ -52,10 +77,18 @@ pub mod markdown;
 #[cfg(feature = "pdf")]
 pub mod metadata;
 #[cfg(feature = "pdf")]
+pub mod outline;
+#[cfg(feature = "pdf")]
+pub(crate) mod regions;
+#[cfg(feature = "pdf")]
 pub mod rendering;
 #[cfg(feature = "pdf")]
+pub mod searchable;
+#[cfg(feature = "pdf")]
 pub mod split;
 #[cfg(feature = "pdf")]
+pub(crate) mod structure;
+#[cfg(feature = "pdf")]
 pub mod table;
 #[cfg(feature = "pdf")]
 pub mod table_clustering;
@@ -65,18 +98,32 @@ pub mod table_edges;
 pub mod table_finder;
 #[cfg(feature = "pdf")]
 pub mod table_geometry;
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+pub mod table_stitching;
+#[cfg(feature = "pdf")]
+pub(crate) mod tagged;
 #[cfg(feature = "pdf")]
 pub mod text;
+#[cfg(feature = "pdf")]
+pub mod text_layer;
 
 #[cfg(feature = "pdf")]
 pub use crate::core::config::HierarchyConfig;
+#[cfg(feature = "pdf")]
+pub use annotations::{extract_annotations_from_document, extract_page_annotations};
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub use bundled::extract_bundled_pdfium;
 #[cfg(feature = "pdf")]
+pub use embedded::{list_embedded_files, read_embedded_files};
+#[cfg(feature = "pdf")]
 pub use error::PdfError;
 #[cfg(feature = "pdf")]
 pub use fonts::{cached_font_count, get_font_descriptors, initialize_font_cache};
 #[cfg(feature = "pdf")]
+pub use forms::{extract_form_fields, extract_xfa_fields};
+#[cfg(feature = "pdf")]
+pub use furniture::detect_page_furniture;
+#[cfg(feature = "pdf")]
 pub use hierarchy::{
     BoundingBox, CharData, FontSizeCluster, HierarchyLevel, TextBlock, assign_hierarchy_levels,
     assign_hierarchy_levels_from_clusters, cluster_font_sizes, extract_chars_with_fonts, should_trigger_ocr,
@@ -86,7 +133,15 @@ pub use images::{PdfImage, PdfImageExtractor, extract_images_from_pdf};
 #[cfg(feature = "pdf")]
 pub use metadata::extract_metadata;
 #[cfg(feature = "pdf")]
+pub use outline::extract_outline;
+#[cfg(feature = "pdf")]
 pub use rendering::{PageRenderOptions, render_page_to_image};
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+pub use searchable::make_searchable_pdf;
+#[cfg(all(feature = "pdf", feature = "ocr", feature = "tokio-runtime"))]
+pub use searchable::make_searchable_pdf_sync;
+#[cfg(feature = "pdf")]
+pub use searchable::{SearchablePage, write_searchable_pdf};
 #[cfg(feature = "pdf")]
 pub use split::{
     PageRange, page_count, page_count_with_password, split_pdf, split_pdf_into_chunks,
@@ -97,11 +152,13 @@ pub use split::{
 pub use table::extract_words_from_page;
 #[cfg(feature = "pdf")]
 pub use table_finder::{
-    DetectedTable, StyledCellText, TableFinderResult, TableSettings, TableStrategy, TextStyle,
-    extract_table_text_styled, find_table, find_tables,
+    DetectedTable, SpannedCell, StyledCellText, TableFinderResult, TableSettings, TableStrategy, TextStyle,
+    extract_table, extract_table_text_styled, find_table, find_tables,
 };
 #[cfg(feature = "pdf")]
 pub use table_geometry::{are_neighbors, join_neighboring_rects};
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+pub use table_stitching::stitch_multi_page_tables;
 #[cfg(feature = "pdf")]
 pub use text::extract_text_from_pdf;
 
@@ -0,0 +1,96 @@
+//! PDF outline (bookmark) extraction.
+//!
+//! Walks the bookmark tree depth-first and flattens it into a table of contents
+//! with titles, nesting levels and target pages.
+
+use crate::types::PdfOutlineEntry;
+use pdfium_render::prelude::*;
+
+/// Upper bound on the number of outline entries read from a document.
+///
+/// Malformed outlines can contain sibling cycles; the cap keeps the walk finite.
+const MAX_OUTLINE_ENTRIES: usize = 10_000;
+
+/// Deepest outline level that is followed.
+const MAX_OUTLINE_DEPTH: u8 = 32;
+
+/// Extract the document outline in reading order.
+///
+/// Returns an empty list when the document has no bookmarks.
+pub fn extract_outline(document: &PdfDocument<'_>) -> Vec<PdfOutlineEntry> {
+    let mut entries = Vec::new();
+    let Some(root) = document.bookmarks().root() else {
+        return entries;
+    };
+
+    // Stack of (bookmark, level); siblings are pushed before children so that
+    // children are visited first ~keep
+    let mut stack = vec![(root, 1u8)];
+    while let Some((bookmark, level)) = stack.pop() {
+        if entries.len() >= MAX_OUTLINE_ENTRIES {
+            tracing::debug!("PDF outline truncated at {} entries", MAX_OUTLINE_ENTRIES);
+            break;
+        }
+
+        if let Some(sibling) = bookmark.next_sibling() {
+            stack.push((sibling, level));
+        }
+        if level < MAX_OUTLINE_DEPTH
+            && let Some(child) = bookmark.first_child()
+        {
+            stack.push((child, level + 1));
+        }
+
+        let Some(title) = bookmark
+            .title()
+            .map(|title| clean_title(&title))
+            .filter(|t| !t.is_empty())
+        else {
+            continue;
+        };
+        entries.push(PdfOutlineEntry {
+            title,
+            level,
+            page: target_page(&bookmark),
+        });
+    }
+
+    entries
+}
+
+/// Target page (1-indexed) of a bookmark's destination or go-to action.
+fn target_page(bookmark: &PdfBookmark<'_>) -> Option<usize> {
+    let action = bookmark.action();
+    let destination = bookmark.destination().or_else(|| {
+        action
+            .as_ref()
+            .and_then(|action| action.as_local_destination_action())
+            .and_then(|local| local.destination().ok())
+    })?;
+
+    destination
+        .page_index()
+        .ok()
+        .and_then(|index| usize::try_from(index).ok())
+        .map(|index| index + 1)
+}
+
+/// Collapse whitespace and strip control characters from a bookmark title.
+fn clean_title(title: &str) -> String {
+    title
+        .split(|c: char| c.is_whitespace() || c.is_control())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_title_collapses_whitespace() {
+        assert_eq!(clean_title("  2.1\tData\r\nSources "), "2.1 Data Sources");
+        assert_eq!(clean_title("\u{0}\u{1}"), "");
+    }
+}
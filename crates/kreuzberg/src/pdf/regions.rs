@@ -0,0 +1,284 @@
+//! Image regions without a text layer on native PDF pages.
+//!
+//! Pages with a text layer can still carry text that only exists as pixels:
+//! scanned stamps, signatures or pasted screenshots of tables. Image objects
+//! that no text-layer line overlaps are collected here so that only those crops
+//! are OCR'd. Each region remembers the last text line above it, which is where
+//! its recognized text is merged into the native content.
+
+use super::error::Result;
+use super::furniture::{TextLine, page_text_lines};
+use super::structure::image_boxes;
+use crate::types::{BoundingBox, PageBoundary};
+use pdfium_render::prelude::*;
+
+/// Minimum width and height (in points) of an image region worth OCR'ing.
+const MIN_REGION_SIDE: f64 = 18.0;
+/// Minimum area (in square points) of an image region worth OCR'ing.
+const MIN_REGION_AREA: f64 = 1500.0;
+
+/// An image region on a page with a text layer, where the region itself has none.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ImageRegion {
+    pub page_number: usize,
+    /// Page size in points, to map the region onto a rendered page image.
+    pub page_width: f64,
+    pub page_height: f64,
+    /// PDF coordinates (y=0 at the bottom of the page).
+    pub bbox: BoundingBox,
+    /// Text of the last text-layer line above the region (`None` when the
+    /// region is above all text on the page).
+    pub anchor: Option<String>,
+}
+
+/// Find the image regions that no text-layer line overlaps.
+///
+/// Pages without any text layer are skipped: they are left to full-page OCR.
+pub(crate) fn find_image_regions(document: &PdfDocument<'_>) -> Result<Vec<ImageRegion>> {
+    let mut regions = Vec::new();
+    for (page_idx, page) in document.pages().iter().enumerate() {
+        let images = image_boxes(&page);
+        if images.is_empty() {
+            continue;
+        }
+        let lines = page_text_lines(&page)?;
+        if lines.is_empty() {
+            continue;
+        }
+
+        regions.extend(
+            untexted_regions(&images, &lines)
+                .into_iter()
+                .map(|(bbox, anchor)| ImageRegion {
+                    page_number: page_idx + 1,
+                    page_width: page.width().value as f64,
+                    page_height: page.height().value as f64,
+                    bbox,
+                    anchor,
+                }),
+        );
+    }
+    Ok(regions)
+}
+
+/// The large enough image boxes without text, each with the last line above it.
+fn untexted_regions(images: &[BoundingBox], lines: &[TextLine]) -> Vec<(BoundingBox, Option<String>)> {
+    images
+        .iter()
+        .filter(|bbox| {
+            let (width, height) = (bbox.x1 - bbox.x0, bbox.y1 - bbox.y0);
+            width >= MIN_REGION_SIDE && height >= MIN_REGION_SIDE && width * height >= MIN_REGION_AREA
+        })
+        .filter(|bbox| !lines.iter().any(|line| overlaps(&line.bbox, bbox)))
+        .map(|bbox| {
+            let anchor = lines
+                .iter()
+                .filter(|line| center_y(&line.bbox) > bbox.y1)
+                .min_by(|a, b| center_y(&a.bbox).total_cmp(&center_y(&b.bbox)))
+                .map(|line| line.text.trim().to_string());
+            (*bbox, anchor)
+        })
+        .collect()
+}
+
+fn center_y(bbox: &BoundingBox) -> f64 {
+    (bbox.y0 + bbox.y1) / 2.0
+}
+
+/// Whether the center of `inner` lies within `outer`.
+fn overlaps(inner: &BoundingBox, outer: &BoundingBox) -> bool {
+    let (x, y) = ((inner.x0 + inner.x1) / 2.0, center_y(inner));
+    x >= outer.x0 && x <= outer.x1 && y >= outer.y0 && y <= outer.y1
+}
+
+/// Letters and digits only, lowercased, for matching a text-layer line against
+/// content that may be formatted differently (Markdown markup, joined lines).
+#[cfg_attr(not(feature = "ocr"), allow(dead_code))]
+fn match_key(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Byte offset in `content[start..end]` where the text of a region is inserted:
+/// the end of the line ending with its anchor, the start of the range for regions
+/// above all text, or the end of the range when the anchor is not found.
+#[cfg_attr(not(feature = "ocr"), allow(dead_code))]
+fn insertion_offset(content: &str, start: usize, end: usize, anchor: Option<&str>) -> usize {
+    let Some(anchor) = anchor.map(match_key).filter(|key| !key.is_empty()) else {
+        return start;
+    };
+
+    let mut offset = start;
+    for line in content[start..end].split_inclusive('\n') {
+        let line_end = offset + line.trim_end_matches(['\r', '\n']).len();
+        if match_key(line).ends_with(&anchor) {
+            return line_end;
+        }
+        offset += line.len();
+    }
+    end
+}
+
+/// Merge the OCR text of image regions into `content` at their reading position.
+///
+/// `regions` pairs each region with its recognized text, in page order. With
+/// `boundaries`, a region is only placed within its page and the boundaries are
+/// shifted to cover the inserted text. Without them, regions are placed in order
+/// from the start of the content.
+#[cfg_attr(not(feature = "ocr"), allow(dead_code))]
+pub(crate) fn merge_region_texts(
+    content: &str,
+    mut boundaries: Option<&mut [PageBoundary]>,
+    regions: &[(&ImageRegion, String)],
+) -> String {
+    let mut merged = content.to_string();
+    let mut cursor = 0;
+
+    for (region, text) in regions {
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let page = boundaries
+            .as_deref()
+            .and_then(|b| b.iter().find(|b| b.page_number == region.page_number));
+        let (start, end) = match page {
+            Some(page) => (cursor.max(page.byte_start), page.byte_end),
+            None => (cursor, merged.len()),
+        };
+        if start > end || end > merged.len() {
+            continue;
+        }
+
+        let at = insertion_offset(&merged, start, end, region.anchor.as_deref());
+        let insert = if at == start && region.anchor.is_none() && at < end {
+            format!("{}\n\n", text)
+        } else {
+            format!("\n\n{}", text)
+        };
+        merged.insert_str(at, &insert);
+        cursor = at + insert.len();
+
+        if let Some(boundaries) = boundaries.as_deref_mut() {
+            for boundary in boundaries.iter_mut() {
+                if boundary.byte_start > at || (boundary.byte_start == at && boundary.page_number != region.page_number)
+                {
+                    boundary.byte_start += insert.len();
+                }
+                if boundary.byte_end >= at && (boundary.byte_end > at || boundary.page_number == region.page_number) {
+                    boundary.byte_end += insert.len();
+                }
+            }
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bbox(x0: f64, y0: f64, x1: f64, y1: f64) -> BoundingBox {
+        BoundingBox { x0, y0, x1, y1 }
+    }
+
+    fn line(text: &str, y0: f64) -> TextLine {
+        TextLine {
+            text: text.to_string(),
+            bbox: bbox(72.0, y0, 300.0, y0 + 10.0),
+        }
+    }
+
+    fn region(page_number: usize, anchor: Option<&str>) -> ImageRegion {
+        ImageRegion {
+            page_number,
+            page_width: 612.0,
+            page_height: 792.0,
+            bbox: bbox(72.0, 100.0, 300.0, 200.0),
+            anchor: anchor.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_untexted_regions_skip_images_with_text_and_small_images() {
+        let lines = vec![
+            line("Invoice 2024-117", 700.0),
+            line("Total due", 400.0),
+            line("Thank you", 50.0),
+        ];
+        let images = vec![
+            // Scanned stamp below "Total due"
+            bbox(72.0, 150.0, 250.0, 250.0),
+            // Background image under the heading, which has a text layer
+            bbox(60.0, 690.0, 400.0, 720.0),
+            // Tiny decoration
+            bbox(500.0, 500.0, 510.0, 510.0),
+            // Logo above all text
+            bbox(400.0, 740.0, 520.0, 780.0),
+        ];
+
+        let regions = untexted_regions(&images, &lines);
+        assert_eq!(
+            regions,
+            vec![(images[0], Some("Total due".to_string())), (images[3], None),]
+        );
+    }
+
+    #[test]
+    fn test_merge_region_texts_inserts_after_anchor_line() {
+        let content = "# Invoice\n\nThe **total due** is listed below.\n\nThank you";
+        let stamp = region(1, Some("total due is listed below."));
+        let merged = merge_region_texts(content, None, &[(&stamp, "PAID 2024-03-01".to_string())]);
+        assert_eq!(
+            merged,
+            "# Invoice\n\nThe **total due** is listed below.\n\nPAID 2024-03-01\n\nThank you"
+        );
+
+        let unknown = region(1, Some("not in the text"));
+        let logo = region(1, None);
+        let merged = merge_region_texts(
+            "Body",
+            None,
+            &[(&logo, "ACME".to_string()), (&unknown, "Signed".to_string())],
+        );
+        assert_eq!(merged, "ACME\n\nBody\n\nSigned");
+    }
+
+    #[test]
+    fn test_merge_region_texts_shifts_page_boundaries() {
+        let content = "Page one\n\nPage two";
+        let mut boundaries = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 8,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 10,
+                byte_end: 18,
+                page_number: 2,
+            },
+        ];
+        let signature = region(2, Some("Page two"));
+        let top = region(1, None);
+
+        let merged = merge_region_texts(
+            content,
+            Some(&mut boundaries),
+            &[(&top, "Header scan".to_string()), (&signature, "J. Smith".to_string())],
+        );
+        assert_eq!(merged, "Header scan\n\nPage one\n\nPage two\n\nJ. Smith");
+        assert_eq!(
+            &merged[boundaries[0].byte_start..boundaries[0].byte_end],
+            "Header scan\n\nPage one"
+        );
+        assert_eq!(
+            &merged[boundaries[1].byte_start..boundaries[1].byte_end],
+            "Page two\n\nJ. Smith"
+        );
+    }
+}
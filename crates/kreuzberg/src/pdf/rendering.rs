@@ -55,52 +55,14 @@ impl PdfRenderer<'_> {
         options: &PageRenderOptions,
         password: Option<&str>,
     ) -> Result<DynamicImage> {
-        let document = self.pdfium.load_pdf_from_byte_slice(pdf_bytes, password).map_err(|e| {
-            let err_msg = super::error::format_pdfium_error(e);
-            if (err_msg.contains("password") || err_msg.contains("Password")) && password.is_some() {
-                PdfError::InvalidPassword
-            } else if err_msg.contains("password") || err_msg.contains("Password") {
-                PdfError::PasswordRequired
-            } else {
-                PdfError::InvalidPdf(err_msg)
-            }
-        })?;
+        let document = self.load_document(pdf_bytes, password)?;
 
         let page = document
             .pages()
             .get(page_index as i32)
             .map_err(|_| PdfError::PageNotFound(page_index))?;
 
-        let width_points = page.width().value;
-        let height_points = page.height().value;
-
-        let dpi = if options.auto_adjust_dpi {
-            calculate_optimal_dpi(
-                width_points as f64,
-                height_points as f64,
-                options.target_dpi,
-                options.max_image_dimension,
-                options.min_dpi,
-                options.max_dpi,
-            )
-        } else {
-            options.target_dpi
-        };
-
-        let scale = dpi as f64 / PDF_POINTS_PER_INCH;
-
-        let config = PdfRenderConfig::new()
-            .set_target_width(((width_points * scale as f32) as i32).max(1))
-            .set_target_height(((height_points * scale as f32) as i32).max(1))
-            .rotate_if_landscape(PdfPageRenderRotation::None, false);
-
-        let bitmap = page
-            .render_with_config(&config)
-            .map_err(|e| PdfError::RenderingFailed(format!("Failed to render page: {}", e)))?;
-
-        let image = bitmap.as_image().into_rgb8();
-
-        Ok(DynamicImage::ImageRgb8(image))
+        render_page(&page, options)
     }
 
     pub fn render_all_pages(&self, pdf_bytes: &[u8], options: &PageRenderOptions) -> Result<Vec<DynamicImage>> {
@@ -113,7 +75,43 @@ impl PdfRenderer<'_> {
         options: &PageRenderOptions,
         password: Option<&str>,
     ) -> Result<Vec<DynamicImage>> {
-        let document = self.pdfium.load_pdf_from_byte_slice(pdf_bytes, password).map_err(|e| {
+        let page_count = self.load_document(pdf_bytes, password)?.pages().len() as usize;
+        self.render_pages_with_password(pdf_bytes, 0..page_count, options, password)
+    }
+
+    /// Render the pages in `page_indices` (0-indexed), loading the document once.
+    pub fn render_pages_with_password(
+        &self,
+        pdf_bytes: &[u8],
+        page_indices: std::ops::Range<usize>,
+        options: &PageRenderOptions,
+        password: Option<&str>,
+    ) -> Result<Vec<DynamicImage>> {
+        let document = self.load_document(pdf_bytes, password)?;
+        let pages = document.pages();
+
+        page_indices
+            .map(|page_index| {
+                let page = pages
+                    .get(page_index as i32)
+                    .map_err(|_| PdfError::PageNotFound(page_index))?;
+                render_page(&page, options)
+            })
+            .collect()
+    }
+
+    /// Width and height of every page in PDF points, in page order.
+    pub fn page_sizes_with_password(&self, pdf_bytes: &[u8], password: Option<&str>) -> Result<Vec<(f32, f32)>> {
+        let document = self.load_document(pdf_bytes, password)?;
+        Ok(document
+            .pages()
+            .iter()
+            .map(|page| (page.width().value, page.height().value))
+            .collect())
+    }
+
+    fn load_document<'b>(&'b self, pdf_bytes: &'b [u8], password: Option<&str>) -> Result<PdfDocument<'b>> {
+        self.pdfium.load_pdf_from_byte_slice(pdf_bytes, password).map_err(|e| {
             let err_msg = super::error::format_pdfium_error(e);
             if (err_msg.contains("password") || err_msg.contains("Password")) && password.is_some() {
                 PdfError::InvalidPassword
@@ -122,18 +120,61 @@ impl PdfRenderer<'_> {
             } else {
                 PdfError::InvalidPdf(err_msg)
             }
-        })?;
-
-        let page_count = document.pages().len() as usize;
-        let mut images = Vec::with_capacity(page_count);
+        })
+    }
+}
 
-        for page_index in 0..page_count {
-            let image = self.render_page_to_image_with_password(pdf_bytes, page_index, options, password)?;
-            images.push(image);
-        }
+/// Render a page of an already loaded document.
+pub(crate) fn render_page(page: &PdfPage<'_>, options: &PageRenderOptions) -> Result<DynamicImage> {
+    let width_points = page.width().value;
+    let height_points = page.height().value;
+
+    let dpi = if options.auto_adjust_dpi {
+        calculate_optimal_dpi(
+            width_points as f64,
+            height_points as f64,
+            options.target_dpi,
+            options.max_image_dimension,
+            options.min_dpi,
+            options.max_dpi,
+        )
+    } else {
+        options.target_dpi
+    };
+
+    let scale = dpi as f64 / PDF_POINTS_PER_INCH;
+
+    let config = PdfRenderConfig::new()
+        .set_target_width(((width_points * scale as f32) as i32).max(1))
+        .set_target_height(((height_points * scale as f32) as i32).max(1))
+        .rotate_if_landscape(PdfPageRenderRotation::None, false);
+
+    let bitmap = page
+        .render_with_config(&config)
+        .map_err(|e| PdfError::RenderingFailed(format!("Failed to render page: {}", e)))?;
+
+    let image = bitmap.as_image().into_rgb8();
+
+    Ok(DynamicImage::ImageRgb8(image))
+}
 
-        Ok(images)
-    }
+/// Crop a region given in PDF coordinates (y=0 at the bottom) out of a rendered page.
+///
+/// Returns `None` when the region lies outside the page image.
+pub(crate) fn crop_to_bbox(
+    page_image: &DynamicImage,
+    page_width: f64,
+    page_height: f64,
+    bbox: &crate::types::BoundingBox,
+) -> Option<DynamicImage> {
+    // PDF coordinates have y=0 at the bottom, image rows start at the top
+    let scale_x = page_image.width() as f64 / page_width;
+    let scale_y = page_image.height() as f64 / page_height;
+    let x = (bbox.x0 * scale_x).max(0.0) as u32;
+    let y = ((page_height - bbox.y1) * scale_y).max(0.0) as u32;
+    let width = (((bbox.x1 - bbox.x0) * scale_x) as u32).min(page_image.width().saturating_sub(x));
+    let height = (((bbox.y1 - bbox.y0) * scale_y) as u32).min(page_image.height().saturating_sub(y));
+    (width > 0 && height > 0).then(|| page_image.crop_imm(x, y, width, height))
 }
 
 pub fn render_page_to_image(pdf_bytes: &[u8], page_index: usize, options: &PageRenderOptions) -> Result<DynamicImage> {
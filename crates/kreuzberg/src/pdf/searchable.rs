@@ -0,0 +1,714 @@
+//! Searchable PDF generation.
+//!
+//! Writes scanned pages as PDF pages showing the original image, with the OCR
+//! words drawn on top in text rendering mode 3 (invisible). Viewers display the
+//! scan while search, selection and copy work on the recognized text.
+//!
+//! The text uses a glyph-less Type0 font: character codes are the UTF-16 code
+//! units of the text and a ToUnicode map turns them back into Unicode, so no
+//! font program has to be embedded. Every word is scaled horizontally to span
+//! its OCR bounding box. When the image was deskewed for OCR, the words are
+//! rotated back onto the original image.
+//!
+//! Pages are rendered, recognized and written a few at a time, so only the
+//! compressed images of earlier pages are kept while the document is built.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::core::config::ExtractionConfig;
+//! use kreuzberg::pdf::searchable::make_searchable_pdf;
+//!
+//! # async fn example() -> kreuzberg::Result<()> {
+//! let scan = std::fs::read("scan.tiff")?;
+//! let pdf = make_searchable_pdf(&scan, "image/tiff", &ExtractionConfig::default()).await?;
+//! std::fs::write("scan.pdf", pdf)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::error::{PdfError, Result};
+use crate::extraction::layout_xml::{LayoutWord, layout_pages};
+use crate::types::OcrElement;
+use image::{DynamicImage, GenericImageView, ImageEncoder};
+use lopdf::{Document, Object, ObjectId, Stream, dictionary};
+use std::fmt::Write;
+
+/// Resolution assumed for image inputs when sizing their pages.
+#[cfg(feature = "ocr")]
+const IMAGE_DPI: f64 = 300.0;
+
+/// JPEG quality used for color page images.
+const JPEG_QUALITY: u8 = 85;
+
+/// Advance width of every glyph of the text layer font, in 1/1000 em.
+const GLYPH_WIDTH: f64 = 500.0;
+
+/// PDF pages rendered per document load.
+#[cfg(feature = "ocr")]
+const RENDER_BATCH_PAGES: usize = 4;
+
+/// One page of a searchable PDF.
+#[derive(Debug, Clone)]
+pub struct SearchablePage {
+    /// Page image, drawn over the whole page.
+    pub image: DynamicImage,
+    /// Page width in PDF points.
+    pub width: f64,
+    /// Page height in PDF points.
+    pub height: f64,
+    /// Recognized text of the page (words or lines).
+    pub elements: Vec<OcrElement>,
+    /// Width and height of the pixel space the element coordinates refer to.
+    pub element_size: (u32, u32),
+    /// Skew angle (degrees) the image was straightened by before OCR, as reported
+    /// in `ImagePreprocessingMetadata::deskew_angle`; 0 when it was not deskewed.
+    /// The element coordinates refer to the straightened image.
+    pub element_skew: f64,
+}
+
+/// Write pages as a PDF with an invisible text layer.
+pub fn write_searchable_pdf(pages: &[SearchablePage]) -> Result<Vec<u8>> {
+    let mut writer = SearchablePdfWriter::new()?;
+    for page in pages {
+        writer.add_page(page)?;
+    }
+    writer.finish()
+}
+
+/// A searchable PDF built one page at a time.
+struct SearchablePdfWriter {
+    doc: Document,
+    pages_id: ObjectId,
+    font_id: ObjectId,
+    kids: Vec<Object>,
+}
+
+impl SearchablePdfWriter {
+    fn new() -> Result<Self> {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = add_text_layer_font(&mut doc)?;
+        Ok(Self {
+            doc,
+            pages_id,
+            font_id,
+            kids: Vec::new(),
+        })
+    }
+
+    /// Add a page; its image is compressed right away.
+    fn add_page(&mut self, page: &SearchablePage) -> Result<()> {
+        let image_id = self.doc.add_object(image_xobject(&page.image)?);
+        let mut content = Stream::new(dictionary! {}, page_content(page).into_bytes());
+        content.compress()?;
+        let content_id = self.doc.add_object(content);
+
+        let page_id = self.doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => self.pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), (page.width as f32).into(), (page.height as f32).into()],
+            "Contents" => content_id,
+            "Resources" => dictionary! {
+                "XObject" => dictionary! { "Im0" => image_id },
+                "Font" => dictionary! { "F1" => self.font_id },
+            },
+        });
+        self.kids.push(page_id.into());
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Vec<u8>> {
+        if self.kids.is_empty() {
+            return Err(PdfError::ExtractionFailed(
+                "A searchable PDF needs at least one page".to_string(),
+            ));
+        }
+
+        let count = self.kids.len() as i64;
+        self.doc.objects.insert(
+            self.pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => self.kids,
+                "Count" => count,
+            }),
+        );
+        let catalog_id = self.doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => self.pages_id,
+        });
+        let info_id = self.doc.add_object(dictionary! {
+            "Producer" => Object::string_literal(format!("kreuzberg {}", env!("CARGO_PKG_VERSION"))),
+        });
+        self.doc.trailer.set("Root", catalog_id);
+        self.doc.trailer.set("Info", info_id);
+
+        let mut bytes = Vec::new();
+        self.doc
+            .save_to(&mut bytes)
+            .map_err(|e| PdfError::IOError(format!("Failed to write searchable PDF: {}", e)))?;
+        Ok(bytes)
+    }
+}
+
+/// Draw the page image, then every word in invisible text over its box.
+fn page_content(page: &SearchablePage) -> String {
+    let mut content = String::new();
+    let _ = writeln!(
+        content,
+        "q\n{:.2} 0 0 {:.2} 0 0 cm\n/Im0 Do\nQ",
+        page.width, page.height
+    );
+
+    let Some(space) = TextSpace::new(page) else {
+        return content;
+    };
+
+    content.push_str("BT\n3 Tr\n");
+    for layout_page in layout_pages(&page.elements, &[]) {
+        for line in layout_page.blocks.iter().flat_map(|block| &block.lines) {
+            for (index, word) in line.words.iter().enumerate() {
+                let is_last = index + 1 == line.words.len();
+                write_word(&mut content, word, !is_last, &space);
+            }
+        }
+    }
+    content.push_str("ET\n");
+    content
+}
+
+/// Maps the pixel space of the OCR elements to PDF points on the page image.
+struct TextSpace {
+    scale_x: f64,
+    scale_y: f64,
+    page_height: f64,
+    center: (f64, f64),
+    sin: f64,
+    cos: f64,
+}
+
+impl TextSpace {
+    fn new(page: &SearchablePage) -> Option<Self> {
+        let (element_width, element_height) = page.element_size;
+        if element_width == 0 || element_height == 0 {
+            return None;
+        }
+        let (sin, cos) = page.element_skew.to_radians().sin_cos();
+        Some(Self {
+            scale_x: page.width / element_width as f64,
+            scale_y: page.height / element_height as f64,
+            page_height: page.height,
+            center: (element_width as f64 / 2.0, element_height as f64 / 2.0),
+            sin,
+            cos,
+        })
+    }
+
+    /// PDF position of a point of the OCR image, undoing the deskew rotation
+    /// around the image center.
+    fn point(&self, x: f64, y: f64) -> (f64, f64) {
+        let (cx, cy) = self.center;
+        let (u, v) = (x - cx, y - cy);
+        let original_x = u * self.cos - v * self.sin + cx;
+        let original_y = u * self.sin + v * self.cos + cy;
+        (original_x * self.scale_x, self.page_height - original_y * self.scale_y)
+    }
+
+    /// Rotation part of the text matrix, following the skewed lines of the image.
+    fn rotation(&self) -> String {
+        if self.sin == 0.0 {
+            "1 0 0 1".to_string()
+        } else {
+            format!("{:.4} {:.4} {:.4} {:.4}", self.cos, -self.sin, self.sin, self.cos)
+        }
+    }
+}
+
+/// Position a word at the bottom of its box, sized to the box height and
+/// stretched to the box width.
+fn write_word(content: &mut String, word: &LayoutWord, trailing_space: bool, space: &TextSpace) {
+    let (x0, y0, x1, y1) = word.bbox;
+    let char_count = word.text.chars().count();
+    if char_count == 0 || x1 <= x0 || y1 <= y0 {
+        return;
+    }
+
+    let font_size = (y1 - y0) as f64 * space.scale_y;
+    let natural_width = char_count as f64 * font_size * GLYPH_WIDTH / 1000.0;
+    let horizontal_scale = 100.0 * (x1 - x0) as f64 * space.scale_x / natural_width;
+    let (x, y) = space.point(x0 as f64, y1 as f64);
+
+    let mut text = word.text.clone();
+    if trailing_space {
+        text.push(' ');
+    }
+    let _ = writeln!(
+        content,
+        "/F1 {font_size:.2} Tf\n{horizontal_scale:.2} Tz\n{} {x:.2} {y:.2} Tm\n<{}> Tj",
+        space.rotation(),
+        hex_codes(&text)
+    );
+}
+
+/// Encode text as 2-byte character codes (UTF-16 code units of the BMP).
+///
+/// Characters outside the Basic Multilingual Plane are left out.
+fn hex_codes(text: &str) -> String {
+    let mut hex = String::with_capacity(text.len() * 4);
+    for ch in text.chars() {
+        let code = ch as u32;
+        if code <= 0xFFFF && !ch.is_control() {
+            let _ = write!(hex, "{:04X}", code);
+        }
+    }
+    hex
+}
+
+/// Add the glyph-less text layer font and return its object ID.
+fn add_text_layer_font(doc: &mut Document) -> Result<ObjectId> {
+    let descriptor_id = doc.add_object(dictionary! {
+        "Type" => "FontDescriptor",
+        "FontName" => "GlyphLessFont",
+        "Flags" => 5,
+        "FontBBox" => vec![0.into(), 0.into(), (GLYPH_WIDTH as i64).into(), 1000.into()],
+        "ItalicAngle" => 0,
+        "Ascent" => 1000,
+        "Descent" => 0,
+        "CapHeight" => 1000,
+        "StemV" => 80,
+    });
+    let cid_font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "CIDFontType2",
+        "BaseFont" => "GlyphLessFont",
+        "CIDSystemInfo" => dictionary! {
+            "Registry" => Object::string_literal("Adobe"),
+            "Ordering" => Object::string_literal("Identity"),
+            "Supplement" => 0,
+        },
+        "FontDescriptor" => descriptor_id,
+        "DW" => GLYPH_WIDTH as i64,
+        "CIDToGIDMap" => "Identity",
+    });
+
+    let mut to_unicode = Stream::new(dictionary! {}, to_unicode_cmap().into_bytes());
+    to_unicode.compress()?;
+    let to_unicode_id = doc.add_object(to_unicode);
+
+    Ok(doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type0",
+        "BaseFont" => "GlyphLessFont",
+        "Encoding" => "Identity-H",
+        "DescendantFonts" => vec![cid_font_id.into()],
+        "ToUnicode" => to_unicode_id,
+    }))
+}
+
+/// ToUnicode CMap mapping every 2-byte code to the same BMP code point.
+fn to_unicode_cmap() -> String {
+    let mut cmap = String::from(
+        "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n\
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+         /CMapName /Adobe-Identity-UCS def\n/CMapType 2 def\n\
+         1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n",
+    );
+    // bfrange entries may only vary in the last byte; surrogates are not characters
+    let high_bytes: Vec<u32> = (0..=0xFF).filter(|high| !(0xD8..=0xDF).contains(high)).collect();
+    for chunk in high_bytes.chunks(100) {
+        let _ = writeln!(cmap, "{} beginbfrange", chunk.len());
+        for high in chunk {
+            let _ = writeln!(cmap, "<{high:02X}00> <{high:02X}FF> <{high:02X}00>");
+        }
+        cmap.push_str("endbfrange\n");
+    }
+    cmap.push_str("endcmap\nCMapName currentdict /CMap defineresource pop\nend\nend\n");
+    cmap
+}
+
+/// Embed a page image: grayscale losslessly (Flate), color as JPEG.
+fn image_xobject(image: &DynamicImage) -> Result<Stream> {
+    let (width, height) = image.dimensions();
+    let encoding_error =
+        |e: image::ImageError| PdfError::ExtractionFailed(format!("Failed to encode page image: {}", e));
+
+    if image.color().has_color() {
+        let rgb = image.to_rgb8();
+        let mut jpeg = Vec::new();
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg, JPEG_QUALITY)
+            .write_image(rgb.as_raw(), width, height, image::ExtendedColorType::Rgb8)
+            .map_err(encoding_error)?;
+        Ok(Stream::new(
+            dictionary! {
+                "Type" => "XObject",
+                "Subtype" => "Image",
+                "Width" => width as i64,
+                "Height" => height as i64,
+                "ColorSpace" => "DeviceRGB",
+                "BitsPerComponent" => 8,
+                "Filter" => "DCTDecode",
+            },
+            jpeg,
+        ))
+    } else {
+        let mut stream = Stream::new(
+            dictionary! {
+                "Type" => "XObject",
+                "Subtype" => "Image",
+                "Width" => width as i64,
+                "Height" => height as i64,
+                "ColorSpace" => "DeviceGray",
+                "BitsPerComponent" => 8,
+            },
+            image.to_luma8().into_raw(),
+        );
+        stream.compress()?;
+        Ok(stream)
+    }
+}
+
+/// OCR a scanned document and write it as a searchable PDF.
+///
+/// Accepts scanned PDFs and images (multi-frame TIFFs become one page per
+/// frame). Every page is recognized with the configured OCR backend (Tesseract
+/// when `config.ocr` is not set); orientation detection and image
+/// preprocessing apply as they do during extraction. The output shows the
+/// original page images, rotated upright when orientation correction ran, with
+/// the recognized words as an invisible text layer.
+///
+/// PDF pages keep their size; image pages are sized for 300 DPI.
+///
+/// # Errors
+///
+/// Returns [`crate::KreuzbergError::UnsupportedFormat`] for other MIME types,
+/// and OCR, rendering or decoding errors of individual pages.
+#[cfg(feature = "ocr")]
+pub async fn make_searchable_pdf(
+    content: &[u8],
+    mime_type: &str,
+    config: &crate::core::config::ExtractionConfig,
+) -> crate::Result<Vec<u8>> {
+    use crate::core::config::{OcrConfig, OutputFormat};
+
+    let ocr_config = config.ocr.clone().unwrap_or_else(|| OcrConfig {
+        backend: "tesseract".to_string(),
+        ..OcrConfig::default()
+    });
+    // Word geometry is only produced when the backend is asked for a positioned format
+    let ocr_config = OcrConfig {
+        output_format: Some(OutputFormat::Hocr),
+        ..ocr_config
+    };
+    let preprocessing = crate::image::enhance::ocr_preprocessing_config(&ocr_config);
+
+    let is_pdf = mime_type == crate::core::mime::PDF_MIME_TYPE;
+    if !is_pdf && !mime_type.starts_with("image/") {
+        return Err(crate::KreuzbergError::UnsupportedFormat(format!(
+            "Searchable PDFs can only be made from PDFs and images, not {}",
+            mime_type
+        )));
+    }
+
+    let backend = {
+        let registry = crate::plugins::registry::get_ocr_backend_registry();
+        let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+            plugin_name: "ocr-registry".to_string(),
+        })?;
+        let backend_name = crate::core::config::canonical_ocr_backend_name(&ocr_config.backend);
+        registry.get(&backend_name)?
+    };
+
+    let mut writer = SearchablePdfWriter::new()?;
+    if is_pdf {
+        let target_dpi = preprocessing.as_ref().map(|config| config.target_dpi);
+        let page_count = super::rendering::PdfRenderer::new()?
+            .page_sizes_with_password(content, None)?
+            .len();
+        let mut start = 0;
+        while start < page_count {
+            let end = (start + RENDER_BATCH_PAGES).min(page_count);
+            for (image, points_per_pixel) in render_pdf_pages(content, start..end, target_dpi)? {
+                let page = ocr_page(
+                    backend.as_ref(),
+                    image,
+                    points_per_pixel,
+                    &ocr_config,
+                    preprocessing.as_ref(),
+                )
+                .await?;
+                writer.add_page(&page)?;
+            }
+            start = end;
+        }
+    } else {
+        for image in decode_image_pages(content, mime_type)? {
+            let page = ocr_page(
+                backend.as_ref(),
+                image,
+                72.0 / IMAGE_DPI,
+                &ocr_config,
+                preprocessing.as_ref(),
+            )
+            .await?;
+            writer.add_page(&page)?;
+        }
+    }
+
+    Ok(writer.finish()?)
+}
+
+/// Recognize one page image and pair it with its words.
+#[cfg(feature = "ocr")]
+async fn ocr_page(
+    backend: &dyn crate::plugins::OcrBackend,
+    image: DynamicImage,
+    points_per_pixel: f64,
+    ocr_config: &crate::core::config::OcrConfig,
+    preprocessing: Option<&crate::types::ImagePreprocessingConfig>,
+) -> crate::Result<SearchablePage> {
+    let image = if ocr_config.orientation_detection_enabled() {
+        crate::ocr::orientation::correct_image_orientation(backend, image, ocr_config)
+            .await?
+            .0
+    } else {
+        image
+    };
+
+    let (image_data, element_size, element_skew) = match preprocessing {
+        Some(preprocessing) => {
+            let enhanced = crate::image::preprocess_for_ocr(&image, preprocessing, None)?;
+            let size = enhanced.image.dimensions();
+            let skew = enhanced.metadata.deskew_angle.unwrap_or(0.0);
+            (crate::image::enhance::encode_png(&enhanced.image)?, size, skew)
+        }
+        None => (encode_png(&image)?, image.dimensions(), 0.0),
+    };
+    let ocr_result = crate::ocr::fallback::recognize(backend, &image_data, ocr_config)
+        .await?
+        .result;
+
+    Ok(SearchablePage {
+        width: image.width() as f64 * points_per_pixel,
+        height: image.height() as f64 * points_per_pixel,
+        image,
+        elements: ocr_result.ocr_elements.unwrap_or_default(),
+        element_size,
+        element_skew,
+    })
+}
+
+/// Synchronous wrapper for [`make_searchable_pdf`].
+#[cfg(all(feature = "ocr", feature = "tokio-runtime"))]
+pub fn make_searchable_pdf_sync(
+    content: &[u8],
+    mime_type: &str,
+    config: &crate::core::config::ExtractionConfig,
+) -> crate::Result<Vec<u8>> {
+    crate::core::extractor::GLOBAL_RUNTIME.block_on(make_searchable_pdf(content, mime_type, config))
+}
+
+/// Render the pages in `page_indices` (0-indexed), each paired with the size of one pixel in points.
+#[cfg(feature = "ocr")]
+fn render_pdf_pages(
+    content: &[u8],
+    page_indices: std::ops::Range<usize>,
+    target_dpi: Option<i32>,
+) -> Result<Vec<(DynamicImage, f64)>> {
+    use super::rendering::{PageRenderOptions, PdfRenderer};
+
+    let mut render_options = PageRenderOptions::default();
+    if let Some(target_dpi) = target_dpi {
+        render_options.target_dpi = target_dpi;
+    }
+
+    let renderer = PdfRenderer::new()?;
+    let sizes = renderer.page_sizes_with_password(content, None)?;
+    let images = renderer.render_pages_with_password(content, page_indices.clone(), &render_options, None)?;
+    Ok(images
+        .into_iter()
+        .zip(&sizes[page_indices])
+        .map(|(image, &(width, _))| {
+            let points_per_pixel = width as f64 / image.width().max(1) as f64;
+            (image, points_per_pixel)
+        })
+        .collect())
+}
+
+/// Decode the pages of an image file: every frame of a TIFF, otherwise the image itself.
+#[cfg(feature = "ocr")]
+fn decode_image_pages(content: &[u8], mime_type: &str) -> crate::Result<Vec<DynamicImage>> {
+    use crate::extraction::image::{
+        decode_jbig2_to_gray, decode_jp2_to_rgb, decode_tiff_frames, is_j2k, is_jbig2, is_jp2,
+    };
+
+    if mime_type.contains("tiff") {
+        decode_tiff_frames(content)
+    } else if is_jp2(content) || is_j2k(content) {
+        Ok(vec![DynamicImage::ImageRgb8(decode_jp2_to_rgb(content)?)])
+    } else if is_jbig2(content) {
+        Ok(vec![DynamicImage::ImageLuma8(decode_jbig2_to_gray(content)?)])
+    } else {
+        let image = image::load_from_memory(content)
+            .map_err(|e| crate::KreuzbergError::parsing(format!("Failed to decode image: {}", e)))?;
+        Ok(vec![image])
+    }
+}
+
+#[cfg(feature = "ocr")]
+fn encode_png(image: &DynamicImage) -> crate::Result<Vec<u8>> {
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    image
+        .write_to(&mut buffer, image::ImageFormat::Png)
+        .map_err(|e| crate::KreuzbergError::parsing(format!("Failed to encode page image: {}", e)))?;
+    Ok(buffer.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrBoundingGeometry, OcrConfidence, OcrElementLevel};
+
+    fn word(text: &str, left: u32, top: u32, width: u32, height: u32) -> OcrElement {
+        OcrElement::new(
+            text,
+            OcrBoundingGeometry::Rectangle {
+                left,
+                top,
+                width,
+                height,
+            },
+            OcrConfidence::from_tesseract(95.0),
+        )
+        .with_level(OcrElementLevel::Word)
+        .with_parent_id("p1_b1_par1_l1")
+    }
+
+    fn page(image: DynamicImage, elements: Vec<OcrElement>) -> SearchablePage {
+        SearchablePage {
+            image,
+            width: 144.0,
+            height: 72.0,
+            elements,
+            element_size: (200, 100),
+            element_skew: 0.0,
+        }
+    }
+
+    fn content_of(doc: &Document, page_id: ObjectId) -> String {
+        String::from_utf8(doc.get_page_content(page_id).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_text_layer() {
+        let image = DynamicImage::ImageLuma8(image::GrayImage::from_pixel(200, 100, image::Luma([255])));
+        let elements = vec![word("Hi", 20, 40, 40, 20), word("Ö", 80, 40, 20, 20)];
+        let bytes = write_searchable_pdf(&[page(image, elements)]).unwrap();
+
+        let doc = Document::load_mem(&bytes).unwrap();
+        let pages = doc.get_pages();
+        assert_eq!(pages.len(), 1);
+        let content = content_of(&doc, pages[&1]);
+
+        assert!(content.contains("144.00 0 0 72.00 0 0 cm\n/Im0 Do"));
+        assert!(content.contains("3 Tr"));
+        // 20px box at 0.72pt/px: 14.4pt high, bottom edge 72 - 60 * 0.72 = 28.8pt
+        assert!(content.contains("/F1 14.40 Tf\n200.00 Tz\n1 0 0 1 14.40 28.80 Tm\n<004800690020> Tj"));
+        assert!(content.contains("<00D6> Tj"));
+        assert_eq!(doc.extract_text(&[1]).unwrap().trim(), "Hi Ö");
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_deskewed_word_lands_on_its_glyphs() {
+        // Five bars of "text", 8px high, sloping down by 3 degrees
+        let tan = 3.0_f64.to_radians().tan();
+        let original = image::GrayImage::from_fn(400, 200, |x, y| {
+            let offset = (f64::from(x) - 200.0) * tan;
+            let on_bar = (0..5).any(|line| {
+                let top = 30.0 + 35.0 * f64::from(line) + offset;
+                (top..top + 8.0).contains(&f64::from(y))
+            });
+            image::Luma([if on_bar && (20..380).contains(&x) { 0 } else { 255 }])
+        });
+        let is_ink = |x: f64, y: f64| original.get_pixel(x.round() as u32, y.round() as u32)[0] < 128;
+
+        let config = crate::types::ImagePreprocessingConfig {
+            deskew: true,
+            ..Default::default()
+        };
+        let enhanced =
+            crate::image::preprocess_for_ocr(&DynamicImage::ImageLuma8(original.clone()), &config, None).unwrap();
+        let skew = enhanced.metadata.deskew_angle.expect("the page should be deskewed");
+
+        // The word box OCR reports for the right end of the third bar, in the deskewed image
+        let rows: Vec<u32> = (0..200)
+            .filter(|&y| enhanced.image.get_pixel(350, y)[0] < 128)
+            .collect();
+        let runs: Vec<&[u32]> = rows.chunk_by(|a, b| b - a == 1).collect();
+        let (top, bottom) = (runs[2][0], *runs[2].last().unwrap() + 1);
+
+        let page = SearchablePage {
+            image: DynamicImage::ImageLuma8(original.clone()),
+            width: 400.0,
+            height: 200.0,
+            elements: vec![word("end", 330, top, 40, bottom - top)],
+            element_size: enhanced.image.dimensions(),
+            element_skew: skew,
+        };
+        let space = TextSpace::new(&page).unwrap();
+        let middle = f64::from(top + bottom) / 2.0;
+        for x in [332.0, 350.0, 368.0] {
+            let (px, py) = space.point(x, middle);
+            assert!(
+                is_ink(px, 200.0 - py),
+                "word point ({x}, {middle}) mapped off its glyphs"
+            );
+        }
+        // Without mapping back, the box misses the skewed bar
+        assert!(!is_ink(350.0, middle));
+
+        let content = page_content(&page);
+        assert!(!content.contains("1 0 0 1 "));
+        assert!(content.contains(&format!(
+            "{:.4} {:.4}",
+            skew.to_radians().cos(),
+            -skew.to_radians().sin()
+        )));
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_embeds_color_as_jpeg() {
+        let image = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(200, 100, image::Rgb([200, 30, 30])));
+        let bytes = write_searchable_pdf(&[page(image, Vec::new())]).unwrap();
+
+        let doc = Document::load_mem(&bytes).unwrap();
+        let filters: Vec<_> = doc
+            .objects
+            .values()
+            .filter_map(|object| object.as_stream().ok())
+            .filter_map(|stream| stream.dict.get(b"Filter").and_then(Object::as_name).ok())
+            .collect();
+        assert!(filters.contains(&b"DCTDecode".as_slice()));
+    }
+
+    #[test]
+    fn test_write_searchable_pdf_requires_pages() {
+        assert!(write_searchable_pdf(&[]).is_err());
+    }
+
+    #[test]
+    fn test_hex_codes() {
+        assert_eq!(hex_codes("A é"), "0041002000E9");
+        assert_eq!(hex_codes("a\u{1F600}\u{7}"), "0061");
+    }
+
+    #[test]
+    fn test_to_unicode_cmap_ranges() {
+        let cmap = to_unicode_cmap();
+        assert_eq!(cmap.matches("<0000> <00FF> <0000>").count(), 1);
+        assert!(!cmap.contains("<D800>"));
+        assert_eq!(cmap.matches("beginbfrange").count(), 3);
+    }
+}
@@ -159,6 +159,27 @@ fn load_document(pdf_bytes: &[u8], password: Option<&str>) -> Result<Document> {
     Ok(doc)
 }
 
+/// Load a PDF, decrypting it with the empty password or the first of `passwords` that works.
+pub(crate) fn load_document_with_passwords(pdf_bytes: &[u8], passwords: &[String]) -> Result<Document> {
+    let doc = Document::load_mem(pdf_bytes).map_err(|e| PdfError::InvalidPdf(format!("Failed to load PDF: {}", e)))?;
+    if !doc.is_encrypted() {
+        return Ok(doc);
+    }
+
+    for password in std::iter::once("").chain(passwords.iter().map(String::as_str)) {
+        let mut candidate = doc.clone();
+        if candidate.decrypt(password).is_ok() {
+            return Ok(candidate);
+        }
+    }
+
+    Err(if passwords.is_empty() {
+        PdfError::PasswordRequired
+    } else {
+        PdfError::InvalidPassword
+    })
+}
+
 fn validate_range(range: &PageRange, page_count: u32) -> Result<()> {
     if range.start == 0 {
         return Err(PdfError::PageNotFound(0));
@@ -178,7 +199,8 @@ fn validate_range(range: &PageRange, page_count: u32) -> Result<()> {
     Ok(())
 }
 
-fn extract_pages(source: &mut Document, pages_to_keep: &[u32]) -> Result<Vec<u8>> {
+/// Save a copy of `source` with only the `pages_to_keep` (1-indexed).
+pub(crate) fn extract_pages(source: &mut Document, pages_to_keep: &[u32]) -> Result<Vec<u8>> {
     // Clone the document so the source stays intact for subsequent splits.
     let mut doc = source.clone();
 
@@ -0,0 +1,660 @@
+//! Native `DocumentStructure` for PDFs.
+//!
+//! Builds the document tree directly from the layout analysis used for Markdown
+//! rendering (paragraphs with font-size heading levels, list detection, table
+//! regions) instead of re-parsing the flattened text. Every node keeps its page
+//! and bounding box in PDF coordinates (y=0 at the bottom of the page).
+
+use super::error::Result;
+use super::markdown::PdfParagraph;
+use super::tagged::TaggedPage;
+use crate::extraction::transform::document_tree::{strip_list_marker, table_to_grid};
+use crate::types::{
+    BoundingBox, ContentLayer, DocumentNode, DocumentStructure, ExtractedImage, NodeContent, NodeId, NodeIndex,
+    OcrRegion, PageFurniture, PdfAnnotation, Table,
+};
+use pdfium_render::prelude::*;
+
+/// A block recognized by the layout analysis or read from the structure tree.
+#[derive(Debug, Clone)]
+pub(crate) enum LayoutBlock {
+    Heading {
+        level: u8,
+        text: String,
+    },
+    Paragraph {
+        text: String,
+    },
+    List {
+        ordered: bool,
+        items: Vec<String>,
+    },
+    /// A display formula, with its linearized text.
+    Formula {
+        text: String,
+    },
+    /// A table tagged in the structure tree (detected tables are placed by position).
+    Table(Table),
+    /// A figure tagged in the structure tree, with its alternate description.
+    Image {
+        description: Option<String>,
+    },
+}
+
+/// A block with its vertical position on the page.
+#[derive(Debug, Clone)]
+pub(crate) struct PositionedBlock {
+    pub block: LayoutBlock,
+    /// Top of the block in PDF coordinates, used to interleave tables and images.
+    pub top: f32,
+    pub bbox: Option<BoundingBox>,
+}
+
+impl From<&PdfParagraph> for PositionedBlock {
+    fn from(para: &PdfParagraph) -> Self {
+        let items = para.list_items();
+        let block = if let Some(formula) = para.formula() {
+            LayoutBlock::Formula {
+                text: formula.to_string(),
+            }
+        } else if let Some(level) = para.heading_level() {
+            LayoutBlock::Heading {
+                level,
+                text: para.text(),
+            }
+        } else if !items.is_empty() {
+            LayoutBlock::List {
+                ordered: items[0].starts_with(|c: char| c.is_ascii_digit()),
+                items: items.iter().map(|item| strip_list_marker(item).to_string()).collect(),
+            }
+        } else {
+            LayoutBlock::Paragraph { text: para.text() }
+        };
+
+        Self {
+            block,
+            top: para.top(),
+            bbox: para.bounding_box(),
+        }
+    }
+}
+
+/// Everything placed on one page of the tree.
+#[derive(Debug, Default)]
+struct PageLayout<'a> {
+    page_number: usize,
+    blocks: Vec<PositionedBlock>,
+    tables: Vec<&'a Table>,
+    images: Vec<BoundingBox>,
+    annotations: Vec<&'a PdfAnnotation>,
+    furniture: Vec<&'a PageFurniture>,
+}
+
+/// Build the `DocumentStructure` of a PDF from its layout analysis.
+///
+/// `layout` holds the classified paragraphs of every page, as returned by
+/// [`super::markdown::layout_document`]. Tables and images are placed between the
+/// paragraphs at their vertical position; annotations follow the page body and
+/// running headers and footers become `Header`/`Footer` nodes. Image nodes
+/// carry no `image_index` until [`link_image_nodes`] matches them with the
+/// extracted images.
+pub(crate) fn build_document_structure(
+    document: &PdfDocument<'_>,
+    layout: &[Vec<PdfParagraph>],
+    tables: &[Table],
+    annotations: &[PdfAnnotation],
+    furniture: &[PageFurniture],
+) -> Result<DocumentStructure> {
+    let mut pages = Vec::with_capacity(layout.len());
+    for (page_idx, page) in document.pages().iter().enumerate() {
+        let page_number = page_idx + 1;
+        pages.push(PageLayout {
+            page_number,
+            blocks: layout
+                .get(page_idx)
+                .map(|paragraphs| paragraphs.iter().map(PositionedBlock::from).collect())
+                .unwrap_or_default(),
+            tables: tables.iter().filter(|t| t.page_number == page_number).collect(),
+            images: image_boxes(&page),
+            annotations: annotations.iter().filter(|a| a.page_number == page_number).collect(),
+            furniture: furniture.iter().filter(|f| f.page_number == page_number).collect(),
+        });
+    }
+
+    Ok(assemble_structure(&pages))
+}
+
+/// Build the `DocumentStructure` of a tagged PDF from its structure tree.
+///
+/// Blocks, tables and figures keep the logical order of the tree. Annotations
+/// and running headers and footers are placed as for untagged documents.
+pub(crate) fn build_tagged_document_structure(
+    pages: &[TaggedPage],
+    annotations: &[PdfAnnotation],
+    furniture: &[PageFurniture],
+) -> DocumentStructure {
+    let pages: Vec<PageLayout<'_>> = pages
+        .iter()
+        .map(|page| PageLayout {
+            page_number: page.page_number,
+            blocks: page.blocks.clone(),
+            annotations: annotations
+                .iter()
+                .filter(|a| a.page_number == page.page_number)
+                .collect(),
+            furniture: furniture.iter().filter(|f| f.page_number == page.page_number).collect(),
+            ..Default::default()
+        })
+        .collect();
+
+    assemble_structure(&pages)
+}
+
+/// Bounding boxes of the image objects on a page, in content stream order.
+pub(crate) fn image_boxes(page: &PdfPage<'_>) -> Vec<BoundingBox> {
+    page.objects()
+        .iter()
+        .filter(|object| object.as_image_object().is_some())
+        .filter_map(|object| object.bounds().ok())
+        .map(|bounds| BoundingBox {
+            x0: bounds.left().value as f64,
+            y0: bounds.bottom().value as f64,
+            x1: bounds.right().value as f64,
+            y1: bounds.top().value as f64,
+        })
+        .collect()
+}
+
+/// Builds the tree, keeping heading sections open across pages.
+struct TreeBuilder {
+    doc: DocumentStructure,
+    section_stack: Vec<(u8, NodeIndex)>,
+}
+
+impl TreeBuilder {
+    fn push(
+        &mut self,
+        content: NodeContent,
+        page: usize,
+        page_end: Option<usize>,
+        bbox: Option<BoundingBox>,
+        layer: ContentLayer,
+    ) -> NodeIndex {
+        let page = Some(page as u32);
+        let index = self.doc.len() as u32;
+        let node = DocumentNode {
+            id: NodeId::generate(content.node_type_str(), content.text().unwrap_or(""), page, index),
+            content,
+            parent: None,
+            children: vec![],
+            content_layer: layer,
+            page,
+            page_end: page_end.map(|p| p as u32).filter(|p| Some(*p) != page),
+            bbox,
+            annotations: vec![],
+        };
+        self.doc.push_node(node)
+    }
+
+    /// Push a body node as a child of the current section.
+    fn push_body(&mut self, content: NodeContent, page: usize, bbox: Option<BoundingBox>) -> NodeIndex {
+        let idx = self.push(content, page, None, bbox, ContentLayer::Body);
+        if let Some((_, parent)) = self.section_stack.last() {
+            self.doc.add_child(*parent, idx);
+        }
+        idx
+    }
+
+    fn push_block(&mut self, block: &PositionedBlock, page: usize) {
+        match &block.block {
+            LayoutBlock::Heading { level, text } => {
+                while self.section_stack.last().is_some_and(|(l, _)| l >= level) {
+                    self.section_stack.pop();
+                }
+                let group = self.push_body(
+                    NodeContent::Group {
+                        label: None,
+                        heading_level: Some(*level),
+                        heading_text: Some(text.clone()),
+                    },
+                    page,
+                    block.bbox,
+                );
+                let heading = self.push(
+                    NodeContent::Heading {
+                        level: *level,
+                        text: text.clone(),
+                    },
+                    page,
+                    None,
+                    block.bbox,
+                    ContentLayer::Body,
+                );
+                self.doc.add_child(group, heading);
+                self.section_stack.push((*level, group));
+            }
+            LayoutBlock::Paragraph { text } => {
+                self.push_body(NodeContent::Paragraph { text: text.clone() }, page, block.bbox);
+            }
+            LayoutBlock::List { ordered, items } => {
+                let list = self.push_body(NodeContent::List { ordered: *ordered }, page, block.bbox);
+                for item in items {
+                    let item = self.push(
+                        NodeContent::ListItem { text: item.clone() },
+                        page,
+                        None,
+                        None,
+                        ContentLayer::Body,
+                    );
+                    self.doc.add_child(list, item);
+                }
+            }
+            LayoutBlock::Formula { text } => {
+                self.push_body(NodeContent::Formula { text: text.clone() }, page, block.bbox);
+            }
+            LayoutBlock::Table(table) => self.push_table(table),
+            LayoutBlock::Image { description } => {
+                self.push_body(
+                    NodeContent::Image {
+                        description: description.clone(),
+                        image_index: None,
+                    },
+                    page,
+                    block.bbox,
+                );
+            }
+        }
+    }
+
+    fn push_table(&mut self, table: &Table) {
+        let idx = self.push(
+            NodeContent::Table {
+                grid: table_to_grid(table),
+            },
+            table.page_number,
+            table.end_page_number,
+            table.bounding_box,
+            ContentLayer::Body,
+        );
+        if let Some((_, parent)) = self.section_stack.last() {
+            self.doc.add_child(*parent, idx);
+        }
+    }
+}
+
+/// Content placed between the text blocks of a page by vertical position.
+enum Floating<'a> {
+    Table(&'a Table),
+    Image(BoundingBox),
+}
+
+fn assemble_structure(pages: &[PageLayout<'_>]) -> DocumentStructure {
+    let mut builder = TreeBuilder {
+        doc: DocumentStructure::with_capacity(pages.iter().map(|p| p.blocks.len() + p.tables.len() + 2).sum()),
+        section_stack: Vec::new(),
+    };
+
+    for (page_idx, page) in pages.iter().enumerate() {
+        let page_number = page.page_number;
+
+        for header in page.furniture.iter().filter(|f| f.layer == ContentLayer::Header) {
+            builder.push(
+                NodeContent::Paragraph {
+                    text: header.text.clone(),
+                },
+                page_number,
+                None,
+                header.bounding_box,
+                ContentLayer::Header,
+            );
+        }
+
+        // Tables and images with a position, top of the page first
+        let mut floating: Vec<(f32, Floating<'_>)> = page
+            .tables
+            .iter()
+            .filter_map(|t| t.bounding_box.map(|bbox| (bbox.y1 as f32, Floating::Table(t))))
+            .chain(page.images.iter().map(|bbox| (bbox.y1 as f32, Floating::Image(*bbox))))
+            .collect();
+        floating.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let mut floating = floating.into_iter().peekable();
+
+        for block in &page.blocks {
+            while let Some((_, item)) = floating.next_if(|(top, _)| *top > block.top) {
+                push_floating(&mut builder, item, page_number);
+            }
+            builder.push_block(block, page_number);
+        }
+        for (_, item) in floating {
+            push_floating(&mut builder, item, page_number);
+        }
+        for table in page.tables.iter().filter(|t| t.bounding_box.is_none()) {
+            builder.push_table(table);
+        }
+
+        for annotation in &page.annotations {
+            builder.push_body(
+                NodeContent::Annotation {
+                    annotation_type: annotation.annotation_type,
+                    text: annotation.contents.clone(),
+                    author: annotation.author.clone(),
+                    highlighted_text: annotation.highlighted_text.clone(),
+                    url: annotation.url.clone(),
+                },
+                page_number,
+                annotation.bounding_box,
+            );
+        }
+
+        for footer in page.furniture.iter().filter(|f| f.layer == ContentLayer::Footer) {
+            builder.push(
+                NodeContent::Paragraph {
+                    text: footer.text.clone(),
+                },
+                page_number,
+                None,
+                footer.bounding_box,
+                ContentLayer::Footer,
+            );
+        }
+
+        if page_idx + 1 < pages.len() {
+            builder.push(NodeContent::PageBreak, page_number, None, None, ContentLayer::Body);
+        }
+    }
+
+    debug_assert!(builder.doc.validate().is_ok());
+    builder.doc
+}
+
+fn push_floating(builder: &mut TreeBuilder, item: Floating<'_>, page_number: usize) {
+    match item {
+        Floating::Table(table) => builder.push_table(table),
+        Floating::Image(bbox) => {
+            builder.push_body(
+                NodeContent::Image {
+                    description: None,
+                    image_index: None,
+                },
+                page_number,
+                Some(bbox),
+            );
+        }
+    }
+}
+
+/// Fill in the `image_index` and description of the image nodes from the
+/// extracted images.
+///
+/// Image nodes come from the page objects and extracted images from the page
+/// resources, so they are matched in order per page, and only on pages where
+/// both agree on the number of images.
+pub(crate) fn link_image_nodes(structure: &mut DocumentStructure, images: &[ExtractedImage]) {
+    let mut pages: Vec<u32> = structure
+        .nodes
+        .iter()
+        .filter(|node| matches!(node.content, NodeContent::Image { .. }))
+        .filter_map(|node| node.page)
+        .collect();
+    pages.dedup();
+
+    for page in pages {
+        let page_images: Vec<&ExtractedImage> = images
+            .iter()
+            .filter(|image| image.page_number == Some(page as usize))
+            .collect();
+        let is_image_on_page =
+            |node: &DocumentNode| node.page == Some(page) && matches!(node.content, NodeContent::Image { .. });
+        if structure.nodes.iter().filter(|node| is_image_on_page(node)).count() != page_images.len() {
+            continue;
+        }
+
+        let nodes = structure.nodes.iter_mut().filter(|node| is_image_on_page(node));
+        for (node, image) in nodes.zip(page_images) {
+            if let NodeContent::Image {
+                description,
+                image_index,
+            } = &mut node.content
+            {
+                *image_index = Some(image.image_index as u32);
+                *description = image.description.clone();
+            }
+        }
+    }
+}
+
+/// Add the OCR text of image regions without a text layer as paragraphs inside
+/// their image nodes (or at the end of the tree when no image node matches).
+pub(crate) fn attach_region_texts(structure: &mut DocumentStructure, regions: &[OcrRegion]) {
+    for region in regions {
+        let page = Some(region.page_number as u32);
+        let image = structure.nodes.iter().position(|node| {
+            node.page == page
+                && node.bbox == Some(region.bounding_box)
+                && matches!(node.content, NodeContent::Image { .. })
+        });
+
+        let index = structure.len() as u32;
+        let paragraph = structure.push_node(DocumentNode {
+            id: NodeId::generate("paragraph", &region.text, page, index),
+            content: NodeContent::Paragraph {
+                text: region.text.clone(),
+            },
+            parent: None,
+            children: vec![],
+            content_layer: ContentLayer::Body,
+            page,
+            page_end: None,
+            bbox: Some(region.bounding_box),
+            annotations: vec![],
+        });
+        if let Some(image) = image {
+            structure.add_child(NodeIndex(image as u32), paragraph);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bbox(y0: f64, y1: f64) -> BoundingBox {
+        BoundingBox {
+            x0: 72.0,
+            y0,
+            x1: 500.0,
+            y1,
+        }
+    }
+
+    fn block(block: LayoutBlock, top: f32) -> PositionedBlock {
+        PositionedBlock {
+            block,
+            top,
+            bbox: Some(bbox(top as f64 - 2.0, top as f64 + 10.0)),
+        }
+    }
+
+    fn table(page_number: usize, bounding_box: Option<BoundingBox>) -> Table {
+        Table {
+            cells: vec![vec!["Region".to_string(), "Revenue".to_string()]],
+            markdown: String::new(),
+            page_number,
+            end_page_number: None,
+            bounding_box,
+            spans: vec![],
+        }
+    }
+
+    #[test]
+    fn test_assemble_structure_places_content_by_position() {
+        let positioned = table(1, Some(bbox(400.0, 500.0)));
+        let furniture = PageFurniture {
+            page_number: 1,
+            layer: ContentLayer::Footer,
+            text: "Page 1".to_string(),
+            bounding_box: Some(bbox(30.0, 40.0)),
+        };
+        let pages = vec![
+            PageLayout {
+                page_number: 1,
+                blocks: vec![
+                    block(
+                        LayoutBlock::Heading {
+                            level: 1,
+                            text: "Results".to_string(),
+                        },
+                        700.0,
+                    ),
+                    block(
+                        LayoutBlock::Paragraph {
+                            text: "Revenue grew.".to_string(),
+                        },
+                        600.0,
+                    ),
+                    block(
+                        LayoutBlock::List {
+                            ordered: false,
+                            items: vec!["North".to_string(), "South".to_string()],
+                        },
+                        300.0,
+                    ),
+                ],
+                tables: vec![&positioned],
+                images: vec![bbox(100.0, 200.0)],
+                annotations: vec![],
+                furniture: vec![&furniture],
+            },
+            PageLayout {
+                page_number: 2,
+                blocks: vec![block(
+                    LayoutBlock::Paragraph {
+                        text: "Outlook is stable.".to_string(),
+                    },
+                    700.0,
+                )],
+                ..Default::default()
+            },
+        ];
+
+        let doc = assemble_structure(&pages);
+        assert!(doc.validate().is_ok());
+
+        let types: Vec<&str> = doc.nodes.iter().map(|n| n.content.node_type_str()).collect();
+        assert_eq!(
+            types,
+            vec![
+                "group",
+                "heading",
+                "paragraph",
+                "table",
+                "list",
+                "list_item",
+                "list_item",
+                "image",
+                "paragraph",
+                "page_break",
+                "paragraph",
+            ]
+        );
+
+        // The section opened on page 1 continues on page 2
+        let group = &doc.nodes[0];
+        assert_eq!(group.bbox.map(|b| b.y1), Some(710.0));
+        assert!(group.children.contains(&NodeIndex(10)));
+        assert_eq!(doc.nodes[10].page, Some(2));
+
+        assert_eq!(doc.nodes[3].bbox.map(|b| b.y1), Some(500.0));
+        assert_eq!(doc.nodes[8].content_layer, ContentLayer::Footer);
+        assert_eq!(doc.nodes[8].parent, None);
+        assert_eq!(doc.nodes[9].parent, None);
+    }
+
+    #[test]
+    fn test_stitched_table_spans_pages_and_unpositioned_tables_follow_text() {
+        let mut stitched = table(1, None);
+        stitched.end_page_number = Some(2);
+        let pages = vec![PageLayout {
+            page_number: 1,
+            blocks: vec![block(
+                LayoutBlock::Paragraph {
+                    text: "Intro".to_string(),
+                },
+                700.0,
+            )],
+            tables: vec![&stitched],
+            ..Default::default()
+        }];
+
+        let doc = assemble_structure(&pages);
+        assert_eq!(doc.nodes[1].content.node_type_str(), "table");
+        assert_eq!(doc.nodes[1].page, Some(1));
+        assert_eq!(doc.nodes[1].page_end, Some(2));
+    }
+
+    #[test]
+    fn test_link_image_nodes_matches_images_per_page() {
+        let pages = vec![
+            PageLayout {
+                page_number: 1,
+                images: vec![bbox(500.0, 600.0), bbox(100.0, 200.0)],
+                ..Default::default()
+            },
+            PageLayout {
+                page_number: 2,
+                images: vec![bbox(500.0, 600.0)],
+                ..Default::default()
+            },
+        ];
+        let mut doc = assemble_structure(&pages);
+
+        let image = |image_index: usize, page_number: usize| ExtractedImage {
+            data: bytes::Bytes::new(),
+            format: "jpeg".into(),
+            image_index,
+            page_number: Some(page_number),
+            width: None,
+            height: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: Some(format!("image {}", image_index)),
+            ocr_result: None,
+            bounding_box: None,
+        };
+        // Page 2 has no matching extracted image count, so it stays unlinked
+        link_image_nodes(&mut doc, &[image(0, 1), image(1, 1)]);
+
+        let linked: Vec<(Option<u32>, Option<u32>)> = doc
+            .nodes
+            .iter()
+            .filter_map(|node| match &node.content {
+                NodeContent::Image { image_index, .. } => Some((node.page, *image_index)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(linked, vec![(Some(1), Some(0)), (Some(1), Some(1)), (Some(2), None)]);
+    }
+
+    #[test]
+    fn test_attach_region_texts_nests_text_in_image_nodes() {
+        let pages = vec![PageLayout {
+            page_number: 1,
+            images: vec![bbox(100.0, 200.0)],
+            ..Default::default()
+        }];
+        let mut doc = assemble_structure(&pages);
+
+        let region = |bounding_box| OcrRegion {
+            page_number: 1,
+            bounding_box,
+            text: "APPROVED".to_string(),
+        };
+        attach_region_texts(&mut doc, &[region(bbox(100.0, 200.0)), region(bbox(300.0, 400.0))]);
+        assert!(doc.validate().is_ok());
+
+        assert_eq!(doc.nodes[0].children, vec![NodeIndex(1)]);
+        assert_eq!(doc.nodes[1].content.text(), Some("APPROVED"));
+        assert_eq!(doc.nodes[2].parent, None);
+    }
+}
@@ -196,6 +196,35 @@ impl DetectedTable {
         rows
     }
 
+    /// Get the cells with their grid positions and spans, row by row.
+    ///
+    /// The grid lines are all distinct cell edges. A merged cell, whose
+    /// internal edges are missing from the drawing, covers several grid rows
+    /// or columns.
+    pub fn spanned_cells(&self) -> Vec<SpannedCell> {
+        let x_lines = grid_lines(self.cells.iter().flat_map(|c| [c.0, c.2]));
+        let y_lines = grid_lines(self.cells.iter().flat_map(|c| [c.1, c.3]));
+
+        let mut cells: Vec<SpannedCell> = self
+            .cells
+            .iter()
+            .map(|&bbox| {
+                let (col, col_end) = (nearest_line(&x_lines, bbox.0), nearest_line(&x_lines, bbox.2));
+                let (row, row_end) = (nearest_line(&y_lines, bbox.1), nearest_line(&y_lines, bbox.3));
+                SpannedCell {
+                    bbox,
+                    row,
+                    col,
+                    row_span: row_end.saturating_sub(row).max(1),
+                    col_span: col_end.saturating_sub(col).max(1),
+                }
+            })
+            .collect();
+        cells.sort_by_key(|c| (c.row, c.col));
+        cells.dedup_by_key(|c| (c.row, c.col));
+        cells
+    }
+
     /// Get the number of rows in this table.
     pub fn row_count(&self) -> usize {
         self.rows().len()
@@ -207,6 +236,39 @@ impl DetectedTable {
     }
 }
 
+/// A table cell placed on the table grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpannedCell {
+    /// Cell bounding box: (x0, top, x1, bottom).
+    pub bbox: Bbox,
+    /// Grid row of the cell's top edge (0-indexed).
+    pub row: usize,
+    /// Grid column of the cell's left edge (0-indexed).
+    pub col: usize,
+    /// Number of grid rows the cell covers.
+    pub row_span: usize,
+    /// Number of grid columns the cell covers.
+    pub col_span: usize,
+}
+
+/// Sorted, distinct grid line positions.
+fn grid_lines(values: impl Iterator<Item = f64>) -> Vec<f64> {
+    let mut lines: Vec<f64> = values.collect();
+    lines.sort_by(f64::total_cmp);
+    lines.dedup_by(|a, b| (*a - *b).abs() < f64::EPSILON);
+    lines
+}
+
+/// Index of the grid line closest to `value`.
+fn nearest_line(lines: &[f64], value: f64) -> usize {
+    lines
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - value).abs().total_cmp(&(*b - value).abs()))
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
 /// Find the single largest table on a PDF page.
 ///
 /// Returns the table with the most cells, or `None` if no tables were found.
@@ -657,8 +719,85 @@ pub fn extract_table_text_styled(
     page_height: f64,
     settings: Option<&TableSettings>,
 ) -> Result<Vec<Vec<StyledCellText>>> {
-    let rows = table.rows();
+    let chars_data = collect_chars(page, page_height)?;
+
+    Ok(table
+        .rows()
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell_opt| match cell_opt {
+                    Some(cell) => cell_text(&chars_data, *cell, settings),
+                    None => StyledCellText {
+                        plain: String::new(),
+                        styled: String::new(),
+                        has_bold: false,
+                    },
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Extract a detected table as a [`Table`](crate::types::Table), keeping merged cells.
+///
+/// Each cell's text is stored at its top-left grid position and every cell
+/// covering more than one grid row or column is recorded in `spans`; the
+/// other positions a merged cell covers are left empty, also in the Markdown.
+pub fn extract_table(
+    table: &DetectedTable,
+    page: &PdfPage,
+    page_number: usize,
+    settings: Option<&TableSettings>,
+) -> Result<crate::types::Table> {
+    let page_height = page.height().value as f64;
+    let chars_data = collect_chars(page, page_height)?;
+
+    let spanned_cells = table.spanned_cells();
+    let row_count = spanned_cells.iter().map(|c| c.row + c.row_span).max().unwrap_or(0);
+    let col_count = spanned_cells.iter().map(|c| c.col + c.col_span).max().unwrap_or(0);
+
+    let mut cells = vec![vec![String::new(); col_count]; row_count];
+    let mut spans = Vec::new();
+    for cell in &spanned_cells {
+        cells[cell.row][cell.col] = cell_text(&chars_data, cell.bbox, settings).plain;
+        if cell.row_span > 1 || cell.col_span > 1 {
+            spans.push(crate::types::CellSpan {
+                row: cell.row,
+                col: cell.col,
+                row_span: cell.row_span,
+                col_span: cell.col_span,
+            });
+        }
+    }
+
+    let (x0, top, x1, bottom) = table.bbox;
+    Ok(crate::types::Table {
+        markdown: table_markdown(&cells),
+        cells,
+        page_number,
+        bounding_box: Some(crate::types::BoundingBox {
+            x0,
+            y0: page_height - bottom,
+            x1,
+            y1: page_height - top,
+        }),
+        end_page_number: None,
+        spans,
+    })
+}
+
+/// Markdown for table cells, with line breaks inside cells folded into spaces.
+pub(crate) fn table_markdown(cells: &[Vec<String>]) -> String {
+    let single_line: Vec<Vec<String>> = cells
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.replace('\n', " ")).collect())
+        .collect();
+    html_to_markdown_rs::hocr::table_to_markdown(&single_line)
+}
 
+/// Collect the characters of a page with their positions and styles.
+fn collect_chars(page: &PdfPage, page_height: f64) -> Result<Vec<CharPos>> {
     let page_text = page
         .text()
         .map_err(|e| PdfError::TextExtractionFailed(format!("Failed to get page text: {}", e)))?;
@@ -757,69 +896,53 @@ pub fn extract_table_text_styled(
         }
     }
 
-    let mut result = Vec::new();
-
-    for row in &rows {
-        let mut row_cells = Vec::new();
-        for cell_opt in row {
-            match cell_opt {
-                Some(cell) => {
-                    let (x0, top, x1, bottom) = *cell;
-                    let mut cell_chars: Vec<&CharPos> = chars_data
-                        .iter()
-                        .filter(|c| c.mid_x >= x0 && c.mid_x < x1 && c.mid_y >= top && c.mid_y < bottom)
-                        .collect();
-
-                    // Sort by y then x for reading order
-                    cell_chars.sort_by(|a, b| {
-                        a.mid_y
-                            .partial_cmp(&b.mid_y)
-                            .unwrap()
-                            .then(a.mid_x.partial_cmp(&b.mid_x).unwrap())
-                    });
+    Ok(chars_data)
+}
 
-                    // Build plain text, optionally inserting spaces based on text_x_tolerance
-                    let plain = if let Some(x_tol) = settings.and_then(|s| s.effective_text_x()) {
-                        let mut buf = String::new();
-                        for (i, ch) in cell_chars.iter().enumerate() {
-                            if i > 0 {
-                                let prev = cell_chars[i - 1];
-                                let gap = ch.mid_x - prev.mid_x;
-                                if gap > x_tol && !prev.ch.is_whitespace() && !ch.ch.is_whitespace() {
-                                    buf.push(' ');
-                                }
-                            }
-                            buf.push(ch.ch);
-                        }
-                        buf.trim().to_string()
-                    } else {
-                        let s: String = cell_chars.iter().map(|c| c.ch).collect();
-                        s.trim().to_string()
-                    };
-
-                    let line_break_threshold = settings.map(|s| s.effective_text_y()).unwrap_or(5.0);
-                    let styled = build_styled_text(&cell_chars, line_break_threshold);
-                    let has_bold = cell_chars.iter().any(|c| c.style.bold);
-
-                    row_cells.push(StyledCellText {
-                        plain,
-                        styled,
-                        has_bold,
-                    });
-                }
-                None => {
-                    row_cells.push(StyledCellText {
-                        plain: String::new(),
-                        styled: String::new(),
-                        has_bold: false,
-                    });
+/// Text of the characters whose midpoint falls within `cell`.
+fn cell_text(chars_data: &[CharPos], cell: Bbox, settings: Option<&TableSettings>) -> StyledCellText {
+    let (x0, top, x1, bottom) = cell;
+    let mut cell_chars: Vec<&CharPos> = chars_data
+        .iter()
+        .filter(|c| c.mid_x >= x0 && c.mid_x < x1 && c.mid_y >= top && c.mid_y < bottom)
+        .collect();
+
+    // Sort by y then x for reading order
+    cell_chars.sort_by(|a, b| {
+        a.mid_y
+            .partial_cmp(&b.mid_y)
+            .unwrap()
+            .then(a.mid_x.partial_cmp(&b.mid_x).unwrap())
+    });
+
+    // Build plain text, optionally inserting spaces based on text_x_tolerance
+    let plain = if let Some(x_tol) = settings.and_then(|s| s.effective_text_x()) {
+        let mut buf = String::new();
+        for (i, ch) in cell_chars.iter().enumerate() {
+            if i > 0 {
+                let prev = cell_chars[i - 1];
+                let gap = ch.mid_x - prev.mid_x;
+                if gap > x_tol && !prev.ch.is_whitespace() && !ch.ch.is_whitespace() {
+                    buf.push(' ');
                 }
             }
+            buf.push(ch.ch);
         }
-        result.push(row_cells);
-    }
+        buf.trim().to_string()
+    } else {
+        let s: String = cell_chars.iter().map(|c| c.ch).collect();
+        s.trim().to_string()
+    };
 
-    Ok(result)
+    let line_break_threshold = settings.map(|s| s.effective_text_y()).unwrap_or(5.0);
+    let styled = build_styled_text(&cell_chars, line_break_threshold);
+    let has_bold = cell_chars.iter().any(|c| c.style.bold);
+
+    StyledCellText {
+        plain,
+        styled,
+        has_bold,
+    }
 }
 
 /// Determine if a character is bold based on font weight and font name heuristics.
@@ -1146,6 +1269,61 @@ mod tests {
         assert!(rows[0][1].is_some());
     }
 
+    #[test]
+    fn test_merged_cells_are_detected_from_missing_edges() {
+        // Two header cells spanning two columns each; the first column's
+        // vertical edge stops at the header, and the body rows are merged
+        // in the first column.
+        let edges = vec![
+            Edge::horizontal(0.0, 200.0, 0.0, EdgeType::Line),
+            Edge::horizontal(0.0, 200.0, 20.0, EdgeType::Line),
+            Edge::horizontal(50.0, 200.0, 40.0, EdgeType::Line),
+            Edge::horizontal(0.0, 200.0, 60.0, EdgeType::Line),
+            Edge::vertical(0.0, 0.0, 60.0, EdgeType::Line),
+            Edge::vertical(50.0, 20.0, 60.0, EdgeType::Line),
+            Edge::vertical(100.0, 0.0, 60.0, EdgeType::Line),
+            Edge::vertical(150.0, 20.0, 60.0, EdgeType::Line),
+            Edge::vertical(200.0, 0.0, 60.0, EdgeType::Line),
+        ];
+
+        let intersections = edges_to_intersections(&edges, 1.0, 1.0);
+        let cells = intersections_to_cells(&intersections, &edges);
+        let table = DetectedTable {
+            bbox: (0.0, 0.0, 200.0, 60.0),
+            cells,
+        };
+
+        let spans: Vec<(usize, usize, usize, usize)> = table
+            .spanned_cells()
+            .iter()
+            .map(|c| (c.row, c.col, c.row_span, c.col_span))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (0, 0, 1, 2),
+                (0, 2, 1, 2),
+                (1, 0, 2, 1),
+                (1, 1, 1, 1),
+                (1, 2, 1, 1),
+                (1, 3, 1, 1),
+                (2, 1, 1, 1),
+                (2, 2, 1, 1),
+                (2, 3, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_markdown() {
+        let cells = vec![
+            vec!["Year".to_string(), String::new()],
+            vec!["a|b".to_string(), "1\n2".to_string()],
+        ];
+        assert_eq!(table_markdown(&cells), "| Year |  |\n| --- | --- |\n| a\\|b | 1 2 |\n");
+        assert!(table_markdown(&[]).is_empty());
+    }
+
     #[test]
     fn test_cells_to_tables_empty() {
         let cells: Vec<Bbox> = Vec::new();
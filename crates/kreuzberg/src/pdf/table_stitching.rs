@@ -0,0 +1,281 @@
+//! Stitching of tables that continue across pages.
+//!
+//! Table detection works one page at a time, so a long table comes out as one
+//! table per page, usually with the header row repeated on each. This post-pass
+//! merges a table into the table on the previous page when it directly
+//! continues it: the last table of one page, followed by the first table of the
+//! next, with the same number of columns at the same horizontal position, the
+//! first ending near the bottom of its page and the second starting near the
+//! top of its page. Header rows repeated on the continuation pages are dropped.
+
+use crate::ocr::table::table_to_markdown;
+use crate::types::{BoundingBox, CellSpan, Table};
+
+/// Maximum difference between the left and right edges of two table
+/// fragments, as a fraction of the wider fragment's width.
+const COLUMN_EDGE_TOLERANCE: f64 = 0.05;
+
+/// Fraction of the page height, measured from the bottom (top) edge, in which
+/// a fragment must end (start) to continue on the next page.
+const PAGE_EDGE_ZONE: f64 = 0.25;
+
+/// Merge tables that continue on the following page into one logical table.
+///
+/// `tables` must be in page order, as produced by per-page table detection,
+/// with bounding boxes in PDF coordinates (y=0 at the bottom of the page).
+/// `page_heights` holds the height of each page, in points, indexed by page
+/// number - 1. Tables without a bounding box are never stitched.
+/// A stitched table keeps the page number and bounding box of its first
+/// fragment and records its last page in `end_page_number`.
+pub fn stitch_multi_page_tables(tables: Vec<Table>, page_heights: &[f64]) -> Vec<Table> {
+    let mut stitched: Vec<Table> = Vec::with_capacity(tables.len());
+
+    for table in tables {
+        if let Some(previous) = stitched.last_mut()
+            && continues(previous, &table, page_heights)
+        {
+            append_fragment(previous, table);
+        } else {
+            stitched.push(table);
+        }
+    }
+
+    for table in stitched.iter_mut().filter(|table| table.end_page_number.is_some()) {
+        table.markdown = table_to_markdown(&table.cells);
+    }
+
+    stitched
+}
+
+/// Whether `next` continues `previous` on the following page.
+///
+/// Both are neighbours in page order, so `previous` is the last table of its
+/// page and `next` the first table of its page.
+fn continues(previous: &Table, next: &Table, page_heights: &[f64]) -> bool {
+    let last_page = previous.end_page_number.unwrap_or(previous.page_number);
+    if next.page_number != last_page + 1 {
+        return false;
+    }
+
+    let columns = column_count(&previous.cells);
+    if columns == 0 || columns != column_count(&next.cells) {
+        return false;
+    }
+
+    let (Some(a), Some(b)) = (&previous.bounding_box, &next.bounding_box) else {
+        return false;
+    };
+    let (Some(last_height), Some(next_height)) = (
+        page_height(page_heights, last_page),
+        page_height(page_heights, next.page_number),
+    ) else {
+        return false;
+    };
+
+    same_horizontal_extent(a, b) && ends_near_bottom(a, last_height) && starts_near_top(b, next_height)
+}
+
+fn page_height(page_heights: &[f64], page_number: usize) -> Option<f64> {
+    page_heights.get(page_number.checked_sub(1)?).copied()
+}
+
+fn ends_near_bottom(bbox: &BoundingBox, page_height: f64) -> bool {
+    bbox.y0.min(bbox.y1) <= page_height * PAGE_EDGE_ZONE
+}
+
+fn starts_near_top(bbox: &BoundingBox, page_height: f64) -> bool {
+    bbox.y0.max(bbox.y1) >= page_height * (1.0 - PAGE_EDGE_ZONE)
+}
+
+fn column_count(cells: &[Vec<String>]) -> usize {
+    cells.iter().map(Vec::len).max().unwrap_or(0)
+}
+
+fn same_horizontal_extent(a: &BoundingBox, b: &BoundingBox) -> bool {
+    let width = (a.x1 - a.x0).max(b.x1 - b.x0);
+    if width <= 0.0 {
+        return false;
+    }
+    let tolerance = width * COLUMN_EDGE_TOLERANCE;
+    (a.x0 - b.x0).abs() <= tolerance && (a.x1 - b.x1).abs() <= tolerance
+}
+
+fn append_fragment(table: &mut Table, fragment: Table) {
+    let end_page = fragment.end_page_number.unwrap_or(fragment.page_number);
+    let mut rows = fragment.cells.into_iter().peekable();
+    let mut skipped_rows = 0;
+    if let (Some(header), Some(first)) = (table.cells.first(), rows.peek())
+        && is_repeated_header(header, first)
+    {
+        rows.next();
+        skipped_rows = 1;
+    }
+
+    let row_offset = table.cells.len();
+    table.spans.extend(
+        fragment
+            .spans
+            .into_iter()
+            .filter(|span| span.row >= skipped_rows)
+            .map(|span| CellSpan {
+                row: span.row - skipped_rows + row_offset,
+                ..span
+            }),
+    );
+    table.cells.extend(rows);
+    table.end_page_number = Some(end_page);
+}
+
+/// Whether `row` repeats the header row, ignoring case and whitespace.
+fn is_repeated_header(header: &[String], row: &[String]) -> bool {
+    header.len() == row.len()
+        && header.iter().any(|cell| !cell.trim().is_empty())
+        && header
+            .iter()
+            .zip(row)
+            .all(|(a, b)| normalize_cell(a) == normalize_cell(b))
+}
+
+fn normalize_cell(cell: &str) -> String {
+    cell.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// US Letter pages.
+    const PAGE_HEIGHTS: [f64; 5] = [792.0; 5];
+
+    fn table(page_number: usize, rows: &[&[&str]], x0: f64, x1: f64) -> Table {
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect();
+        Table {
+            markdown: table_to_markdown(&cells),
+            cells,
+            page_number,
+            bounding_box: Some(BoundingBox {
+                x0,
+                y0: 50.0,
+                x1,
+                y1: 700.0,
+            }),
+            end_page_number: None,
+            spans: vec![],
+        }
+    }
+
+    #[test]
+    fn test_stitch_drops_repeated_headers_and_records_page_span() {
+        let tables = vec![
+            table(1, &[&["Account", "Balance"], &["Cash", "100"]], 72.0, 540.0),
+            table(2, &[&["ACCOUNT", "Balance "], &["Loans", "250"]], 73.0, 538.0),
+            table(3, &[&["Equity", "75"]], 72.0, 540.0),
+        ];
+
+        let stitched = stitch_multi_page_tables(tables, &PAGE_HEIGHTS);
+        assert_eq!(stitched.len(), 1);
+
+        let table = &stitched[0];
+        assert_eq!(table.page_number, 1);
+        assert_eq!(table.end_page_number, Some(3));
+        assert_eq!(
+            table.cells,
+            vec![
+                vec!["Account", "Balance"],
+                vec!["Cash", "100"],
+                vec!["Loans", "250"],
+                vec!["Equity", "75"],
+            ]
+        );
+        assert!(table.markdown.contains("Equity"));
+        assert_eq!(table.markdown.matches("Account").count(), 1);
+    }
+
+    #[test]
+    fn test_stitch_shifts_merged_cells_of_continuation() {
+        let first = table(1, &[&["Item", "Q1"], &["A", "1"]], 72.0, 540.0);
+        let mut second = table(2, &[&["Item", "Q1"], &["B", "2"], &["", "3"]], 72.0, 540.0);
+        second.spans = vec![CellSpan {
+            row: 1,
+            col: 0,
+            row_span: 2,
+            col_span: 1,
+        }];
+
+        let stitched = stitch_multi_page_tables(vec![first, second], &PAGE_HEIGHTS);
+        assert_eq!(stitched.len(), 1);
+        assert_eq!(stitched[0].cells.len(), 4);
+        assert_eq!(
+            stitched[0].spans,
+            vec![CellSpan {
+                row: 2,
+                col: 0,
+                row_span: 2,
+                col_span: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stitch_keeps_incompatible_tables_apart() {
+        let tables = vec![
+            table(1, &[&["A", "B"], &["1", "2"]], 72.0, 540.0),
+            // Different column count ~keep
+            table(2, &[&["A", "B", "C"], &["1", "2", "3"]], 72.0, 540.0),
+            // Different horizontal position ~keep
+            table(3, &[&["A", "B", "C"], &["4", "5", "6"]], 300.0, 540.0),
+            // Not on the following page ~keep
+            table(5, &[&["A", "B", "C"], &["7", "8", "9"]], 300.0, 540.0),
+        ];
+
+        let stitched = stitch_multi_page_tables(tables, &PAGE_HEIGHTS);
+        assert_eq!(stitched.len(), 4);
+        assert!(stitched.iter().all(|table| table.end_page_number.is_none()));
+    }
+
+    #[test]
+    fn test_stitch_only_joins_last_and_first_table_of_adjacent_pages() {
+        let tables = vec![
+            table(1, &[&["Q", "Revenue"], &["Q1", "10"]], 72.0, 540.0),
+            table(1, &[&["Region", "Share"], &["North", "40%"]], 72.0, 540.0),
+            table(2, &[&["Region", "Share"], &["South", "60%"]], 72.0, 540.0),
+            table(2, &[&["Notes", "Text"], &["n/a", "-"]], 72.0, 540.0),
+        ];
+
+        let stitched = stitch_multi_page_tables(tables, &PAGE_HEIGHTS);
+        assert_eq!(stitched.len(), 3);
+        assert_eq!(stitched[1].cells.len(), 3);
+        assert_eq!(stitched[1].end_page_number, Some(2));
+        assert_eq!(stitched[2].page_number, 2);
+        assert!(stitched[2].end_page_number.is_none());
+    }
+
+    #[test]
+    fn test_stitch_requires_fragments_at_page_break() {
+        let mut short = table(1, &[&["A", "B"], &["1", "2"]], 72.0, 540.0);
+        // Ends mid-page ~keep
+        short.bounding_box.as_mut().unwrap().y0 = 400.0;
+        let next = table(2, &[&["A", "B"], &["3", "4"]], 72.0, 540.0);
+        let stitched = stitch_multi_page_tables(vec![short, next.clone()], &PAGE_HEIGHTS);
+        assert_eq!(stitched.len(), 2);
+
+        let first = table(1, &[&["A", "B"], &["1", "2"]], 72.0, 540.0);
+        let mut low = next.clone();
+        // Starts mid-page ~keep
+        low.bounding_box.as_mut().unwrap().y1 = 400.0;
+        let stitched = stitch_multi_page_tables(vec![first.clone(), low], &PAGE_HEIGHTS);
+        assert_eq!(stitched.len(), 2);
+
+        let mut unplaced = next.clone();
+        unplaced.bounding_box = None;
+        let stitched = stitch_multi_page_tables(vec![first.clone(), unplaced], &PAGE_HEIGHTS);
+        assert_eq!(stitched.len(), 2);
+
+        // Unknown page height ~keep
+        let stitched = stitch_multi_page_tables(vec![first, next], &PAGE_HEIGHTS[..1]);
+        assert_eq!(stitched.len(), 2);
+    }
+}
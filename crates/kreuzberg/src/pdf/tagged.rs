@@ -0,0 +1,674 @@
+//! Logical structure trees of tagged PDFs.
+//!
+//! Accessible PDFs (exported from Word, InDesign or LaTeX with tagging enabled)
+//! carry a structure tree that gives the reading order and the role of every
+//! piece of content: headings, paragraphs, lists, tables, and figures with
+//! alternate text. When the tree tags the text of the document, it replaces the
+//! column detection and font-size clustering of [`super::markdown`] for the
+//! Markdown output, the document structure and the tables. Untagged and
+//! partially tagged files keep using the heuristics.
+
+use super::error::{PdfError, Result};
+use super::furniture::{furniture_on_page, is_on_furniture};
+use super::structure::{LayoutBlock, PositionedBlock};
+use super::table_finder::table_markdown;
+use crate::types::{BoundingBox, PageFurniture, Table};
+use pdfium_render::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Minimum share of the document's text that the structure tree must tag.
+/// Running headers and footers are usually artifacts outside the tree; trees
+/// that tag less than this leave body text out and are ignored.
+const MIN_TAGGED_COVERAGE: f64 = 0.8;
+
+/// The blocks of one page, in the logical order of the structure tree.
+#[derive(Debug, Clone)]
+pub(crate) struct TaggedPage {
+    pub page_number: usize,
+    pub blocks: Vec<PositionedBlock>,
+}
+
+/// Text and extent of one marked content sequence of a page.
+#[derive(Debug, Default)]
+struct MarkedContent {
+    text: String,
+    /// Non-whitespace characters, for the coverage of the tree.
+    chars: usize,
+    bbox: Option<BoundingBox>,
+}
+
+/// Role of a structure element, from its (role-mapped) structure type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Heading(u8),
+    Paragraph,
+    List,
+    ListItem,
+    ListLabel,
+    ListBody,
+    Table,
+    TableRow,
+    TableCell,
+    Figure,
+    Formula,
+    /// Inline content (spans, links, emphasis) that belongs to the enclosing block.
+    Inline,
+    /// Grouping elements (Document, Part, Sect, Div) and unknown types.
+    Container,
+}
+
+fn role(element_type: &str) -> Role {
+    match element_type {
+        "Title" | "H" | "H1" => Role::Heading(1),
+        "H2" => Role::Heading(2),
+        "H3" => Role::Heading(3),
+        "H4" => Role::Heading(4),
+        "H5" => Role::Heading(5),
+        "H6" => Role::Heading(6),
+        "P" | "Caption" | "BlockQuote" | "Note" | "Code" | "BibEntry" | "TOCI" | "FENote" | "Aside" => Role::Paragraph,
+        "L" | "TOC" => Role::List,
+        "LI" => Role::ListItem,
+        "Lbl" => Role::ListLabel,
+        "LBody" => Role::ListBody,
+        "Table" => Role::Table,
+        "TR" => Role::TableRow,
+        "TH" | "TD" => Role::TableCell,
+        "Figure" => Role::Figure,
+        "Formula" => Role::Formula,
+        "Span" | "Link" | "Quote" | "Em" | "Strong" | "Sub" | "Reference" | "Annot" | "Form" | "Ruby" | "RB" | "RT"
+        | "RP" | "Warichu" | "WT" | "WP" => Role::Inline,
+        _ => Role::Container,
+    }
+}
+
+/// Read the structure tree of a tagged PDF.
+///
+/// Returns `None` when the document is untagged, or when its tree tags less than
+/// [`MIN_TAGGED_COVERAGE`] of the text (characters on `furniture` lines are not
+/// counted). Lists, tables and figures keep their roles; other elements with
+/// text become paragraphs.
+pub(crate) fn read_tagged_document(
+    document: &PdfDocument<'_>,
+    furniture: &[PageFurniture],
+) -> Result<Option<Vec<TaggedPage>>> {
+    let is_tagged = document
+        .pages()
+        .iter()
+        .any(|page| page.struct_tree().is_some_and(|tree| !tree.children().is_empty()));
+    if !is_tagged {
+        return Ok(None);
+    }
+
+    let mut pages = Vec::new();
+    let (mut tagged_chars, mut total_chars) = (0usize, 0usize);
+    for (page_idx, page) in document.pages().iter().enumerate() {
+        let page_number = page_idx + 1;
+        let (contents, page_chars) = marked_contents(&page, &furniture_on_page(furniture, page_number))?;
+        total_chars += page_chars;
+
+        let mut walker = TreeWalker::new(&contents, page_number);
+        if let Some(tree) = page.struct_tree() {
+            for element in tree.children() {
+                walker.walk(&element);
+            }
+        }
+        tagged_chars += walker.tagged_chars();
+        pages.push(TaggedPage {
+            page_number,
+            blocks: walker.blocks,
+        });
+    }
+
+    if total_chars == 0 || (tagged_chars as f64) < MIN_TAGGED_COVERAGE * total_chars as f64 {
+        tracing::debug!(
+            "PDF structure tree tags {} of {} characters, using layout analysis",
+            tagged_chars,
+            total_chars
+        );
+        return Ok(None);
+    }
+    Ok(Some(pages))
+}
+
+/// The tables tagged in the structure tree.
+pub(crate) fn tagged_tables(pages: &[TaggedPage]) -> Vec<Table> {
+    pages
+        .iter()
+        .flat_map(|page| &page.blocks)
+        .filter_map(|block| match &block.block {
+            LayoutBlock::Table(table) => Some(table.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Render the blocks of a tagged document as Markdown.
+pub(crate) fn render_markdown(pages: &[TaggedPage]) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for block in pages.iter().flat_map(|page| &page.blocks) {
+        match &block.block {
+            LayoutBlock::Heading { level, text } => parts.push(format!("{} {}", "#".repeat(*level as usize), text)),
+            LayoutBlock::Paragraph { text } => parts.push(text.clone()),
+            LayoutBlock::Formula { text } => parts.push(format!("$$\n{}\n$$", text)),
+            LayoutBlock::List { ordered, items } => parts.push(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, item)| {
+                        if *ordered {
+                            format!("{}. {}", idx + 1, item)
+                        } else {
+                            format!("- {}", item)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            LayoutBlock::Table(table) => parts.push(table.markdown.trim().to_string()),
+            LayoutBlock::Image {
+                description: Some(description),
+            } => parts.push(format!("*Figure: {}*", description)),
+            LayoutBlock::Image { description: None } => {}
+        }
+    }
+    parts.join("\n\n")
+}
+
+/// Collect the marked content sequences of a page, with the number of
+/// non-whitespace characters on the page.
+///
+/// Spaces and line breaks generated by Pdfium belong to no text object; they
+/// are kept with the sequence of the preceding character.
+fn marked_contents(page: &PdfPage<'_>, furniture: &[&PageFurniture]) -> Result<(HashMap<i32, MarkedContent>, usize)> {
+    let text = page
+        .text()
+        .map_err(|e| PdfError::TextExtractionFailed(format!("Failed to get page text: {}", e)))?;
+    let chars = text.chars();
+
+    let mut contents: HashMap<i32, MarkedContent> = HashMap::new();
+    let mut total = 0;
+    let mut current = None;
+    for idx in 0..chars.len() {
+        let Ok(pdf_char) = chars.get(idx) else {
+            continue;
+        };
+        let Some(ch) = pdf_char.unicode_char() else {
+            continue;
+        };
+        let bounds = pdf_char.loose_bounds().ok();
+        if !ch.is_whitespace()
+            && let Some(bounds) = &bounds
+            && is_on_furniture(
+                furniture,
+                (bounds.left().value + bounds.right().value) / 2.0,
+                bounds.bottom().value,
+            )
+        {
+            current = None;
+            continue;
+        }
+
+        let id = match pdf_char.marked_content_id() {
+            Some(id) => Some(id),
+            None if ch.is_whitespace() => current,
+            None => None,
+        };
+        current = id;
+
+        if !ch.is_whitespace() {
+            total += 1;
+        }
+        let Some(id) = id else {
+            continue;
+        };
+        let content = contents.entry(id).or_default();
+        content.text.push(ch);
+        if !ch.is_whitespace() {
+            content.chars += 1;
+            if let Some(bounds) = bounds {
+                let char_box = BoundingBox {
+                    x0: bounds.left().value as f64,
+                    y0: bounds.bottom().value as f64,
+                    x1: bounds.right().value as f64,
+                    y1: bounds.top().value as f64,
+                };
+                content.bbox = Some(union(content.bbox, char_box));
+            }
+        }
+    }
+    Ok((contents, total))
+}
+
+fn union(a: Option<BoundingBox>, b: BoundingBox) -> BoundingBox {
+    match a {
+        Some(a) => BoundingBox {
+            x0: a.x0.min(b.x0),
+            y0: a.y0.min(b.y0),
+            x1: a.x1.max(b.x1),
+            y1: a.y1.max(b.y1),
+        },
+        None => b,
+    }
+}
+
+/// Collapse whitespace runs (including line breaks) into single spaces.
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether a list label marks an ordered list item ("1.", "a)", "iv.").
+fn is_ordered_label(label: &str) -> bool {
+    let label = label.trim();
+    let body = label.trim_end_matches(['.', ')']);
+    !body.is_empty()
+        && body.len() < label.len()
+        && (body.chars().all(|c| c.is_ascii_digit())
+            || body.chars().all(|c| c.is_ascii_alphabetic()) && body.len() <= 4)
+}
+
+/// Walks the structure tree of one page and turns its elements into blocks.
+struct TreeWalker<'c> {
+    contents: &'c HashMap<i32, MarkedContent>,
+    page_number: usize,
+    used: HashSet<i32>,
+    blocks: Vec<PositionedBlock>,
+}
+
+impl<'c> TreeWalker<'c> {
+    fn new(contents: &'c HashMap<i32, MarkedContent>, page_number: usize) -> Self {
+        Self {
+            contents,
+            page_number,
+            used: HashSet::new(),
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Non-whitespace characters of the page that belong to walked elements.
+    fn tagged_chars(&self) -> usize {
+        self.used
+            .iter()
+            .filter_map(|id| self.contents.get(id))
+            .map(|content| content.chars)
+            .sum()
+    }
+
+    fn push(&mut self, block: LayoutBlock, bbox: Option<BoundingBox>) {
+        self.blocks.push(PositionedBlock {
+            block,
+            top: bbox.map_or(0.0, |b| b.y1 as f32),
+            bbox,
+        });
+    }
+
+    fn walk(&mut self, element: &PdfStructElement<'_>) {
+        let element_type = element.element_type().unwrap_or_default();
+        match role(&element_type) {
+            Role::Heading(level) => {
+                let (text, bbox) = self.content(element);
+                if !text.is_empty() {
+                    self.push(LayoutBlock::Heading { level, text }, bbox);
+                }
+            }
+            Role::List => self.walk_list(element),
+            Role::Table => self.walk_table(element),
+            Role::Figure => {
+                let (text, bbox) = self.content(element);
+                let description = element
+                    .alt_text()
+                    .or_else(|| element.actual_text())
+                    .map(|alt| normalize_whitespace(&alt))
+                    .or_else(|| (!text.is_empty()).then_some(text));
+                self.push(LayoutBlock::Image { description }, bbox);
+            }
+            Role::Formula => {
+                // The alternate text of a tagged formula is often its LaTeX source.
+                let (text, bbox) = self.content(element);
+                let text = element
+                    .alt_text()
+                    .or_else(|| element.actual_text())
+                    .map(|alt| normalize_whitespace(&alt))
+                    .filter(|alt| !alt.is_empty())
+                    .unwrap_or(text);
+                if !text.is_empty() {
+                    self.push(LayoutBlock::Formula { text }, bbox);
+                }
+            }
+            Role::Container if self.has_block_kids(element) => {
+                let mut inline = Vec::new();
+                for kid in element.kids() {
+                    match kid {
+                        PdfStructElementKid::Element(child) if self.is_block(&child) => {
+                            self.flush_paragraph(&mut inline);
+                            self.walk(&child);
+                        }
+                        kid => inline.push(kid),
+                    }
+                }
+                self.flush_paragraph(&mut inline);
+            }
+            _ => {
+                let (text, bbox) = self.content(element);
+                if !text.is_empty() {
+                    self.push(LayoutBlock::Paragraph { text }, bbox);
+                }
+            }
+        }
+    }
+
+    /// Inline content between the blocks of a container becomes a paragraph.
+    fn flush_paragraph(&mut self, inline: &mut Vec<PdfStructElementKid<'_>>) {
+        if inline.is_empty() {
+            return;
+        }
+        let (text, bbox) = self.kids_content(inline, &|_| true);
+        inline.clear();
+        if !text.is_empty() {
+            self.push(LayoutBlock::Paragraph { text }, bbox);
+        }
+    }
+
+    fn is_block(&self, element: &PdfStructElement<'_>) -> bool {
+        let element_type = element.element_type().unwrap_or_default();
+        !matches!(role(&element_type), Role::Inline | Role::ListLabel)
+    }
+
+    fn has_block_kids(&self, element: &PdfStructElement<'_>) -> bool {
+        element.children().iter().any(|child| self.is_block(child))
+    }
+
+    fn walk_list(&mut self, list: &PdfStructElement<'_>) {
+        let mut items = Vec::new();
+        let mut ordered = None;
+        let mut bbox = None;
+        self.collect_list_items(list, &mut items, &mut ordered, &mut bbox);
+        if !items.is_empty() {
+            self.push(
+                LayoutBlock::List {
+                    ordered: ordered.unwrap_or(false),
+                    items,
+                },
+                bbox,
+            );
+        }
+    }
+
+    /// Collect the items of a list. Nested lists are flattened after their parent item.
+    fn collect_list_items(
+        &mut self,
+        list: &PdfStructElement<'_>,
+        items: &mut Vec<String>,
+        ordered: &mut Option<bool>,
+        bbox: &mut Option<BoundingBox>,
+    ) {
+        for item in list.children() {
+            let item_type = item.element_type().unwrap_or_default();
+            match role(&item_type) {
+                Role::ListItem | Role::Paragraph => {
+                    let mut nested = Vec::new();
+                    let mut text = String::new();
+                    for kid in item.kids() {
+                        let PdfStructElementKid::Element(child) = &kid else {
+                            let (kid_text, kid_bbox) = self.kids_content(std::slice::from_ref(&kid), &|_| true);
+                            text.push_str(&kid_text);
+                            *bbox = merge(*bbox, kid_bbox);
+                            continue;
+                        };
+                        let child_type = child.element_type().unwrap_or_default();
+                        match role(&child_type) {
+                            Role::ListLabel => {
+                                let (label, label_bbox) = self.content(child);
+                                ordered.get_or_insert_with(|| is_ordered_label(&label));
+                                *bbox = merge(*bbox, label_bbox);
+                            }
+                            Role::List => nested.push(child.clone()),
+                            _ => {
+                                let (body, body_bbox) = self.content_without_lists(child, &mut nested);
+                                if !text.is_empty() && !body.is_empty() {
+                                    text.push(' ');
+                                }
+                                text.push_str(&body);
+                                *bbox = merge(*bbox, body_bbox);
+                            }
+                        }
+                    }
+                    let text = normalize_whitespace(&text);
+                    if !text.is_empty() {
+                        items.push(text);
+                    }
+                    for nested_list in nested {
+                        self.collect_list_items(&nested_list, items, ordered, bbox);
+                    }
+                }
+                Role::List => self.collect_list_items(&item, items, ordered, bbox),
+                _ => {
+                    let (text, item_bbox) = self.content(&item);
+                    if !text.is_empty() {
+                        items.push(text);
+                        *bbox = merge(*bbox, item_bbox);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Content of a list body, setting its nested lists aside.
+    fn content_without_lists<'e>(
+        &mut self,
+        element: &PdfStructElement<'e>,
+        nested: &mut Vec<PdfStructElement<'e>>,
+    ) -> (String, Option<BoundingBox>) {
+        let kids = element.kids();
+        for kid in &kids {
+            if let PdfStructElementKid::Element(child) = kid
+                && role(&child.element_type().unwrap_or_default()) == Role::List
+            {
+                nested.push(child.clone());
+            }
+        }
+        self.kids_content(&kids, &|child| {
+            role(&child.element_type().unwrap_or_default()) != Role::List
+        })
+    }
+
+    fn walk_table(&mut self, table: &PdfStructElement<'_>) {
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut bbox = None;
+        self.collect_rows(table, &mut rows, &mut bbox);
+        rows.retain(|row| !row.is_empty());
+        let Some(columns) = rows.iter().map(Vec::len).max() else {
+            return;
+        };
+        for row in &mut rows {
+            row.resize(columns, String::new());
+        }
+
+        let markdown = table_markdown(&rows);
+        self.push(
+            LayoutBlock::Table(Table {
+                cells: rows,
+                markdown,
+                page_number: self.page_number,
+                bounding_box: bbox,
+                end_page_number: None,
+                spans: vec![],
+            }),
+            bbox,
+        );
+    }
+
+    /// Collect table rows, looking through row groups (THead, TBody, TFoot).
+    fn collect_rows(
+        &mut self,
+        element: &PdfStructElement<'_>,
+        rows: &mut Vec<Vec<String>>,
+        bbox: &mut Option<BoundingBox>,
+    ) {
+        for child in element.children() {
+            let child_type = child.element_type().unwrap_or_default();
+            match role(&child_type) {
+                Role::TableRow => {
+                    let mut row = Vec::new();
+                    for cell in child.children() {
+                        let (text, cell_bbox) = self.content(&cell);
+                        *bbox = merge(*bbox, cell_bbox);
+                        row.push(text);
+                    }
+                    rows.push(row);
+                }
+                Role::Paragraph | Role::Heading(_) => {
+                    // Table captions
+                    let (text, caption_bbox) = self.content(&child);
+                    if !text.is_empty() {
+                        self.push(LayoutBlock::Paragraph { text }, caption_bbox);
+                    }
+                }
+                _ => self.collect_rows(&child, rows, bbox),
+            }
+        }
+    }
+
+    /// The text of an element and its descendants, in tree order, with its extent.
+    /// `/ActualText` replaces the text of the content it tags.
+    fn content(&mut self, element: &PdfStructElement<'_>) -> (String, Option<BoundingBox>) {
+        let kids = element.kids();
+        let (text, bbox) = self.kids_content(&kids, &|_| true);
+        match element.actual_text() {
+            Some(actual) => (normalize_whitespace(&actual), bbox),
+            None => (text, bbox),
+        }
+    }
+
+    fn kids_content(
+        &mut self,
+        kids: &[PdfStructElementKid<'_>],
+        include: &dyn Fn(&PdfStructElement<'_>) -> bool,
+    ) -> (String, Option<BoundingBox>) {
+        let mut text = String::new();
+        let mut bbox = None;
+        for kid in kids {
+            match kid {
+                PdfStructElementKid::MarkedContent(id) => {
+                    self.used.insert(*id);
+                    if let Some(content) = self.contents.get(id) {
+                        text.push_str(&content.text);
+                        bbox = merge(bbox, content.bbox);
+                    }
+                }
+                PdfStructElementKid::Element(child) if include(child) => {
+                    let (child_text, child_bbox) = self.content(child);
+                    if !text.is_empty() && !text.ends_with(char::is_whitespace) && !child_text.is_empty() {
+                        // Sibling elements (table cells, list labels) are separate words
+                        // unless the document placed a space between them.
+                        let joins_word = matches!(role(&child.element_type().unwrap_or_default()), Role::Inline);
+                        if !joins_word {
+                            text.push(' ');
+                        }
+                    }
+                    text.push_str(&child_text);
+                    bbox = merge(bbox, child_bbox);
+                }
+                PdfStructElementKid::Element(_) => {}
+            }
+        }
+        (normalize_whitespace(&text), bbox)
+    }
+}
+
+fn merge(a: Option<BoundingBox>, b: Option<BoundingBox>) -> Option<BoundingBox> {
+    match b {
+        Some(b) => Some(union(a, b)),
+        None => a,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roles_of_standard_structure_types() {
+        assert_eq!(role("H2"), Role::Heading(2));
+        assert_eq!(role("Title"), Role::Heading(1));
+        assert_eq!(role("P"), Role::Paragraph);
+        assert_eq!(role("L"), Role::List);
+        assert_eq!(role("TD"), Role::TableCell);
+        assert_eq!(role("Formula"), Role::Formula);
+        assert_eq!(role("Span"), Role::Inline);
+        assert_eq!(role("Sect"), Role::Container);
+        assert_eq!(role("MyCustomStyle"), Role::Container);
+    }
+
+    #[test]
+    fn test_ordered_list_labels() {
+        assert!(is_ordered_label("1."));
+        assert!(is_ordered_label("12)"));
+        assert!(is_ordered_label("b."));
+        assert!(!is_ordered_label("•"));
+        assert!(!is_ordered_label("-"));
+        assert!(!is_ordered_label("1"));
+    }
+
+    #[test]
+    fn test_render_markdown_keeps_tree_order() {
+        let block = |block: LayoutBlock| PositionedBlock {
+            block,
+            top: 0.0,
+            bbox: None,
+        };
+        let cells = vec![
+            vec!["Region".to_string(), "Revenue".to_string()],
+            vec!["North".to_string(), "12".to_string()],
+        ];
+        let pages = vec![
+            TaggedPage {
+                page_number: 1,
+                blocks: vec![
+                    block(LayoutBlock::Heading {
+                        level: 1,
+                        text: "Annual report".to_string(),
+                    }),
+                    block(LayoutBlock::Paragraph {
+                        text: "Revenue grew.".to_string(),
+                    }),
+                    block(LayoutBlock::Formula {
+                        text: "r = \\frac{p}{q}".to_string(),
+                    }),
+                    block(LayoutBlock::List {
+                        ordered: true,
+                        items: vec!["Hire".to_string(), "Expand".to_string()],
+                    }),
+                ],
+            },
+            TaggedPage {
+                page_number: 2,
+                blocks: vec![
+                    block(LayoutBlock::Table(Table {
+                        markdown: table_markdown(&cells),
+                        cells,
+                        page_number: 2,
+                        bounding_box: None,
+                        end_page_number: None,
+                        spans: vec![],
+                    })),
+                    block(LayoutBlock::Image {
+                        description: Some("Revenue by region".to_string()),
+                    }),
+                    block(LayoutBlock::Image { description: None }),
+                ],
+            },
+        ];
+
+        assert_eq!(
+            render_markdown(&pages),
+            "# Annual report\n\nRevenue grew.\n\n$$\nr = \\frac{p}{q}\n$$\n\n1. Hire\n2. Expand\n\n\
+             | Region | Revenue |\n| --- | --- |\n| North | 12 |\n\n*Figure: Revenue by region*"
+        );
+        assert_eq!(tagged_tables(&pages).len(), 1);
+    }
+
+    #[test]
+    fn test_normalize_whitespace() {
+        assert_eq!(normalize_whitespace("  Quarterly\r\n  results "), "Quarterly results");
+    }
+}
@@ -6,7 +6,7 @@ use super::bindings::{PdfiumHandle, bind_pdfium};
 use super::error::{PdfError, Result};
 use crate::core::config::PageConfig;
 use crate::pdf::metadata::PdfExtractionMetadata;
-use crate::types::{PageBoundary, PageContent};
+use crate::types::{PageBoundary, PageContent, PageFurniture};
 use pdfium_render::prelude::*;
 use std::borrow::Cow;
 
@@ -138,9 +138,22 @@ pub type PdfUnifiedExtractionResult = (
 pub fn extract_text_and_metadata_from_pdf_document(
     document: &PdfDocument<'_>,
     extraction_config: Option<&crate::core::config::ExtractionConfig>,
+) -> Result<PdfUnifiedExtractionResult> {
+    extract_text_and_metadata_with_furniture(document, extraction_config, &[])
+}
+
+/// Like [`extract_text_and_metadata_from_pdf_document`], with the given running
+/// headers and footers removed from the text of their pages.
+pub(crate) fn extract_text_and_metadata_with_furniture(
+    document: &PdfDocument<'_>,
+    extraction_config: Option<&crate::core::config::ExtractionConfig>,
+    furniture: &[PageFurniture],
 ) -> Result<PdfUnifiedExtractionResult> {
     let page_config = extraction_config.and_then(|c| c.pages.as_ref());
-    let (text, boundaries, page_contents) = extract_text_from_pdf_document(document, page_config, extraction_config)?;
+    let (text, boundaries, page_contents) = match page_config {
+        None => extract_text_lazy_fast_path(document, furniture)?,
+        Some(config) => extract_text_lazy_with_tracking(document, config, extraction_config, furniture)?,
+    };
 
     let metadata = crate::pdf::metadata::extract_metadata_from_document_impl(document, boundaries.as_deref(), &text)?;
 
@@ -177,12 +190,12 @@ pub fn extract_text_from_pdf_document(
     extraction_config: Option<&crate::core::config::ExtractionConfig>,
 ) -> Result<PdfTextExtractionResult> {
     if page_config.is_none() {
-        return extract_text_lazy_fast_path(document);
+        return extract_text_lazy_fast_path(document, &[]);
     }
 
     let config = page_config.unwrap();
 
-    extract_text_lazy_with_tracking(document, config, extraction_config)
+    extract_text_lazy_with_tracking(document, config, extraction_config, &[])
 }
 
 /// Strip `/Rotate` entries from PDF bytes to work around a pdfium bug where
@@ -274,7 +287,10 @@ fn find_rotate_offset(bytes: &[u8], start: usize) -> Option<usize> {
 /// and extrapolating for the full document. This reduces String reallocation
 /// calls from O(n) to O(log n) while maintaining low peak memory usage.
 /// For large documents, this can reduce allocation overhead by 40-50%.
-fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtractionResult> {
+fn extract_text_lazy_fast_path(
+    document: &PdfDocument<'_>,
+    furniture: &[PageFurniture],
+) -> Result<PdfTextExtractionResult> {
     let page_count = document.pages().len() as usize;
     let mut content = String::new();
     let mut total_sample_size = 0usize;
@@ -285,7 +301,7 @@ fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtr
             .text()
             .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
 
-        let page_text = text.all();
+        let page_text = page_text_without_furniture(text.all(), furniture, page_idx + 1);
         let page_size = page_text.len();
 
         if page_idx > 0 {
@@ -328,6 +344,7 @@ fn extract_text_lazy_with_tracking(
     document: &PdfDocument<'_>,
     config: &PageConfig,
     extraction_config: Option<&crate::core::config::ExtractionConfig>,
+    furniture: &[PageFurniture],
 ) -> Result<PdfTextExtractionResult> {
     let mut content = String::new();
     let page_count = document.pages().len() as usize;
@@ -360,7 +377,7 @@ fn extract_text_lazy_with_tracking(
             .text()
             .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
 
-        let page_text_ref = text.all();
+        let page_text_ref = page_text_without_furniture(text.all(), furniture, page_number);
         let page_size = page_text_ref.len();
 
         if page_idx < 5 {
@@ -390,7 +407,7 @@ fn extract_text_lazy_with_tracking(
         if let Some(ref mut pages) = page_contents {
             // Extract hierarchy if enabled
             let hierarchy = if should_extract_hierarchy {
-                extract_page_hierarchy(&page, hierarchy_config.as_ref())?
+                page_hierarchy_without_furniture(&page, hierarchy_config.as_ref(), furniture, page_number)?
             } else {
                 None
             };
@@ -403,6 +420,7 @@ fn extract_text_lazy_with_tracking(
                 images: Vec::new(),
                 hierarchy,
                 is_blank,
+                annotations: Vec::new(),
             });
         }
 
@@ -417,6 +435,39 @@ fn extract_text_lazy_with_tracking(
     Ok((content, Some(boundaries), page_contents))
 }
 
+/// Remove the running headers and footers detected on a page from its text.
+pub(crate) fn page_text_without_furniture(
+    page_text: String,
+    furniture: &[PageFurniture],
+    page_number: usize,
+) -> String {
+    if furniture.is_empty() {
+        return page_text;
+    }
+    let on_page = crate::pdf::furniture::furniture_on_page(furniture, page_number);
+    crate::pdf::furniture::strip_furniture_lines(&page_text, &on_page)
+}
+
+/// Extract the text hierarchy of a page, without the blocks of its running
+/// headers and footers.
+pub(crate) fn page_hierarchy_without_furniture(
+    page: &pdfium_render::prelude::PdfPage,
+    hierarchy_config: Option<&crate::core::config::HierarchyConfig>,
+    furniture: &[PageFurniture],
+    page_number: usize,
+) -> Result<Option<crate::types::PageHierarchy>> {
+    let mut hierarchy = extract_page_hierarchy(page, hierarchy_config)?;
+    if let Some(hierarchy) = hierarchy.as_mut().filter(|_| !furniture.is_empty()) {
+        let on_page = crate::pdf::furniture::furniture_on_page(furniture, page_number);
+        hierarchy.blocks.retain(|block| {
+            let text = block.text.split_whitespace().collect::<Vec<_>>().join(" ");
+            !on_page.iter().any(|f| f.text == text)
+        });
+        hierarchy.block_count = hierarchy.blocks.len();
+    }
+    Ok(hierarchy)
+}
+
 /// Extract text hierarchy from a single PDF page.
 ///
 /// Uses font size clustering to identify heading levels (H1-H6) and assigns
@@ -0,0 +1,312 @@
+//! Health of native PDF text layers.
+//!
+//! Fonts embedded without a ToUnicode map (or with a broken one) make pdfium
+//! decode glyphs to private-use code points, replacement characters or letters
+//! that do not form words. Such pages still look like they have text, so the
+//! document-level OCR fallback does not catch them. Each page's text is scored
+//! here, and pages below `PdfConfig::text_layer_threshold` are re-extracted
+//! with OCR by the PDF extractor.
+
+use super::error::{PdfError, Result};
+use super::text::page_text_without_furniture;
+use crate::types::{PageBoundary, PageFurniture, TextLayerHealth};
+use pdfium_render::prelude::*;
+
+/// Minimum non-whitespace characters for a page's text layer to be judged.
+const MIN_ASSESSED_CHARS: usize = 20;
+/// Minimum words in space-separated scripts for the dictionary signal.
+#[cfg(feature = "stopwords")]
+const MIN_DICTIONARY_WORDS: usize = 20;
+/// Dictionary hit rate of letter sequences that are not words: with the
+/// stopword lists of all languages combined, short random tokens still hit.
+const RANDOM_DICTIONARY_HIT_RATE: f64 = 0.1;
+/// Dictionary hit rate at and above which text counts as regular prose.
+/// Running text hits 50-70%; tables and lists of names hit less, so the signal
+/// only penalizes rates close to random.
+const EXPECTED_DICTIONARY_HIT_RATE: f64 = 0.3;
+
+/// The text layer of a page, with its health.
+#[derive(Debug, Clone)]
+pub(crate) struct PageTextLayer {
+    pub page_number: usize,
+    /// Native text of the page, as it appears in the extracted content.
+    #[cfg_attr(not(feature = "ocr"), allow(dead_code))]
+    pub text: String,
+    pub health: TextLayerHealth,
+}
+
+/// Score a page's native text.
+///
+/// Returns `None` when the text is too short to judge. The score multiplies the
+/// share of valid glyphs (private-use and replacement characters count twice)
+/// with the dictionary hit rate of the words and the `text::quality` score,
+/// each weighted so that a single weak signal cannot sink clean text on its own.
+pub fn assess_text_layer(text: &str) -> Option<TextLayerHealth> {
+    let mut non_whitespace = 0usize;
+    let mut private_use = 0usize;
+    let mut replacement = 0usize;
+    for ch in text.chars().filter(|c| !c.is_whitespace()) {
+        non_whitespace += 1;
+        if is_private_use(ch) {
+            private_use += 1;
+        } else if ch == '\u{FFFD}' || ch.is_control() {
+            replacement += 1;
+        }
+    }
+    if non_whitespace < MIN_ASSESSED_CHARS {
+        return None;
+    }
+
+    let private_use_ratio = private_use as f64 / non_whitespace as f64;
+    let replacement_ratio = replacement as f64 / non_whitespace as f64;
+    let dictionary_hit_rate = dictionary_hit_rate(text);
+    let quality_score = quality_score(text);
+
+    let glyph_score = (1.0 - 2.0 * (private_use_ratio + replacement_ratio)).max(0.0);
+    let dictionary_score = dictionary_hit_rate.map_or(1.0, |rate| {
+        ((rate - RANDOM_DICTIONARY_HIT_RATE) / (EXPECTED_DICTIONARY_HIT_RATE - RANDOM_DICTIONARY_HIT_RATE))
+            .clamp(0.0, 1.0)
+    });
+    let quality_factor = quality_score.map_or(1.0, |score| 0.7 + 0.3 * score);
+
+    Some(TextLayerHealth {
+        score: glyph_score * (0.4 + 0.6 * dictionary_score) * quality_factor,
+        private_use_ratio,
+        replacement_ratio,
+        dictionary_hit_rate,
+        quality_score,
+        ocr_fallback: false,
+    })
+}
+
+/// Assess the text layer of every page that has enough text to judge.
+///
+/// Page texts are sliced from `content` when it was tracked per page
+/// (`boundaries`), and read from the document again otherwise.
+pub(crate) fn assess_pages(
+    document: &PdfDocument<'_>,
+    content: &str,
+    boundaries: Option<&[PageBoundary]>,
+    furniture: &[PageFurniture],
+) -> Result<Vec<PageTextLayer>> {
+    let page_texts: Vec<(usize, String)> = match boundaries {
+        Some(boundaries) => boundaries
+            .iter()
+            .filter_map(|b| {
+                content
+                    .get(b.byte_start..b.byte_end)
+                    .map(|text| (b.page_number, text.to_string()))
+            })
+            .collect(),
+        None => document
+            .pages()
+            .iter()
+            .enumerate()
+            .map(|(page_idx, page)| {
+                let text = page
+                    .text()
+                    .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
+                Ok((
+                    page_idx + 1,
+                    page_text_without_furniture(text.all(), furniture, page_idx + 1),
+                ))
+            })
+            .collect::<Result<_>>()?,
+    };
+
+    Ok(page_texts
+        .into_iter()
+        .filter_map(|(page_number, text)| {
+            assess_text_layer(&text).map(|health| PageTextLayer {
+                page_number,
+                text,
+                health,
+            })
+        })
+        .collect())
+}
+
+/// Replace the native text of a page in `content`.
+///
+/// With `boundaries`, the page's byte range is replaced and the boundaries are
+/// shifted. Without them, the first occurrence of `native_text` at or after
+/// `from` is replaced. Returns the end of the replacement, or `None` when the
+/// page could not be located.
+#[cfg_attr(not(feature = "ocr"), allow(dead_code))]
+pub(crate) fn replace_page_text(
+    content: &mut String,
+    boundaries: Option<&mut [PageBoundary]>,
+    page_number: usize,
+    native_text: &str,
+    replacement: &str,
+    from: usize,
+) -> Option<usize> {
+    let (start, end) = match boundaries.as_deref() {
+        Some(boundaries) => {
+            let page = boundaries.iter().find(|b| b.page_number == page_number)?;
+            (page.byte_start, page.byte_end)
+        }
+        None => {
+            let start = from + content.get(from..)?.find(native_text)?;
+            (start, start + native_text.len())
+        }
+    };
+    if start > end || end > content.len() {
+        return None;
+    }
+
+    content.replace_range(start..end, replacement);
+    let new_end = start + replacement.len();
+
+    if let Some(boundaries) = boundaries {
+        for boundary in boundaries.iter_mut() {
+            if boundary.page_number == page_number {
+                boundary.byte_end = new_end;
+            } else if boundary.byte_start >= end {
+                boundary.byte_start = boundary.byte_start - end + new_end;
+                boundary.byte_end = boundary.byte_end - end + new_end;
+            }
+        }
+    }
+
+    Some(new_end)
+}
+
+fn is_private_use(ch: char) -> bool {
+    matches!(ch, '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}')
+}
+
+/// Share of words found in any stopword list. Only words of letters from the
+/// Latin, Greek and Cyrillic blocks are counted: scripts written without spaces
+/// cannot be split into words here.
+#[cfg(feature = "stopwords")]
+fn dictionary_hit_rate(text: &str) -> Option<f64> {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|word| word.chars().count() >= 2 && word.chars().all(|c| c.is_alphabetic() && (c as u32) < 0x0530))
+        .map(str::to_lowercase)
+        .collect();
+    if words.len() < MIN_DICTIONARY_WORDS {
+        return None;
+    }
+
+    let stopwords = &*crate::stopwords::STOPWORDS;
+    let hits = words
+        .iter()
+        .filter(|word| stopwords.values().any(|set| set.contains(word.as_str())))
+        .count();
+    Some(hits as f64 / words.len() as f64)
+}
+
+#[cfg(not(feature = "stopwords"))]
+fn dictionary_hit_rate(_text: &str) -> Option<f64> {
+    None
+}
+
+#[cfg(feature = "quality")]
+fn quality_score(text: &str) -> Option<f64> {
+    Some(crate::text::calculate_quality_score(text, None))
+}
+
+#[cfg(not(feature = "quality"))]
+fn quality_score(_text: &str) -> Option<f64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROSE: &str = "The committee reviewed the annual report and agreed that the budget for the \
+        next year should be increased. It was noted that most of the projects are on schedule, \
+        but some of them will need more staff in the coming months.";
+
+    #[test]
+    fn test_clean_text_is_healthy() {
+        let health = assess_text_layer(PROSE).unwrap();
+        assert_eq!(health.private_use_ratio, 0.0);
+        assert_eq!(health.replacement_ratio, 0.0);
+        assert!(health.score > 0.8, "score {}", health.score);
+        assert!(!health.ocr_fallback);
+    }
+
+    #[test]
+    fn test_private_use_glyphs_are_broken() {
+        let text: String = PROSE
+            .chars()
+            .map(|c| {
+                if c.is_alphabetic() {
+                    char::from_u32(0xE000 + c as u32).unwrap()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        let health = assess_text_layer(&text).unwrap();
+        assert!(health.private_use_ratio > 0.9);
+        assert!(health.score < 0.1, "score {}", health.score);
+
+        let replaced = PROSE.replace(['e', 'a', 'o'], "\u{FFFD}");
+        let health = assess_text_layer(&replaced).unwrap();
+        assert!(health.replacement_ratio > 0.2);
+        assert!(health.score < 0.5, "score {}", health.score);
+    }
+
+    #[cfg(feature = "stopwords")]
+    #[test]
+    fn test_shifted_letters_miss_the_dictionary() {
+        // Glyph ids mapped to the wrong code points: every letter shifted by three
+        let shifted: String = PROSE
+            .chars()
+            .map(|c| match c {
+                'a'..='z' => (b'a' + (c as u8 - b'a' + 3) % 26) as char,
+                _ => c,
+            })
+            .collect();
+        let health = assess_text_layer(&shifted).unwrap();
+        assert!(health.dictionary_hit_rate.unwrap() < RANDOM_DICTIONARY_HIT_RATE);
+        assert!(health.score < 0.5, "score {}", health.score);
+        assert!(assess_text_layer(PROSE).unwrap().dictionary_hit_rate.unwrap() > EXPECTED_DICTIONARY_HIT_RATE);
+    }
+
+    #[test]
+    fn test_short_text_is_not_assessed() {
+        assert!(assess_text_layer("Page 4").is_none());
+        assert!(assess_text_layer("   ").is_none());
+    }
+
+    #[test]
+    fn test_replace_page_text_shifts_boundaries() {
+        let mut content = "first page\n\nbroken\n\nthird page".to_string();
+        let mut boundaries = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 10,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 12,
+                byte_end: 18,
+                page_number: 2,
+            },
+            PageBoundary {
+                byte_start: 20,
+                byte_end: 30,
+                page_number: 3,
+            },
+        ];
+
+        let end = replace_page_text(&mut content, Some(&mut boundaries), 2, "broken", "OCR text", 0);
+        assert_eq!(end, Some(20));
+        assert_eq!(content, "first page\n\nOCR text\n\nthird page");
+        assert_eq!(&content[boundaries[1].byte_start..boundaries[1].byte_end], "OCR text");
+        assert_eq!(&content[boundaries[2].byte_start..boundaries[2].byte_end], "third page");
+
+        let mut content = "broken\n\nbroken".to_string();
+        let end = replace_page_text(&mut content, None, 2, "broken", "fixed", 6);
+        assert_eq!(content, "broken\n\nfixed");
+        assert_eq!(end, Some(13));
+        assert_eq!(replace_page_text(&mut content, None, 3, "missing", "x", 0), None);
+    }
+}
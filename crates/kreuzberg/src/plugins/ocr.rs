@@ -5,7 +5,7 @@
 use crate::Result;
 use crate::core::config::OcrConfig;
 use crate::plugins::Plugin;
-use crate::types::ExtractionResult;
+use crate::types::{ExtractionResult, OcrRotation};
 use async_trait::async_trait;
 use std::path::Path;
 use std::sync::Arc;
@@ -280,6 +280,32 @@ pub trait OcrBackend: Plugin {
     fn supports_table_detection(&self) -> bool {
         false
     }
+
+    /// Optional: Detect the orientation of a page image.
+    ///
+    /// Called before `process_image` when orientation correction is enabled
+    /// (`OcrConfig::auto_rotate` or `preprocessing.auto_rotate`). Returns the
+    /// clockwise rotation that makes the text upright.
+    ///
+    /// Defaults to `Ok(None)`, in which case the pipeline falls back to a
+    /// projection-profile heuristic.
+    async fn detect_orientation(&self, image_bytes: &[u8], config: &OcrConfig) -> Result<Option<OcrRotation>> {
+        let _ = (image_bytes, config);
+        Ok(None)
+    }
+
+    /// Optional: Detect the dominant script of a page image.
+    ///
+    /// Called before recognition when the OCR language is `"auto"`. Returns a
+    /// Tesseract OSD script name such as `"Latin"`, `"Cyrillic"`, `"Han"` or
+    /// `"Arabic"`, which selects the language of the recognition pass.
+    ///
+    /// Defaults to `Ok(None)`, in which case the language is detected from a
+    /// first recognition pass.
+    async fn detect_script(&self, image_bytes: &[u8], config: &OcrConfig) -> Result<Option<String>> {
+        let _ = (image_bytes, config);
+        Ok(None)
+    }
 }
 
 /// Register an OCR backend with the global registry.
@@ -401,6 +401,8 @@ mod tests {
             markdown: "| A | B |".to_string(),
             page_number: 0,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let result = ExtractionResult {
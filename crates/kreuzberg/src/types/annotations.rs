@@ -0,0 +1,222 @@
+//! PDF page annotation types.
+//!
+//! Annotations live in a separate layer on top of the page content: reviewer
+//! comments, highlights, sticky notes, stamps and link areas. They are reported
+//! per page in `PageContent::annotations`, summarized in the PDF metadata and
+//! emitted as `Annotation` nodes in the `DocumentStructure`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::extraction::BoundingBox;
+
+/// A single annotation attached to a PDF page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PdfAnnotation {
+    /// Page number the annotation is attached to (1-indexed).
+    pub page_number: usize,
+
+    /// Annotation subtype.
+    pub annotation_type: PdfAnnotationType,
+
+    /// Author of the annotation (the `/T` entry).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Creation timestamp (ISO 8601 format).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// Last modification timestamp (ISO 8601 format).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified_at: Option<String>,
+
+    /// Comment text or, for non-text annotations, the alternate description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<String>,
+
+    /// Page text covered by a text markup annotation (highlight, underline,
+    /// squiggly, strike-out), read from the annotation's quad points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlighted_text: Option<String>,
+
+    /// URI target of a link annotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Target page of an internal link annotation (1-indexed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_page: Option<usize>,
+
+    /// Annotation rectangle in PDF coordinates (y=0 at the bottom of the page).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
+}
+
+/// PDF annotation subtypes.
+///
+/// Popup annotations are not reported on their own: they only display the
+/// contents of their parent annotation. Form field widgets are not annotations
+/// in this sense and are skipped as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PdfAnnotationType {
+    /// Sticky note.
+    Text,
+    FreeText,
+    Highlight,
+    Underline,
+    Squiggly,
+    Strikeout,
+    Link,
+    Stamp,
+    Ink,
+    Square,
+    Circle,
+    Line,
+    Polygon,
+    Polyline,
+    Caret,
+    FileAttachment,
+    Redact,
+    /// Any other subtype (multimedia, 3D, printer marks, ...).
+    Other,
+}
+
+impl PdfAnnotationType {
+    /// Get the serialized name of this annotation type.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PdfAnnotationType::Text => "text",
+            PdfAnnotationType::FreeText => "free_text",
+            PdfAnnotationType::Highlight => "highlight",
+            PdfAnnotationType::Underline => "underline",
+            PdfAnnotationType::Squiggly => "squiggly",
+            PdfAnnotationType::Strikeout => "strikeout",
+            PdfAnnotationType::Link => "link",
+            PdfAnnotationType::Stamp => "stamp",
+            PdfAnnotationType::Ink => "ink",
+            PdfAnnotationType::Square => "square",
+            PdfAnnotationType::Circle => "circle",
+            PdfAnnotationType::Line => "line",
+            PdfAnnotationType::Polygon => "polygon",
+            PdfAnnotationType::Polyline => "polyline",
+            PdfAnnotationType::Caret => "caret",
+            PdfAnnotationType::FileAttachment => "file_attachment",
+            PdfAnnotationType::Redact => "redact",
+            PdfAnnotationType::Other => "other",
+        }
+    }
+
+    /// Whether this is a text markup annotation whose quad points cover page text.
+    pub fn is_text_markup(&self) -> bool {
+        matches!(
+            self,
+            PdfAnnotationType::Highlight
+                | PdfAnnotationType::Underline
+                | PdfAnnotationType::Squiggly
+                | PdfAnnotationType::Strikeout
+        )
+    }
+}
+
+/// Document-level summary of the annotations in a PDF.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PdfAnnotationSummary {
+    /// Total number of annotations.
+    pub total_count: usize,
+
+    /// Number of annotations per type, keyed by the serialized type name.
+    pub counts_by_type: BTreeMap<String, usize>,
+
+    /// Distinct annotation authors in order of first appearance.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub authors: Vec<String>,
+
+    /// Pages carrying at least one annotation (1-indexed, ascending).
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub pages: Vec<usize>,
+}
+
+impl PdfAnnotationSummary {
+    /// Summarize a list of annotations.
+    pub fn from_annotations(annotations: &[PdfAnnotation]) -> Self {
+        let mut summary = Self {
+            total_count: annotations.len(),
+            ..Default::default()
+        };
+
+        for annotation in annotations {
+            *summary
+                .counts_by_type
+                .entry(annotation.annotation_type.as_str().to_string())
+                .or_insert(0) += 1;
+
+            if let Some(author) = &annotation.author
+                && !summary.authors.contains(author)
+            {
+                summary.authors.push(author.clone());
+            }
+
+            if !summary.pages.contains(&annotation.page_number) {
+                summary.pages.push(annotation.page_number);
+            }
+        }
+
+        summary.pages.sort_unstable();
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotation(page_number: usize, annotation_type: PdfAnnotationType, author: Option<&str>) -> PdfAnnotation {
+        PdfAnnotation {
+            page_number,
+            annotation_type,
+            author: author.map(str::to_string),
+            created_at: None,
+            modified_at: None,
+            contents: None,
+            highlighted_text: None,
+            url: None,
+            target_page: None,
+            bounding_box: None,
+        }
+    }
+
+    #[test]
+    fn test_summary_counts_types_authors_and_pages() {
+        let annotations = vec![
+            annotation(3, PdfAnnotationType::Highlight, Some("Alice")),
+            annotation(1, PdfAnnotationType::Text, Some("Bob")),
+            annotation(3, PdfAnnotationType::Highlight, Some("Alice")),
+            annotation(1, PdfAnnotationType::Link, None),
+        ];
+
+        let summary = PdfAnnotationSummary::from_annotations(&annotations);
+        assert_eq!(summary.total_count, 4);
+        assert_eq!(summary.counts_by_type["highlight"], 2);
+        assert_eq!(summary.counts_by_type["text"], 1);
+        assert_eq!(summary.counts_by_type["link"], 1);
+        assert_eq!(summary.authors, vec!["Alice", "Bob"]);
+        assert_eq!(summary.pages, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_annotation_type_serialization_matches_as_str() {
+        for annotation_type in [
+            PdfAnnotationType::FreeText,
+            PdfAnnotationType::Strikeout,
+            PdfAnnotationType::FileAttachment,
+        ] {
+            let json = serde_json::to_value(annotation_type).unwrap();
+            assert_eq!(json, annotation_type.as_str());
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::annotations::PdfAnnotationType;
 use super::extraction::BoundingBox;
 
 // ============================================================================
@@ -166,7 +167,7 @@ pub struct DocumentNode {
 /// Content layer classification for document nodes.
 ///
 /// Replaces separate body/furniture arrays with per-node granularity.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ContentLayer {
@@ -257,6 +258,22 @@ pub enum NodeContent {
 
     /// Page break marker.
     PageBreak,
+
+    /// Page annotation (PDF comment, highlight, sticky note, link).
+    ///
+    /// `text` carries the annotation contents; `highlighted_text` the page text
+    /// covered by a text markup annotation.
+    Annotation {
+        annotation_type: PdfAnnotationType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        highlighted_text: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+    },
 }
 
 // ============================================================================
@@ -378,6 +395,7 @@ impl NodeContent {
             | NodeContent::Code { text, .. }
             | NodeContent::Formula { text }
             | NodeContent::Footnote { text } => Some(text),
+            NodeContent::Annotation { text, .. } => text.as_deref(),
             NodeContent::Table { .. }
             | NodeContent::Image { .. }
             | NodeContent::List { .. }
@@ -403,6 +421,7 @@ impl NodeContent {
             NodeContent::Footnote { .. } => "footnote",
             NodeContent::Group { .. } => "group",
             NodeContent::PageBreak => "page_break",
+            NodeContent::Annotation { .. } => "annotation",
         }
     }
 }
@@ -758,6 +777,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_annotation_node_content() {
+        let content = NodeContent::Annotation {
+            annotation_type: PdfAnnotationType::Highlight,
+            text: Some("Check this".to_string()),
+            author: Some("Alice".to_string()),
+            highlighted_text: Some("quarterly revenue".to_string()),
+            url: None,
+        };
+        assert_eq!(content.text(), Some("Check this"));
+        assert_eq!(content.node_type_str(), "annotation");
+
+        let json = serde_json::to_value(&content).unwrap();
+        assert_eq!(json["node_type"], "annotation");
+        assert_eq!(json["annotation_type"], "highlight");
+        assert!(json.get("url").is_none());
+
+        let roundtrip: NodeContent = serde_json::from_value(json).unwrap();
+        assert_eq!(roundtrip, content);
+    }
+
     #[test]
     fn test_serde_roundtrip() {
         let mut doc = DocumentStructure::new();
@@ -0,0 +1,69 @@
+//! Embedded file (attachment) types.
+//!
+//! Container documents such as PDF portfolios carry complete files of their
+//! own. They are always listed in the container's metadata; when recursive
+//! extraction is enabled, each entry also carries the result of extracting the
+//! file with the extractor registered for its MIME type.
+
+use serde::{Deserialize, Serialize};
+
+use super::extraction::ExtractionResult;
+
+/// A file embedded in a container document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "api", schema(no_recursion))]
+pub struct EmbeddedFile {
+    /// File name as stored in the container.
+    pub filename: String,
+
+    /// MIME type detected from the file name or, failing that, the content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// Uncompressed size in bytes.
+    pub size: usize,
+
+    /// Description given by the author of the container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Extraction result of the file (only when embedded file extraction is enabled).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Box<ExtractionResult>>,
+
+    /// Why the file was not extracted: unsupported type, failed extraction or
+    /// an exceeded security limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_file_serialization_skips_empty_fields() {
+        let file = EmbeddedFile {
+            filename: "totals.csv".to_string(),
+            mime_type: Some("text/csv".to_string()),
+            size: 12,
+            description: None,
+            result: Some(Box::new(ExtractionResult {
+                content: "a,b\n1,2".to_string(),
+                mime_type: "text/csv".into(),
+                ..Default::default()
+            })),
+            error: None,
+        };
+
+        let json = serde_json::to_value(&file).unwrap();
+        assert_eq!(json["filename"], "totals.csv");
+        assert_eq!(json["result"]["content"], "a,b\n1,2");
+        assert!(json.get("description").is_none());
+        assert!(json.get("error").is_none());
+
+        let parsed: EmbeddedFile = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.result.unwrap().content, "a,b\n1,2");
+    }
+}
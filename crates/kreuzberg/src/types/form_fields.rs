@@ -0,0 +1,137 @@
+//! PDF form field types.
+//!
+//! Interactive forms keep their filled-in values outside the page content, so
+//! they are reported separately as structured key-value data in the PDF
+//! metadata. AcroForm fields carry their widget's page and rectangle; XFA forms
+//! only provide the values from their data packet.
+
+use serde::{Deserialize, Serialize};
+
+use super::extraction::BoundingBox;
+
+/// A single form field and its value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct FormField {
+    /// Fully qualified field name (e.g. `applicant.address.city`).
+    pub name: String,
+
+    /// Field type.
+    pub field_type: FormFieldType,
+
+    /// Field value; for check boxes and radio buttons the export value of the
+    /// selected option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Checked state of check boxes and radio button groups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_checked: Option<bool>,
+
+    /// Whether the field is marked read-only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_read_only: bool,
+
+    /// Page carrying the field's widget (1-indexed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<usize>,
+
+    /// Widget rectangle in PDF coordinates (y=0 at the bottom of the page).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
+
+    /// Form technology the field was read from.
+    #[serde(default)]
+    pub source: FormFieldSource,
+}
+
+impl FormField {
+    /// Value to show for the field, or `None` when it has not been filled in.
+    ///
+    /// Checked boxes without an export value show as `Yes`; unchecked boxes
+    /// and empty fields have no display value.
+    pub fn display_value(&self) -> Option<&str> {
+        match self.is_checked {
+            Some(false) => None,
+            Some(true) => Some(self.value.as_deref().unwrap_or("Yes")),
+            None => self.value.as_deref().filter(|value| !value.trim().is_empty()),
+        }
+    }
+}
+
+/// Form field types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FormFieldType {
+    Text,
+    Checkbox,
+    RadioButton,
+    ComboBox,
+    ListBox,
+    Signature,
+    /// Type not known, e.g. values read from an XFA data packet.
+    Unknown,
+}
+
+/// Form technology a field was read from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FormFieldSource {
+    #[default]
+    AcroForm,
+    Xfa,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(field_type: FormFieldType, value: Option<&str>, is_checked: Option<bool>) -> FormField {
+        FormField {
+            name: "field".to_string(),
+            field_type,
+            value: value.map(str::to_string),
+            is_checked,
+            is_read_only: false,
+            page_number: None,
+            bounding_box: None,
+            source: FormFieldSource::AcroForm,
+        }
+    }
+
+    #[test]
+    fn test_display_value() {
+        assert_eq!(
+            field(FormFieldType::Text, Some("Jane"), None).display_value(),
+            Some("Jane")
+        );
+        assert_eq!(field(FormFieldType::Text, Some("  "), None).display_value(), None);
+        assert_eq!(
+            field(FormFieldType::Checkbox, None, Some(true)).display_value(),
+            Some("Yes")
+        );
+        assert_eq!(
+            field(FormFieldType::RadioButton, Some("Married"), Some(true)).display_value(),
+            Some("Married")
+        );
+        assert_eq!(field(FormFieldType::Checkbox, None, Some(false)).display_value(), None);
+    }
+
+    #[test]
+    fn test_serialization_defaults() {
+        let json = serde_json::to_value(field(FormFieldType::ComboBox, Some("DE"), None)).unwrap();
+        assert_eq!(json["field_type"], "combo_box");
+        assert_eq!(json["source"], "acro_form");
+        assert!(json.get("is_read_only").is_none());
+
+        let parsed: FormField = serde_json::from_value(serde_json::json!({
+            "name": "total",
+            "field_type": "unknown",
+        }))
+        .unwrap();
+        assert_eq!(parsed.source, FormFieldSource::AcroForm);
+        assert!(!parsed.is_read_only);
+    }
+}
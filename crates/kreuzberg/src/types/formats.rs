@@ -203,10 +203,10 @@ pub struct ImagePreprocessingConfig {
     /// Target DPI for the image (300 is standard, 600 for small text).
     pub target_dpi: i32,
 
-    /// Auto-detect and correct image rotation.
+    /// Auto-detect and correct image rotation (off by default).
     pub auto_rotate: bool,
 
-    /// Correct skew (tilted images).
+    /// Correct skew (tilted images, off by default).
     pub deskew: bool,
 
     /// Remove noise from the image.
@@ -215,6 +215,9 @@ pub struct ImagePreprocessingConfig {
     /// Enhance contrast for better text visibility.
     pub contrast_enhance: bool,
 
+    /// Convert the image to black and white before OCR (on by default).
+    pub binarize: bool,
+
     /// Binarization method: "otsu", "sauvola", "adaptive".
     pub binarization_method: String,
 
@@ -226,10 +229,11 @@ impl Default for ImagePreprocessingConfig {
     fn default() -> Self {
         Self {
             target_dpi: 300,
-            auto_rotate: true,
-            deskew: true,
+            auto_rotate: false,
+            deskew: false,
             denoise: false,
             contrast_enhance: false,
+            binarize: true,
             binarization_method: "otsu".to_string(),
             invert_colors: false,
         }
@@ -355,7 +359,8 @@ impl Default for TesseractConfig {
 /// Image preprocessing metadata.
 ///
 /// Tracks the transformations applied to an image during OCR preprocessing,
-/// including DPI normalization, resizing, and resampling.
+/// including DPI normalization, resizing, resampling, and the enhancement
+/// steps configured by [`ImagePreprocessingConfig`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
 pub struct ImagePreprocessingMetadata {
@@ -383,6 +388,22 @@ pub struct ImagePreprocessingMetadata {
     pub skipped_resize: bool,
     /// Error message if resize failed
     pub resize_error: Option<String>,
+    /// Enhancement steps applied before OCR, in order
+    /// ("invert", "contrast_enhance", "denoise", "auto_rotate", "deskew", "binarize")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operations: Vec<String>,
+    /// Clockwise rotation in degrees (0, 90, 180, 270) applied by auto-rotation
+    #[serde(default)]
+    pub rotation_degrees: i32,
+    /// Detected skew angle in degrees that was corrected by deskewing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deskew_angle: Option<f64>,
+    /// Binarization method applied ("otsu", "sauvola", "adaptive")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binarization_method: Option<String>,
+    /// Global threshold chosen by Otsu binarization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binarization_threshold: Option<u8>,
 }
 
 /// Image extraction configuration (internal use).
@@ -0,0 +1,51 @@
+//! Page furniture types.
+//!
+//! Running headers and footers (page numbers, running titles, confidentiality
+//! notices) repeat on every page without belonging to the body text. When they
+//! are stripped from the content they are kept here, so that they can still be
+//! placed in the `DocumentStructure` as header and footer nodes.
+
+use serde::{Deserialize, Serialize};
+
+use super::document_structure::ContentLayer;
+use super::extraction::BoundingBox;
+
+/// A running header or footer line found on a page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PageFurniture {
+    /// Page the line appears on (1-indexed).
+    pub page_number: usize,
+
+    /// `Header` or `Footer`.
+    pub layer: ContentLayer,
+
+    /// Text of the line.
+    pub text: String,
+
+    /// Position of the line in PDF coordinates (y=0 at the bottom of the page).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_furniture_serialization() {
+        let furniture = PageFurniture {
+            page_number: 3,
+            layer: ContentLayer::Footer,
+            text: "Page 3 of 10".to_string(),
+            bounding_box: None,
+        };
+
+        let json = serde_json::to_value(&furniture).unwrap();
+        assert_eq!(json["layer"], "footer");
+        assert!(json.get("bounding_box").is_none());
+
+        let parsed: PageFurniture = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, furniture);
+    }
+}
@@ -123,6 +123,8 @@ pub struct Metadata {
     pub format: Option<FormatMetadata>,
 
     /// Image preprocessing metadata (when OCR preprocessing was applied)
+    ///
+    /// For multi-page inputs (PDFs, multi-frame TIFFs) this describes the first page only.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_preprocessing: Option<ImagePreprocessingMetadata>,
 
@@ -1,26 +1,38 @@
 //! Core types for document extraction.
 
 // Module declarations
+pub mod annotations;
 pub mod djot;
 pub mod document_structure;
+pub mod embedded_files;
 pub mod extraction;
+pub mod form_fields;
 pub mod formats;
+pub mod furniture;
 pub mod metadata;
 pub mod ocr_elements;
+pub mod ocr_regions;
+pub mod outline;
 pub mod page;
 pub mod serde_helpers;
 pub mod tables;
 
 // Re-export all types for backward compatibility
+pub use annotations::*;
 pub use djot::*;
 pub use document_structure::{
     AnnotationKind, ContentLayer, DocumentNode, DocumentStructure, GridCell, NodeContent, NodeId, NodeIndex, TableGrid,
     TextAnnotation,
 };
+pub use embedded_files::EmbeddedFile;
 pub use extraction::*;
+pub use form_fields::*;
 pub use formats::*;
+pub use furniture::PageFurniture;
 pub use metadata::*;
 pub use ocr_elements::*;
+pub use ocr_regions::OcrRegion;
+pub use outline::PdfOutlineEntry;
 pub use page::*;
 pub use tables::*;
 
@@ -72,6 +84,8 @@ mod tests {
             markdown: "| A | B |\n|---|---|\n".to_string(),
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let json = serde_json::to_value(&table).unwrap();
@@ -91,6 +105,8 @@ mod tests {
             markdown: "| X | Y |\n|---|---|\n| 1 | 2 |\n".to_string(),
             page_number: 5,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -108,6 +124,8 @@ mod tests {
             markdown: "| shared |".to_string(),
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         });
 
         let tables_before = [Arc::clone(&shared_table), Arc::clone(&shared_table)].to_vec();
@@ -124,12 +142,16 @@ mod tests {
                 markdown: "| A |".to_string(),
                 page_number: 1,
                 bounding_box: None,
+                end_page_number: None,
+                spans: vec![],
             },
             Table {
                 cells: vec![vec!["B".to_string()]],
                 markdown: "| B |".to_string(),
                 page_number: 2,
                 bounding_box: None,
+                end_page_number: None,
+                spans: vec![],
             },
         ];
 
@@ -153,17 +175,22 @@ mod tests {
                     markdown: "| Table1 |".to_string(),
                     page_number: 3,
                     bounding_box: None,
+                    end_page_number: None,
+                    spans: vec![],
                 }),
                 Arc::new(Table {
                     cells: vec![vec!["Table2".to_string()]],
                     markdown: "| Table2 |".to_string(),
                     page_number: 3,
                     bounding_box: None,
+                    end_page_number: None,
+                    spans: vec![],
                 }),
             ],
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            annotations: Vec::new(),
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -215,6 +242,7 @@ mod tests {
             images: vec![image1, image2],
             hierarchy: None,
             is_blank: None,
+            annotations: Vec::new(),
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -234,6 +262,8 @@ mod tests {
             markdown: "| shared across pages |".to_string(),
             page_number: 0,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         });
 
         let page1 = PageContent {
@@ -243,6 +273,7 @@ mod tests {
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            annotations: Vec::new(),
         };
 
         let page2 = PageContent {
@@ -252,6 +283,7 @@ mod tests {
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            annotations: Vec::new(),
         };
 
         assert!(Arc::ptr_eq(&page1.tables[0], &page2.tables[0]));
@@ -274,6 +306,7 @@ mod tests {
             images: Vec::new(),
             hierarchy: None,
             is_blank: None,
+            annotations: Vec::new(),
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -291,6 +324,8 @@ mod tests {
             markdown: "| A |".to_string(),
             page_number: 1,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let table2 = Table {
@@ -298,6 +333,8 @@ mod tests {
             markdown: "| B |".to_string(),
             page_number: 2,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let json = serde_json::to_string(&vec![table1, table2]).unwrap();
@@ -133,11 +133,15 @@ impl OcrConfidence {
     }
 }
 
-/// Rotation information for an OCR element.
+/// Rotation information for an OCR element or page.
+///
+/// For pages, `angle_degrees` is the clockwise rotation that was applied to the
+/// scanned image before recognition; bounding boxes refer to the rotated image
+/// and can be mapped back with [`OcrRotation::map_to_original`].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
 pub struct OcrRotation {
-    /// Rotation angle in degrees (0, 90, 180, 270 for PaddleOCR).
+    /// Rotation angle in degrees (0, 90, 180, 270 for PaddleOCR and page orientation).
     pub angle_degrees: f64,
 
     /// Confidence score for the rotation detection.
@@ -171,6 +175,19 @@ impl OcrRotation {
             confidence: Some((angle_score as f64).clamp(0.0, 1.0)),
         })
     }
+
+    /// Map a point of the rotated image back to the original (unrotated) image.
+    ///
+    /// `rotated_width` and `rotated_height` are the dimensions of the rotated
+    /// image. Angles that are not a multiple of 90 degrees leave the point unchanged.
+    pub fn map_to_original(&self, x: f64, y: f64, rotated_width: f64, rotated_height: f64) -> (f64, f64) {
+        match (self.angle_degrees.round() as i64).rem_euclid(360) {
+            90 => (y, rotated_width - x),
+            180 => (rotated_width - x, rotated_height - y),
+            270 => (rotated_height - y, x),
+            _ => (x, y),
+        }
+    }
 }
 
 /// Hierarchical level of an OCR element.
@@ -396,6 +413,21 @@ mod tests {
         assert!(OcrRotation::from_paddle(3, 0.92).is_ok());
     }
 
+    #[test]
+    fn test_rotation_map_to_original() {
+        // Original page 100x200 (w x h); rotating by 90° clockwise yields 200x100
+        let rotation = |angle_degrees| OcrRotation {
+            angle_degrees,
+            confidence: None,
+        };
+
+        // Original top-left corner ends up at the top-right after a 90° turn
+        assert_eq!(rotation(90.0).map_to_original(200.0, 0.0, 200.0, 100.0), (0.0, 0.0));
+        assert_eq!(rotation(180.0).map_to_original(0.0, 0.0, 100.0, 200.0), (100.0, 200.0));
+        assert_eq!(rotation(270.0).map_to_original(0.0, 100.0, 200.0, 100.0), (0.0, 0.0));
+        assert_eq!(rotation(0.0).map_to_original(5.0, 7.0, 100.0, 200.0), (5.0, 7.0));
+    }
+
     #[test]
     fn test_element_level_from_tesseract() {
         assert_eq!(OcrElementLevel::from_tesseract_level(1), OcrElementLevel::Block);
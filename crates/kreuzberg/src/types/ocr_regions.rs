@@ -0,0 +1,50 @@
+//! Text recognized in image regions of otherwise native PDF pages.
+//!
+//! Scanned stamps, signatures or pasted screenshots carry no text layer even
+//! when the rest of the page does. These regions can be OCR'd on their own and
+//! their text merged into the native content; each merged region is recorded
+//! here.
+
+use serde::{Deserialize, Serialize};
+
+use super::extraction::BoundingBox;
+
+/// An image region without a text layer whose text was recognized with OCR.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct OcrRegion {
+    /// Page the region is on (1-indexed).
+    pub page_number: usize,
+
+    /// Position of the region in PDF coordinates (y=0 at the bottom of the page).
+    pub bounding_box: BoundingBox,
+
+    /// Recognized text.
+    pub text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ocr_region_serialization() {
+        let region = OcrRegion {
+            page_number: 2,
+            bounding_box: BoundingBox {
+                x0: 400.0,
+                y0: 60.0,
+                x1: 540.0,
+                y1: 120.0,
+            },
+            text: "APPROVED".to_string(),
+        };
+
+        let json = serde_json::to_value(&region).unwrap();
+        assert_eq!(json["page_number"], 2);
+        assert_eq!(json["bounding_box"]["x1"], 540.0);
+
+        let parsed: OcrRegion = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, region);
+    }
+}
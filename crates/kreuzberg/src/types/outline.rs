@@ -0,0 +1,193 @@
+//! PDF outline (bookmark) types.
+//!
+//! The outline is the author's own table of contents. It is reported flattened
+//! in the PDF metadata and, when present, decides the heading levels of the
+//! Markdown output and the `DocumentStructure` instead of font-size clustering.
+
+use serde::{Deserialize, Serialize};
+
+/// Deepest heading level that Markdown and `DocumentStructure` can express.
+const MAX_HEADING_LEVEL: u8 = 6;
+
+/// A single outline (bookmark) entry in document order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct PdfOutlineEntry {
+    /// Bookmark title.
+    pub title: String,
+
+    /// Nesting depth in the outline tree (1 = top level).
+    pub level: u8,
+
+    /// Target page (1-indexed), when the bookmark points into the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+}
+
+/// Assigns heading levels from a document outline.
+///
+/// Text that matches an outline title becomes a heading at the bookmark's
+/// depth. Headings found by other means are kept but pushed below the deepest
+/// outline level, so the outline always forms the top of the hierarchy. Each
+/// bookmark matches at most once, and only on its target page when both pages
+/// are known.
+#[derive(Debug)]
+pub(crate) struct OutlineHeadings<'a> {
+    entries: &'a [PdfOutlineEntry],
+    titles: Vec<String>,
+    used: Vec<bool>,
+    depth: u8,
+    ignore_pages: bool,
+}
+
+impl<'a> OutlineHeadings<'a> {
+    pub(crate) fn new(entries: &'a [PdfOutlineEntry]) -> Self {
+        Self {
+            entries,
+            titles: entries.iter().map(|entry| normalize_title(&entry.title)).collect(),
+            used: vec![false; entries.len()],
+            depth: entries
+                .iter()
+                .map(|entry| entry.level.min(MAX_HEADING_LEVEL))
+                .max()
+                .unwrap_or(0),
+            ignore_pages: false,
+        }
+    }
+
+    /// Match titles regardless of their target page, for content that has no
+    /// page information.
+    pub(crate) fn ignore_pages(mut self) -> Self {
+        self.ignore_pages = true;
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Heading level of the first unused bookmark whose title matches `text`.
+    pub(crate) fn match_heading(&mut self, text: &str, page: Option<usize>) -> Option<u8> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let normalized = normalize_title(text);
+        if normalized.is_empty() {
+            return None;
+        }
+
+        let index = (0..self.entries.len()).find(|&index| {
+            !self.used[index] && self.page_matches(index, page) && titles_match(&self.titles[index], &normalized)
+        })?;
+
+        self.used[index] = true;
+        Some(self.entries[index].level.clamp(1, MAX_HEADING_LEVEL))
+    }
+
+    /// Resolve the final heading level of `text`, given the level detected by
+    /// another heuristic (`None` for body text).
+    pub(crate) fn resolve_level(&mut self, text: &str, page: Option<usize>, detected: Option<u8>) -> Option<u8> {
+        if self.is_empty() {
+            return detected;
+        }
+        self.match_heading(text, page)
+            .or_else(|| detected.map(|level| self.demote(level)))
+    }
+
+    /// Push a heading level found by another heuristic below the outline levels.
+    pub(crate) fn demote(&self, level: u8) -> u8 {
+        self.depth.saturating_add(level).min(MAX_HEADING_LEVEL)
+    }
+
+    fn page_matches(&self, index: usize, page: Option<usize>) -> bool {
+        if self.ignore_pages {
+            return true;
+        }
+        match (self.entries[index].page, page) {
+            (Some(target), Some(page)) => target == page,
+            _ => true,
+        }
+    }
+}
+
+/// Lowercase alphanumeric words separated by single spaces.
+fn normalize_title(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Compare normalized titles, tolerating a section number present on only one
+/// side ("Introduction" vs "1.2 Introduction").
+fn titles_match(title: &str, text: &str) -> bool {
+    if title == text {
+        return true;
+    }
+    let stripped_title = strip_section_number(title);
+    let stripped_text = strip_section_number(text);
+    !stripped_title.is_empty() && stripped_title == stripped_text
+}
+
+fn strip_section_number(normalized: &str) -> &str {
+    let mut rest = normalized;
+    while let Some((token, tail)) = rest.split_once(' ')
+        && token.chars().all(|c| c.is_ascii_digit())
+    {
+        rest = tail;
+    }
+    rest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(title: &str, level: u8, page: Option<usize>) -> PdfOutlineEntry {
+        PdfOutlineEntry {
+            title: title.to_string(),
+            level,
+            page,
+        }
+    }
+
+    #[test]
+    fn test_match_heading_normalizes_titles_and_section_numbers() {
+        let entries = vec![entry("1. Introduction", 1, Some(2)), entry("Scope", 2, Some(2))];
+        let mut outline = OutlineHeadings::new(&entries);
+
+        assert_eq!(outline.match_heading("1.2 Scope", Some(2)), Some(2));
+        assert_eq!(outline.match_heading("1 INTRODUCTION", Some(2)), Some(1));
+        // Each bookmark matches once ~keep
+        assert_eq!(outline.match_heading("Introduction", Some(2)), None);
+    }
+
+    #[test]
+    fn test_match_heading_respects_target_page() {
+        let entries = vec![entry("Results", 1, Some(5))];
+
+        let mut outline = OutlineHeadings::new(&entries);
+        assert_eq!(outline.match_heading("Results", Some(3)), None);
+        assert_eq!(outline.match_heading("Results", Some(5)), Some(1));
+
+        let mut outline = OutlineHeadings::new(&entries).ignore_pages();
+        assert_eq!(outline.match_heading("Results", Some(3)), Some(1));
+    }
+
+    #[test]
+    fn test_resolve_level_demotes_unmatched_headings() {
+        let entries = vec![entry("Methods", 1, None), entry("Sampling", 2, None)];
+        let mut outline = OutlineHeadings::new(&entries);
+
+        assert_eq!(outline.resolve_level("Methods", None, Some(3)), Some(1));
+        assert_eq!(outline.resolve_level("Figure legend", None, Some(1)), Some(3));
+        assert_eq!(outline.resolve_level("Body text", None, None), None);
+
+        let mut empty = OutlineHeadings::new(&[]);
+        assert_eq!(empty.resolve_level("Methods", None, Some(2)), Some(2));
+    }
+}
@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 // Import serde helper and types from sibling modules
+use super::annotations::PdfAnnotation;
 use super::extraction::ExtractedImage;
+use super::ocr_elements::OcrRotation;
 use super::serde_helpers::serde_vec_arc;
 use super::tables::Table;
 
@@ -103,6 +105,64 @@ pub struct PageInfo {
     /// in scanned documents or PDFs with blank separator pages.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_blank: Option<bool>,
+
+    /// Orientation correction applied before OCR (scanned pages only)
+    ///
+    /// `angle_degrees` is the clockwise rotation that made the page upright.
+    /// OCR bounding boxes refer to the rotated page; use
+    /// [`OcrRotation::map_to_original`] to map them back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<OcrRotation>,
+
+    /// Health of the native text layer (PDF pages with enough text to judge)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_layer: Option<TextLayerHealth>,
+
+    /// OCR backend that produced the page text (OCR'd pages only)
+    ///
+    /// Differs from `OcrConfig.backend` when the page was recognized with low
+    /// confidence and the fallback backend did better.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_backend: Option<String>,
+
+    /// OCR language the page was recognized with (OCR'd pages only)
+    ///
+    /// With `OcrConfig.language = "auto"` this is the language detected for the
+    /// page, e.g. `eng+deu`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_language: Option<String>,
+
+    /// Mean recognition confidence (0.0-1.0) of the page's OCR text, when the
+    /// backend reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_confidence: Option<f64>,
+}
+
+/// Health assessment of a page's native text layer.
+///
+/// Fonts without a usable ToUnicode map make PDF text layers decode to
+/// private-use glyphs, replacement characters or shifted letters. The signals
+/// below are combined into `score`; pages scoring below
+/// `PdfConfig::text_layer_threshold` are re-extracted with OCR when an OCR
+/// configuration is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct TextLayerHealth {
+    /// Combined health score (0.0 = unreadable, 1.0 = clean)
+    pub score: f64,
+    /// Share of non-whitespace characters in the Unicode private use areas
+    pub private_use_ratio: f64,
+    /// Share of non-whitespace characters that are replacement (U+FFFD) or control characters
+    pub replacement_ratio: f64,
+    /// Share of words found in the stopword dictionaries (None when there are too few words to judge)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dictionary_hit_rate: Option<f64>,
+    /// Text quality score of the page text (None without the `quality` feature)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality_score: Option<f64>,
+    /// Whether the native text of the page was replaced with OCR
+    #[serde(default)]
+    pub ocr_fallback: bool,
 }
 
 /// Content for a single page/slide.
@@ -157,6 +217,12 @@ pub struct PageContent {
     /// and contains no tables or images.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_blank: Option<bool>,
+
+    /// Annotations attached to this page (PDF comments, highlights, links).
+    ///
+    /// Only populated when `PdfConfig::extract_annotations` is enabled.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub annotations: Vec<PdfAnnotation>,
 }
 
 /// Page hierarchy structure containing heading levels and block information.
@@ -2,6 +2,7 @@
 
 use super::extraction::BoundingBox;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Extracted table structure.
 ///
@@ -21,6 +22,87 @@ pub struct Table {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub bounding_box: Option<BoundingBox>,
+    /// Last page of a table that continues across pages (1-indexed).
+    /// `None` for tables on a single page; `page_number` is the first page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub end_page_number: Option<usize>,
+    /// Merged cells spanning more than one row or column.
+    /// A merged cell's text is stored at its top-left position in `cells`;
+    /// the other positions it covers hold empty strings.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub spans: Vec<CellSpan>,
+}
+
+impl Table {
+    /// Render the table as HTML, with `rowspan`/`colspan` for merged cells.
+    ///
+    /// The first row is rendered as header cells.
+    pub fn to_html(&self) -> String {
+        let covered = self.covered_positions();
+        let mut html = String::from("<table>");
+        for (row_idx, row) in self.cells.iter().enumerate() {
+            html.push_str("<tr>");
+            let tag = if row_idx == 0 { "th" } else { "td" };
+            for (col_idx, cell) in row.iter().enumerate() {
+                if covered.contains(&(row_idx, col_idx)) {
+                    continue;
+                }
+                html.push('<');
+                html.push_str(tag);
+                if let Some(span) = self.span_at(row_idx, col_idx) {
+                    if span.row_span > 1 {
+                        html.push_str(&format!(" rowspan=\"{}\"", span.row_span));
+                    }
+                    if span.col_span > 1 {
+                        html.push_str(&format!(" colspan=\"{}\"", span.col_span));
+                    }
+                }
+                html.push('>');
+                html.push_str(&html_escape(cell));
+                html.push_str("</");
+                html.push_str(tag);
+                html.push('>');
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</table>");
+        html
+    }
+
+    /// The merged cell anchored at `row`, `col`, if any.
+    pub fn span_at(&self, row: usize, col: usize) -> Option<&CellSpan> {
+        self.spans.iter().find(|span| span.row == row && span.col == col)
+    }
+
+    /// Positions covered by a merged cell other than its anchor.
+    pub fn covered_positions(&self) -> HashSet<(usize, usize)> {
+        self.spans
+            .iter()
+            .flat_map(|span| {
+                (span.row..span.row + span.row_span)
+                    .flat_map(move |row| (span.col..span.col + span.col_span).map(move |col| (row, col)))
+                    .filter(move |&position| position != (span.row, span.col))
+            })
+            .collect()
+    }
+}
+
+/// A merged table cell spanning several rows and/or columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct CellSpan {
+    /// Row of the cell's top-left position (0-indexed)
+    pub row: usize,
+    /// Column of the cell's top-left position (0-indexed)
+    pub col: usize,
+    /// Number of rows the cell spans
+    #[serde(default = "default_span")]
+    pub row_span: usize,
+    /// Number of columns the cell spans
+    #[serde(default = "default_span")]
+    pub col_span: usize,
 }
 
 /// Individual table cell with content and optional styling.
@@ -46,6 +128,13 @@ fn default_span() -> usize {
     1
 }
 
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,6 +154,8 @@ mod tests {
                 x1: 500.0,
                 y1: 700.0,
             }),
+            end_page_number: None,
+            spans: vec![],
         };
 
         let json = serde_json::to_string(&table).unwrap();
@@ -90,6 +181,8 @@ mod tests {
             markdown: "| X |".to_string(),
             page_number: 2,
             bounding_box: None,
+            end_page_number: None,
+            spans: vec![],
         };
 
         let json = serde_json::to_string(&table).unwrap();
@@ -122,6 +215,8 @@ mod tests {
                 x1: 30.0,
                 y1: 40.0,
             }),
+            end_page_number: None,
+            spans: vec![],
         };
 
         let cloned = table.clone();
@@ -147,6 +242,8 @@ mod tests {
                 x1: 540.0,
                 y1: 600.75,
             }),
+            end_page_number: None,
+            spans: vec![],
         };
 
         // Serialize and deserialize
@@ -159,4 +256,46 @@ mod tests {
         assert_eq!(deserialized.page_number, original.page_number);
         assert_eq!(deserialized.bounding_box, original.bounding_box);
     }
+
+    #[test]
+    fn test_table_spans_render_as_html() {
+        let table = Table {
+            cells: vec![
+                vec!["Region".to_string(), "2024".to_string(), String::new()],
+                vec![String::new(), "H1".to_string(), "H2".to_string()],
+                vec!["North <EU>".to_string(), "1".to_string(), "2".to_string()],
+            ],
+            markdown: String::new(),
+            page_number: 1,
+            bounding_box: None,
+            end_page_number: None,
+            spans: vec![
+                CellSpan {
+                    row: 0,
+                    col: 0,
+                    row_span: 2,
+                    col_span: 1,
+                },
+                CellSpan {
+                    row: 0,
+                    col: 1,
+                    row_span: 1,
+                    col_span: 2,
+                },
+            ],
+        };
+
+        assert_eq!(table.covered_positions(), HashSet::from([(0, 2), (1, 0)]));
+        assert_eq!(
+            table.to_html(),
+            "<table><tr><th rowspan=\"2\">Region</th><th colspan=\"2\">2024</th></tr>\
+             <tr><td>H1</td><td>H2</td></tr>\
+             <tr><td>North &lt;EU&gt;</td><td>1</td><td>2</td></tr></table>"
+        );
+
+        let json = serde_json::to_value(&table).unwrap();
+        assert_eq!(json["spans"][1]["col_span"], 2);
+        let parsed: Table = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.spans, table.spans);
+    }
 }
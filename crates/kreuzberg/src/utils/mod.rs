@@ -4,6 +4,7 @@
 //! - Quality processing: clean OCR artifacts, calculate quality scores
 //! - String utilities: safe decoding, mojibake fixing, encoding detection
 //! - Object pooling: reusable pools for batch processing to reduce allocations
+//! - Clustering: grouping coordinates by proximity (table detection)
 
 #[cfg(feature = "quality")]
 pub mod quality;
@@ -11,6 +12,7 @@ pub mod quality;
 #[cfg(feature = "quality")]
 pub mod string_utils;
 
+pub mod clustering;
 pub mod pool;
 pub mod pool_sizing;
 pub mod string_pool;
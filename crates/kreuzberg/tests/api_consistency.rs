@@ -253,6 +253,7 @@ fn test_extraction_config_no_unknown_fields_in_default() {
         "output_format",
         "include_document_structure",
         "security_limits",
+        "cache",
     ];
 
     for key in obj.keys() {
@@ -11,7 +11,11 @@ use tower::ServiceExt;
 
 use kreuzberg::{
     ExtractionConfig,
-    api::{HealthResponse, InfoResponse, create_router},
+    api::{ApiSizeLimits, HealthResponse, InfoResponse, create_router, create_router_with_limits_and_server_config},
+    core::{
+        ServerConfig,
+        config::{CacheBackendType, CacheConfig},
+    },
 };
 
 /// Test the health check endpoint.
@@ -319,6 +323,137 @@ async fn test_extract_multiple_files() {
     }
 }
 
+/// Test streaming extract endpoint returns pages as NDJSON.
+#[tokio::test]
+async fn test_extract_stream_text_file() {
+    let app = create_router(ExtractionConfig::default());
+
+    let boundary = "----boundary";
+    let body_content = format!(
+        "--{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"test.txt\"\r\n\
+         Content-Type: text/plain\r\n\
+         \r\n\
+         Streamed content\r\n\
+         --{}--\r\n",
+        boundary, boundary
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/extract/stream")
+                .header("content-type", format!("multipart/form-data; boundary={}", boundary))
+                .body(Body::from(body_content))
+                .expect("Operation failed"),
+        )
+        .await
+        .expect("Operation failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").expect("Missing content type"),
+        "application/x-ndjson"
+    );
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    let body = String::from_utf8(body.to_vec()).expect("Response is not UTF-8");
+    let pages: Vec<serde_json::Value> = body
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Failed to deserialize NDJSON line"))
+        .collect();
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0]["page_number"], 1);
+    assert!(
+        pages[0]["content"]
+            .as_str()
+            .expect("Failed to extract string from JSON value")
+            .contains("Streamed content")
+    );
+}
+
+/// Test streaming extract endpoint rejects more than one file.
+#[tokio::test]
+async fn test_extract_stream_multiple_files() {
+    let app = create_router(ExtractionConfig::default());
+
+    let boundary = "----boundary";
+    let body_content = format!(
+        "--{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"a.txt\"\r\n\
+         Content-Type: text/plain\r\n\
+         \r\n\
+         First\r\n\
+         --{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"b.txt\"\r\n\
+         Content-Type: text/plain\r\n\
+         \r\n\
+         Second\r\n\
+         --{}--\r\n",
+        boundary, boundary, boundary
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/extract/stream")
+                .header("content-type", format!("multipart/form-data; boundary={}", boundary))
+                .body(Body::from(body_content))
+                .expect("Operation failed"),
+        )
+        .await
+        .expect("Operation failed");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Test streaming extract endpoint returns an error response for an unreadable PDF.
+#[cfg(feature = "pdf")]
+#[tokio::test]
+async fn test_extract_stream_invalid_pdf() {
+    let app = create_router(ExtractionConfig::default());
+
+    let boundary = "----boundary";
+    let body_content = format!(
+        "--{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"broken.pdf\"\r\n\
+         Content-Type: application/pdf\r\n\
+         \r\n\
+         This is not a PDF\r\n\
+         --{}--\r\n",
+        boundary, boundary
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/extract/stream")
+                .header("content-type", format!("multipart/form-data; boundary={}", boundary))
+                .body(Body::from(body_content))
+                .expect("Operation failed"),
+        )
+        .await
+        .expect("Operation failed");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        response.headers().get("content-type").expect("Missing content type"),
+        "application/json"
+    );
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    let error: serde_json::Value = serde_json::from_slice(&body).expect("Failed to deserialize error response");
+    assert_eq!(error["error_type"], "ParsingError");
+}
+
 /// Test extract endpoint with markdown content.
 #[tokio::test]
 async fn test_extract_markdown_file() {
@@ -944,6 +1079,44 @@ async fn test_cache_clear_endpoint() {
     assert!(clear_result["freed_mb"].is_number());
 }
 
+/// Test that cache endpoints use the backend configured in ServerConfig.
+#[tokio::test]
+async fn test_cache_stats_endpoint_uses_server_cache_backend() {
+    let server_config = ServerConfig {
+        cache: Some(CacheConfig {
+            backend: CacheBackendType::Memory,
+            max_size_mb: 5.0,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let app = create_router_with_limits_and_server_config(
+        ExtractionConfig::default(),
+        ApiSizeLimits::default(),
+        server_config,
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/cache/stats")
+                .body(Body::empty())
+                .expect("Failed to create HTTP request body"),
+        )
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    let stats: serde_json::Value = serde_json::from_slice(&body).expect("Failed to deserialize JSON response");
+
+    assert_eq!(stats["backend"], "memory");
+    assert_eq!(stats["directory"], ":memory:");
+}
+
 /// Test extract endpoint with mixed content types.
 #[tokio::test]
 async fn test_extract_mixed_content_types() {
@@ -640,6 +640,7 @@ fn test_ocr_with_preprocessing_extreme_dpi() {
                     deskew: true,
                     denoise: false,
                     contrast_enhance: false,
+                    binarize: true,
                     binarization_method: "otsu".to_string(),
                     invert_colors: false,
                 }),
@@ -686,6 +687,7 @@ fn test_ocr_with_invalid_binarization_method() {
                     deskew: true,
                     denoise: false,
                     contrast_enhance: false,
+                    binarize: true,
                     binarization_method: "invalid_method_xyz".to_string(),
                     invert_colors: false,
                 }),
@@ -9,7 +9,7 @@
 mod helpers;
 
 use helpers::*;
-use kreuzberg::core::config::{ExtractionConfig, PageConfig};
+use kreuzberg::core::config::{ExtractionConfig, PageConfig, PageSelection};
 use kreuzberg::extract_file_sync;
 
 /// Test that page markers are inserted when enabled.
@@ -135,6 +135,7 @@ fn test_markers_and_extract_pages_together() {
             insert_page_markers: true,
             extract_pages: true,
             marker_format: "--- PAGE {page_num} ---".to_string(),
+            ..Default::default()
         }),
         ..Default::default()
     };
@@ -245,6 +246,41 @@ fn test_multi_page_markers() {
     }
 }
 
+/// Test that a page selection keeps the original page numbers in markers and pages.
+#[test]
+fn test_page_selection_keeps_original_page_numbers() {
+    if skip_if_missing("pdfs/multi_page.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdfs/multi_page.pdf");
+    let config = ExtractionConfig {
+        pages: Some(PageConfig {
+            insert_page_markers: true,
+            extract_pages: true,
+            selection: Some(PageSelection::Pages(vec![2])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = extract_file_sync(&file_path, None, &config).expect("Failed to extract PDF page selection");
+
+    let pages = result.pages.expect("Pages should be extracted");
+    assert_eq!(pages.len(), 1, "Only the selected page should be extracted");
+    assert_eq!(pages[0].page_number, 2);
+    assert!(result.content.contains("<!-- PAGE 2 -->"));
+    assert!(!result.content.contains("<!-- PAGE 1 -->"));
+
+    let boundaries = result.metadata.pages.and_then(|p| p.boundaries).expect("Boundaries");
+    let boundary = &boundaries[0];
+    assert_eq!(boundary.page_number, 2);
+    assert_eq!(
+        result.content[boundary.byte_start..boundary.byte_end].trim(),
+        pages[0].content.trim()
+    );
+}
+
 /// Test default marker format value.
 #[test]
 fn test_default_marker_format() {
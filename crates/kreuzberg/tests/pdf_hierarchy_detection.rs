@@ -38,11 +38,22 @@ async fn test_full_hierarchy_extraction() {
             extract_pages: true,
             insert_page_markers: false,
             marker_format: "\n\n<!-- PAGE {page_num} -->\n\n".to_string(),
+            selection: None,
         }),
         pdf_options: Some(PdfConfig {
             extract_images: false,
             passwords: None,
             extract_metadata: true,
+            extract_annotations: false,
+            extract_form_fields: false,
+            render_form_fields: false,
+            extract_embedded_files: false,
+            strip_page_furniture: false,
+            ocr_image_regions: false,
+            extract_figures: false,
+            detect_formulas: false,
+            text_layer_threshold: 0.5,
+            text_elements: None,
             hierarchy: Some(HierarchyConfig {
                 enabled: true,
                 k_clusters: 6,
@@ -151,11 +162,22 @@ async fn test_hierarchy_disabled() {
             extract_pages: true,
             insert_page_markers: false,
             marker_format: "\n\n<!-- PAGE {page_num} -->\n\n".to_string(),
+            selection: None,
         }),
         pdf_options: Some(PdfConfig {
             extract_images: false,
             passwords: None,
             extract_metadata: true,
+            extract_annotations: false,
+            extract_form_fields: false,
+            render_form_fields: false,
+            extract_embedded_files: false,
+            strip_page_furniture: false,
+            ocr_image_regions: false,
+            extract_figures: false,
+            detect_formulas: false,
+            text_layer_threshold: 0.5,
+            text_elements: None,
             hierarchy: Some(HierarchyConfig {
                 enabled: false,
                 k_clusters: 6,
@@ -207,11 +229,22 @@ async fn test_hierarchy_with_explicit_disabled() {
             extract_pages: true,
             insert_page_markers: false,
             marker_format: "\n\n<!-- PAGE {page_num} -->\n\n".to_string(),
+            selection: None,
         }),
         pdf_options: Some(PdfConfig {
             extract_images: false,
             passwords: None,
             extract_metadata: true,
+            extract_annotations: false,
+            extract_form_fields: false,
+            render_form_fields: false,
+            extract_embedded_files: false,
+            strip_page_furniture: false,
+            ocr_image_regions: false,
+            extract_figures: false,
+            detect_formulas: false,
+            text_layer_threshold: 0.5,
+            text_elements: None,
             hierarchy: Some(HierarchyConfig {
                 enabled: false,
                 k_clusters: 6,
@@ -264,11 +297,22 @@ async fn test_hierarchy_different_k_clusters() {
                 extract_pages: true,
                 insert_page_markers: false,
                 marker_format: "\n\n<!-- PAGE {page_num} -->\n\n".to_string(),
+                selection: None,
             }),
             pdf_options: Some(PdfConfig {
                 extract_images: false,
                 passwords: None,
                 extract_metadata: true,
+                extract_annotations: false,
+                extract_form_fields: false,
+                render_form_fields: false,
+                extract_embedded_files: false,
+                strip_page_furniture: false,
+                ocr_image_regions: false,
+                extract_figures: false,
+                detect_formulas: false,
+                text_layer_threshold: 0.5,
+                text_elements: None,
                 hierarchy: Some(HierarchyConfig {
                     enabled: true,
                     k_clusters: *k,
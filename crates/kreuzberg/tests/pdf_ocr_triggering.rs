@@ -282,6 +282,16 @@ fn test_ocr_custom_threshold() {
             extract_images: false,
             passwords: None,
             extract_metadata: true,
+            extract_annotations: false,
+            extract_form_fields: false,
+            render_form_fields: false,
+            extract_embedded_files: false,
+            strip_page_furniture: false,
+            ocr_image_regions: false,
+            extract_figures: false,
+            detect_formulas: false,
+            text_layer_threshold: 0.5,
+            text_elements: None,
             hierarchy: Some(HierarchyConfig {
                 enabled: true,
                 k_clusters: 6,
@@ -192,6 +192,8 @@ if not TYPE_CHECKING:
         MARKDOWN = "markdown"
         DJOT = "djot"
         HTML = "html"
+        HOCR = "hocr"
+        ALTO = "alto"
 
     class ResultFormat(str, Enum):
         """Result format controlling extraction output structure."""
@@ -8,6 +8,8 @@ class OutputFormat(StrEnum):
     MARKDOWN = "markdown"
     DJOT = "djot"
     HTML = "html"
+    HOCR = "hocr"
+    ALTO = "alto"
 
 class ResultFormat(StrEnum):
     UNIFIED = "unified"
@@ -92,6 +94,7 @@ __all__ = [
     "TableGrid",
     "TesseractConfig",
     "TextAnnotation",
+    "TextLayerHealth",
     "TokenReductionConfig",
     "ValidationError",
     "ValidatorProtocol",
@@ -371,7 +374,7 @@ class ExtractionConfig:
 
         output_format (str): Output content format.
             Controls the format of the extracted content.
-            Values: "plain" (default), "markdown", "djot", "html". Default: "plain"
+            Values: "plain" (default), "markdown", "djot", "html", "hocr", "alto". Default: "plain"
 
     Example:
         Basic extraction with defaults:
@@ -754,6 +757,45 @@ class PdfConfig:
             for detecting document structure and organization. None = no hierarchy detection.
             Default: None
 
+        extract_annotations (bool): Extract page annotations (comments, highlights,
+            sticky notes, links). Default: False
+
+        extract_form_fields (bool): Extract interactive form fields (AcroForm and XFA)
+            with their values into the PDF metadata. Default: False
+
+        render_form_fields (bool): Append the filled-in form fields to the content as
+            a "Field: Value" section (requires extract_form_fields). Default: False
+
+        extract_embedded_files (bool): Extract the files embedded in the PDF
+            (attachments, portfolio members) through the matching extractors,
+            subject to the security limits. Default: False
+
+        strip_page_furniture (bool): Detect running headers and footers (page numbers,
+            running titles) and move them out of the content into the PDF metadata
+            and header/footer nodes of the document structure. Default: False
+
+        ocr_image_regions (bool): When OCR is configured, OCR the image regions of
+            native pages that have no text layer (stamps, signatures, screenshots)
+            and merge their text at its reading position. Default: False
+
+        extract_figures (bool): Detect vector figures (charts, diagrams drawn with
+            path operators), render each one cropped to its bounding box and add it to
+            ``images`` with its caption as ``description``. Default: False
+
+        detect_formulas (bool): Detect display equations in untagged PDFs from math
+            fonts, symbol density and centered lines, and emit them as ``$$`` blocks
+            in Markdown and ``formula`` nodes with a linearized text. Default: False
+
+        text_layer_threshold (float): Minimum text-layer health score (0.0-1.0) of a
+            page. When OCR is configured, pages whose native text scores lower (fonts
+            without ToUnicode maps, mojibake) are re-extracted with OCR. 0.0 disables
+            the check. Default: 0.5
+
+        text_elements (OcrElementConfig | None): Export the words and lines of the
+            native text layer as ``ocr_elements``, with geometry in points, font
+            name, size and bold/italic flags. ``min_level`` "word" includes words
+            and lines, "line" only lines. Default: None
+
     Example:
         Basic PDF configuration:
             >>> from kreuzberg import ExtractionConfig, PdfConfig
@@ -773,6 +815,16 @@ class PdfConfig:
     passwords: list[str] | None
     extract_metadata: bool
     hierarchy: HierarchyConfig | None
+    extract_annotations: bool
+    extract_form_fields: bool
+    render_form_fields: bool
+    extract_embedded_files: bool
+    strip_page_furniture: bool
+    ocr_image_regions: bool
+    extract_figures: bool
+    detect_formulas: bool
+    text_layer_threshold: float
+    text_elements: OcrElementConfig | None
 
     def __init__(
         self,
@@ -781,6 +833,16 @@ class PdfConfig:
         passwords: list[str] | None = None,
         extract_metadata: bool | None = None,
         hierarchy: HierarchyConfig | None = None,
+        extract_annotations: bool | None = None,
+        extract_form_fields: bool | None = None,
+        render_form_fields: bool | None = None,
+        extract_embedded_files: bool | None = None,
+        strip_page_furniture: bool | None = None,
+        ocr_image_regions: bool | None = None,
+        extract_figures: bool | None = None,
+        detect_formulas: bool | None = None,
+        text_layer_threshold: float | None = None,
+        text_elements: OcrElementConfig | None = None,
     ) -> None: ...
 
 class HierarchyConfig:
@@ -836,6 +898,9 @@ class PageConfig:
         extract_pages (bool): Enable page tracking and per-page extraction. Default: False
         insert_page_markers (bool): Insert page markers into `content`. Default: False
         marker_format (str): Marker template containing `{page_num}`. Default: "\\n\\n<!-- PAGE {page_num} -->\\n\\n"
+        selection (dict | None): Extract only these PDF pages: `{"first": 10}`,
+            `{"ranges": [[40, 55]]}` or `{"pages": [1, 3, 5]}`. Page numbers in the
+            result stay those of the original document. Default: None (all pages)
 
     Example:
         >>> from kreuzberg import ExtractionConfig, PageConfig
@@ -852,6 +917,7 @@ class PageConfig:
         extract_pages: bool | None = None,
         insert_page_markers: bool | None = None,
         marker_format: str | None = None,
+        selection: dict[str, Any] | None = None,
     ) -> None: ...
 
 class KeywordAlgorithm:
@@ -1068,10 +1134,10 @@ class ImagePreprocessingConfig:
             Default: 300
 
         auto_rotate (bool): Automatically detect and correct image rotation.
-            Default: True
+            Default: False
 
         deskew (bool): Correct skewed images to improve OCR accuracy.
-            Default: True
+            Default: False
 
         denoise (bool): Apply denoising filters to reduce noise in images.
             Improves OCR accuracy on low-quality scans. Default: False
@@ -1079,6 +1145,9 @@ class ImagePreprocessingConfig:
         contrast_enhance (bool): Enhance contrast to improve text readability.
             Default: False
 
+        binarize (bool): Convert the image to black and white before OCR.
+            Default: True
+
         binarization_method (str): Method for converting images to black and white.
             Options depend on the OCR backend. Default: "otsu"
 
@@ -1103,6 +1172,7 @@ class ImagePreprocessingConfig:
     deskew: bool
     denoise: bool
     contrast_enhance: bool
+    binarize: bool
     binarization_method: str
     invert_colors: bool
 
@@ -1116,6 +1186,7 @@ class ImagePreprocessingConfig:
         contrast_enhance: bool | None = None,
         binarization_method: str | None = None,
         invert_colors: bool | None = None,
+        binarize: bool | None = None,
     ) -> None: ...
 
 class TesseractConfig:
@@ -1272,6 +1343,81 @@ class TesseractConfig:
         thresholding_method: bool | None = None,
     ) -> None: ...
 
+PdfAnnotationType: TypeAlias = Literal[
+    "text",
+    "free_text",
+    "highlight",
+    "underline",
+    "squiggly",
+    "strikeout",
+    "link",
+    "stamp",
+    "ink",
+    "square",
+    "circle",
+    "line",
+    "polygon",
+    "polyline",
+    "caret",
+    "file_attachment",
+    "redact",
+    "other",
+]
+
+class PdfAnnotation(TypedDict, total=False):
+    page_number: int
+    annotation_type: PdfAnnotationType
+    author: str
+    created_at: str
+    modified_at: str
+    contents: str
+    highlighted_text: str
+    url: str
+    target_page: int
+    bounding_box: BoundingBox
+
+class PdfAnnotationSummary(TypedDict, total=False):
+    total_count: int
+    counts_by_type: dict[str, int]
+    authors: list[str]
+    pages: list[int]
+
+FormFieldType = Literal["text", "checkbox", "radio_button", "combo_box", "list_box", "signature", "unknown"]
+
+class FormField(TypedDict, total=False):
+    name: str
+    field_type: FormFieldType
+    value: str
+    is_checked: bool
+    is_read_only: bool
+    page_number: int
+    bounding_box: BoundingBox
+    source: Literal["acro_form", "xfa"]
+
+class EmbeddedFile(TypedDict, total=False):
+    filename: str
+    mime_type: str
+    size: int
+    description: str
+    result: dict[str, Any]
+    error: str
+
+class PdfOutlineEntry(TypedDict, total=False):
+    title: str
+    level: int
+    page: int
+
+class PageFurniture(TypedDict, total=False):
+    page_number: int
+    layer: Literal["header", "footer"]
+    text: str
+    bounding_box: BoundingBox
+
+class OcrRegion(TypedDict, total=False):
+    page_number: int
+    bounding_box: BoundingBox
+    text: str
+
 class PdfMetadata(TypedDict, total=False):
     pdf_version: str
     producer: str
@@ -1279,6 +1425,12 @@ class PdfMetadata(TypedDict, total=False):
     width: int
     height: int
     page_count: int
+    annotations: PdfAnnotationSummary
+    outline: list[PdfOutlineEntry]
+    form_fields: list[FormField]
+    embedded_files: list[EmbeddedFile]
+    page_furniture: list[PageFurniture]
+    ocr_regions: list[OcrRegion]
 
 class ExcelMetadata(TypedDict, total=False):
     sheet_count: int
@@ -1388,6 +1540,11 @@ class ImagePreprocessingMetadata(TypedDict, total=False):
     calculated_dpi: int
     skipped_resize: bool
     resize_error: str
+    operations: list[str]
+    rotation_degrees: int
+    deskew_angle: float
+    binarization_method: str
+    binarization_threshold: int
 
 class ErrorMetadata(TypedDict, total=False):
     error_type: str
@@ -1398,6 +1555,14 @@ class PageBoundary(TypedDict):
     byte_end: int
     page_number: int
 
+class TextLayerHealth(TypedDict, total=False):
+    score: float
+    private_use_ratio: float
+    replacement_ratio: float
+    dictionary_hit_rate: float | None
+    quality_score: float | None
+    ocr_fallback: bool
+
 class PageInfo(TypedDict, total=False):
     number: int
     title: str | None
@@ -1406,6 +1571,11 @@ class PageInfo(TypedDict, total=False):
     table_count: int | None
     hidden: bool | None
     is_blank: bool | None
+    rotation: OcrRotation | None
+    text_layer: TextLayerHealth | None
+    ocr_backend: str | None
+    ocr_language: str | None
+    ocr_confidence: float | None
 
 class PageStructure(TypedDict, total=False):
     total_count: int
@@ -1436,6 +1606,12 @@ class Metadata(TypedDict, total=False):
     width: int | None
     height: int | None
     page_count: int | None
+    annotations: PdfAnnotationSummary | None
+    outline: list[PdfOutlineEntry] | None
+    form_fields: list[FormField] | None
+    embedded_files: list[EmbeddedFile] | None
+    page_furniture: list[PageFurniture] | None
+    ocr_regions: list[OcrRegion] | None
 
     # Excel-specific (flattened from ExcelMetadata)
     sheet_count: int
@@ -1648,6 +1824,7 @@ NodeContentType: TypeAlias = Literal[
     "footnote",
     "group",
     "page_break",
+    "annotation",
 ]
 
 ContentLayer: TypeAlias = Literal["body", "header", "footer", "footnote"]
@@ -1710,6 +1887,7 @@ class NodeContent(TypedDict, total=False):
         footnote: node_type, text
         group: node_type, label, heading_level, heading_text
         page_break: node_type
+        annotation: node_type, annotation_type, text, author, highlighted_text, url
     """
 
     node_type: NodeContentType
@@ -1723,6 +1901,10 @@ class NodeContent(TypedDict, total=False):
     label: str | None
     heading_level: int | None
     heading_text: str | None
+    annotation_type: PdfAnnotationType
+    author: str | None
+    highlighted_text: str | None
+    url: str | None
 
 class DocumentNode(TypedDict, total=False):
     """A node in the hierarchical document structure.
@@ -1805,12 +1987,21 @@ class PageContent(TypedDict):
     tables: list[ExtractedTable]
     images: list[ExtractedImage]
     is_blank: bool | None
+    annotations: list[PdfAnnotation]
 
 class ExtractedTable:
     cells: list[list[str]]
     markdown: str
     page_number: int
     bounding_box: BoundingBox | None
+    end_page_number: int | None
+    spans: list[CellSpan]
+
+class CellSpan(TypedDict):
+    row: int
+    col: int
+    row_span: int
+    col_span: int
 
 @overload
 def extract_file_sync(
@@ -9,10 +9,11 @@ def test_image_preprocessing_config_default_construction() -> None:
     """ImagePreprocessingConfig should have sensible defaults."""
     config = ImagePreprocessingConfig()
     assert config.target_dpi == 300
-    assert config.auto_rotate is True
-    assert config.deskew is True
+    assert config.auto_rotate is False
+    assert config.deskew is False
     assert config.denoise is False
     assert config.contrast_enhance is False
+    assert config.binarize is True
     assert config.binarization_method == "otsu"
     assert config.invert_colors is False
     # Verify all expected attributes exist
@@ -47,6 +47,11 @@ pub fn parse_ocr_config(ruby: &Ruby, hash: RHash) -> Result<OcrConfig, Error> {
         element_config: None,
         tesseract_config: None,
         output_format: None,
+        preprocessing: None,
+        auto_rotate: false,
+        fallback: None,
+        table_detection: None,
+        cache: None,
     };
 
     if let Some(val) = get_kw(ruby, hash, "tesseract_config")
@@ -229,6 +234,72 @@ pub fn parse_pdf_config(ruby: &Ruby, hash: RHash) -> Result<PdfConfig, Error> {
         true
     };
 
+    let extract_annotations = if let Some(val) = get_kw(ruby, hash, "extract_annotations") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let extract_form_fields = if let Some(val) = get_kw(ruby, hash, "extract_form_fields") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let render_form_fields = if let Some(val) = get_kw(ruby, hash, "render_form_fields") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let extract_embedded_files = if let Some(val) = get_kw(ruby, hash, "extract_embedded_files") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let strip_page_furniture = if let Some(val) = get_kw(ruby, hash, "strip_page_furniture") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let ocr_image_regions = if let Some(val) = get_kw(ruby, hash, "ocr_image_regions") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let extract_figures = if let Some(val) = get_kw(ruby, hash, "extract_figures") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let detect_formulas = if let Some(val) = get_kw(ruby, hash, "detect_formulas") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let text_layer_threshold = if let Some(val) = get_kw(ruby, hash, "text_layer_threshold") {
+        f64::try_convert(val)?
+    } else {
+        0.5
+    };
+
+    let text_elements = if let Some(val) = get_kw(ruby, hash, "text_elements")
+        && !val.is_nil()
+    {
+        let ec_json = ruby_value_to_json(val)?;
+        Some(
+            serde_json::from_value::<kreuzberg::types::OcrElementConfig>(ec_json)
+                .map_err(|e| runtime_error(format!("Invalid text_elements: {}", e)))?,
+        )
+    } else {
+        None
+    };
+
     let hierarchy = if let Some(val) = get_kw(ruby, hash, "hierarchy") {
         if !val.is_nil() {
             let h_hash = RHash::try_convert(val)?;
@@ -244,6 +315,16 @@ pub fn parse_pdf_config(ruby: &Ruby, hash: RHash) -> Result<PdfConfig, Error> {
         extract_images,
         passwords,
         extract_metadata,
+        extract_annotations,
+        extract_form_fields,
+        render_form_fields,
+        extract_embedded_files,
+        strip_page_furniture,
+        ocr_image_regions,
+        extract_figures,
+        detect_formulas,
+        text_layer_threshold,
+        text_elements,
         hierarchy,
     };
 
@@ -315,13 +396,13 @@ pub fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<Imag
     let auto_rotate = if let Some(val) = get_kw(ruby, hash, "auto_rotate") {
         bool::try_convert(val)?
     } else {
-        true
+        false
     };
 
     let deskew = if let Some(val) = get_kw(ruby, hash, "deskew") {
         bool::try_convert(val)?
     } else {
-        true
+        false
     };
 
     let denoise = if let Some(val) = get_kw(ruby, hash, "denoise") {
@@ -336,6 +417,12 @@ pub fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<Imag
         false
     };
 
+    let binarize = if let Some(val) = get_kw(ruby, hash, "binarize") {
+        bool::try_convert(val)?
+    } else {
+        true
+    };
+
     let binarization_method = if let Some(val) = get_kw(ruby, hash, "binarization_method") {
         symbol_to_string(val)?
     } else {
@@ -354,6 +441,7 @@ pub fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<Imag
         deskew,
         denoise,
         contrast_enhance,
+        binarize,
         binarization_method,
         invert_colors,
     };
@@ -874,10 +962,23 @@ pub fn parse_page_config(ruby: &Ruby, hash: RHash) -> Result<PageConfig, Error>
         "\n\n<!-- PAGE {page_num} -->\n\n".to_string()
     };
 
+    let selection = if let Some(val) = get_kw(ruby, hash, "selection")
+        && !val.is_nil()
+    {
+        let selection_json = ruby_value_to_json(val)?;
+        Some(
+            serde_json::from_value(selection_json)
+                .map_err(|e| runtime_error(format!("Invalid page selection: {}", e)))?,
+        )
+    } else {
+        None
+    };
+
     let config = PageConfig {
         extract_pages,
         insert_page_markers,
         marker_format,
+        selection,
     };
 
     Ok(config)
@@ -63,6 +63,17 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
         } else {
             table_hash.aset("bounding_box", ruby.qnil().as_value())?;
         }
+        table_hash.aset("end_page_number", table.end_page_number)?;
+        let spans_array = ruby.ary_new();
+        for span in table.spans {
+            let span_hash = ruby.hash_new();
+            span_hash.aset("row", span.row)?;
+            span_hash.aset("col", span.col)?;
+            span_hash.aset("row_span", span.row_span)?;
+            span_hash.aset("col_span", span.col_span)?;
+            spans_array.push(span_hash)?;
+        }
+        table_hash.aset("spans", spans_array)?;
 
         tables_array.push(table_hash)?;
     }
@@ -292,6 +303,12 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
             }
             page_hash.aset("images", images_array)?;
 
+            if !page_content.annotations.is_empty() {
+                let annotations_json = serde_json::to_value(&page_content.annotations)
+                    .map_err(|e| runtime_error(format!("Failed to serialize page annotations: {}", e)))?;
+                page_hash.aset("annotations", json_value_to_ruby(ruby, &annotations_json)?)?;
+            }
+
             pages_array.push(page_hash)?;
         }
         set_hash_entry(ruby, &hash, "pages", pages_array.into_value_with(ruby))?;
@@ -495,6 +512,17 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                 NodeContent::PageBreak => {
                     content_hash.aset("node_type", "page_break")?;
                 }
+                NodeContent::Annotation { annotation_type, text, author, highlighted_text, url } => {
+                    content_hash.aset("node_type", "annotation")?;
+                    content_hash.aset("annotation_type", annotation_type.as_str())?;
+                    for (key, value) in [("text", text), ("author", author), ("highlighted_text", highlighted_text), ("url", url)] {
+                        if let Some(value) = value {
+                            content_hash.aset(key, value)?;
+                        } else {
+                            content_hash.aset(key, ruby.qnil().as_value())?;
+                        }
+                    }
+                }
             }
             node_hash.aset("content", content_hash)?;
 
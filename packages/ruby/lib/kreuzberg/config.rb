@@ -514,16 +514,17 @@ module Kreuzberg
     #
     class ImagePreprocessing
       attr_reader :target_dpi, :auto_rotate, :deskew, :denoise,
-                  :contrast_enhance, :binarization_method, :invert_colors
+                  :contrast_enhance, :binarize, :binarization_method, :invert_colors
 
       VALID_BINARIZATION_METHODS = %w[otsu sauvola niblack wolf bradley adaptive].freeze
 
       def initialize(
         target_dpi: 300,
-        auto_rotate: true,
-        deskew: true,
+        auto_rotate: false,
+        deskew: false,
         denoise: false,
         contrast_enhance: true,
+        binarize: true,
         binarization_method: 'otsu',
         invert_colors: false
       )
@@ -532,6 +533,7 @@ module Kreuzberg
         @deskew = deskew ? true : false
         @denoise = denoise ? true : false
         @contrast_enhance = contrast_enhance ? true : false
+        @binarize = binarize ? true : false
         @binarization_method = binarization_method.to_s
         @invert_colors = invert_colors ? true : false
 
@@ -550,6 +552,7 @@ module Kreuzberg
           deskew: @deskew,
           denoise: @denoise,
           contrast_enhance: @contrast_enhance,
+          binarize: @binarize,
           binarization_method: @binarization_method,
           invert_colors: @invert_colors
         }
@@ -6,10 +6,11 @@ RSpec.describe Kreuzberg::Config::ImagePreprocessing do
       config = described_class.new
 
       expect(config.target_dpi).to eq 300
-      expect(config.auto_rotate).to be true
-      expect(config.deskew).to be true
+      expect(config.auto_rotate).to be false
+      expect(config.deskew).to be false
       expect(config.denoise).to be false
       expect(config.contrast_enhance).to be true
+      expect(config.binarize).to be true
       expect(config.binarization_method).to eq 'otsu'
       expect(config.invert_colors).to be false
     end
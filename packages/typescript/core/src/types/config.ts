@@ -313,6 +313,12 @@ export interface PageConfig {
 	 * Default: "\n\n<!-- PAGE {page_num} -->\n\n"
 	 */
 	markerFormat?: string;
+
+	/**
+	 * Extract only these PDF pages. Page numbers in the result stay those of the original document.
+	 * Default: all pages
+	 */
+	selection?: { first: number } | { ranges: Array<[number, number]> } | { pages: number[] };
 }
 
 export interface ExtractionConfig {
@@ -338,8 +344,10 @@ export interface ExtractionConfig {
 	 * - "markdown": Markdown formatted output
 	 * - "djot": Djot markup format
 	 * - "html": HTML formatted output
+	 * - "hocr": hOCR (XHTML) with word bounding boxes
+	 * - "alto": ALTO XML with word positions
 	 */
-	outputFormat?: "plain" | "markdown" | "djot" | "html";
+	outputFormat?: "plain" | "markdown" | "djot" | "html" | "hocr" | "alto";
 	/**
 	 * Result structure format (default: Unified).
 	 * Controls whether results are returned in unified format with all